@@ -0,0 +1,206 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Command line parsing
+
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::Hash;
+use anyhow::{anyhow, bail, Context, Result};
+use gateway_client::keypair::Keypair;
+
+pub const DEFAULT_URL: &str = "http://127.0.0.1:8899";
+
+/// What to do with the built instruction
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mode {
+    /// Print the instruction
+    Print,
+    /// Simulate an unsigned transaction with the instruction
+    Simulate,
+    /// Sign and send a transaction with the instruction
+    Send,
+    /// Print a transaction with the instruction, signed by the given keypairs only
+    Export,
+}
+
+pub struct Options {
+    pub url: String,
+    pub mode: Mode,
+    /// The admin role that signs the instruction
+    pub authority: Option<Pubkey>,
+    /// The fee payer, which also pays rent for the accounts the instruction creates
+    pub payer: Option<Pubkey>,
+    pub keypairs: Vec<Keypair>,
+    pub blockhash: Option<Hash>,
+}
+
+impl Options {
+    /// Splits the options out of the arguments, which may appear anywhere, and returns the
+    /// positional arguments that remain
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<(Self, Args)> {
+        let mut options = Self {
+            url: DEFAULT_URL.to_string(),
+            mode: Mode::Print,
+            authority: None,
+            payer: None,
+            keypairs: Vec::new(),
+            blockhash: None,
+        };
+        let mut positionals = VecDeque::new();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| anyhow!("{arg} expects a value"));
+            match arg.as_str() {
+                "--url" | "-u" => options.url = value()?,
+                "--authority" => options.authority = Some(parse_pubkey("--authority", &value()?)?),
+                "--payer" => options.payer = Some(parse_pubkey("--payer", &value()?)?),
+                "--keypair" | "-k" => options.keypairs.push(read_keypair(&value()?)?),
+                "--blockhash" => {
+                    let blockhash = value()?;
+                    let blockhash = Hash::from_str(&blockhash)
+                        .map_err(|error| anyhow!("--blockhash {blockhash}: {error}"))?;
+                    options.blockhash = Some(blockhash);
+                }
+                "--simulate" => options.set_mode(Mode::Simulate)?,
+                "--send" => options.set_mode(Mode::Send)?,
+                "--export" => options.set_mode(Mode::Export)?,
+                _ if arg.starts_with('-') && arg != "-" => bail!("unknown option {arg}"),
+                _ => positionals.push_back(arg),
+            }
+        }
+
+        if options.keypairs.is_empty() && options.authority.is_none() {
+            if let Some(keypair) = default_keypair()? {
+                options.keypairs.push(keypair);
+            }
+        }
+
+        Ok((options, Args(positionals)))
+    }
+
+    fn set_mode(&mut self, mode: Mode) -> Result<()> {
+        if self.mode != Mode::Print {
+            bail!("--simulate, --send and --export are exclusive");
+        }
+        self.mode = mode;
+        Ok(())
+    }
+
+    /// Returns the authority, which defaults to the first keypair
+    pub fn authority(&self) -> Result<Pubkey> {
+        self.authority
+            .or_else(|| self.keypairs.first().map(Keypair::pubkey))
+            .ok_or_else(|| anyhow!("pass --authority or --keypair to choose the signing role"))
+    }
+
+    /// Returns the payer, which defaults to the authority
+    pub fn payer(&self) -> Result<Pubkey> {
+        self.payer.map_or_else(|| self.authority(), Ok)
+    }
+}
+
+/// The positional arguments, consumed in order
+pub struct Args(VecDeque<String>);
+
+impl Args {
+    pub fn next(&mut self, name: &str) -> Result<String> {
+        self.0
+            .pop_front()
+            .ok_or_else(|| anyhow!("missing <{name}>"))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn parse<T: FromStr>(&mut self, name: &str) -> Result<T>
+    where
+        T::Err: std::fmt::Display,
+    {
+        let value = self.next(name)?;
+        value
+            .parse()
+            .map_err(|error| anyhow!("<{name}> {value}: {error}"))
+    }
+
+    pub fn pubkey(&mut self, name: &str) -> Result<Pubkey> {
+        parse_pubkey(name, &self.next(name)?)
+    }
+
+    /// Parses an EVM address, as `0x` followed by 40 hex digits, or the 32-byte left-padded
+    /// form as a base58 public key
+    pub fn evm_address(&mut self, name: &str) -> Result<Pubkey> {
+        let value = self.next(name)?;
+        let Some(hex) = value.strip_prefix("0x") else {
+            return parse_pubkey(name, &value);
+        };
+        if hex.len() != 40 {
+            bail!("<{name}> {value}: expected 20 bytes");
+        }
+
+        let mut address = [0; 32];
+        for (byte, digits) in address[12..].iter_mut().zip(hex.as_bytes().chunks(2)) {
+            *byte = std::str::from_utf8(digits)
+                .ok()
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| anyhow!("<{name}> {value}: invalid hex"))?;
+        }
+        Ok(Pubkey::new_from_array(address))
+    }
+
+    /// Consumes the remaining arguments
+    pub fn rest(&mut self) -> impl Iterator<Item = String> + '_ {
+        self.0.drain(..)
+    }
+
+    /// Fails if any arguments were not consumed
+    pub fn finish(self) -> Result<()> {
+        match self.0.front() {
+            Some(arg) => bail!("unexpected argument {arg}"),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn parse_pubkey(name: &str, value: &str) -> Result<Pubkey> {
+    Pubkey::from_str(value).map_err(|error| anyhow!("<{name}> {value}: {error}"))
+}
+
+/// Reads a keypair file in the Solana CLI format, a JSON array of 64 bytes
+pub fn read_keypair(path: &str) -> Result<Keypair> {
+    let json = std::fs::read_to_string(path).with_context(|| format!("reading {path}"))?;
+    let bytes: Vec<u8> =
+        serde_json::from_str(&json).with_context(|| format!("{path}: not a keypair file"))?;
+    Keypair::from_bytes(&bytes).ok_or_else(|| anyhow!("{path}: not a valid keypair"))
+}
+
+/// Reads the Solana CLI default keypair, if there is one
+fn default_keypair() -> Result<Option<Keypair>> {
+    let Some(home) = std::env::var_os("HOME") else {
+        return Ok(None);
+    };
+    let path = std::path::Path::new(&home).join(".config/solana/id.json");
+    if !path.exists() {
+        return Ok(None);
+    }
+    read_keypair(&path.to_string_lossy()).map(Some)
+}
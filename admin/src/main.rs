@@ -0,0 +1,202 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Builds, simulates and sends the admin instructions of the Gateway programs.
+//!
+//! Every instruction can also be exported as a transaction that is signed by the keypairs at
+//! hand only, for signing offline or by a multisig, and the current GatewayWallet and
+//! GatewayMinter state can be printed.
+
+mod args;
+mod minter;
+mod rpc;
+mod transaction;
+mod wallet;
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anyhow::{bail, Result};
+use base64::{prelude::BASE64_STANDARD, Engine};
+
+use crate::args::{Mode, Options, DEFAULT_URL};
+use crate::rpc::RpcClient;
+use crate::transaction::Transaction;
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("error: {error:#}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let (options, mut args) = Options::parse(std::env::args().skip(1))?;
+    let command = args.next("COMMAND").unwrap_or_default();
+
+    let instruction = match command.as_str() {
+        "wallet" => wallet::build(&instruction_name(&mut args)?, &options, args)?,
+        "minter" => minter::build(&instruction_name(&mut args)?, &options, args)?,
+        "state" => {
+            let rpc = RpcClient::new(&options.url)?;
+            let program = args.next("PROGRAM")?;
+            args.finish()?;
+            return match program.as_str() {
+                "wallet" => wallet::print_state(&rpc),
+                "minter" => minter::print_state(&rpc),
+                _ => bail!("unknown program {program}"),
+            };
+        }
+        "" | "help" => {
+            print_usage();
+            return Ok(());
+        }
+        _ => bail!("unknown command {command}, see gateway-admin help"),
+    };
+
+    match options.mode {
+        Mode::Print => print_instruction(&instruction),
+        Mode::Simulate => simulate(&options, instruction),
+        Mode::Send => send(&options, instruction),
+        Mode::Export => export(&options, instruction),
+    }
+}
+
+fn instruction_name(args: &mut args::Args) -> Result<String> {
+    Ok(args.next("INSTRUCTION")?.replace('-', "_"))
+}
+
+fn print_instruction(instruction: &Instruction) -> Result<()> {
+    println!("Program: {}", instruction.program_id);
+    println!("Accounts:");
+    for (index, account) in instruction.accounts.iter().enumerate() {
+        let signer = if account.is_signer { " signer" } else { "" };
+        let writable = if account.is_writable { " writable" } else { "" };
+        println!("  {index}: {}{signer}{writable}", account.pubkey);
+    }
+    println!(
+        "Data (base64): {}",
+        BASE64_STANDARD.encode(&instruction.data)
+    );
+    Ok(())
+}
+
+fn simulate(options: &Options, instruction: Instruction) -> Result<()> {
+    let rpc = RpcClient::new(&options.url)?;
+    // The blockhash is replaced by the validator, and the signatures are not checked
+    let transaction = Transaction::new(&[instruction], &options.payer()?, &Default::default());
+    let simulation = rpc.simulate_transaction(&transaction.serialize())?;
+
+    for log in &simulation.logs {
+        println!("{log}");
+    }
+    if let Some(units) = simulation.units_consumed {
+        println!("Compute units: {units}");
+    }
+    if let Some(data) = &simulation.return_data {
+        let hex: String = data.iter().map(|byte| format!("{byte:02x}")).collect();
+        println!("Return data: {hex}");
+    }
+    match simulation.err {
+        Some(err) => bail!("simulation failed: {err}"),
+        None => Ok(()),
+    }
+}
+
+fn send(options: &Options, instruction: Instruction) -> Result<()> {
+    let rpc = RpcClient::new(&options.url)?;
+    let blockhash = match options.blockhash {
+        Some(blockhash) => blockhash,
+        None => rpc.get_latest_blockhash()?,
+    };
+    let mut transaction = Transaction::new(&[instruction], &options.payer()?, &blockhash);
+    transaction.sign(&options.keypairs);
+
+    let missing = transaction.missing_signers();
+    if !missing.is_empty() {
+        let missing: Vec<_> = missing.iter().map(ToString::to_string).collect();
+        bail!(
+            "missing keypairs for {}, pass --keypair or use --export",
+            missing.join(", ")
+        );
+    }
+    println!(
+        "Signature: {}",
+        rpc.send_transaction(&transaction.serialize())?
+    );
+    Ok(())
+}
+
+fn export(options: &Options, instruction: Instruction) -> Result<()> {
+    let blockhash = match options.blockhash {
+        Some(blockhash) => blockhash,
+        None => RpcClient::new(&options.url)?.get_latest_blockhash()?,
+    };
+    let mut transaction = Transaction::new(&[instruction], &options.payer()?, &blockhash);
+    transaction.sign(&options.keypairs);
+
+    let missing = transaction.missing_signers();
+    println!("Blockhash: {blockhash}");
+    println!("Signers:");
+    for signer in transaction.signers() {
+        let status = if missing.contains(signer) {
+            "unsigned"
+        } else {
+            "signed"
+        };
+        println!("  {signer} ({status})");
+    }
+    println!(
+        "Message (base64): {}",
+        BASE64_STANDARD.encode(transaction.message.serialize())
+    );
+    println!(
+        "Transaction (base64): {}",
+        BASE64_STANDARD.encode(transaction.serialize())
+    );
+    Ok(())
+}
+
+fn print_usage() {
+    println!(
+        "Usage: gateway-admin [OPTIONS] <wallet|minter> <INSTRUCTION> [ARGS]...
+       gateway-admin [OPTIONS] state <wallet|minter>
+
+Options:
+  -u, --url <URL>          JSON-RPC URL, http only [default: {DEFAULT_URL}]
+  -k, --keypair <PATH>     A keypair file to sign with, may be repeated
+                           [default: ~/.config/solana/id.json]
+      --authority <PUBKEY> The role that signs the instruction [default: the first keypair]
+      --payer <PUBKEY>     Pays fees and rent [default: the authority]
+      --blockhash <HASH>   The blockhash for --send or --export, instead of the latest one
+      --simulate           Simulate the transaction without checking signatures
+      --send               Sign the transaction with the keypairs and send it
+      --export             Print the transaction signed by the keypairs at hand, for signing
+                           offline or by a multisig
+
+Without --simulate, --send or --export the instruction is printed.
+EVM addresses are given as 0x followed by 40 hex digits."
+    );
+    for (program, instructions) in [
+        ("wallet", wallet::INSTRUCTIONS),
+        ("minter", minter::INSTRUCTIONS),
+    ] {
+        println!("\n{program} instructions:");
+        for (name, args) in instructions {
+            println!("  {name} {args}");
+        }
+    }
+}
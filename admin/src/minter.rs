@@ -0,0 +1,171 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GatewayMinter admin instructions
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::AccountDeserialize;
+use anyhow::{anyhow, bail, Result};
+use gateway_client::gateway_minter::{
+    instructions::*,
+    state::{GatewayMinter, ReplayStore, TokenMintMode},
+};
+use gateway_client::{minter, pda};
+
+use crate::args::{Args, Options};
+use crate::rpc::RpcClient;
+
+/// The admin and view instructions and their arguments
+pub const INSTRUCTIONS: &[(&str, &str)] = &[
+    ("initialize", "<LOCAL_DOMAIN>"),
+    ("transfer_ownership", "<NEW_OWNER>"),
+    ("accept_ownership", ""),
+    ("update_pauser", "<NEW_PAUSER>"),
+    ("update_token_controller", "<NEW_TOKEN_CONTROLLER>"),
+    ("add_attester", "<ATTESTER>"),
+    ("remove_attester", "<ATTESTER>"),
+    ("pause", ""),
+    ("unpause", ""),
+    ("add_token", "<MINT>"),
+    (
+        "configure_token_minter",
+        "<MINT> <custody|mint_authority> <MINTING_ALLOWANCE>",
+    ),
+    ("set_mint_rate_limit", "<MINT> <CAPACITY> <REFILL_PER_SLOT>"),
+    ("burn_token_custody", "<MINT> <AMOUNT>"),
+    (
+        "set_replay_store",
+        "<hash_accounts|migrating|nonce_buckets>",
+    ),
+    ("set_transfer_receipts", "<true|false>"),
+    ("migrate_gateway_minter", ""),
+    ("get_config", ""),
+    ("get_token_config", "<MINT>"),
+];
+
+/// Builds an admin instruction, signed by the authority
+pub fn build(name: &str, options: &Options, mut args: Args) -> Result<Instruction> {
+    let authority = options.authority()?;
+    let payer = options.payer()?;
+
+    let instruction = match name {
+        "initialize" => minter::initialize(
+            payer,
+            authority,
+            InitializeParams {
+                local_domain: args.parse("LOCAL_DOMAIN")?,
+            },
+        ),
+        "transfer_ownership" => minter::transfer_ownership(
+            authority,
+            TransferOwnershipParams {
+                new_owner: args.pubkey("NEW_OWNER")?,
+            },
+        ),
+        "accept_ownership" => minter::accept_ownership(authority),
+        "update_pauser" => minter::update_pauser(
+            authority,
+            UpdatePauserParams {
+                new_pauser: args.pubkey("NEW_PAUSER")?,
+            },
+        ),
+        "update_token_controller" => minter::update_token_controller(
+            authority,
+            UpdateTokenControllerParams {
+                new_token_controller: args.pubkey("NEW_TOKEN_CONTROLLER")?,
+            },
+        ),
+        "add_attester" => minter::add_attester(
+            authority,
+            AddAttesterParams {
+                attester: args.evm_address("ATTESTER")?,
+            },
+        ),
+        "remove_attester" => minter::remove_attester(
+            authority,
+            RemoveAttesterParams {
+                attester: args.evm_address("ATTESTER")?,
+            },
+        ),
+        "pause" => minter::pause(authority),
+        "unpause" => minter::unpause(authority),
+        "add_token" => minter::add_token(payer, authority, args.pubkey("MINT")?),
+        "configure_token_minter" => minter::configure_token_minter(
+            payer,
+            authority,
+            args.pubkey("MINT")?,
+            ConfigureTokenMinterParams {
+                mint_mode: match args.next("MINT_MODE")?.as_str() {
+                    "custody" => TokenMintMode::Custody,
+                    "mint_authority" => TokenMintMode::MintAuthority,
+                    other => bail!("<MINT_MODE> {other}: unknown mint mode"),
+                },
+                minting_allowance: args.parse("MINTING_ALLOWANCE")?,
+            },
+        ),
+        "set_mint_rate_limit" => minter::set_mint_rate_limit(
+            payer,
+            authority,
+            args.pubkey("MINT")?,
+            SetMintRateLimitParams {
+                capacity: args.parse("CAPACITY")?,
+                refill_per_slot: args.parse("REFILL_PER_SLOT")?,
+            },
+        ),
+        "burn_token_custody" => {
+            minter::burn_token_custody(authority, args.pubkey("MINT")?, args.parse("AMOUNT")?)
+        }
+        "set_replay_store" => minter::set_replay_store(
+            authority,
+            SetReplayStoreParams {
+                replay_store: match args.next("REPLAY_STORE")?.as_str() {
+                    "hash_accounts" => ReplayStore::HashAccounts,
+                    "migrating" => ReplayStore::Migrating,
+                    "nonce_buckets" => ReplayStore::NonceBuckets,
+                    other => bail!("<REPLAY_STORE> {other}: unknown replay store"),
+                },
+            },
+        ),
+        "set_transfer_receipts" => minter::set_transfer_receipts(
+            authority,
+            SetTransferReceiptsParams {
+                enabled: args.parse("ENABLED")?,
+            },
+        ),
+        "migrate_gateway_minter" => minter::migrate_gateway_minter(payer, authority),
+        "get_config" => minter::get_config(),
+        "get_token_config" => minter::get_token_config(args.pubkey("MINT")?),
+        _ => bail!("unknown minter instruction {name}"),
+    };
+
+    args.finish()?;
+    Ok(instruction)
+}
+
+/// Prints the decoded GatewayMinter state account
+pub fn print_state(rpc: &RpcClient) -> Result<()> {
+    let address = pda::gateway_minter();
+    let data = rpc
+        .get_account_data(&address)?
+        .ok_or_else(|| anyhow!("GatewayMinter {address} does not exist"))?;
+    let state = GatewayMinter::try_deserialize(&mut data.as_slice())?;
+
+    println!("GatewayMinter {address}");
+    println!("{state:#?}");
+    Ok(())
+}
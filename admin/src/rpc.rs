@@ -0,0 +1,209 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! A minimal JSON-RPC client for a Solana validator, over plain HTTP
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::Hash;
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use serde_json::{json, Value};
+
+/// The result of simulating a transaction
+pub struct Simulation {
+    /// The transaction error, if the transaction failed
+    pub err: Option<Value>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+    /// The data set with `set_return_data` by the last instruction, such as a view instruction
+    pub return_data: Option<Vec<u8>>,
+}
+
+pub struct RpcClient {
+    url: String,
+    host: String,
+    port: u16,
+    path: String,
+}
+
+impl RpcClient {
+    /// Only `http://` URLs are supported. Reach a TLS endpoint through a local validator, a
+    /// proxy or an SSH tunnel.
+    pub fn new(url: &str) -> Result<Self> {
+        let Some(rest) = url.strip_prefix("http://") else {
+            bail!("{url}: only http:// URLs are supported");
+        };
+        let (authority, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, "/"),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().with_context(|| format!("{url}: port"))?),
+            None => (authority, 80),
+        };
+
+        Ok(Self {
+            url: url.to_string(),
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+        })
+    }
+
+    /// Returns the data of an account, or `None` if it does not exist
+    pub fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+        if result["value"].is_null() {
+            return Ok(None);
+        }
+
+        let data = result["value"]["data"][0]
+            .as_str()
+            .ok_or_else(|| anyhow!("getAccountInfo: missing account data"))?;
+        Ok(Some(BASE64_STANDARD.decode(data)?))
+    }
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        let blockhash = result["value"]["blockhash"]
+            .as_str()
+            .ok_or_else(|| anyhow!("getLatestBlockhash: missing blockhash"))?;
+        Hash::from_str(blockhash).map_err(|error| anyhow!("getLatestBlockhash: {error}"))
+    }
+
+    /// Simulates a transaction without checking its signatures, so it can be simulated before
+    /// it is signed
+    pub fn simulate_transaction(&self, transaction: &[u8]) -> Result<Simulation> {
+        let result = self.call(
+            "simulateTransaction",
+            json!([
+                BASE64_STANDARD.encode(transaction),
+                {
+                    "encoding": "base64",
+                    "commitment": "confirmed",
+                    "sigVerify": false,
+                    "replaceRecentBlockhash": true,
+                }
+            ]),
+        )?;
+        let value = &result["value"];
+
+        Ok(Simulation {
+            err: Some(value["err"].clone()).filter(|err| !err.is_null()),
+            logs: value["logs"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|log| log.as_str().map(str::to_string))
+                .collect(),
+            units_consumed: value["unitsConsumed"].as_u64(),
+            return_data: value["returnData"]["data"][0]
+                .as_str()
+                .map(|data| BASE64_STANDARD.decode(data))
+                .transpose()
+                .map_err(|error| anyhow!("simulateTransaction: return data: {error}"))?,
+        })
+    }
+
+    /// Sends a signed transaction and returns its signature
+    pub fn send_transaction(&self, transaction: &[u8]) -> Result<String> {
+        let result = self.call(
+            "sendTransaction",
+            json!([
+                BASE64_STANDARD.encode(transaction),
+                { "encoding": "base64", "preflightCommitment": "confirmed" }
+            ]),
+        )?;
+        result
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("sendTransaction: missing signature"))
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let body = body.to_string();
+
+        let mut stream = TcpStream::connect((self.host.as_str(), self.port))
+            .with_context(|| format!("connecting to {}", self.url))?;
+        write!(
+            stream,
+            "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            self.path,
+            self.host,
+            body.len(),
+        )?;
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response)?;
+
+        let response: Value = serde_json::from_slice(&http_body(&response)?)
+            .with_context(|| format!("{method}: invalid response"))?;
+        if let Some(error) = response.get("error") {
+            bail!("{method}: {error}");
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow!("{method}: missing result"))
+    }
+}
+
+/// Returns the body of an HTTP/1.1 response, joining its chunks if it is chunked
+fn http_body(response: &[u8]) -> Result<Vec<u8>> {
+    let header_end = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(|| anyhow!("truncated HTTP response"))?;
+    let headers = String::from_utf8_lossy(&response[..header_end]).to_ascii_lowercase();
+    let mut body = &response[header_end + 4..];
+
+    let status = headers.lines().next().unwrap_or_default();
+    if status.split_whitespace().nth(1) != Some("200") {
+        bail!("HTTP {status}: {}", String::from_utf8_lossy(body));
+    }
+    if !headers.contains("transfer-encoding: chunked") {
+        return Ok(body.to_vec());
+    }
+
+    let mut joined = Vec::new();
+    loop {
+        let line_end = body
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .ok_or_else(|| anyhow!("truncated HTTP chunk"))?;
+        let size = std::str::from_utf8(&body[..line_end])?;
+        let size = usize::from_str_radix(size.split(';').next().unwrap_or_default().trim(), 16)?;
+        if size == 0 {
+            return Ok(joined);
+        }
+
+        let chunk = body
+            .get(line_end + 2..line_end + 2 + size)
+            .ok_or_else(|| anyhow!("truncated HTTP chunk"))?;
+        joined.extend_from_slice(chunk);
+        body = body.get(line_end + 4 + size..).unwrap_or_default();
+    }
+}
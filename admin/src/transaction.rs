@@ -0,0 +1,77 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Legacy transactions in the wire format, signed by any subset of their signers
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::{
+    hash::Hash, instruction::Instruction, message::Message, short_vec::ShortU16,
+};
+use gateway_client::keypair::Keypair;
+
+pub struct Transaction {
+    pub message: Message,
+    /// One signature per required signer, zeroed for the signers that have not signed
+    pub signatures: Vec<[u8; 64]>,
+}
+
+impl Transaction {
+    pub fn new(instructions: &[Instruction], payer: &Pubkey, blockhash: &Hash) -> Self {
+        let message = Message::new_with_blockhash(instructions, Some(payer), blockhash);
+        let signatures = vec![[0; 64]; message.header.num_required_signatures as usize];
+        Self {
+            message,
+            signatures,
+        }
+    }
+
+    /// Returns the signers of the transaction in signature order
+    pub fn signers(&self) -> &[Pubkey] {
+        &self.message.account_keys[..self.signatures.len()]
+    }
+
+    /// Signs the transaction with every keypair that is one of its signers
+    pub fn sign(&mut self, keypairs: &[Keypair]) {
+        let message = self.message.serialize();
+        for (signer, signature) in self.message.account_keys.iter().zip(&mut self.signatures) {
+            if let Some(keypair) = keypairs.iter().find(|keypair| keypair.pubkey() == *signer) {
+                *signature = keypair.sign(&message);
+            }
+        }
+    }
+
+    /// Returns the signers that have not signed the transaction
+    pub fn missing_signers(&self) -> Vec<Pubkey> {
+        self.signers()
+            .iter()
+            .zip(&self.signatures)
+            .filter(|(_, signature)| **signature == [0; 64])
+            .map(|(signer, _)| *signer)
+            .collect()
+    }
+
+    /// Serializes the transaction in the wire format: the short-vec encoded signatures
+    /// followed by the message
+    pub fn serialize(&self) -> Vec<u8> {
+        let length = ShortU16(self.signatures.len() as u16);
+        let mut bytes = bincode::serialize(&length).expect("a short u16 serializes");
+        bytes.extend(self.signatures.iter().flatten());
+        bytes.extend(self.message.serialize());
+        bytes
+    }
+}
@@ -0,0 +1,317 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GatewayWallet admin instructions
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::AccountDeserialize;
+use anyhow::{anyhow, bail, Result};
+use gateway_client::gateway_wallet::{
+    instructions::*,
+    state::{FeeSplit, GatewayWallet, ReplayStore},
+};
+use gateway_client::{pda, wallet};
+
+use crate::args::{parse_pubkey, Args, Options};
+use crate::rpc::RpcClient;
+
+/// The admin and view instructions and their arguments
+pub const INSTRUCTIONS: &[(&str, &str)] = &[
+    ("initialize", "<LOCAL_DOMAIN> <WITHDRAWAL_DELAY>"),
+    ("transfer_ownership", "<NEW_OWNER>"),
+    ("accept_ownership", ""),
+    ("update_pauser", "<NEW_PAUSER>"),
+    ("update_denylister", "<NEW_DENYLISTER>"),
+    ("update_token_controller", "<NEW_TOKEN_CONTROLLER>"),
+    ("update_withdrawal_delay", "<NEW_DELAY>"),
+    ("update_fee_recipient", "<NEW_FEE_RECIPIENT>"),
+    ("update_fee_splits", "[<RECIPIENT>:<BPS>]..."),
+    ("add_token", "<MINT>"),
+    ("add_burn_signer", "<SIGNER>"),
+    ("remove_burn_signer", "<SIGNER>"),
+    ("pause", ""),
+    ("unpause", ""),
+    ("denylist", "<ACCOUNT>"),
+    ("undenylist", "<ACCOUNT>"),
+    ("batch_denylist", "<ACCOUNT>..."),
+    ("batch_undenylist", "<ACCOUNT>..."),
+    ("freeze_deposit", "<MINT> <DEPOSITOR>"),
+    ("unfreeze_deposit", "<MINT> <DEPOSITOR>"),
+    (
+        "set_fee_schedule",
+        "<MINT> <DESTINATION_DOMAIN> <MIN_FEE> <FEE_BPS>",
+    ),
+    (
+        "enable_destination_domain",
+        "<DOMAIN> <DESTINATION_CONTRACT>",
+    ),
+    ("disable_destination_domain", "<DOMAIN>"),
+    ("set_outflow_limit", "<MINT> <WINDOW_SLOTS> <MAX_OUTFLOW>"),
+    ("set_outflow_override", "<MINT> <OVERRIDE_ALLOWANCE>"),
+    (
+        "set_replay_store",
+        "<hash_accounts|migrating|nonce_buckets>",
+    ),
+    ("set_transfer_receipts", "<true|false>"),
+    ("set_destination_domain_enforcement", "<true|false>"),
+    ("initialize_token_totals", "<MINT> <TOTAL_DEPOSITS>"),
+    ("migrate_gateway_wallet", ""),
+    ("reconcile", "<MINT>"),
+    (
+        "sweep_custody_surplus",
+        "<MINT> <DESTINATION_TOKEN_ACCOUNT>",
+    ),
+    ("get_balance", "<MINT> <DEPOSITOR>"),
+    ("get_withdrawable", "<MINT> <DEPOSITOR>"),
+    ("is_authorized_for_balance", "<MINT> <DEPOSITOR> <ADDRESS>"),
+    ("get_config", ""),
+];
+
+/// Builds an admin instruction, signed by the authority
+pub fn build(name: &str, options: &Options, mut args: Args) -> Result<Instruction> {
+    let authority = options.authority()?;
+    let payer = options.payer()?;
+
+    let instruction = match name {
+        "initialize" => wallet::initialize(
+            payer,
+            authority,
+            InitializeParams {
+                local_domain: args.parse("LOCAL_DOMAIN")?,
+                withdrawal_delay: args.parse("WITHDRAWAL_DELAY")?,
+            },
+        ),
+        "transfer_ownership" => wallet::transfer_ownership(
+            authority,
+            TransferOwnershipParams {
+                new_owner: args.pubkey("NEW_OWNER")?,
+            },
+        ),
+        "accept_ownership" => wallet::accept_ownership(authority),
+        "update_pauser" => wallet::update_pauser(
+            authority,
+            UpdatePauserParams {
+                new_pauser: args.pubkey("NEW_PAUSER")?,
+            },
+        ),
+        "update_denylister" => wallet::update_denylister(
+            authority,
+            UpdateDenylisterParams {
+                new_denylister: args.pubkey("NEW_DENYLISTER")?,
+            },
+        ),
+        "update_token_controller" => wallet::update_token_controller(
+            authority,
+            UpdateTokenControllerParams {
+                new_token_controller: args.pubkey("NEW_TOKEN_CONTROLLER")?,
+            },
+        ),
+        "update_withdrawal_delay" => wallet::update_withdrawal_delay(
+            authority,
+            UpdateWithdrawalDelayParams {
+                new_delay: args.parse("NEW_DELAY")?,
+            },
+        ),
+        "update_fee_recipient" => wallet::update_fee_recipient(
+            authority,
+            UpdateFeeRecipientParams {
+                new_fee_recipient: args.pubkey("NEW_FEE_RECIPIENT")?,
+            },
+        ),
+        "update_fee_splits" => wallet::update_fee_splits(
+            authority,
+            UpdateFeeSplitsParams {
+                fee_splits: args
+                    .rest()
+                    .map(|split| parse_fee_split(&split))
+                    .collect::<Result<_>>()?,
+            },
+        ),
+        "add_token" => wallet::add_token(payer, authority, args.pubkey("MINT")?),
+        "add_burn_signer" => wallet::add_burn_signer(
+            authority,
+            AddBurnSignerParams {
+                signer: args.evm_address("SIGNER")?,
+            },
+        ),
+        "remove_burn_signer" => wallet::remove_burn_signer(
+            authority,
+            RemoveBurnSignerParams {
+                signer: args.evm_address("SIGNER")?,
+            },
+        ),
+        "pause" => wallet::pause(authority),
+        "unpause" => wallet::unpause(authority),
+        "denylist" => wallet::denylist(
+            payer,
+            authority,
+            DenylistParams {
+                account: args.pubkey("ACCOUNT")?,
+            },
+        ),
+        "undenylist" => wallet::undenylist(
+            payer,
+            authority,
+            UndenylistParams {
+                account: args.pubkey("ACCOUNT")?,
+            },
+        ),
+        "batch_denylist" => wallet::batch_denylist(
+            payer,
+            authority,
+            BatchDenylistParams {
+                accounts: parse_accounts(&mut args)?,
+            },
+        ),
+        "batch_undenylist" => wallet::batch_undenylist(
+            payer,
+            authority,
+            BatchUndenylistParams {
+                accounts: parse_accounts(&mut args)?,
+            },
+        ),
+        "freeze_deposit" => {
+            wallet::freeze_deposit(authority, args.pubkey("MINT")?, args.pubkey("DEPOSITOR")?)
+        }
+        "unfreeze_deposit" => {
+            wallet::unfreeze_deposit(authority, args.pubkey("MINT")?, args.pubkey("DEPOSITOR")?)
+        }
+        "set_fee_schedule" => wallet::set_fee_schedule(
+            payer,
+            authority,
+            args.pubkey("MINT")?,
+            SetFeeScheduleParams {
+                destination_domain: args.parse("DESTINATION_DOMAIN")?,
+                min_fee: args.parse("MIN_FEE")?,
+                fee_bps: args.parse("FEE_BPS")?,
+            },
+        ),
+        "enable_destination_domain" => wallet::enable_destination_domain(
+            payer,
+            authority,
+            EnableDestinationDomainParams {
+                domain: args.parse("DOMAIN")?,
+                destination_contract: args.evm_address("DESTINATION_CONTRACT")?,
+            },
+        ),
+        "disable_destination_domain" => {
+            wallet::disable_destination_domain(authority, args.parse("DOMAIN")?)
+        }
+        "set_outflow_limit" => wallet::set_outflow_limit(
+            payer,
+            authority,
+            args.pubkey("MINT")?,
+            SetOutflowLimitParams {
+                window_slots: args.parse("WINDOW_SLOTS")?,
+                max_outflow: args.parse("MAX_OUTFLOW")?,
+            },
+        ),
+        "set_outflow_override" => wallet::set_outflow_override(
+            authority,
+            args.pubkey("MINT")?,
+            SetOutflowOverrideParams {
+                override_allowance: args.parse("OVERRIDE_ALLOWANCE")?,
+            },
+        ),
+        "set_replay_store" => wallet::set_replay_store(
+            authority,
+            SetReplayStoreParams {
+                replay_store: match args.next("REPLAY_STORE")?.as_str() {
+                    "hash_accounts" => ReplayStore::HashAccounts,
+                    "migrating" => ReplayStore::Migrating,
+                    "nonce_buckets" => ReplayStore::NonceBuckets,
+                    other => bail!("<REPLAY_STORE> {other}: unknown replay store"),
+                },
+            },
+        ),
+        "set_transfer_receipts" => wallet::set_transfer_receipts(
+            authority,
+            SetTransferReceiptsParams {
+                enabled: args.parse("ENABLED")?,
+            },
+        ),
+        "set_destination_domain_enforcement" => wallet::set_destination_domain_enforcement(
+            authority,
+            SetDestinationDomainEnforcementParams {
+                enforced: args.parse("ENFORCED")?,
+            },
+        ),
+        "initialize_token_totals" => wallet::initialize_token_totals(
+            payer,
+            authority,
+            args.pubkey("MINT")?,
+            InitializeTokenTotalsParams {
+                total_deposits: args.parse("TOTAL_DEPOSITS")?,
+            },
+        ),
+        "migrate_gateway_wallet" => wallet::migrate_gateway_wallet(payer, authority),
+        "reconcile" => wallet::reconcile(args.pubkey("MINT")?),
+        "sweep_custody_surplus" => wallet::sweep_custody_surplus(
+            authority,
+            args.pubkey("MINT")?,
+            args.pubkey("DESTINATION_TOKEN_ACCOUNT")?,
+        ),
+        "get_balance" => wallet::get_balance(args.pubkey("MINT")?, args.pubkey("DEPOSITOR")?),
+        "get_withdrawable" => {
+            wallet::get_withdrawable(args.pubkey("MINT")?, args.pubkey("DEPOSITOR")?)
+        }
+        "is_authorized_for_balance" => wallet::is_authorized_for_balance(
+            args.pubkey("MINT")?,
+            args.pubkey("DEPOSITOR")?,
+            args.pubkey("ADDRESS")?,
+        ),
+        "get_config" => wallet::get_config(),
+        _ => bail!("unknown wallet instruction {name}"),
+    };
+
+    args.finish()?;
+    Ok(instruction)
+}
+
+/// Prints the decoded GatewayWallet state account
+pub fn print_state(rpc: &RpcClient) -> Result<()> {
+    let address = pda::gateway_wallet();
+    let data = rpc
+        .get_account_data(&address)?
+        .ok_or_else(|| anyhow!("GatewayWallet {address} does not exist"))?;
+    let state = GatewayWallet::try_deserialize(&mut data.as_slice())?;
+
+    println!("GatewayWallet {address}");
+    println!("{state:#?}");
+    Ok(())
+}
+
+fn parse_fee_split(split: &str) -> Result<FeeSplit> {
+    let (recipient, bps) = split
+        .split_once(':')
+        .ok_or_else(|| anyhow!("{split}: expected <RECIPIENT>:<BPS>"))?;
+    Ok(FeeSplit {
+        recipient: parse_pubkey("RECIPIENT", recipient)?,
+        bps: bps.parse().map_err(|error| anyhow!("{split}: {error}"))?,
+    })
+}
+
+fn parse_accounts(args: &mut Args) -> Result<Vec<Pubkey>> {
+    if args.is_empty() {
+        bail!("missing <ACCOUNT>");
+    }
+    args.rest()
+        .map(|account| parse_pubkey("ACCOUNT", &account))
+        .collect()
+}
@@ -0,0 +1,408 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Runs the gateway-admin binary offline and against a stub JSON-RPC server
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::thread::{self, JoinHandle};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountSerialize;
+use base64::{prelude::BASE64_STANDARD, Engine};
+use curve25519_dalek::{edwards::CompressedEdwardsY, EdwardsPoint, Scalar};
+use gateway_client::gateway_minter::state::{GatewayMinter, ReplayStore};
+use gateway_client::keypair::Keypair;
+use gateway_client::{pda, wallet};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha512};
+
+const BLOCKHASH: &str = "4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM";
+
+fn gateway_admin(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_gateway-admin"))
+        .args(args)
+        // Keeps the Solana CLI default keypair out of the tests
+        .env("HOME", "/nonexistent")
+        .output()
+        .expect("gateway-admin runs")
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    assert!(!output.status.success());
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+/// Returns the value printed after `label: `
+fn field<'a>(stdout: &'a str, label: &str) -> &'a str {
+    stdout
+        .lines()
+        .find_map(|line| line.strip_prefix(label)?.strip_prefix(": "))
+        .unwrap_or_else(|| panic!("{label} is not printed in {stdout}"))
+}
+
+/// Writes a keypair file in the Solana CLI format
+fn keypair_file(seed: u8) -> (Keypair, PathBuf) {
+    let keypair = Keypair::from_seed([seed; 32]);
+    let mut bytes = vec![seed; 32];
+    bytes.extend_from_slice(keypair.pubkey().as_ref());
+
+    let path =
+        std::env::temp_dir().join(format!("gateway-admin-{}-{seed}.json", std::process::id()));
+    std::fs::write(&path, serde_json::to_string(&bytes).unwrap()).unwrap();
+    (keypair, path)
+}
+
+/// Serves one JSON-RPC result per request, and returns the requests it received
+fn rpc_server(results: Vec<Value>) -> (String, JoinHandle<Vec<Value>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for result in results {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            requests.push(serde_json::from_slice(&body).unwrap());
+
+            // Chunked, as validators behind a proxy often answer
+            let response = json!({ "jsonrpc": "2.0", "id": 1, "result": result }).to_string();
+            let (head, tail) = response.split_at(response.len() / 2);
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                 Transfer-Encoding: chunked\r\n\r\n{:x}\r\n{head}\r\n{:x}\r\n{tail}\r\n0\r\n\r\n",
+                head.len(),
+                tail.len(),
+            )
+            .unwrap();
+        }
+        requests
+    });
+    (url, handle)
+}
+
+fn verify(pubkey: &Pubkey, message: &[u8], signature: &[u8]) -> bool {
+    let r = CompressedEdwardsY(signature[..32].try_into().unwrap());
+    let a = CompressedEdwardsY(pubkey.to_bytes()).decompress().unwrap();
+    let s = Scalar::from_canonical_bytes(signature[32..].try_into().unwrap()).unwrap();
+    let k: [u8; 64] = Sha512::new()
+        .chain_update(r.as_bytes())
+        .chain_update(pubkey)
+        .chain_update(message)
+        .finalize()
+        .into();
+    let k = Scalar::from_bytes_mod_order_wide(&k);
+    EdwardsPoint::vartime_double_scalar_mul_basepoint(&-k, &a, &s).compress() == r
+}
+
+#[test]
+fn usage_lists_every_instruction() {
+    let usage = stdout(&gateway_admin(&["help"]));
+    for name in [
+        "update_fee_splits",
+        "set_replay_store",
+        "burn_token_custody",
+    ] {
+        assert!(usage.contains(&format!("  {name} ")), "{name}");
+    }
+}
+
+#[test]
+fn prints_the_instruction_built_by_the_client() {
+    let owner = Pubkey::new_unique();
+    let output = gateway_admin(&[
+        "--authority",
+        &owner.to_string(),
+        "wallet",
+        "disable-destination-domain",
+        "6",
+    ]);
+    let stdout = stdout(&output);
+
+    let instruction = wallet::disable_destination_domain(owner, 6);
+    assert_eq!(
+        field(&stdout, "Program"),
+        instruction.program_id.to_string()
+    );
+    assert_eq!(
+        field(&stdout, "Data (base64)"),
+        BASE64_STANDARD.encode(&instruction.data)
+    );
+    for (index, account) in instruction.accounts.iter().enumerate() {
+        let line = field(&stdout, &format!("  {index}"));
+        assert!(line.starts_with(&account.pubkey.to_string()));
+        assert_eq!(line.contains("signer"), account.is_signer);
+        assert_eq!(line.contains("writable"), account.is_writable);
+    }
+}
+
+#[test]
+fn parses_evm_addresses() {
+    let output = gateway_admin(&[
+        "--authority",
+        &Pubkey::new_unique().to_string(),
+        "minter",
+        "add_attester",
+        "0x00112233445566778899aabbccddeeff00112233",
+    ]);
+    let data = BASE64_STANDARD
+        .decode(field(&stdout(&output), "Data (base64)"))
+        .unwrap();
+
+    let mut attester = [0; 32];
+    attester[12..].copy_from_slice(&[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff, 0x00, 0x11, 0x22, 0x33,
+    ]);
+    assert!(data.ends_with(&attester));
+}
+
+#[test]
+fn rejects_bad_arguments() {
+    let authority = Pubkey::new_unique().to_string();
+    let cases: [(&[&str], &str); 5] = [
+        (&["wallet", "pause"], "pass --authority or --keypair"),
+        (
+            &["--authority", &authority, "wallet", "mint"],
+            "unknown wallet instruction mint",
+        ),
+        (
+            &["--authority", &authority, "minter", "add_token"],
+            "missing <MINT>",
+        ),
+        (
+            &["--authority", &authority, "wallet", "pause", "now"],
+            "unexpected argument now",
+        ),
+        (
+            &["--url", "https://api.devnet.solana.com", "state", "wallet"],
+            "only http:// URLs are supported",
+        ),
+    ];
+
+    for (args, error) in cases {
+        let stderr = stderr(&gateway_admin(args));
+        assert!(stderr.contains(error), "{args:?}: {stderr}");
+    }
+}
+
+#[test]
+fn exports_a_partially_signed_transaction_offline() {
+    let (payer, path) = keypair_file(1);
+    let owner = Pubkey::new_unique();
+    let output = gateway_admin(&[
+        "--export",
+        "--keypair",
+        path.to_str().unwrap(),
+        "--payer",
+        &payer.pubkey().to_string(),
+        "--authority",
+        &owner.to_string(),
+        "--blockhash",
+        BLOCKHASH,
+        "wallet",
+        "set_fee_schedule",
+        &Pubkey::new_unique().to_string(),
+        "6",
+        "100",
+        "5",
+    ]);
+    let stdout = stdout(&output);
+    std::fs::remove_file(path).unwrap();
+
+    assert_eq!(field(&stdout, "Blockhash"), BLOCKHASH);
+    assert!(stdout.contains(&format!("  {} (signed)", payer.pubkey())));
+    assert!(stdout.contains(&format!("  {owner} (unsigned)")));
+
+    let message = BASE64_STANDARD
+        .decode(field(&stdout, "Message (base64)"))
+        .unwrap();
+    let transaction = BASE64_STANDARD
+        .decode(field(&stdout, "Transaction (base64)"))
+        .unwrap();
+    // The payer's signature and a blank one for the owner, followed by the message
+    assert_eq!(transaction[0], 2);
+    assert!(verify(&payer.pubkey(), &message, &transaction[1..65]));
+    assert_eq!(transaction[65..129], [0; 64]);
+    assert_eq!(transaction[129..], message);
+}
+
+#[test]
+fn simulates_without_signatures() {
+    let (url, server) = rpc_server(vec![json!({
+        "context": { "slot": 1 },
+        "value": {
+            "err": null,
+            "logs": ["Program log: Instruction: Pause"],
+            "unitsConsumed": 4200,
+            "returnData": { "programId": Pubkey::new_unique().to_string(), "data": ["AQI=", "base64"] },
+        },
+    })]);
+    let output = gateway_admin(&[
+        "--url",
+        &url,
+        "--simulate",
+        "--authority",
+        &Pubkey::new_unique().to_string(),
+        "minter",
+        "pause",
+    ]);
+    let stdout = stdout(&output);
+
+    assert!(stdout.contains("Program log: Instruction: Pause"));
+    assert!(stdout.contains("Compute units: 4200"));
+    assert!(stdout.contains("Return data: 0102"));
+    let request = &server.join().unwrap()[0];
+    assert_eq!(request["method"], "simulateTransaction");
+    assert_eq!(request["params"][1]["sigVerify"], false);
+    assert_eq!(request["params"][1]["replaceRecentBlockhash"], true);
+}
+
+#[test]
+fn sends_a_signed_transaction() {
+    let (pauser, path) = keypair_file(2);
+    let (url, server) = rpc_server(vec![
+        json!({
+            "context": { "slot": 1 },
+            "value": { "blockhash": BLOCKHASH, "lastValidBlockHeight": 9 },
+        }),
+        json!("5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW"),
+    ]);
+    let output = gateway_admin(&[
+        "--url",
+        &url,
+        "--send",
+        "--keypair",
+        path.to_str().unwrap(),
+        "wallet",
+        "unpause",
+    ]);
+    let stdout = stdout(&output);
+    std::fs::remove_file(path).unwrap();
+
+    assert!(stdout.starts_with("Signature: 5VERv8"));
+    let requests = server.join().unwrap();
+    assert_eq!(requests[0]["method"], "getLatestBlockhash");
+    assert_eq!(requests[1]["method"], "sendTransaction");
+
+    let transaction = BASE64_STANDARD
+        .decode(requests[1]["params"][0].as_str().unwrap())
+        .unwrap();
+    assert_eq!(transaction[0], 1);
+    assert!(verify(
+        &pauser.pubkey(),
+        &transaction[65..],
+        &transaction[1..65]
+    ));
+}
+
+#[test]
+fn refuses_to_send_without_every_keypair() {
+    let (url, server) = rpc_server(vec![json!({
+        "context": { "slot": 1 },
+        "value": { "blockhash": BLOCKHASH, "lastValidBlockHeight": 9 },
+    })]);
+    let owner = Pubkey::new_unique();
+    let output = gateway_admin(&[
+        "--url",
+        &url,
+        "--send",
+        "--authority",
+        &owner.to_string(),
+        "minter",
+        "accept_ownership",
+    ]);
+
+    assert!(stderr(&output).contains(&format!("missing keypairs for {owner}")));
+    server.join().unwrap();
+}
+
+#[test]
+fn prints_the_decoded_state() {
+    let state = GatewayMinter {
+        bump: 255,
+        owner: Pubkey::new_unique(),
+        pending_owner: Pubkey::default(),
+        pauser: Pubkey::new_unique(),
+        token_controller: Pubkey::new_unique(),
+        paused: false,
+        enabled_attesters: vec![],
+        local_domain: 5,
+        version: 1,
+        supported_tokens: vec![],
+        custody_token_account_bumps: vec![],
+        transfer_receipts_enabled: false,
+        replay_store: ReplayStore::HashAccounts,
+        replay_migration_started_slot: 0,
+    };
+    let mut data = Vec::new();
+    state.try_serialize(&mut data).unwrap();
+
+    let (url, server) = rpc_server(vec![json!({
+        "context": { "slot": 1 },
+        "value": {
+            "data": [BASE64_STANDARD.encode(&data), "base64"],
+            "owner": gateway_client::gateway_minter::ID.to_string(),
+        },
+    })]);
+    let stdout = stdout(&gateway_admin(&["--url", &url, "state", "minter"]));
+
+    assert!(stdout.starts_with(&format!("GatewayMinter {}", pda::gateway_minter())));
+    assert!(stdout.contains(&format!("owner: {},", state.owner)));
+    assert!(stdout.contains("local_domain: 5,"));
+    let request = &server.join().unwrap()[0];
+    assert_eq!(request["method"], "getAccountInfo");
+    assert_eq!(request["params"][0], pda::gateway_minter().to_string());
+}
+
+#[test]
+fn reports_missing_state() {
+    let (url, server) = rpc_server(vec![json!({ "context": { "slot": 1 }, "value": null })]);
+    let stderr = stderr(&gateway_admin(&["--url", &url, "state", "wallet"]));
+
+    assert!(stderr.contains(&format!(
+        "GatewayWallet {} does not exist",
+        pda::gateway_wallet()
+    )));
+    server.join().unwrap();
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Ed25519 keypairs for signing transactions
+
+use anchor_lang::prelude::Pubkey;
+use curve25519_dalek::{scalar::clamp_integer, EdwardsPoint, Scalar};
+use sha2::{Digest, Sha512};
+use solana_signature::Signature;
+use solana_signer::{Signer, SignerError};
+
+/// An Ed25519 keypair derived from a 32-byte seed, as in RFC 8032
+pub struct Keypair {
+    scalar: Scalar,
+    prefix: [u8; 32],
+    pubkey: Pubkey,
+}
+
+impl Keypair {
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        let hash: [u8; 64] = Sha512::digest(seed).into();
+        let scalar = Scalar::from_bytes_mod_order(clamp_integer(hash[..32].try_into().unwrap()));
+        let pubkey = EdwardsPoint::mul_base(&scalar).compress().to_bytes();
+        Self {
+            scalar,
+            prefix: hash[32..].try_into().unwrap(),
+            pubkey: Pubkey::new_from_array(pubkey),
+        }
+    }
+
+    /// Parses the 64 bytes of a Solana CLI keypair file, which are the seed followed by the
+    /// public key. Returns `None` if the public key does not belong to the seed.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (seed, pubkey) = bytes.split_at_checked(32)?;
+        let keypair = Self::from_seed(seed.try_into().ok()?);
+        (keypair.pubkey.as_ref() == pubkey).then_some(keypair)
+    }
+
+    /// Returns a keypair with a seed that is unique within the process
+    pub fn new_unique() -> Self {
+        Self::from_seed(Pubkey::new_unique().to_bytes())
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        let hash: [u8; 64] = Sha512::new()
+            .chain_update(self.prefix)
+            .chain_update(message)
+            .finalize()
+            .into();
+        let r = Scalar::from_bytes_mod_order_wide(&hash);
+        let r_bytes = EdwardsPoint::mul_base(&r).compress().to_bytes();
+        let challenge: [u8; 64] = Sha512::new()
+            .chain_update(r_bytes)
+            .chain_update(self.pubkey)
+            .chain_update(message)
+            .finalize()
+            .into();
+        let s = r + Scalar::from_bytes_mod_order_wide(&challenge) * self.scalar;
+
+        let mut signature = [0; 64];
+        signature[..32].copy_from_slice(&r_bytes);
+        signature[32..].copy_from_slice(s.as_bytes());
+        signature
+    }
+}
+
+impl Signer for Keypair {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        Ok(self.sign(message).into())
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
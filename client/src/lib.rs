@@ -0,0 +1,32 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Client SDK for the Gateway programs.
+//!
+//! Builds the instructions of the GatewayWallet and GatewayMinter programs without
+//! hand-assembling accounts. Every builder derives the PDAs its instruction needs, and the
+//! `gateway_burn` and `gateway_mint` builders order their remaining accounts the way the
+//! programs expect them.
+
+pub mod keypair;
+pub mod minter;
+pub mod pda;
+pub mod wallet;
+
+pub use gateway_minter;
+pub use gateway_wallet;
@@ -0,0 +1,523 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GatewayMinter instruction builders
+//!
+//! Each builder returns the instruction with every account it needs, including the event
+//! authority used by `emit_cpi!`. Signers are given by the caller, and all other accounts are
+//! derived.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData};
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token;
+use gateway_minter::{
+    accounts,
+    hook::MintHook,
+    instruction as ix,
+    instructions::*,
+    state::{ReplayStore, TokenMintMode},
+    ID,
+};
+use gateway_shared::replay::ReplayKey;
+
+use crate::pda;
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn event_authority() -> Pubkey {
+    pda::event_authority(&ID)
+}
+
+pub fn initialize(
+    payer: Pubkey,
+    upgrade_authority: Pubkey,
+    params: InitializeParams,
+) -> Instruction {
+    instruction(
+        accounts::InitializeContext {
+            payer,
+            upgrade_authority,
+            gateway_minter: pda::gateway_minter(),
+            gateway_minter_program_data: pda::program_data(&ID),
+            gateway_minter_program: ID,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Initialize { params },
+    )
+}
+
+/// The hook program of an attestation element and the accounts it is invoked with
+#[derive(Clone, Debug)]
+pub struct MintHookAccounts {
+    pub program_id: Pubkey,
+    /// The accounts attested in the hook, in order
+    pub accounts: Vec<AccountMeta>,
+}
+
+impl MintHookAccounts {
+    /// Returns the hook accounts of an attestation element's hook data, if it encodes a hook
+    pub fn from_hook_data(hook_data: &[u8]) -> Option<Self> {
+        let hook = MintHook::decode(hook_data).ok()??;
+        Some(Self {
+            program_id: hook.program_id,
+            accounts: hook.accounts,
+        })
+    }
+}
+
+/// The accounts of an attestation element in a `gateway_mint`
+#[derive(Clone, Debug)]
+pub struct GatewayMintElement {
+    /// The attested destination token
+    pub destination_token: Pubkey,
+    /// The attested destination recipient
+    pub destination_recipient: Pubkey,
+    /// The attested transfer spec hash
+    pub transfer_spec_hash: [u8; 32],
+    /// The encoded transfer spec, which keys the transfer's record unless the replay store is
+    /// `HashAccounts`
+    pub transfer_spec: Option<Vec<u8>>,
+    /// `GatewayMinter::replay_store`
+    pub replay_store: ReplayStore,
+    /// `TokenConfig::mint_mode` of the destination token
+    pub mint_mode: TokenMintMode,
+    /// Whether the destination recipient is a wallet owner. The tokens are then minted to its
+    /// associated token account, which is created if it does not exist.
+    pub recipient_is_owner: bool,
+    /// The hook encoded in the element's hook data, if any
+    pub hook: Option<MintHookAccounts>,
+}
+
+/// Mints the tokens of a signed attestation set
+pub fn gateway_mint(
+    payer: Pubkey,
+    destination_caller: Pubkey,
+    params: GatewayMintParams,
+    elements: &[GatewayMintElement],
+) -> Instruction {
+    let mut instruction = instruction(
+        gateway_mint_accounts(payer, destination_caller),
+        ix::GatewayMint { params },
+    );
+    instruction
+        .accounts
+        .extend(gateway_mint_remaining_accounts(elements));
+    instruction
+}
+
+/// Mints the tokens of a signed attestation set that the program reconstructs from `params`
+pub fn gateway_mint_with_params(
+    payer: Pubkey,
+    destination_caller: Pubkey,
+    params: GatewayMintReconstructParams,
+    elements: &[GatewayMintElement],
+) -> Instruction {
+    let mut instruction = instruction(
+        gateway_mint_accounts(payer, destination_caller),
+        ix::GatewayMintWithParams { params },
+    );
+    instruction
+        .accounts
+        .extend(gateway_mint_remaining_accounts(elements));
+    instruction
+}
+
+fn gateway_mint_accounts(
+    payer: Pubkey,
+    destination_caller: Pubkey,
+) -> accounts::GatewayMintContext {
+    accounts::GatewayMintContext {
+        payer,
+        destination_caller,
+        gateway_minter: pda::gateway_minter(),
+        system_program: system_program::ID,
+        token_program: token::ID,
+        event_authority: event_authority(),
+        program: ID,
+    }
+}
+
+impl GatewayMintElement {
+    /// Returns the record of the transfer
+    pub fn replay_key(&self) -> ReplayKey {
+        let used_transfer_spec_hash = ReplayKey::TransferSpecHash(self.transfer_spec_hash);
+        match (self.replay_store, &self.transfer_spec) {
+            (ReplayStore::Migrating | ReplayStore::NonceBuckets, Some(transfer_spec)) => {
+                ReplayKey::from_transfer_spec(self.transfer_spec_hash, transfer_spec)
+                    .unwrap_or(used_transfer_spec_hash)
+            }
+            _ => used_transfer_spec_hash,
+        }
+    }
+}
+
+/// Returns the `gateway_mint` remaining accounts.
+///
+/// Each element first contributes a quadruplet: its custody token account (or token mint in
+/// mint-authority mode), its destination recipient token account, the account that records the
+/// transfer as used, and the token config account of its destination token. After all
+/// quadruplets, each element in turn contributes its used transfer spec hash account if it is
+/// recorded by nonce while migrating, the accounts that create its recipient's associated token
+/// account, and its hook program followed by the hook's accounts.
+pub fn gateway_mint_remaining_accounts(elements: &[GatewayMintElement]) -> Vec<AccountMeta> {
+    let quadruplets = elements.iter().flat_map(|element| {
+        let token_source = match element.mint_mode {
+            TokenMintMode::Custody => pda::gateway_minter_custody(&element.destination_token),
+            TokenMintMode::MintAuthority => element.destination_token,
+        };
+        let destination_account = if element.recipient_is_owner {
+            get_associated_token_address(&element.destination_recipient, &element.destination_token)
+        } else {
+            element.destination_recipient
+        };
+
+        [
+            AccountMeta::new(token_source, false),
+            AccountMeta::new(destination_account, false),
+            AccountMeta::new(element.replay_key().find_address(&ID).0, false),
+            AccountMeta::new(pda::token_config(&element.destination_token), false),
+        ]
+    });
+
+    let extra_accounts = elements.iter().flat_map(|element| {
+        // While migrating, a transfer recorded by nonce is also checked against its used transfer
+        // spec hash account
+        let migration_hash_account = (element.replay_store == ReplayStore::Migrating
+            && matches!(element.replay_key(), ReplayKey::Nonce { .. }))
+        .then(|| {
+            AccountMeta::new_readonly(
+                pda::used_transfer_spec_hash(&ID, &element.transfer_spec_hash),
+                false,
+            )
+        });
+        let recipient_token_account_creation = element
+            .recipient_is_owner
+            .then(|| {
+                [
+                    AccountMeta::new_readonly(element.destination_recipient, false),
+                    AccountMeta::new_readonly(element.destination_token, false),
+                    AccountMeta::new_readonly(associated_token::ID, false),
+                ]
+            })
+            .into_iter()
+            .flatten();
+        let hook = element.hook.iter().flat_map(|hook| {
+            std::iter::once(AccountMeta::new_readonly(hook.program_id, false))
+                .chain(hook.accounts.iter().cloned())
+        });
+
+        migration_hash_account
+            .into_iter()
+            .chain(recipient_token_account_creation)
+            .chain(hook)
+    });
+
+    quadruplets.chain(extra_accounts).collect()
+}
+
+pub fn transfer_ownership(owner: Pubkey, params: TransferOwnershipParams) -> Instruction {
+    instruction(
+        accounts::TransferOwnershipContext {
+            owner,
+            gateway_minter: pda::gateway_minter(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::TransferOwnership { params },
+    )
+}
+
+pub fn accept_ownership(pending_owner: Pubkey) -> Instruction {
+    instruction(
+        accounts::AcceptOwnershipContext {
+            pending_owner,
+            gateway_minter: pda::gateway_minter(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::AcceptOwnership {
+            params: AcceptOwnershipParams {},
+        },
+    )
+}
+
+pub fn update_pauser(owner: Pubkey, params: UpdatePauserParams) -> Instruction {
+    instruction(
+        accounts::UpdatePauserContext {
+            owner,
+            gateway_minter: pda::gateway_minter(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::UpdatePauser { params },
+    )
+}
+
+pub fn update_token_controller(owner: Pubkey, params: UpdateTokenControllerParams) -> Instruction {
+    instruction(
+        accounts::UpdateTokenControllerContext {
+            owner,
+            gateway_minter: pda::gateway_minter(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::UpdateTokenController { params },
+    )
+}
+
+pub fn add_attester(owner: Pubkey, params: AddAttesterParams) -> Instruction {
+    instruction(
+        accounts::AddAttesterContext {
+            owner,
+            gateway_minter: pda::gateway_minter(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::AddAttester { params },
+    )
+}
+
+pub fn remove_attester(owner: Pubkey, params: RemoveAttesterParams) -> Instruction {
+    instruction(
+        accounts::RemoveAttesterContext {
+            owner,
+            gateway_minter: pda::gateway_minter(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::RemoveAttester { params },
+    )
+}
+
+/// Adds a supported token, creating its custody token account
+pub fn add_token(payer: Pubkey, token_controller: Pubkey, token_mint: Pubkey) -> Instruction {
+    instruction(
+        accounts::AddTokenContext {
+            payer,
+            token_controller,
+            gateway_minter: pda::gateway_minter(),
+            token_mint,
+            custody_token_account: pda::gateway_minter_custody(&token_mint),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::AddToken {},
+    )
+}
+
+pub fn burn_token_custody(
+    token_controller: Pubkey,
+    token_mint: Pubkey,
+    amount: u64,
+) -> Instruction {
+    instruction(
+        accounts::BurnTokenCustodyContext {
+            token_controller,
+            gateway_minter: pda::gateway_minter(),
+            token_mint,
+            custody_token_account: pda::gateway_minter_custody(&token_mint),
+            token_program: token::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::BurnTokenCustody { amount },
+    )
+}
+
+pub fn pause(pauser: Pubkey) -> Instruction {
+    instruction(
+        accounts::PauseContext {
+            pauser,
+            gateway_minter: pda::gateway_minter(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Pause {},
+    )
+}
+
+pub fn unpause(pauser: Pubkey) -> Instruction {
+    instruction(
+        accounts::UnpauseContext {
+            pauser,
+            gateway_minter: pda::gateway_minter(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Unpause {},
+    )
+}
+
+pub fn configure_token_minter(
+    payer: Pubkey,
+    token_controller: Pubkey,
+    token_mint: Pubkey,
+    params: ConfigureTokenMinterParams,
+) -> Instruction {
+    instruction(
+        accounts::ConfigureTokenMinterContext {
+            payer,
+            token_controller,
+            gateway_minter: pda::gateway_minter(),
+            token_mint,
+            token_config: pda::token_config(&token_mint),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::ConfigureTokenMinter { params },
+    )
+}
+
+pub fn set_mint_rate_limit(
+    payer: Pubkey,
+    token_controller: Pubkey,
+    token_mint: Pubkey,
+    params: SetMintRateLimitParams,
+) -> Instruction {
+    instruction(
+        accounts::SetMintRateLimitContext {
+            payer,
+            token_controller,
+            gateway_minter: pda::gateway_minter(),
+            token_mint,
+            token_config: pda::token_config(&token_mint),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::SetMintRateLimit { params },
+    )
+}
+
+/// Refunds the rent of an expired used transfer spec hash account to `rent_payer`.
+///
+/// Without a transfer spec in `params`, the account is shrunk to a tombstone. With one, the
+/// account is closed after recording its explicit nonce in a nonce bucket, which `payer` signs
+/// for and pays.
+pub fn close_used_transfer_spec_hash(
+    payer: Pubkey,
+    rent_payer: Pubkey,
+    params: CloseUsedTransferSpecHashParams,
+) -> Instruction {
+    let nonce_bucket = params.transfer_spec.as_ref().map(|transfer_spec| {
+        ReplayKey::from_transfer_spec(params.transfer_spec_hash, transfer_spec)
+            .unwrap_or(ReplayKey::TransferSpecHash(params.transfer_spec_hash))
+            .find_address(&ID)
+            .0
+    });
+    instruction(
+        accounts::CloseUsedTransferSpecHashContext {
+            rent_payer,
+            used_transfer_spec_hash: pda::used_transfer_spec_hash(&ID, &params.transfer_spec_hash),
+            gateway_minter: pda::gateway_minter(),
+            payer: nonce_bucket.map(|_| payer),
+            nonce_bucket,
+            system_program: nonce_bucket.map(|_| system_program::ID),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::CloseUsedTransferSpecHash { params },
+    )
+}
+
+pub fn set_replay_store(owner: Pubkey, params: SetReplayStoreParams) -> Instruction {
+    instruction(
+        accounts::SetReplayStoreContext {
+            owner,
+            gateway_minter: pda::gateway_minter(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::SetReplayStore { params },
+    )
+}
+
+pub fn set_transfer_receipts(owner: Pubkey, params: SetTransferReceiptsParams) -> Instruction {
+    instruction(
+        accounts::SetTransferReceiptsContext {
+            owner,
+            gateway_minter: pda::gateway_minter(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::SetTransferReceipts { params },
+    )
+}
+
+/// Grows a GatewayMinter state account created before its current layout
+pub fn migrate_gateway_minter(payer: Pubkey, owner: Pubkey) -> Instruction {
+    instruction(
+        accounts::MigrateGatewayMinterContext {
+            payer,
+            owner,
+            gateway_minter: pda::gateway_minter(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::MigrateGatewayMinter {},
+    )
+}
+
+/// Returns whether a mint has used the transfer spec hash as return data
+pub fn is_transfer_spec_hash_used(transfer_spec_hash: [u8; 32]) -> Instruction {
+    instruction(
+        accounts::IsTransferSpecHashUsedContext {
+            used_transfer_spec_hash: pda::used_transfer_spec_hash(&ID, &transfer_spec_hash),
+        },
+        ix::IsTransferSpecHashUsed {
+            params: IsTransferSpecHashUsedParams { transfer_spec_hash },
+        },
+    )
+}
+
+/// Returns the `MinterConfig` as return data
+pub fn get_config() -> Instruction {
+    instruction(
+        accounts::GetConfigContext {
+            gateway_minter: pda::gateway_minter(),
+        },
+        ix::GetConfig {},
+    )
+}
+
+/// Returns the `TokenConfig` of a supported token as return data
+pub fn get_token_config(token_mint: Pubkey) -> Instruction {
+    instruction(
+        accounts::GetTokenConfigContext {
+            gateway_minter: pda::gateway_minter(),
+            token_config: pda::token_config(&token_mint),
+        },
+        ix::GetTokenConfig {
+            params: GetTokenConfigParams { token: token_mint },
+        },
+    )
+}
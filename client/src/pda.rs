@@ -0,0 +1,167 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! PDA derivation for every seed used by the Gateway programs
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use gateway_minter::seeds::{GATEWAY_MINTER_CUSTODY_SEED, GATEWAY_MINTER_SEED, TOKEN_CONFIG_SEED};
+use gateway_shared::{
+    replay::{NonceNamespace, ReplayKey},
+    USED_TRANSFER_SPEC_HASH_SEED_PREFIX,
+};
+use gateway_wallet::seeds::{
+    DENYLIST_SEED, DESTINATION_DOMAIN_SEED, FEE_SCHEDULE_SEED, GATEWAY_DELEGATE_SEED,
+    GATEWAY_DEPOSIT_SEED, GATEWAY_WALLET_CUSTODY_SEED, GATEWAY_WALLET_SEED, OUTFLOW_LIMIT_SEED,
+    TOKEN_TOTALS_SEED,
+};
+
+// Seed of the event authority PDA that signs the `emit_cpi!` self-CPI
+const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+fn find(seeds: &[&[u8]], program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(seeds, program_id).0
+}
+
+/// The GatewayWallet state account
+pub fn gateway_wallet() -> Pubkey {
+    find(&[GATEWAY_WALLET_SEED], &gateway_wallet::ID)
+}
+
+/// The GatewayWallet custody token account of a token
+pub fn gateway_wallet_custody(token_mint: &Pubkey) -> Pubkey {
+    find(
+        &[GATEWAY_WALLET_CUSTODY_SEED, token_mint.as_ref()],
+        &gateway_wallet::ID,
+    )
+}
+
+/// The deposit account of a depositor for a token
+pub fn gateway_deposit(token_mint: &Pubkey, depositor: &Pubkey) -> Pubkey {
+    find(
+        &[
+            GATEWAY_DEPOSIT_SEED,
+            token_mint.as_ref(),
+            depositor.as_ref(),
+        ],
+        &gateway_wallet::ID,
+    )
+}
+
+/// The account that records a depositor's delegate for a token
+pub fn gateway_delegate(token_mint: &Pubkey, depositor: &Pubkey, delegate: &Pubkey) -> Pubkey {
+    find(
+        &[
+            GATEWAY_DELEGATE_SEED,
+            token_mint.as_ref(),
+            depositor.as_ref(),
+            delegate.as_ref(),
+        ],
+        &gateway_wallet::ID,
+    )
+}
+
+/// The denylist account of an address, which exists while the address is denylisted
+pub fn denylist(account: &Pubkey) -> Pubkey {
+    find(&[DENYLIST_SEED, account.as_ref()], &gateway_wallet::ID)
+}
+
+/// The minimum fee schedule of a token for a destination domain
+pub fn fee_schedule(token_mint: &Pubkey, destination_domain: u32) -> Pubkey {
+    find(
+        &[
+            FEE_SCHEDULE_SEED,
+            token_mint.as_ref(),
+            &destination_domain.to_be_bytes(),
+        ],
+        &gateway_wallet::ID,
+    )
+}
+
+/// The destination domain registry entry of a domain
+pub fn destination_domain(domain: u32) -> Pubkey {
+    find(
+        &[DESTINATION_DOMAIN_SEED, &domain.to_be_bytes()],
+        &gateway_wallet::ID,
+    )
+}
+
+/// The outflow limit of a token
+pub fn outflow_limit(token_mint: &Pubkey) -> Pubkey {
+    find(
+        &[OUTFLOW_LIMIT_SEED, token_mint.as_ref()],
+        &gateway_wallet::ID,
+    )
+}
+
+/// The GatewayWallet deposit totals of a token
+pub fn token_totals(token_mint: &Pubkey) -> Pubkey {
+    find(
+        &[TOKEN_TOTALS_SEED, token_mint.as_ref()],
+        &gateway_wallet::ID,
+    )
+}
+
+/// The nonce bucket that records a nonce of a namespace in the GatewayWallet
+pub fn nonce_bucket(namespace: &NonceNamespace, nonce: u64) -> Pubkey {
+    ReplayKey::Nonce {
+        namespace: *namespace,
+        nonce,
+    }
+    .find_address(&gateway_wallet::ID)
+    .0
+}
+
+/// The GatewayMinter state account
+pub fn gateway_minter() -> Pubkey {
+    find(&[GATEWAY_MINTER_SEED], &gateway_minter::ID)
+}
+
+/// The GatewayMinter custody token account of a token
+pub fn gateway_minter_custody(token_mint: &Pubkey) -> Pubkey {
+    find(
+        &[GATEWAY_MINTER_CUSTODY_SEED, token_mint.as_ref()],
+        &gateway_minter::ID,
+    )
+}
+
+/// The GatewayMinter minting config of a token
+pub fn token_config(token_mint: &Pubkey) -> Pubkey {
+    find(
+        &[TOKEN_CONFIG_SEED, token_mint.as_ref()],
+        &gateway_minter::ID,
+    )
+}
+
+/// The account that marks a transfer spec hash as used by either program
+pub fn used_transfer_spec_hash(program_id: &Pubkey, transfer_spec_hash: &[u8; 32]) -> Pubkey {
+    find(
+        &[USED_TRANSFER_SPEC_HASH_SEED_PREFIX, transfer_spec_hash],
+        program_id,
+    )
+}
+
+/// The event authority of either program
+pub fn event_authority(program_id: &Pubkey) -> Pubkey {
+    find(&[EVENT_AUTHORITY_SEED], program_id)
+}
+
+/// The program data account of either program, which holds its upgrade authority
+pub fn program_data(program_id: &Pubkey) -> Pubkey {
+    find(&[program_id.as_ref()], &bpf_loader_upgradeable::ID)
+}
@@ -0,0 +1,955 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GatewayWallet instruction builders
+//!
+//! Each builder returns the instruction with every account it needs, including the event
+//! authority used by `emit_cpi!`. Signers are given by the caller, and all other accounts are
+//! derived.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, instruction::Instruction, sysvar};
+use anchor_lang::{system_program, InstructionData};
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token;
+use gateway_shared::replay::{NonceNamespace, ReplayKey};
+use gateway_wallet::{
+    accounts,
+    burn_data::{BurnDataStruct, BurnIntent},
+    ed25519::gateway_burn_ed25519_instruction_data,
+    instruction as ix,
+    instructions::*,
+    state::ReplayStore,
+    ID,
+};
+use solana_signer::{Signer, SignerError};
+
+use crate::pda;
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn event_authority() -> Pubkey {
+    pda::event_authority(&ID)
+}
+
+pub fn initialize(
+    payer: Pubkey,
+    upgrade_authority: Pubkey,
+    params: InitializeParams,
+) -> Instruction {
+    instruction(
+        accounts::InitializeContext {
+            payer,
+            upgrade_authority,
+            gateway_wallet: pda::gateway_wallet(),
+            gateway_wallet_program_data: pda::program_data(&ID),
+            gateway_wallet_program: ID,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Initialize { params },
+    )
+}
+
+/// Deposits `amount` of the token from `owner_token_account` into the owner's deposit
+pub fn deposit(
+    payer: Pubkey,
+    owner: Pubkey,
+    owner_token_account: Pubkey,
+    token_mint: Pubkey,
+    amount: u64,
+) -> Instruction {
+    instruction(
+        accounts::DepositContext {
+            payer,
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            owner_token_account,
+            custody_token_account: pda::gateway_wallet_custody(&token_mint),
+            deposit: pda::gateway_deposit(&token_mint, &owner),
+            token_totals: pda::token_totals(&token_mint),
+            depositor_denylist: pda::denylist(&owner),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Deposit { amount },
+    )
+}
+
+/// Deposits `amount` of the token from `owner_token_account` into the deposit of `depositor`
+pub fn deposit_for(
+    payer: Pubkey,
+    owner: Pubkey,
+    owner_token_account: Pubkey,
+    token_mint: Pubkey,
+    depositor: Pubkey,
+    amount: u64,
+) -> Instruction {
+    instruction(
+        accounts::DepositForContext {
+            payer,
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            owner_token_account,
+            custody_token_account: pda::gateway_wallet_custody(&token_mint),
+            deposit: pda::gateway_deposit(&token_mint, &depositor),
+            token_totals: pda::token_totals(&token_mint),
+            sender_denylist: pda::denylist(&owner),
+            depositor_denylist: pda::denylist(&depositor),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::DepositFor { amount, depositor },
+    )
+}
+
+/// The accounts of a `gateway_burn`, which depend on the burn intent and the GatewayWallet state
+#[derive(Clone, Debug)]
+pub struct GatewayBurnAccounts {
+    pub payer: Pubkey,
+    /// The source domain of the transfer spec, i.e. `GatewayWallet::local_domain`
+    pub source_domain: u32,
+    /// The source token of the transfer spec
+    pub token_mint: Pubkey,
+    /// The source depositor of the transfer spec
+    pub depositor: Pubkey,
+    /// The source signer of the transfer spec, if it is a delegate of the depositor
+    pub delegate: Option<Pubkey>,
+    /// The destination domain of the transfer spec
+    pub destination_domain: u32,
+    /// The transfer spec hash of the burn intent
+    pub transfer_spec_hash: [u8; 32],
+    /// The salt of the transfer spec
+    pub salt: [u8; 32],
+    /// `GatewayWallet::fee_recipient`
+    pub fee_recipient: Pubkey,
+    /// The recipients of `GatewayWallet::fee_splits`, in order
+    pub fee_split_recipients: Vec<Pubkey>,
+    /// `GatewayWallet::replay_store`
+    pub replay_store: ReplayStore,
+}
+
+/// Burns the depositor's balance for a signed burn intent.
+///
+/// The transaction must place the Ed25519 instruction that verifies the burn intent signature
+/// immediately before this instruction.
+pub fn gateway_burn(burn: &GatewayBurnAccounts, params: GatewayBurnParams) -> Instruction {
+    let mut instruction = instruction(
+        accounts::GatewayBurnContext {
+            payer: burn.payer,
+            gateway_wallet: pda::gateway_wallet(),
+            token_mint: burn.token_mint,
+            custody_token_account: pda::gateway_wallet_custody(&burn.token_mint),
+            fee_recipient_token_account: get_associated_token_address(
+                &burn.fee_recipient,
+                &burn.token_mint,
+            ),
+            deposit: pda::gateway_deposit(&burn.token_mint, &burn.depositor),
+            token_totals: pda::token_totals(&burn.token_mint),
+            delegate_account: burn.delegate.map(|delegate| {
+                pda::gateway_delegate(&burn.token_mint, &burn.depositor, &delegate)
+            }),
+            destination_domain: pda::destination_domain(burn.destination_domain),
+            fee_schedule: pda::fee_schedule(&burn.token_mint, burn.destination_domain),
+            outflow_limit: pda::outflow_limit(&burn.token_mint),
+            instructions_sysvar: sysvar::instructions::ID,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::GatewayBurn { params },
+    );
+    instruction
+        .accounts
+        .extend(gateway_burn_remaining_accounts(burn));
+    instruction
+}
+
+/// Returns the `gateway_burn` remaining accounts: the accounts that record the transfer as used,
+/// followed by the token account of each fee split recipient
+pub fn gateway_burn_remaining_accounts(burn: &GatewayBurnAccounts) -> Vec<AccountMeta> {
+    let used_transfer_spec_hash = ReplayKey::TransferSpecHash(burn.transfer_spec_hash);
+    let replay_keys = match burn.replay_store {
+        ReplayStore::HashAccounts => vec![used_transfer_spec_hash],
+        ReplayStore::Migrating | ReplayStore::NonceBuckets => {
+            let namespace = NonceNamespace {
+                source_domain: burn.source_domain,
+                source_contract: ID,
+                source_depositor: burn.depositor,
+            };
+            let replay_key = ReplayKey::from_salt(burn.transfer_spec_hash, &burn.salt, namespace);
+            // While migrating, a transfer recorded by nonce is also checked against its used
+            // transfer spec hash account
+            if burn.replay_store == ReplayStore::Migrating && replay_key != used_transfer_spec_hash
+            {
+                vec![replay_key, used_transfer_spec_hash]
+            } else {
+                vec![replay_key]
+            }
+        }
+    };
+
+    replay_keys
+        .iter()
+        .map(|replay_key| AccountMeta::new(replay_key.find_address(&ID).0, false))
+        .chain(burn.fee_split_recipients.iter().map(|recipient| {
+            AccountMeta::new(
+                get_associated_token_address(recipient, &burn.token_mint),
+                false,
+            )
+        }))
+        .collect()
+}
+
+/// Signs the burn intent message as `user` and returns the burn data for the burn signer to sign
+pub fn sign_burn_intent(
+    user: &impl Signer,
+    fee: u64,
+    burn_intent: &BurnIntent,
+) -> std::result::Result<BurnDataStruct, SignerError> {
+    let user_signature = user.try_sign_message(&burn_intent.message())?;
+    Ok(BurnDataStruct {
+        fee,
+        user_signature: user_signature.into(),
+        burn_intent: burn_intent.clone(),
+    })
+}
+
+/// Returns the Ed25519 instruction and the `gateway_burn` instruction for a burn intent signed by
+/// `user`, the source signer of the transfer spec.
+///
+/// `burn_signature` is the burn signer's signature of the burn data returned by
+/// `sign_burn_intent`. The two instructions must be placed in the transaction in order, with the
+/// `gateway_burn` instruction at `burn_instruction_index`.
+pub fn gateway_burn_with_user_signature(
+    burn: &GatewayBurnAccounts,
+    user: &impl Signer,
+    fee: u64,
+    burn_intent: &BurnIntent,
+    burn_signature: Vec<u8>,
+    burn_instruction_index: u16,
+) -> std::result::Result<[Instruction; 2], SignerError> {
+    let burn_data = sign_burn_intent(user, fee, burn_intent)?;
+    let ed25519_instruction = Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data: gateway_burn_ed25519_instruction_data(
+            burn_instruction_index,
+            burn_intent.message().len() as u16,
+        )
+        .to_vec(),
+    };
+    let gateway_burn_instruction = gateway_burn(
+        burn,
+        GatewayBurnParams {
+            encoded_burn_data: burn_data.encode(),
+            burn_signature,
+        },
+    );
+
+    Ok([ed25519_instruction, gateway_burn_instruction])
+}
+
+/// Completes the depositor's withdrawal into `depositor_token_account`
+pub fn withdraw(
+    depositor: Pubkey,
+    depositor_token_account: Pubkey,
+    token_mint: Pubkey,
+) -> Instruction {
+    instruction(
+        accounts::WithdrawContext {
+            depositor,
+            gateway_wallet: pda::gateway_wallet(),
+            custody_token_account: pda::gateway_wallet_custody(&token_mint),
+            depositor_token_account,
+            deposit: pda::gateway_deposit(&token_mint, &depositor),
+            token_totals: pda::token_totals(&token_mint),
+            outflow_limit: pda::outflow_limit(&token_mint),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Withdraw {},
+    )
+}
+
+/// Moves `amount` of the depositor's available balance to the deposit of `recipient`. The
+/// signer is the depositor or one of its delegates.
+pub fn transfer_balance(
+    payer: Pubkey,
+    signer: Pubkey,
+    params: TransferBalanceParams,
+) -> Instruction {
+    instruction(
+        accounts::TransferBalanceContext {
+            payer,
+            signer,
+            gateway_wallet: pda::gateway_wallet(),
+            deposit: pda::gateway_deposit(&params.token, &params.depositor),
+            recipient_deposit: pda::gateway_deposit(&params.token, &params.recipient),
+            delegate_account: (signer != params.depositor)
+                .then(|| pda::gateway_delegate(&params.token, &params.depositor, &signer)),
+            signer_denylist: pda::denylist(&signer),
+            depositor_denylist: pda::denylist(&params.depositor),
+            recipient_denylist: pda::denylist(&params.recipient),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::TransferBalance { params },
+    )
+}
+
+pub fn initiate_withdrawal(depositor: Pubkey, token_mint: Pubkey, amount: u64) -> Instruction {
+    instruction(
+        accounts::InitiateWithdrawalContext {
+            depositor,
+            gateway_wallet: pda::gateway_wallet(),
+            deposit: pda::gateway_deposit(&token_mint, &depositor),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::InitiateWithdrawal { amount },
+    )
+}
+
+pub fn transfer_ownership(owner: Pubkey, params: TransferOwnershipParams) -> Instruction {
+    instruction(
+        accounts::TransferOwnershipContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::TransferOwnership { params },
+    )
+}
+
+pub fn accept_ownership(pending_owner: Pubkey) -> Instruction {
+    instruction(
+        accounts::AcceptOwnershipContext {
+            pending_owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::AcceptOwnership {
+            params: AcceptOwnershipParams {},
+        },
+    )
+}
+
+pub fn update_pauser(owner: Pubkey, params: UpdatePauserParams) -> Instruction {
+    instruction(
+        accounts::UpdatePauserContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::UpdatePauser { params },
+    )
+}
+
+pub fn update_denylister(owner: Pubkey, params: UpdateDenylisterParams) -> Instruction {
+    instruction(
+        accounts::UpdateDenylisterContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::UpdateDenylister { params },
+    )
+}
+
+pub fn update_token_controller(owner: Pubkey, params: UpdateTokenControllerParams) -> Instruction {
+    instruction(
+        accounts::UpdateTokenControllerContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::UpdateTokenController { params },
+    )
+}
+
+pub fn update_withdrawal_delay(owner: Pubkey, params: UpdateWithdrawalDelayParams) -> Instruction {
+    instruction(
+        accounts::UpdateWithdrawalDelayContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::UpdateWithdrawalDelay { params },
+    )
+}
+
+/// Adds a supported token, creating its custody token account and deposit totals
+pub fn add_token(payer: Pubkey, token_controller: Pubkey, token_mint: Pubkey) -> Instruction {
+    instruction(
+        accounts::AddTokenContext {
+            payer,
+            token_controller,
+            gateway_wallet: pda::gateway_wallet(),
+            token_mint,
+            custody_token_account: pda::gateway_wallet_custody(&token_mint),
+            token_totals: pda::token_totals(&token_mint),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::AddToken {},
+    )
+}
+
+pub fn add_burn_signer(owner: Pubkey, params: AddBurnSignerParams) -> Instruction {
+    instruction(
+        accounts::AddBurnSignerContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::AddBurnSigner { params },
+    )
+}
+
+pub fn remove_burn_signer(owner: Pubkey, params: RemoveBurnSignerParams) -> Instruction {
+    instruction(
+        accounts::RemoveBurnSignerContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::RemoveBurnSigner { params },
+    )
+}
+
+pub fn add_delegate(
+    payer: Pubkey,
+    depositor: Pubkey,
+    token_mint: Pubkey,
+    delegate: Pubkey,
+) -> Instruction {
+    instruction(
+        accounts::AddDelegateContext {
+            payer,
+            depositor,
+            gateway_wallet: pda::gateway_wallet(),
+            token_mint,
+            delegate_account: pda::gateway_delegate(&token_mint, &depositor, &delegate),
+            depositor_denylist: pda::denylist(&depositor),
+            delegate_denylist: pda::denylist(&delegate),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::AddDelegate { delegate },
+    )
+}
+
+pub fn remove_delegate(depositor: Pubkey, token_mint: Pubkey, delegate: Pubkey) -> Instruction {
+    instruction(
+        accounts::RemoveDelegateContext {
+            depositor,
+            gateway_wallet: pda::gateway_wallet(),
+            token_mint,
+            delegate_account: pda::gateway_delegate(&token_mint, &depositor, &delegate),
+            depositor_denylist: pda::denylist(&depositor),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::RemoveDelegate { delegate },
+    )
+}
+
+pub fn denylist(payer: Pubkey, denylister: Pubkey, params: DenylistParams) -> Instruction {
+    instruction(
+        accounts::DenylistContext {
+            payer,
+            denylister,
+            gateway_wallet: pda::gateway_wallet(),
+            denylist: pda::denylist(&params.account),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Denylist { params },
+    )
+}
+
+pub fn undenylist(payer: Pubkey, denylister: Pubkey, params: UndenylistParams) -> Instruction {
+    instruction(
+        accounts::UndenylistContext {
+            payer,
+            denylister,
+            gateway_wallet: pda::gateway_wallet(),
+            denylist: pda::denylist(&params.account),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Undenylist { params },
+    )
+}
+
+pub fn pause(pauser: Pubkey) -> Instruction {
+    instruction(
+        accounts::PauseContext {
+            pauser,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Pause {},
+    )
+}
+
+pub fn unpause(pauser: Pubkey) -> Instruction {
+    instruction(
+        accounts::UnpauseContext {
+            pauser,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Unpause {},
+    )
+}
+
+pub fn update_fee_recipient(owner: Pubkey, params: UpdateFeeRecipientParams) -> Instruction {
+    instruction(
+        accounts::UpdateFeeRecipientContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::UpdateFeeRecipient { params },
+    )
+}
+
+/// Denylists several accounts, passing their denylist accounts as remaining accounts
+pub fn batch_denylist(
+    payer: Pubkey,
+    denylister: Pubkey,
+    params: BatchDenylistParams,
+) -> Instruction {
+    let denylist_accounts = denylist_accounts(&params.accounts);
+    let mut instruction = instruction(
+        accounts::BatchDenylistContext {
+            payer,
+            denylister,
+            gateway_wallet: pda::gateway_wallet(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::BatchDenylist { params },
+    );
+    instruction.accounts.extend(denylist_accounts);
+    instruction
+}
+
+/// Removes several accounts from the denylist, passing their denylist accounts as remaining
+/// accounts
+pub fn batch_undenylist(
+    payer: Pubkey,
+    denylister: Pubkey,
+    params: BatchUndenylistParams,
+) -> Instruction {
+    let denylist_accounts = denylist_accounts(&params.accounts);
+    let mut instruction = instruction(
+        accounts::BatchUndenylistContext {
+            payer,
+            denylister,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::BatchUndenylist { params },
+    );
+    instruction.accounts.extend(denylist_accounts);
+    instruction
+}
+
+fn denylist_accounts(accounts: &[Pubkey]) -> Vec<AccountMeta> {
+    accounts
+        .iter()
+        .map(|account| AccountMeta::new(pda::denylist(account), false))
+        .collect()
+}
+
+pub fn freeze_deposit(denylister: Pubkey, token_mint: Pubkey, depositor: Pubkey) -> Instruction {
+    instruction(
+        accounts::FreezeDepositContext {
+            payer: denylister,
+            denylister,
+            gateway_wallet: pda::gateway_wallet(),
+            deposit: pda::gateway_deposit(&token_mint, &depositor),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::FreezeDeposit {},
+    )
+}
+
+pub fn unfreeze_deposit(denylister: Pubkey, token_mint: Pubkey, depositor: Pubkey) -> Instruction {
+    instruction(
+        accounts::UnfreezeDepositContext {
+            payer: denylister,
+            denylister,
+            gateway_wallet: pda::gateway_wallet(),
+            deposit: pda::gateway_deposit(&token_mint, &depositor),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::UnfreezeDeposit {},
+    )
+}
+
+pub fn update_fee_splits(owner: Pubkey, params: UpdateFeeSplitsParams) -> Instruction {
+    instruction(
+        accounts::UpdateFeeSplitsContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::UpdateFeeSplits { params },
+    )
+}
+
+pub fn set_fee_schedule(
+    payer: Pubkey,
+    owner: Pubkey,
+    token_mint: Pubkey,
+    params: SetFeeScheduleParams,
+) -> Instruction {
+    instruction(
+        accounts::SetFeeScheduleContext {
+            payer,
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            token_mint,
+            fee_schedule: pda::fee_schedule(&token_mint, params.destination_domain),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::SetFeeSchedule { params },
+    )
+}
+
+pub fn enable_destination_domain(
+    payer: Pubkey,
+    owner: Pubkey,
+    params: EnableDestinationDomainParams,
+) -> Instruction {
+    instruction(
+        accounts::EnableDestinationDomainContext {
+            payer,
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            destination_domain: pda::destination_domain(params.domain),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::EnableDestinationDomain { params },
+    )
+}
+
+pub fn disable_destination_domain(owner: Pubkey, domain: u32) -> Instruction {
+    instruction(
+        accounts::DisableDestinationDomainContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            destination_domain: pda::destination_domain(domain),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::DisableDestinationDomain {},
+    )
+}
+
+pub fn set_outflow_limit(
+    payer: Pubkey,
+    owner: Pubkey,
+    token_mint: Pubkey,
+    params: SetOutflowLimitParams,
+) -> Instruction {
+    instruction(
+        accounts::SetOutflowLimitContext {
+            payer,
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            token_mint,
+            outflow_limit: pda::outflow_limit(&token_mint),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::SetOutflowLimit { params },
+    )
+}
+
+pub fn set_outflow_override(
+    owner: Pubkey,
+    token_mint: Pubkey,
+    params: SetOutflowOverrideParams,
+) -> Instruction {
+    instruction(
+        accounts::SetOutflowOverrideContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            outflow_limit: pda::outflow_limit(&token_mint),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::SetOutflowOverride { params },
+    )
+}
+
+/// Refunds the rent of an expired used transfer spec hash account to `rent_payer`.
+///
+/// Without a transfer spec in `params`, the account is shrunk to a tombstone. With one, the
+/// account is closed after recording its explicit nonce in a nonce bucket, which `payer` signs
+/// for and pays.
+pub fn close_used_transfer_spec_hash(
+    payer: Pubkey,
+    rent_payer: Pubkey,
+    params: CloseUsedTransferSpecHashParams,
+) -> Instruction {
+    let nonce_bucket = params.transfer_spec.as_ref().map(|transfer_spec| {
+        ReplayKey::from_transfer_spec(params.transfer_spec_hash, transfer_spec)
+            .unwrap_or(ReplayKey::TransferSpecHash(params.transfer_spec_hash))
+            .find_address(&ID)
+            .0
+    });
+    instruction(
+        accounts::CloseUsedTransferSpecHashContext {
+            rent_payer,
+            used_transfer_spec_hash: pda::used_transfer_spec_hash(&ID, &params.transfer_spec_hash),
+            gateway_wallet: pda::gateway_wallet(),
+            payer: nonce_bucket.map(|_| payer),
+            nonce_bucket,
+            system_program: nonce_bucket.map(|_| system_program::ID),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::CloseUsedTransferSpecHash { params },
+    )
+}
+
+pub fn set_replay_store(owner: Pubkey, params: SetReplayStoreParams) -> Instruction {
+    instruction(
+        accounts::SetReplayStoreContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::SetReplayStore { params },
+    )
+}
+
+pub fn set_transfer_receipts(owner: Pubkey, params: SetTransferReceiptsParams) -> Instruction {
+    instruction(
+        accounts::SetTransferReceiptsContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::SetTransferReceipts { params },
+    )
+}
+
+pub fn set_destination_domain_enforcement(
+    owner: Pubkey,
+    params: SetDestinationDomainEnforcementParams,
+) -> Instruction {
+    instruction(
+        accounts::SetDestinationDomainEnforcementContext {
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::SetDestinationDomainEnforcement { params },
+    )
+}
+
+/// Compares the deposit totals of the token with its custody balance
+pub fn reconcile(token_mint: Pubkey) -> Instruction {
+    instruction(
+        accounts::ReconcileContext {
+            gateway_wallet: pda::gateway_wallet(),
+            token_mint,
+            custody_token_account: pda::gateway_wallet_custody(&token_mint),
+            token_totals: pda::token_totals(&token_mint),
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::Reconcile {},
+    )
+}
+
+/// Initializes the deposit totals of a token supported before the totals existed from its audited
+/// total deposits
+pub fn initialize_token_totals(
+    payer: Pubkey,
+    token_controller: Pubkey,
+    token_mint: Pubkey,
+    params: InitializeTokenTotalsParams,
+) -> Instruction {
+    instruction(
+        accounts::InitializeTokenTotalsContext {
+            payer,
+            token_controller,
+            gateway_wallet: pda::gateway_wallet(),
+            token_mint,
+            custody_token_account: pda::gateway_wallet_custody(&token_mint),
+            token_totals: pda::token_totals(&token_mint),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::InitializeTokenTotals { params },
+    )
+}
+
+/// Grows a GatewayWallet state account created before its current layout
+pub fn migrate_gateway_wallet(payer: Pubkey, owner: Pubkey) -> Instruction {
+    instruction(
+        accounts::MigrateGatewayWalletContext {
+            payer,
+            owner,
+            gateway_wallet: pda::gateway_wallet(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::MigrateGatewayWallet {},
+    )
+}
+
+/// Transfers the custody balance in excess of the deposit totals to `destination_token_account`
+pub fn sweep_custody_surplus(
+    token_controller: Pubkey,
+    token_mint: Pubkey,
+    destination_token_account: Pubkey,
+) -> Instruction {
+    instruction(
+        accounts::SweepCustodySurplusContext {
+            token_controller,
+            gateway_wallet: pda::gateway_wallet(),
+            token_mint,
+            custody_token_account: pda::gateway_wallet_custody(&token_mint),
+            token_totals: pda::token_totals(&token_mint),
+            destination_token_account,
+            token_program: token::ID,
+            event_authority: event_authority(),
+            program: ID,
+        },
+        ix::SweepCustodySurplus {},
+    )
+}
+
+/// Returns the `DepositBalance` of the depositor for the token as return data
+pub fn get_balance(token_mint: Pubkey, depositor: Pubkey) -> Instruction {
+    instruction(
+        accounts::GetBalanceContext {
+            deposit: pda::gateway_deposit(&token_mint, &depositor),
+        },
+        ix::GetBalance {
+            params: DepositViewParams {
+                token: token_mint,
+                depositor,
+            },
+        },
+    )
+}
+
+/// Returns the amount the depositor can withdraw in the current slot as return data
+pub fn get_withdrawable(token_mint: Pubkey, depositor: Pubkey) -> Instruction {
+    instruction(
+        accounts::GetWithdrawableContext {
+            deposit: pda::gateway_deposit(&token_mint, &depositor),
+            outflow_limit: pda::outflow_limit(&token_mint),
+        },
+        ix::GetWithdrawable {
+            params: DepositViewParams {
+                token: token_mint,
+                depositor,
+            },
+        },
+    )
+}
+
+/// Returns whether `addr` is authorized for the depositor's balance of the token as return data
+pub fn is_authorized_for_balance(
+    token_mint: Pubkey,
+    depositor: Pubkey,
+    addr: Pubkey,
+) -> Instruction {
+    instruction(
+        accounts::IsAuthorizedForBalanceContext {
+            delegate_account: pda::gateway_delegate(&token_mint, &depositor, &addr),
+        },
+        ix::IsAuthorizedForBalance {
+            params: IsAuthorizedForBalanceParams {
+                token: token_mint,
+                depositor,
+                addr,
+            },
+        },
+    )
+}
+
+/// Returns the `WalletConfig` as return data
+pub fn get_config() -> Instruction {
+    instruction(
+        accounts::GetConfigContext {
+            gateway_wallet: pda::gateway_wallet(),
+        },
+        ix::GetConfig {},
+    )
+}
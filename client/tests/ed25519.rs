@@ -0,0 +1,128 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::ed25519_program;
+use gateway_client::wallet::{gateway_burn_with_user_signature, GatewayBurnAccounts};
+use gateway_wallet::burn_data::{BurnData, BurnIntent, TransferSpec};
+use gateway_wallet::ed25519::Ed25519InstructionData;
+use gateway_wallet::state::ReplayStore;
+use solana_signer::{Signer, SignerError};
+
+// A signer with a fixed public key and signature, which is enough to check where the Ed25519
+// instruction points within the gateway_burn instruction
+struct TestSigner {
+    pubkey: Pubkey,
+    signature: [u8; 64],
+}
+
+impl Signer for TestSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(
+        &self,
+        _message: &[u8],
+    ) -> Result<solana_signature::Signature, SignerError> {
+        Ok(self.signature.into())
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn ed25519_instruction_points_into_gateway_burn() {
+    let user = TestSigner {
+        pubkey: Pubkey::new_unique(),
+        signature: [3; 64],
+    };
+    let burn_intent = BurnIntent {
+        max_block_height: 100,
+        max_fee: 10,
+        transfer_spec: TransferSpec {
+            version: 1,
+            source_domain: 5,
+            destination_domain: 6,
+            source_contract: gateway_wallet::ID,
+            destination_contract: Pubkey::new_unique(),
+            source_token: Pubkey::new_unique(),
+            destination_token: Pubkey::new_unique(),
+            source_depositor: Pubkey::new_unique(),
+            destination_recipient: Pubkey::new_unique(),
+            source_signer: user.pubkey,
+            destination_caller: Pubkey::default(),
+            value: 1_000,
+            salt: [1; 32],
+            hook_data: vec![4, 5, 6],
+        },
+    };
+    let spec = &burn_intent.transfer_spec;
+    let burn = GatewayBurnAccounts {
+        payer: Pubkey::new_unique(),
+        source_domain: spec.source_domain,
+        token_mint: spec.source_token,
+        depositor: spec.source_depositor,
+        delegate: None,
+        destination_domain: spec.destination_domain,
+        transfer_spec_hash: spec.hash(),
+        salt: spec.salt,
+        fee_recipient: Pubkey::new_unique(),
+        fee_split_recipients: vec![],
+        replay_store: ReplayStore::HashAccounts,
+    };
+
+    let [ed25519_instruction, burn_instruction] =
+        gateway_burn_with_user_signature(&burn, &user, 7, &burn_intent, vec![9; 65], 1).unwrap();
+
+    assert_eq!(ed25519_instruction.program_id, ed25519_program::ID);
+    assert!(ed25519_instruction.accounts.is_empty());
+
+    let header = Ed25519InstructionData::new(&ed25519_instruction.data).unwrap();
+    assert_eq!(header.num_signatures().unwrap(), 1);
+    assert_eq!(header.signature_instruction_index().unwrap(), 1);
+    assert_eq!(header.public_key_instruction_index().unwrap(), 1);
+    assert_eq!(header.message_instruction_index().unwrap(), 1);
+
+    let data = &burn_instruction.data;
+    let signature_offset = header.signature_offset().unwrap() as usize;
+    let public_key_offset = header.public_key_offset().unwrap() as usize;
+    let message_offset = header.message_data_offset().unwrap() as usize;
+    let message_size = header.message_data_size().unwrap() as usize;
+
+    assert_eq!(
+        data[signature_offset..signature_offset + 64],
+        user.signature
+    );
+    assert_eq!(
+        data[public_key_offset..public_key_offset + 32],
+        user.pubkey.to_bytes()
+    );
+    assert_eq!(
+        data[message_offset..message_offset + message_size],
+        burn_intent.message()
+    );
+
+    // The burn data within the instruction is parsed by the program as signed by the user
+    let burn_data_length = u32::from_le_bytes(data[2..6].try_into().unwrap()) as usize;
+    let burn_data = BurnData::new(&data[6..6 + burn_data_length]).unwrap();
+    assert_eq!(burn_data.fee().unwrap(), 7);
+    assert_eq!(burn_data.source_signer().unwrap(), user.pubkey);
+}
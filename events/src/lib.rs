@@ -0,0 +1,265 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Decodes the events of the GatewayWallet and GatewayMinter programs.
+//!
+//! Both programs emit their events with `emit_cpi!`, a self-CPI signed by the event authority
+//! PDA whose data is the event instruction tag followed by the event discriminator and its
+//! Borsh encoding. The decoders here find those self-CPIs among the inner instructions of a
+//! transaction and return them as typed events, which serialize with serde.
+
+mod rpc;
+
+use std::fmt;
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::CompiledInstruction;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use gateway_client::pda;
+use gateway_minter::events as minter_events;
+use gateway_wallet::events as wallet_events;
+use serde::{Deserialize, Serialize};
+
+pub use rpc::decode_rpc_transaction;
+
+/// An error decoding an event or the transaction that holds it
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecodeError {
+    /// The event discriminator is not one of the program's events
+    UnknownEvent { program_id: Pubkey, data: Vec<u8> },
+    /// The event data does not decode as the event its discriminator names
+    InvalidEventData { event: &'static str },
+    /// An inner instruction refers to an account or program the transaction does not have
+    AccountIndexOutOfRange(u8),
+    /// The transaction is not in the shape returned by `getTransaction`
+    InvalidTransaction(String),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownEvent { program_id, data } => {
+                write!(f, "unknown event of {program_id} with data {data:?}")
+            }
+            Self::InvalidEventData { event } => write!(f, "invalid {event} event data"),
+            Self::AccountIndexOutOfRange(index) => write!(f, "account index {index} out of range"),
+            Self::InvalidTransaction(reason) => write!(f, "invalid transaction: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+// Declares an enum with a variant for each event of a program, named after the event
+macro_rules! program_events {
+    ($(#[$meta:meta])* $name:ident($module:ident) { $($event:ident,)* }) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        #[serde(tag = "name", content = "data")]
+        pub enum $name {
+            $($event($module::$event),)*
+        }
+
+        impl $name {
+            /// Decodes an event from its discriminator and Borsh encoding
+            pub fn decode(data: &[u8]) -> Option<Result<Self, DecodeError>> {
+                $(
+                    if let Some(mut event) = data.strip_prefix($module::$event::DISCRIMINATOR) {
+                        return Some(
+                            <$module::$event as AnchorDeserialize>::deserialize(&mut event)
+                                .ok()
+                                .filter(|_| event.is_empty())
+                                .map(Self::$event)
+                                .ok_or(DecodeError::InvalidEventData {
+                                    event: stringify!($event),
+                                }),
+                        );
+                    }
+                )*
+                None
+            }
+
+            /// Returns the name of the event
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$event(_) => stringify!($event),)*
+                }
+            }
+        }
+    };
+}
+
+program_events! {
+    /// An event of the GatewayWallet program
+    WalletEvent(wallet_events) {
+        GatewayWalletInitialized,
+        OwnershipTransferStarted,
+        OwnershipTransferred,
+        PauserChanged,
+        DenylisterChanged,
+        TokenControllerUpdated,
+        TokenSupported,
+        Deposited,
+        DelegateAdded,
+        DelegateRemoved,
+        Denylisted,
+        UnDenylisted,
+        WithdrawalInitiated,
+        BurnSignerAdded,
+        BurnSignerRemoved,
+        WithdrawalCompleted,
+        WithdrawalDelayChanged,
+        FeeRecipientChanged,
+        Paused,
+        Unpaused,
+        GatewayBurned,
+        InsufficientBalance,
+        DepositFrozen,
+        DepositUnfrozen,
+        FeeSplitsChanged,
+        FeeScheduleSet,
+        DestinationDomainEnabled,
+        DestinationDomainDisabled,
+        OutflowLimitSet,
+        OutflowOverrideSet,
+        UsedTransferSpecHashClosed,
+        ReplayStoreSet,
+        TransferReceiptsSet,
+        CustodyReconciled,
+        CustodySurplusSwept,
+        BalanceTransferred,
+        TokenTotalsInitialized,
+        GatewayWalletMigrated,
+        DestinationDomainEnforcementSet,
+    }
+}
+
+program_events! {
+    /// An event of the GatewayMinter program
+    MinterEvent(minter_events) {
+        OwnershipTransferStarted,
+        OwnershipTransferred,
+        GatewayMinterInitialized,
+        PauserChanged,
+        AttestationSignerAdded,
+        AttestationSignerRemoved,
+        TokenControllerUpdated,
+        TokenSupported,
+        TokenCustodyBurned,
+        Paused,
+        Unpaused,
+        AttestationUsed,
+        TokenMinterConfigured,
+        MintRateLimitSet,
+        MintRateLimited,
+        UsedTransferSpecHashClosed,
+        TransferReceiptsSet,
+        ReplayStoreSet,
+        GatewayMinterMigrated,
+    }
+}
+
+/// An event of either Gateway program
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "program", content = "event", rename_all = "snake_case")]
+pub enum GatewayEvent {
+    Wallet(WalletEvent),
+    Minter(MinterEvent),
+}
+
+impl GatewayEvent {
+    /// Returns the ID of the program that emitted the event
+    pub fn program_id(&self) -> Pubkey {
+        match self {
+            Self::Wallet(_) => gateway_wallet::ID,
+            Self::Minter(_) => gateway_minter::ID,
+        }
+    }
+
+    /// Returns the name of the event
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Wallet(event) => event.name(),
+            Self::Minter(event) => event.name(),
+        }
+    }
+}
+
+/// Decodes the event of an `emit_cpi!` self-CPI, given the program it invokes, its accounts
+/// and its data. Returns `None` for any other instruction.
+pub fn decode_instruction(
+    program_id: &Pubkey,
+    accounts: &[Pubkey],
+    data: &[u8],
+) -> Result<Option<GatewayEvent>, DecodeError> {
+    if *program_id != gateway_wallet::ID && *program_id != gateway_minter::ID {
+        return Ok(None);
+    }
+    // The event authority is the only account, and only the program can sign for it
+    let Some(event) = data.strip_prefix(EVENT_IX_TAG_LE) else {
+        return Ok(None);
+    };
+    if accounts.first() != Some(&pda::event_authority(program_id)) {
+        return Ok(None);
+    }
+
+    let decoded = if *program_id == gateway_wallet::ID {
+        WalletEvent::decode(event).map(|event| event.map(GatewayEvent::Wallet))
+    } else {
+        MinterEvent::decode(event).map(|event| event.map(GatewayEvent::Minter))
+    };
+    decoded
+        .ok_or_else(|| DecodeError::UnknownEvent {
+            program_id: *program_id,
+            data: event.to_vec(),
+        })?
+        .map(Some)
+}
+
+/// Decodes the events among the inner instructions of a transaction, in the order they were
+/// emitted. `account_keys` are the accounts of the transaction the instructions index into:
+/// its static account keys followed by the writable and then the readonly addresses loaded
+/// from address lookup tables.
+pub fn decode_inner_instructions(
+    account_keys: &[Pubkey],
+    inner_instructions: &[CompiledInstruction],
+) -> Result<Vec<GatewayEvent>, DecodeError> {
+    let key = |index: u8| {
+        account_keys
+            .get(index as usize)
+            .copied()
+            .ok_or(DecodeError::AccountIndexOutOfRange(index))
+    };
+
+    let mut events = Vec::new();
+    for instruction in inner_instructions {
+        let program_id = key(instruction.program_id_index)?;
+        let accounts = instruction
+            .accounts
+            .iter()
+            .map(|index| key(*index))
+            .collect::<Result<Vec<_>, _>>()?;
+        events.extend(decode_instruction(
+            &program_id,
+            &accounts,
+            &instruction.data,
+        )?);
+    }
+    Ok(events)
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Decoding of transactions as returned by the `getTransaction` JSON-RPC method
+
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use serde_json::Value;
+
+use crate::{decode_instruction, DecodeError, GatewayEvent};
+
+/// Decodes the events of a transaction returned by `getTransaction`, with the `json` or
+/// `jsonParsed` encoding, in the order they were emitted
+pub fn decode_rpc_transaction(transaction: &Value) -> Result<Vec<GatewayEvent>, DecodeError> {
+    let meta = &transaction["meta"];
+    let static_keys = transaction["transaction"]["message"]["accountKeys"]
+        .as_array()
+        .ok_or_else(|| invalid("missing transaction.message.accountKeys"))?;
+    let loaded_keys = ["writable", "readonly"].into_iter().flat_map(|kind| {
+        meta["loadedAddresses"][kind]
+            .as_array()
+            .into_iter()
+            .flatten()
+    });
+    let account_keys = static_keys
+        .iter()
+        // With the jsonParsed encoding the static account keys are objects
+        .map(|key| key.get("pubkey").unwrap_or(key))
+        .chain(loaded_keys)
+        .map(pubkey)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut events = Vec::new();
+    let Some(inner_instructions) = meta["innerInstructions"].as_array() else {
+        return Ok(events);
+    };
+    for instruction in inner_instructions
+        .iter()
+        .flat_map(|inner| inner["instructions"].as_array().into_iter().flatten())
+    {
+        // Instructions of programs the node cannot parse keep their raw data
+        let Some(data) = instruction["data"].as_str() else {
+            continue;
+        };
+        let data = bs58::decode(data)
+            .into_vec()
+            .map_err(|error| invalid(&format!("instruction data: {error}")))?;
+
+        let (program_id, accounts) = match instruction["programIdIndex"].as_u64() {
+            Some(index) => {
+                let key = |index: &Value| {
+                    index
+                        .as_u64()
+                        .and_then(|index| account_keys.get(index as usize))
+                        .copied()
+                        .ok_or_else(|| invalid(&format!("account index {index}")))
+                };
+                let accounts = instruction["accounts"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(key)
+                    .collect::<Result<Vec<_>, _>>()?;
+                (key(&Value::from(index))?, accounts)
+            }
+            None => {
+                let accounts = instruction["accounts"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(pubkey)
+                    .collect::<Result<Vec<_>, _>>()?;
+                (pubkey(&instruction["programId"])?, accounts)
+            }
+        };
+        events.extend(decode_instruction(&program_id, &accounts, &data)?);
+    }
+    Ok(events)
+}
+
+fn pubkey(value: &Value) -> Result<Pubkey, DecodeError> {
+    value
+        .as_str()
+        .and_then(|key| Pubkey::from_str(key).ok())
+        .ok_or_else(|| invalid(&format!("{value} is not a public key")))
+}
+
+fn invalid(reason: &str) -> DecodeError {
+    DecodeError::InvalidTransaction(reason.to_string())
+}
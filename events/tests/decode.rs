@@ -0,0 +1,277 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Decodes events emitted in the in-process SVM and encoded by hand
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::CompiledInstruction;
+use anchor_lang::Event;
+use gateway_client::{minter, pda, wallet};
+use gateway_events::{
+    decode_inner_instructions, decode_instruction, decode_rpc_transaction, DecodeError,
+    GatewayEvent, MinterEvent, WalletEvent,
+};
+use gateway_minter::events as minter_events;
+use gateway_program_tests::fixtures::{MinterFixture, WalletFixture};
+use gateway_program_tests::TransactionMetadata;
+use gateway_wallet::events as wallet_events;
+use gateway_wallet::state::{FeeSplit, ReplayStore};
+use serde_json::json;
+
+fn decode_metadata(metadata: &TransactionMetadata) -> Vec<GatewayEvent> {
+    metadata
+        .inner_instructions
+        .iter()
+        .filter_map(|inner| {
+            let accounts: Vec<_> = inner
+                .instruction
+                .accounts
+                .iter()
+                .map(|a| a.pubkey)
+                .collect();
+            decode_instruction(
+                &inner.instruction.program_id,
+                &accounts,
+                &inner.instruction.data,
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+// The data of the emit_cpi! self-CPI for `event`
+fn cpi_data(event: &impl Event) -> Vec<u8> {
+    [EVENT_IX_TAG_LE, &event.data()].concat()
+}
+
+fn deposited() -> wallet_events::Deposited {
+    wallet_events::Deposited {
+        token: Pubkey::new_unique(),
+        depositor: Pubkey::new_unique(),
+        sender: Pubkey::new_unique(),
+        value: 1_000_000,
+    }
+}
+
+#[test]
+fn decodes_wallet_events_emitted_by_the_program() {
+    let mut fixture = WalletFixture::new();
+    let (depositor, token_account) = fixture.funded_depositor(500);
+    let metadata = fixture
+        .svm
+        .process(
+            &[
+                wallet::deposit(
+                    fixture.payer,
+                    depositor,
+                    token_account,
+                    fixture.token_mint,
+                    500,
+                ),
+                wallet::initiate_withdrawal(depositor, fixture.token_mint, 200),
+            ],
+            &[fixture.payer, depositor],
+        )
+        .unwrap();
+
+    let events = decode_metadata(&metadata);
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        events[0],
+        GatewayEvent::Wallet(WalletEvent::Deposited(wallet_events::Deposited {
+            token: fixture.token_mint,
+            depositor,
+            sender: depositor,
+            value: 500,
+        }))
+    );
+    let GatewayEvent::Wallet(WalletEvent::WithdrawalInitiated(withdrawal)) = &events[1] else {
+        panic!("unexpected event {:?}", events[1]);
+    };
+    assert_eq!(withdrawal.value, 200);
+    assert_eq!(withdrawal.remaining_available, 300);
+    assert_eq!(withdrawal.total_withdrawing, 200);
+}
+
+#[test]
+fn decodes_minter_events_emitted_by_the_program() {
+    let mut fixture = MinterFixture::new();
+    let metadata = fixture
+        .svm
+        .process(&[minter::pause(fixture.owner)], &[fixture.owner])
+        .unwrap();
+
+    assert_eq!(
+        decode_metadata(&metadata),
+        [GatewayEvent::Minter(MinterEvent::Paused(
+            minter_events::Paused {
+                account: fixture.owner
+            }
+        ))]
+    );
+}
+
+#[test]
+fn ignores_other_instructions() {
+    let event_authority = pda::event_authority(&gateway_wallet::ID);
+    let data = cpi_data(&deposited());
+
+    // Another program, a program instruction that is not an event, and an event CPI without
+    // the event authority
+    let cases = [
+        (Pubkey::new_unique(), event_authority, data.clone()),
+        (gateway_wallet::ID, event_authority, data[8..].to_vec()),
+        (gateway_wallet::ID, Pubkey::new_unique(), data.clone()),
+        (
+            gateway_minter::ID,
+            pda::event_authority(&gateway_wallet::ID),
+            data,
+        ),
+    ];
+    for (program_id, account, data) in cases {
+        assert_eq!(decode_instruction(&program_id, &[account], &data), Ok(None));
+    }
+}
+
+#[test]
+fn fails_for_unknown_or_invalid_events() {
+    let event_authority = pda::event_authority(&gateway_minter::ID);
+
+    let unknown = [EVENT_IX_TAG_LE, &[10, 200], &[1, 2, 3]].concat();
+    assert_eq!(
+        decode_instruction(&gateway_minter::ID, &[event_authority], &unknown),
+        Err(DecodeError::UnknownEvent {
+            program_id: gateway_minter::ID,
+            data: vec![10, 200, 1, 2, 3],
+        })
+    );
+
+    let event = minter_events::TransferReceiptsSet { enabled: true };
+    let mut trailing = cpi_data(&event);
+    trailing.push(0);
+    let mut truncated = cpi_data(&event);
+    truncated.pop();
+    for data in [trailing, truncated] {
+        assert_eq!(
+            decode_instruction(&gateway_minter::ID, &[event_authority], &data),
+            Err(DecodeError::InvalidEventData {
+                event: "TransferReceiptsSet"
+            })
+        );
+    }
+}
+
+#[test]
+fn decodes_compiled_inner_instructions() {
+    let event = deposited();
+    let account_keys = [
+        Pubkey::new_unique(),
+        gateway_wallet::ID,
+        pda::event_authority(&gateway_wallet::ID),
+    ];
+    let instructions = [
+        // The token transfer of the deposit
+        CompiledInstruction::new_from_raw_parts(0, vec![1, 2, 3], vec![0, 1]),
+        CompiledInstruction::new_from_raw_parts(1, cpi_data(&event), vec![2]),
+    ];
+
+    assert_eq!(
+        decode_inner_instructions(&account_keys, &instructions),
+        Ok(vec![GatewayEvent::Wallet(WalletEvent::Deposited(event))])
+    );
+    assert_eq!(
+        decode_inner_instructions(
+            &account_keys,
+            &[CompiledInstruction::new_from_raw_parts(3, vec![], vec![])]
+        ),
+        Err(DecodeError::AccountIndexOutOfRange(3))
+    );
+}
+
+#[test]
+fn decodes_rpc_transactions() {
+    let event = wallet_events::ReplayStoreSet {
+        previous_replay_store: ReplayStore::HashAccounts,
+        new_replay_store: ReplayStore::Migrating,
+    };
+    let data = bs58::encode(cpi_data(&event)).into_string();
+    let payer = Pubkey::new_unique().to_string();
+    let event_authority = pda::event_authority(&gateway_wallet::ID).to_string();
+    let expected = vec![GatewayEvent::Wallet(WalletEvent::ReplayStoreSet(event))];
+
+    // The event authority is loaded from an address lookup table
+    let encoded = json!({
+        "transaction": { "message": { "accountKeys": [payer, gateway_wallet::ID.to_string()] } },
+        "meta": {
+            "loadedAddresses": { "writable": [], "readonly": [event_authority] },
+            "innerInstructions": [{
+                "index": 0,
+                "instructions": [{ "programIdIndex": 1, "accounts": [2], "data": data }],
+            }],
+        },
+    });
+    assert_eq!(decode_rpc_transaction(&encoded), Ok(expected.clone()));
+
+    let parsed = json!({
+        "transaction": { "message": { "accountKeys": [{ "pubkey": payer, "signer": true }] } },
+        "meta": {
+            "innerInstructions": [{
+                "index": 0,
+                "instructions": [
+                    { "program": "system", "parsed": { "type": "transfer" } },
+                    {
+                        "programId": gateway_wallet::ID.to_string(),
+                        "accounts": [event_authority],
+                        "data": data,
+                    },
+                ],
+            }],
+        },
+    });
+    assert_eq!(decode_rpc_transaction(&parsed), Ok(expected));
+
+    assert!(matches!(
+        decode_rpc_transaction(&json!({ "meta": {} })),
+        Err(DecodeError::InvalidTransaction(_))
+    ));
+}
+
+#[test]
+fn serializes_with_serde() {
+    let event = GatewayEvent::Wallet(WalletEvent::FeeSplitsChanged(
+        wallet_events::FeeSplitsChanged {
+            old_fee_splits: vec![],
+            new_fee_splits: vec![FeeSplit {
+                recipient: Pubkey::new_unique(),
+                bps: 250,
+            }],
+        },
+    ));
+
+    let value = serde_json::to_value(&event).unwrap();
+    assert_eq!(value["program"], "wallet");
+    assert_eq!(value["event"]["name"], "FeeSplitsChanged");
+    assert_eq!(value["event"]["data"]["new_fee_splits"][0]["bps"], 250);
+    assert_eq!(
+        serde_json::from_value::<GatewayEvent>(value).unwrap(),
+        event
+    );
+    assert_eq!(event.name(), "FeeSplitsChanged");
+    assert_eq!(event.program_id(), gateway_wallet::ID);
+}
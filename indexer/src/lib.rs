@@ -0,0 +1,354 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Rebuilds the balances of GatewayWallet deposits from the program's events.
+//!
+//! A [`Ledger`] replays the balance events in the order they were emitted, checking each
+//! against the balances replayed so far, and can then be compared with the `GatewayDeposit`
+//! accounts and the custody token accounts. Any difference that the program could not pay out is
+//! reported as a [`Divergence`], which audits that the accounting of deposits, withdrawals,
+//! transfers and burns matches the tokens the program holds.
+
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::Pubkey;
+use gateway_events::{GatewayEvent, WalletEvent};
+use gateway_wallet::state::GatewayDeposit;
+use serde::Serialize;
+
+/// Identifies the deposit of a depositor for a token
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct DepositKey {
+    pub token: Pubkey,
+    pub depositor: Pubkey,
+}
+
+/// The balances of a deposit, as the `GatewayDeposit` account records them
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Balance {
+    pub available_amount: u64,
+    pub withdrawing_amount: u64,
+}
+
+impl Balance {
+    pub fn total(&self) -> u128 {
+        self.available_amount as u128 + self.withdrawing_amount as u128
+    }
+}
+
+impl From<&GatewayDeposit> for Balance {
+    fn from(deposit: &GatewayDeposit) -> Self {
+        Self {
+            available_amount: deposit.available_amount,
+            withdrawing_amount: deposit.withdrawing_amount,
+        }
+    }
+}
+
+/// A difference between the replayed balances and what an event or account reports
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum Divergence {
+    /// An event reports balances that the replayed balances cannot produce. `expected` and
+    /// `reported` are the balances after a `WithdrawalInitiated`, the balances before an
+    /// `InsufficientBalance`, the withdrawing amount paid by a `WithdrawalCompleted`, and the
+    /// amounts taken from each balance by a `GatewayBurned`.
+    Event {
+        /// The position of the event among the events applied to the ledger
+        event_index: u64,
+        event: &'static str,
+        key: DepositKey,
+        expected: Balance,
+        reported: Balance,
+    },
+    /// A `GatewayDeposit` account differs from the replayed balances, or is missing although
+    /// the replayed balances are not zero
+    Deposit {
+        key: DepositKey,
+        replayed: Balance,
+        on_chain: Option<Balance>,
+    },
+    /// A custody token account holds less than the sum of the replayed balances of its token, so
+    /// not every depositor can be paid out. Holding more is not a divergence, as tokens can be
+    /// sent to it directly; see [`Ledger::custody_surplus`].
+    Custody {
+        token: Pubkey,
+        replayed_total: u128,
+        custody_balance: u64,
+    },
+}
+
+/// The deposit balances replayed from events
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    balances: BTreeMap<DepositKey, Balance>,
+    // The amount requested by a burn that found an insufficient balance, which the
+    // `GatewayBurned` event that follows reports capped to the balance
+    insufficient_burn: Option<(DepositKey, u64)>,
+    events_applied: u64,
+}
+
+impl Ledger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the replayed balances of a deposit, which are zero for an unknown deposit
+    pub fn balance(&self, key: &DepositKey) -> Balance {
+        self.balances.get(key).copied().unwrap_or_default()
+    }
+
+    /// Returns the replayed balances of every deposit that had an event
+    pub fn balances(&self) -> &BTreeMap<DepositKey, Balance> {
+        &self.balances
+    }
+
+    /// Returns the sum of the replayed balances of a token
+    pub fn total(&self, token: &Pubkey) -> u128 {
+        self.balances
+            .iter()
+            .filter(|(key, _)| key.token == *token)
+            .map(|(_, balance)| balance.total())
+            .sum()
+    }
+
+    /// Applies the events of both programs in the order they were emitted, returning the
+    /// divergences they report
+    pub fn apply_all<'a>(
+        &mut self,
+        events: impl IntoIterator<Item = &'a GatewayEvent>,
+    ) -> Vec<Divergence> {
+        events
+            .into_iter()
+            .filter_map(|event| self.apply(event))
+            .collect()
+    }
+
+    /// Applies an event. Events other than the GatewayWallet balance events are only counted.
+    ///
+    /// When an event diverges from the replayed balances, the ledger follows the event from
+    /// then on, so that a single divergence is reported once.
+    pub fn apply(&mut self, event: &GatewayEvent) -> Option<Divergence> {
+        let event_index = self.events_applied;
+        self.events_applied += 1;
+        let GatewayEvent::Wallet(event) = event else {
+            return None;
+        };
+
+        let (key, expected, reported) = match event {
+            WalletEvent::Deposited(deposited) => {
+                let key = DepositKey {
+                    token: deposited.token,
+                    depositor: deposited.depositor,
+                };
+                let balance = self.balances.entry(key).or_default();
+                balance.available_amount = balance.available_amount.saturating_add(deposited.value);
+                return None;
+            }
+            WalletEvent::WithdrawalInitiated(withdrawal) => {
+                let key = DepositKey {
+                    token: withdrawal.token,
+                    depositor: withdrawal.depositor,
+                };
+                let balance = self.balances.entry(key).or_default();
+                let expected = Balance {
+                    available_amount: balance.available_amount.wrapping_sub(withdrawal.value),
+                    withdrawing_amount: balance.withdrawing_amount.wrapping_add(withdrawal.value),
+                };
+                let valid = withdrawal.value <= balance.available_amount;
+                let reported = Balance {
+                    available_amount: withdrawal.remaining_available,
+                    withdrawing_amount: withdrawal.total_withdrawing,
+                };
+                *balance = reported;
+                if valid && expected == reported {
+                    return None;
+                }
+                (key, expected, reported)
+            }
+            WalletEvent::WithdrawalCompleted(withdrawal) => {
+                let key = DepositKey {
+                    token: withdrawal.token,
+                    depositor: withdrawal.depositor,
+                };
+                let balance = self.balances.entry(key).or_default();
+                let expected = *balance;
+                balance.withdrawing_amount = 0;
+                if withdrawal.value == expected.withdrawing_amount {
+                    return None;
+                }
+                let reported = Balance {
+                    withdrawing_amount: withdrawal.value,
+                    ..expected
+                };
+                (key, expected, reported)
+            }
+            WalletEvent::InsufficientBalance(insufficient) => {
+                let key = DepositKey {
+                    token: insufficient.token,
+                    depositor: insufficient.depositor,
+                };
+                self.insufficient_burn = Some((key, insufficient.value));
+                let expected = self.balance(&key);
+                let reported = Balance {
+                    available_amount: insufficient.available_balance,
+                    withdrawing_amount: insufficient.withdrawing_balance,
+                };
+                // The balances are reduced by the GatewayBurned event that follows
+                if expected.total() < insufficient.value as u128 && expected == reported {
+                    return None;
+                }
+                self.balances.insert(key, reported);
+                (key, expected, reported)
+            }
+            WalletEvent::GatewayBurned(burned) => {
+                let key = DepositKey {
+                    token: burned.token,
+                    depositor: burned.depositor,
+                };
+                let deducted = burned.value as u128 + burned.fee as u128;
+                let requested = match self.insufficient_burn.take() {
+                    Some((insufficient_key, requested)) if insufficient_key == key => {
+                        requested as u128
+                    }
+                    _ => deducted,
+                };
+
+                let balance = self.balances.entry(key).or_default();
+                let expected = reduce_balance(balance, requested);
+                let reported = Balance {
+                    available_amount: burned.from_available,
+                    withdrawing_amount: burned.from_withdrawing,
+                };
+                balance.available_amount = balance
+                    .available_amount
+                    .saturating_sub(burned.from_available);
+                balance.withdrawing_amount = balance
+                    .withdrawing_amount
+                    .saturating_sub(burned.from_withdrawing);
+                if expected == reported && reported.total() == deducted {
+                    return None;
+                }
+                (key, expected, reported)
+            }
+            WalletEvent::BalanceTransferred(transfer) => {
+                let recipient = DepositKey {
+                    token: transfer.token,
+                    depositor: transfer.recipient,
+                };
+                let balance = self.balances.entry(recipient).or_default();
+                balance.available_amount = balance.available_amount.saturating_add(transfer.value);
+
+                let key = DepositKey {
+                    token: transfer.token,
+                    depositor: transfer.depositor,
+                };
+                let balance = self.balances.entry(key).or_default();
+                let expected = *balance;
+                balance.available_amount = balance.available_amount.saturating_sub(transfer.value);
+                if transfer.value <= expected.available_amount {
+                    return None;
+                }
+                let reported = Balance {
+                    available_amount: transfer.value,
+                    ..expected
+                };
+                (key, expected, reported)
+            }
+            _ => return None,
+        };
+
+        Some(Divergence::Event {
+            event_index,
+            event: event.name(),
+            key,
+            expected,
+            reported,
+        })
+    }
+
+    /// Compares the replayed balances with the `GatewayDeposit` accounts of a token, which
+    /// must be all of them
+    pub fn check_deposits<'a>(
+        &self,
+        token: &Pubkey,
+        deposits: impl IntoIterator<Item = &'a GatewayDeposit>,
+    ) -> Vec<Divergence> {
+        let mut on_chain: BTreeMap<DepositKey, Balance> = deposits
+            .into_iter()
+            .filter(|deposit| deposit.token_mint == *token)
+            .map(|deposit| {
+                let key = DepositKey {
+                    token: deposit.token_mint,
+                    depositor: deposit.depositor,
+                };
+                (key, Balance::from(deposit))
+            })
+            .collect();
+
+        let mut divergences = Vec::new();
+        for (key, replayed) in self.balances.iter().filter(|(key, _)| key.token == *token) {
+            let on_chain = on_chain.remove(key);
+            if on_chain.unwrap_or_default() != *replayed {
+                divergences.push(Divergence::Deposit {
+                    key: *key,
+                    replayed: *replayed,
+                    on_chain,
+                });
+            }
+        }
+        // Deposits that never had an event must be empty
+        for (key, on_chain) in on_chain {
+            if on_chain != Balance::default() {
+                divergences.push(Divergence::Deposit {
+                    key,
+                    replayed: Balance::default(),
+                    on_chain: Some(on_chain),
+                });
+            }
+        }
+        divergences
+    }
+
+    /// Checks that the custody token account of a token holds at least the sum of its replayed
+    /// balances
+    pub fn check_custody(&self, token: &Pubkey, custody_balance: u64) -> Option<Divergence> {
+        let replayed_total = self.total(token);
+        (replayed_total > custody_balance as u128).then_some(Divergence::Custody {
+            token: *token,
+            replayed_total,
+            custody_balance,
+        })
+    }
+
+    /// Returns how much the custody token account of a token holds beyond the sum of its
+    /// replayed balances, such as tokens sent to it directly
+    pub fn custody_surplus(&self, token: &Pubkey, custody_balance: u64) -> u128 {
+        (custody_balance as u128).saturating_sub(self.total(token))
+    }
+}
+
+/// Returns how much a burn of `amount` takes from each balance: as much as possible from the
+/// available balance, then from the withdrawing balance
+fn reduce_balance(balance: &Balance, amount: u128) -> Balance {
+    let from_available = amount.min(balance.available_amount as u128);
+    let from_withdrawing = (amount - from_available).min(balance.withdrawing_amount as u128);
+    Balance {
+        available_amount: from_available as u64,
+        withdrawing_amount: from_withdrawing as u64,
+    }
+}
@@ -0,0 +1,338 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Replays events emitted in the in-process SVM and written by hand
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::{pda, wallet};
+use gateway_events::{decode_instruction, GatewayEvent, WalletEvent};
+use gateway_indexer::{Balance, DepositKey, Divergence, Ledger};
+use gateway_program_tests::fixtures::{token_account, WalletFixture, WITHDRAWAL_DELAY};
+use gateway_program_tests::TransactionMetadata;
+use gateway_wallet::events::{
+    BalanceTransferred, Deposited, GatewayBurned, InsufficientBalance, WithdrawalCompleted,
+    WithdrawalInitiated,
+};
+use gateway_wallet::state::GatewayDeposit;
+
+fn events(metadata: &TransactionMetadata) -> Vec<GatewayEvent> {
+    metadata
+        .inner_instructions
+        .iter()
+        .filter_map(|inner| {
+            let accounts: Vec<_> = inner
+                .instruction
+                .accounts
+                .iter()
+                .map(|account| account.pubkey)
+                .collect();
+            decode_instruction(
+                &inner.instruction.program_id,
+                &accounts,
+                &inner.instruction.data,
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+fn deposited(key: DepositKey, value: u64) -> GatewayEvent {
+    GatewayEvent::Wallet(WalletEvent::Deposited(Deposited {
+        token: key.token,
+        depositor: key.depositor,
+        sender: key.depositor,
+        value,
+    }))
+}
+
+fn withdrawal_initiated(key: DepositKey, value: u64, balance: Balance) -> GatewayEvent {
+    GatewayEvent::Wallet(WalletEvent::WithdrawalInitiated(WithdrawalInitiated {
+        token: key.token,
+        depositor: key.depositor,
+        value,
+        remaining_available: balance.available_amount,
+        total_withdrawing: balance.withdrawing_amount,
+        withdrawal_block: 100,
+    }))
+}
+
+fn gateway_burned(key: DepositKey, value: u64, fee: u64, taken: Balance) -> GatewayEvent {
+    GatewayEvent::Wallet(WalletEvent::GatewayBurned(GatewayBurned {
+        token: key.token,
+        depositor: key.depositor,
+        transfer_spec_hash: [7; 32],
+        destination_domain: 6,
+        destination_recipient: [8; 32],
+        signer: key.depositor,
+        value,
+        fee,
+        from_available: taken.available_amount,
+        from_withdrawing: taken.withdrawing_amount,
+        fee_shares: vec![],
+    }))
+}
+
+fn balance(available_amount: u64, withdrawing_amount: u64) -> Balance {
+    Balance {
+        available_amount,
+        withdrawing_amount,
+    }
+}
+
+fn key() -> DepositKey {
+    DepositKey {
+        token: Pubkey::new_unique(),
+        depositor: Pubkey::new_unique(),
+    }
+}
+
+#[test]
+fn matches_the_program_accounts() {
+    let mut fixture = WalletFixture::new();
+    let mint = fixture.token_mint;
+    let mut emitted = Vec::new();
+
+    let (alice, alice_tokens) = fixture.funded_depositor(1_000);
+    let (bob, bob_tokens) = fixture.funded_depositor(500);
+    for (depositor, tokens, amount) in [(alice, alice_tokens, 1_000), (bob, bob_tokens, 500)] {
+        let metadata = fixture
+            .svm
+            .process(
+                &[wallet::deposit(
+                    fixture.payer,
+                    depositor,
+                    tokens,
+                    mint,
+                    amount,
+                )],
+                &[fixture.payer, depositor],
+            )
+            .unwrap();
+        emitted.extend(events(&metadata));
+    }
+
+    let metadata = fixture
+        .svm
+        .process(&[wallet::initiate_withdrawal(alice, mint, 400)], &[alice])
+        .unwrap();
+    emitted.extend(events(&metadata));
+    let slot = fixture.svm.clock().slot;
+    fixture.svm.warp_to_slot(slot + WITHDRAWAL_DELAY);
+    let metadata = fixture
+        .svm
+        .process(&[wallet::withdraw(alice, alice_tokens, mint)], &[alice])
+        .unwrap();
+    emitted.extend(events(&metadata));
+    let metadata = fixture
+        .svm
+        .process(&[wallet::initiate_withdrawal(bob, mint, 100)], &[bob])
+        .unwrap();
+    emitted.extend(events(&metadata));
+
+    let mut ledger = Ledger::new();
+    assert_eq!(ledger.apply_all(&emitted), []);
+    let alice_key = DepositKey {
+        token: mint,
+        depositor: alice,
+    };
+    assert_eq!(ledger.balance(&alice_key), balance(600, 0));
+
+    let deposits = [fixture.deposit(&alice), fixture.deposit(&bob)];
+    let custody = token_account(&fixture.svm, &pda::gateway_wallet_custody(&mint)).amount;
+    assert_eq!(custody, 1_100);
+    assert_eq!(ledger.check_deposits(&mint, &deposits), []);
+    assert_eq!(ledger.check_custody(&mint, custody), None);
+    assert_eq!(ledger.custody_surplus(&mint, custody), 0);
+
+    // A deposit account or custody balance that the events cannot explain
+    let mut tampered = fixture.deposit(&alice);
+    tampered.available_amount += 1;
+    assert_eq!(
+        ledger.check_deposits(&mint, [&tampered, &deposits[1]]),
+        [Divergence::Deposit {
+            key: alice_key,
+            replayed: balance(600, 0),
+            on_chain: Some(balance(601, 0)),
+        }]
+    );
+    assert_eq!(
+        ledger.check_custody(&mint, custody - 1),
+        Some(Divergence::Custody {
+            token: mint,
+            replayed_total: 1_100,
+            custody_balance: 1_099,
+        })
+    );
+    assert_eq!(ledger.custody_surplus(&mint, custody - 1), 0);
+
+    // Tokens sent to the custody token account directly are a surplus rather than a divergence
+    assert_eq!(ledger.check_custody(&mint, custody + 1), None);
+    assert_eq!(ledger.custody_surplus(&mint, custody + 1), 1);
+}
+
+#[test]
+fn replays_burns_like_reduce_balance() {
+    let key = key();
+    let mut ledger = Ledger::new();
+
+    let events = [
+        deposited(key, 100),
+        withdrawal_initiated(key, 60, balance(40, 60)),
+        // Takes all of the available balance, then from the withdrawing balance
+        gateway_burned(key, 50, 5, balance(40, 15)),
+        // Requests more than remains, so the burn is capped to the balance
+        GatewayEvent::Wallet(WalletEvent::InsufficientBalance(InsufficientBalance {
+            token: key.token,
+            depositor: key.depositor,
+            value: 100,
+            available_balance: 0,
+            withdrawing_balance: 45,
+        })),
+        gateway_burned(key, 45, 0, balance(0, 45)),
+    ];
+    assert_eq!(ledger.apply_all(&events), []);
+    assert_eq!(ledger.balance(&key), Balance::default());
+    assert_eq!(ledger.total(&key.token), 0);
+}
+
+#[test]
+fn flags_events_the_balances_cannot_produce() {
+    let key = key();
+    let mut ledger = Ledger::new();
+    ledger.apply_all(&[
+        deposited(key, 100),
+        withdrawal_initiated(key, 60, balance(40, 60)),
+    ]);
+
+    // Takes from the withdrawing balance while some is still available
+    assert_eq!(
+        ledger.apply(&gateway_burned(key, 50, 0, balance(10, 40))),
+        Some(Divergence::Event {
+            event_index: 2,
+            event: "GatewayBurned",
+            key,
+            expected: balance(40, 10),
+            reported: balance(10, 40),
+        })
+    );
+    // The ledger follows the event
+    assert_eq!(ledger.balance(&key), balance(30, 20));
+
+    // Pays out more than the withdrawing balance
+    let completed = GatewayEvent::Wallet(WalletEvent::WithdrawalCompleted(WithdrawalCompleted {
+        token: key.token,
+        depositor: key.depositor,
+        value: 25,
+    }));
+    assert_eq!(
+        ledger.apply(&completed),
+        Some(Divergence::Event {
+            event_index: 3,
+            event: "WithdrawalCompleted",
+            key,
+            expected: balance(30, 20),
+            reported: balance(30, 25),
+        })
+    );
+
+    // Withdraws more than is available
+    assert!(matches!(
+        ledger.apply(&withdrawal_initiated(key, 31, balance(0, 31))),
+        Some(Divergence::Event { event_index: 4, .. })
+    ));
+    assert_eq!(ledger.balance(&key), balance(0, 31));
+}
+
+#[test]
+fn flags_missing_and_unknown_deposit_accounts() {
+    let replayed = key();
+    let unknown = DepositKey {
+        token: replayed.token,
+        depositor: Pubkey::new_unique(),
+    };
+    let mut ledger = Ledger::new();
+    ledger.apply(&deposited(replayed, 10));
+
+    let deposit = GatewayDeposit {
+        bump: 255,
+        depositor: unknown.depositor,
+        token_mint: unknown.token,
+        available_amount: 5,
+        withdrawing_amount: 0,
+        withdrawal_block: 0,
+        frozen: false,
+    };
+    assert_eq!(
+        ledger.check_deposits(&replayed.token, [&deposit]),
+        [
+            Divergence::Deposit {
+                key: replayed,
+                replayed: balance(10, 0),
+                on_chain: None,
+            },
+            Divergence::Deposit {
+                key: unknown,
+                replayed: Balance::default(),
+                on_chain: Some(balance(5, 0)),
+            },
+        ]
+    );
+}
+
+#[test]
+fn replays_balance_transfers() {
+    let from = key();
+    let to = DepositKey {
+        token: from.token,
+        depositor: Pubkey::new_unique(),
+    };
+    let transferred = |value| {
+        GatewayEvent::Wallet(WalletEvent::BalanceTransferred(BalanceTransferred {
+            token: from.token,
+            depositor: from.depositor,
+            recipient: to.depositor,
+            signer: from.depositor,
+            value,
+        }))
+    };
+    let mut ledger = Ledger::new();
+
+    assert_eq!(
+        ledger.apply_all(&[
+            deposited(from, 100),
+            withdrawal_initiated(from, 30, balance(70, 30)),
+            transferred(70),
+        ]),
+        []
+    );
+    assert_eq!(ledger.balance(&from), balance(0, 30));
+    assert_eq!(ledger.balance(&to), balance(70, 0));
+    assert_eq!(ledger.total(&from.token), 100);
+
+    // Transfers more than is available
+    assert_eq!(
+        ledger.apply(&transferred(10)),
+        Some(Divergence::Event {
+            event_index: 3,
+            event: "BalanceTransferred",
+            key: from,
+            expected: balance(0, 30),
+            reported: balance(10, 30),
+        })
+    );
+}
@@ -0,0 +1,272 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Programs available in the SVM and the natively implemented builtins
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{ed25519_program, entrypoint::ProgramResult},
+    system_program,
+};
+use curve25519_dalek::{edwards::CompressedEdwardsY, EdwardsPoint, Scalar};
+use sha2::{Digest, Sha512};
+
+/// A program entrypoint
+pub type Processor = fn(&Pubkey, &[AccountInfo], &[u8]) -> ProgramResult;
+
+/// The builtin programs, which are owned by the native loader
+pub const NATIVE_PROGRAMS: [Pubkey; 4] = [
+    system_program::ID,
+    ed25519_program::ID,
+    spl_token::ID,
+    spl_associated_token_account::ID,
+];
+
+/// Returns the entrypoint of the program, if it can be invoked
+pub fn processor(program_id: &Pubkey) -> Option<Processor> {
+    let processor: Processor = if *program_id == gateway_wallet::ID {
+        gateway_wallet_entry
+    } else if *program_id == gateway_minter::ID {
+        gateway_minter_entry
+    } else if *program_id == system_program::ID {
+        process_system_instruction
+    } else if *program_id == spl_token::ID {
+        spl_token::processor::Processor::process
+    } else if *program_id == spl_associated_token_account::ID {
+        spl_associated_token_account::processor::process_instruction
+    } else {
+        return None;
+    };
+    Some(processor)
+}
+
+// Anchor entrypoints borrow the account infos for the lifetime of the accounts, so the infos are
+// leaked to extend them from the scope of the invocation
+fn gateway_wallet_entry<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts: &'a [AccountInfo<'a>] = Box::leak(accounts.to_vec().into_boxed_slice());
+    gateway_wallet::entry(program_id, accounts, data)
+}
+
+fn gateway_minter_entry<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts: &'a [AccountInfo<'a>] = Box::leak(accounts.to_vec().into_boxed_slice());
+    gateway_minter::entry(program_id, accounts, data)
+}
+
+// `SystemError` codes
+const ACCOUNT_ALREADY_IN_USE: u32 = 0;
+const RESULT_WITH_NEGATIVE_LAMPORTS: u32 = 1;
+
+// The most an account can be allocated by the system program
+const MAX_PERMITTED_DATA_LENGTH: u64 = 10 * 1024 * 1024;
+
+fn read_u64(data: &[u8], offset: usize) -> std::result::Result<u64, ProgramError> {
+    data.get(offset..offset + 8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> std::result::Result<Pubkey, ProgramError> {
+    data.get(offset..offset + 32)
+        .map(|bytes| Pubkey::try_from(bytes).unwrap())
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+fn account_at<'a, 'b>(
+    accounts: &'b [AccountInfo<'a>],
+    index: usize,
+) -> std::result::Result<&'b AccountInfo<'a>, ProgramError> {
+    accounts
+        .get(index)
+        .ok_or(ProgramError::NotEnoughAccountKeys)
+}
+
+/// The system program instructions used by the Gateway programs and their dependencies
+fn process_system_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let tag = data
+        .get(..4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    match tag {
+        // CreateAccount
+        0 => {
+            let lamports = read_u64(data, 4)?;
+            let space = read_u64(data, 12)?;
+            let owner = read_pubkey(data, 20)?;
+            let from = account_at(accounts, 0)?;
+            let to = account_at(accounts, 1)?;
+            if to.lamports() > 0 {
+                return Err(ProgramError::Custom(ACCOUNT_ALREADY_IN_USE));
+            }
+            allocate(to, space)?;
+            assign(to, &owner)?;
+            transfer(from, to, lamports)
+        }
+        // Assign
+        1 => assign(account_at(accounts, 0)?, &read_pubkey(data, 4)?),
+        // Transfer
+        2 => transfer(
+            account_at(accounts, 0)?,
+            account_at(accounts, 1)?,
+            read_u64(data, 4)?,
+        ),
+        // Allocate
+        8 => allocate(account_at(accounts, 0)?, read_u64(data, 4)?),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+fn allocate(account: &AccountInfo, space: u64) -> ProgramResult {
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !account.data_is_empty() || *account.owner != system_program::ID {
+        return Err(ProgramError::Custom(ACCOUNT_ALREADY_IN_USE));
+    }
+    if space > MAX_PERMITTED_DATA_LENGTH {
+        return Err(ProgramError::InvalidArgument);
+    }
+    account.realloc(space as usize, true)
+}
+
+fn assign(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner == owner {
+        return Ok(());
+    }
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if *account.owner != system_program::ID {
+        return Err(ProgramError::IllegalOwner);
+    }
+    account.assign(owner);
+    Ok(())
+}
+
+fn transfer(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    if !from.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !from.data_is_empty() || *from.owner != system_program::ID {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if from.lamports() < lamports {
+        return Err(ProgramError::Custom(RESULT_WITH_NEGATIVE_LAMPORTS));
+    }
+    **from.try_borrow_mut_lamports()? -= lamports;
+    **to.try_borrow_mut_lamports()? += lamports;
+    Ok(())
+}
+
+// `PrecompileError` codes
+const INVALID_PUBLIC_KEY: u32 = 0;
+const INVALID_SIGNATURE: u32 = 2;
+const INVALID_DATA_OFFSETS: u32 = 3;
+const INVALID_INSTRUCTION_DATA_SIZE: u32 = 4;
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+/// Verifies the signatures of an Ed25519 precompile instruction, given the data of every
+/// instruction in the transaction
+pub fn verify_ed25519(data: &[u8], instruction_datas: &[&[u8]]) -> ProgramResult {
+    if data.len() < SIGNATURE_OFFSETS_START {
+        return Err(ProgramError::Custom(INVALID_INSTRUCTION_DATA_SIZE));
+    }
+    let num_signatures = data[0] as usize;
+    if num_signatures == 0 && data.len() > SIGNATURE_OFFSETS_START {
+        return Err(ProgramError::Custom(INVALID_INSTRUCTION_DATA_SIZE));
+    }
+    if data.len() < SIGNATURE_OFFSETS_START + num_signatures * SIGNATURE_OFFSETS_SIZE {
+        return Err(ProgramError::Custom(INVALID_INSTRUCTION_DATA_SIZE));
+    }
+
+    for i in 0..num_signatures {
+        let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_SIZE;
+        let offsets: Vec<u16> = data[start..start + SIGNATURE_OFFSETS_SIZE]
+            .chunks_exact(2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+            .collect();
+        let slice = |instruction_index: u16, offset: u16, size: usize| {
+            let instruction = if instruction_index == u16::MAX {
+                data
+            } else {
+                instruction_datas
+                    .get(instruction_index as usize)
+                    .ok_or(ProgramError::Custom(INVALID_DATA_OFFSETS))?
+            };
+            instruction
+                .get(offset as usize..offset as usize + size)
+                .ok_or(ProgramError::Custom(INVALID_DATA_OFFSETS))
+        };
+
+        let signature = slice(offsets[1], offsets[0], 64)?;
+        let public_key = slice(offsets[3], offsets[2], 32)?;
+        let message = slice(offsets[6], offsets[4], offsets[5] as usize)?;
+        verify_strict(
+            public_key.try_into().unwrap(),
+            signature.try_into().unwrap(),
+            message,
+        )?;
+    }
+    Ok(())
+}
+
+// Verifies an Ed25519 signature, rejecting non-canonical and small order encodings
+fn verify_strict(public_key: &[u8; 32], signature: &[u8; 64], message: &[u8]) -> ProgramResult {
+    let a = CompressedEdwardsY(*public_key)
+        .decompress()
+        .filter(|a| !a.is_small_order())
+        .ok_or(ProgramError::Custom(INVALID_PUBLIC_KEY))?;
+    let r_bytes: [u8; 32] = signature[..32].try_into().unwrap();
+    let r = CompressedEdwardsY(r_bytes)
+        .decompress()
+        .filter(|r| !r.is_small_order())
+        .ok_or(ProgramError::Custom(INVALID_SIGNATURE))?;
+    let s: Option<Scalar> =
+        Scalar::from_canonical_bytes(signature[32..].try_into().unwrap()).into();
+    let s = s.ok_or(ProgramError::Custom(INVALID_SIGNATURE))?;
+
+    let k = challenge(&r_bytes, public_key, message);
+    let expected_r = EdwardsPoint::vartime_double_scalar_mul_basepoint(&k, &-a, &s);
+    if expected_r != r {
+        return Err(ProgramError::Custom(INVALID_SIGNATURE));
+    }
+    Ok(())
+}
+
+/// Returns the RFC 8032 challenge scalar `SHA512(R || A || M)`
+pub(crate) fn challenge(r: &[u8; 32], public_key: &[u8; 32], message: &[u8]) -> Scalar {
+    let hash = Sha512::new()
+        .chain_update(r)
+        .chain_update(public_key)
+        .chain_update(message)
+        .finalize();
+    Scalar::from_bytes_mod_order_wide(&hash.into())
+}
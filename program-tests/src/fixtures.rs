@@ -0,0 +1,360 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Common account setup for the tests
+
+use anchor_lang::{prelude::*, solana_program::program_pack::Pack};
+use anchor_spl::associated_token::get_associated_token_address;
+use gateway_client::{minter, wallet};
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+
+use crate::{Account, Svm, TransactionError};
+
+/// The domain of the chain in the tests
+pub const LOCAL_DOMAIN: u32 = 5;
+
+/// The withdrawal delay of the GatewayWallet in the tests, in slots
+pub const WITHDRAWAL_DELAY: u64 = 10;
+
+/// The lamports given to every payer
+pub const PAYER_LAMPORTS: u64 = 100_000_000_000;
+
+fn set_packed<T: Pack>(svm: &mut Svm, address: Pubkey, state: T) {
+    let mut data = vec![0; T::LEN];
+    T::pack(state, &mut data).unwrap();
+    svm.set_account(address, Account::new_rent_exempt(data, spl_token::ID));
+}
+
+/// Creates a token mint with 6 decimals
+pub fn create_mint(svm: &mut Svm, mint_authority: &Pubkey) -> Pubkey {
+    let mint = Pubkey::new_unique();
+    set_packed(
+        svm,
+        mint,
+        Mint {
+            mint_authority: Some(*mint_authority).into(),
+            supply: 0,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: None.into(),
+        },
+    );
+    mint
+}
+
+/// Sets the token account at `address` to hold `amount` of `mint`, adding it to the mint supply
+pub fn set_token_account(
+    svm: &mut Svm,
+    address: Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) {
+    let mut mint_state = Mint::unpack(&svm.get_account(mint).unwrap().data).unwrap();
+    mint_state.supply += amount;
+    set_packed(svm, *mint, mint_state);
+
+    set_packed(
+        svm,
+        address,
+        TokenAccount {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            delegate: None.into(),
+            state: AccountState::Initialized,
+            is_native: None.into(),
+            delegated_amount: 0,
+            close_authority: None.into(),
+        },
+    );
+}
+
+/// Creates the associated token account of `owner` holding `amount` of `mint`
+pub fn create_associated_token_account(
+    svm: &mut Svm,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let address = get_associated_token_address(owner, mint);
+    set_token_account(svm, address, mint, owner, amount);
+    address
+}
+
+/// Returns the state of the token account at `address`
+pub fn token_account(svm: &Svm, address: &Pubkey) -> TokenAccount {
+    TokenAccount::unpack(&svm.get_account(address).expect("token account exists").data).unwrap()
+}
+
+/// Returns the supply of `mint`
+pub fn mint_supply(svm: &Svm, mint: &Pubkey) -> u64 {
+    Mint::unpack(&svm.get_account(mint).expect("mint exists").data)
+        .unwrap()
+        .supply
+}
+
+/// An initialized GatewayWallet with one supported token
+pub struct WalletFixture {
+    pub svm: Svm,
+    /// The upgrade authority, which is every role after initialization
+    pub owner: Pubkey,
+    pub payer: Pubkey,
+    pub token_mint: Pubkey,
+}
+
+impl WalletFixture {
+    pub fn new() -> Self {
+        let mut svm = Svm::new();
+        let owner = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        svm.set_upgradeable_program(gateway_wallet::ID, Some(owner));
+        svm.airdrop(&owner, PAYER_LAMPORTS);
+        svm.airdrop(&payer, PAYER_LAMPORTS);
+
+        svm.process(
+            &[wallet::initialize(
+                payer,
+                owner,
+                gateway_wallet::instructions::InitializeParams {
+                    local_domain: LOCAL_DOMAIN,
+                    withdrawal_delay: WITHDRAWAL_DELAY,
+                },
+            )],
+            &[payer, owner],
+        )
+        .expect("initialize succeeds");
+
+        let token_mint = create_mint(&mut svm, &Pubkey::new_unique());
+        svm.process(
+            &[wallet::add_token(payer, owner, token_mint)],
+            &[payer, owner],
+        )
+        .expect("add_token succeeds");
+
+        Self {
+            svm,
+            owner,
+            payer,
+            token_mint,
+        }
+    }
+
+    /// Returns the GatewayWallet state
+    pub fn state(&self) -> gateway_wallet::state::GatewayWallet {
+        self.svm
+            .get_anchor_account(&gateway_client::pda::gateway_wallet())
+            .expect("gateway wallet exists")
+    }
+
+    /// Returns a new depositor and its token account, holding `amount` of the token
+    pub fn funded_depositor(&mut self, amount: u64) -> (Pubkey, Pubkey) {
+        let depositor = Pubkey::new_unique();
+        self.svm.airdrop(&depositor, PAYER_LAMPORTS);
+        let token_account =
+            create_associated_token_account(&mut self.svm, &self.token_mint, &depositor, amount);
+        (depositor, token_account)
+    }
+
+    /// Returns a new depositor with `amount` of the token deposited
+    pub fn depositor(&mut self, amount: u64) -> Pubkey {
+        let (depositor, token_account) = self.funded_depositor(amount);
+        self.svm
+            .process(
+                &[wallet::deposit(
+                    self.payer,
+                    depositor,
+                    token_account,
+                    self.token_mint,
+                    amount,
+                )],
+                &[self.payer, depositor],
+            )
+            .expect("deposit succeeds");
+        depositor
+    }
+
+    /// Adds `account` to the denylist
+    pub fn denylist(&mut self, account: Pubkey) {
+        self.svm
+            .process(
+                &[wallet::denylist(
+                    self.payer,
+                    self.owner,
+                    gateway_wallet::instructions::DenylistParams { account },
+                )],
+                &[self.payer, self.owner],
+            )
+            .expect("denylist succeeds");
+    }
+
+    /// Starts a withdrawal of `amount` from the deposit of `depositor`
+    pub fn initiate_withdrawal(&mut self, depositor: Pubkey, amount: u64) {
+        self.svm
+            .process(
+                &[wallet::initiate_withdrawal(
+                    depositor,
+                    self.token_mint,
+                    amount,
+                )],
+                &[depositor],
+            )
+            .expect("initiate_withdrawal succeeds");
+    }
+
+    /// Returns the deposit of `depositor`
+    pub fn deposit(&self, depositor: &Pubkey) -> gateway_wallet::state::GatewayDeposit {
+        self.svm
+            .get_anchor_account(&gateway_client::pda::gateway_deposit(
+                &self.token_mint,
+                depositor,
+            ))
+            .expect("deposit exists")
+    }
+
+    /// Shrinks the deposit of `depositor` to the layout deposits were created with before they
+    /// could be frozen
+    pub fn set_legacy_deposit(&mut self, depositor: &Pubkey) {
+        let address = gateway_client::pda::gateway_deposit(&self.token_mint, depositor);
+        let mut account = self
+            .svm
+            .get_account(&address)
+            .expect("deposit exists")
+            .clone();
+        account.data.truncate(account.data.len() - 1);
+        account.lamports = Rent::default().minimum_balance(account.data.len());
+        self.svm.set_account(address, account);
+    }
+
+    /// Removes the deposit totals of the token, as for a token supported before the totals existed
+    pub fn remove_token_totals(&mut self) {
+        self.svm.set_account(
+            gateway_client::pda::token_totals(&self.token_mint),
+            Account::default(),
+        );
+    }
+
+    /// Returns the deposit totals of the token
+    pub fn total_deposits(&self) -> u64 {
+        self.svm
+            .get_anchor_account::<gateway_wallet::state::TokenTotals>(
+                &gateway_client::pda::token_totals(&self.token_mint),
+            )
+            .expect("token totals exist")
+            .total_deposits
+    }
+}
+
+impl Default for WalletFixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An initialized GatewayMinter with one supported token, whose mint authority is the GatewayMinter PDA
+pub struct MinterFixture {
+    pub svm: Svm,
+    /// The upgrade authority, which is every role after initialization
+    pub owner: Pubkey,
+    pub payer: Pubkey,
+    pub token_mint: Pubkey,
+}
+
+impl MinterFixture {
+    pub fn new() -> Self {
+        let mut svm = Svm::new();
+        let owner = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        svm.set_upgradeable_program(gateway_minter::ID, Some(owner));
+        svm.airdrop(&owner, PAYER_LAMPORTS);
+        svm.airdrop(&payer, PAYER_LAMPORTS);
+
+        svm.process(
+            &[minter::initialize(
+                payer,
+                owner,
+                gateway_minter::instructions::InitializeParams {
+                    local_domain: LOCAL_DOMAIN,
+                },
+            )],
+            &[payer, owner],
+        )
+        .expect("initialize succeeds");
+
+        let token_mint = create_mint(&mut svm, &gateway_client::pda::gateway_minter());
+        svm.process(
+            &[minter::add_token(payer, owner, token_mint)],
+            &[payer, owner],
+        )
+        .expect("add_token succeeds");
+
+        Self {
+            svm,
+            owner,
+            payer,
+            token_mint,
+        }
+    }
+
+    /// Returns the GatewayMinter state
+    pub fn state(&self) -> gateway_minter::state::GatewayMinter {
+        self.svm
+            .get_anchor_account(&gateway_client::pda::gateway_minter())
+            .expect("gateway minter exists")
+    }
+
+    /// Returns the minting config of `token_mint`, if it was configured
+    pub fn token_config(&self, token_mint: &Pubkey) -> Option<gateway_minter::state::TokenConfig> {
+        self.svm
+            .get_anchor_account(&gateway_client::pda::token_config(token_mint))
+    }
+
+    /// Sets the balance of the token's custody token account to `amount`
+    pub fn fund_custody(&mut self, amount: u64) {
+        set_token_account(
+            &mut self.svm,
+            gateway_client::pda::gateway_minter_custody(&self.token_mint),
+            &self.token_mint,
+            &gateway_client::pda::gateway_minter(),
+            amount,
+        );
+    }
+}
+
+impl Default for MinterFixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Asserts that a transaction failed with the custom program error `error`
+#[track_caller]
+pub fn assert_error<T: std::fmt::Debug>(
+    result: std::result::Result<T, TransactionError>,
+    error: impl Into<u32>,
+) {
+    let error = error.into();
+    match result {
+        Err(transaction_error) => assert_eq!(
+            transaction_error.custom_error(),
+            Some(error),
+            "expected custom error {error}, got {transaction_error:?}"
+        ),
+        Ok(metadata) => panic!("expected custom error {error}, got success: {metadata:?}"),
+    }
+}
@@ -0,0 +1,147 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+//! Proptest strategies for the message parser property tests
+//!
+//! Proptest shrinks a failing case to a minimal input and records it under
+//! `proptest-regressions/`, so that it is replayed first on the next run. Setting
+//! `PROPTEST_CASES` runs more cases than the default.
+
+use std::env;
+
+use proptest::{collection::vec, prelude::*, sample::Index, test_runner::Config};
+
+/// The number of cases run for each property, unless `PROPTEST_CASES` is set
+pub const DEFAULT_CASES: u32 = 2_000;
+
+/// Returns the proptest config of the parser property tests
+pub fn config() -> Config {
+    let mut config = Config::default();
+    if env::var("PROPTEST_CASES").is_err() {
+        config.cases = DEFAULT_CASES;
+    }
+    config
+}
+
+/// Up to `max_length` arbitrary bytes
+pub fn bytes(max_length: usize) -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), 0..=max_length)
+}
+
+/// A u64 that is often at or near a boundary
+pub fn u64() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        1 => Just(0),
+        1 => u64::MAX - 1..=u64::MAX,
+        2 => any::<u64>(),
+    ]
+}
+
+/// A value written over a big-endian u32 length field
+#[derive(Clone, Debug)]
+pub enum LengthOverwrite {
+    Above(u32),
+    Below(u32),
+    NearMax(u32),
+    Arbitrary(u32),
+}
+
+/// A mutation of encoded message bytes
+#[derive(Clone, Debug)]
+pub enum Mutation {
+    /// Flip the bits of a byte
+    FlipBits { index: Index, mask: u8 },
+    /// Truncate
+    Truncate { length: Index },
+    /// Append bytes
+    Append { bytes: Vec<u8> },
+    /// Remove a range of bytes
+    Remove { start: Index, length: usize },
+    /// Insert bytes
+    Insert { index: Index, bytes: Vec<u8> },
+    /// Overwrite a length field
+    OverwriteLength {
+        field: Index,
+        length: LengthOverwrite,
+    },
+}
+
+fn length_overwrite() -> impl Strategy<Value = LengthOverwrite> {
+    prop_oneof![
+        (1..=8u32).prop_map(LengthOverwrite::Above),
+        (1..=8u32).prop_map(LengthOverwrite::Below),
+        (0..8u32).prop_map(LengthOverwrite::NearMax),
+        any::<u32>().prop_map(LengthOverwrite::Arbitrary),
+    ]
+}
+
+/// Between one and four mutations, to be applied with `mutate`
+pub fn mutations() -> impl Strategy<Value = Vec<Mutation>> {
+    let mutation = prop_oneof![
+        (any::<Index>(), 1..=u8::MAX).prop_map(|(index, mask)| Mutation::FlipBits { index, mask }),
+        any::<Index>().prop_map(|length| Mutation::Truncate { length }),
+        bytes(64).prop_map(|bytes| Mutation::Append { bytes }),
+        (any::<Index>(), 0..=64usize)
+            .prop_map(|(start, length)| Mutation::Remove { start, length }),
+        (any::<Index>(), bytes(64)).prop_map(|(index, bytes)| Mutation::Insert { index, bytes }),
+        (any::<Index>(), length_overwrite())
+            .prop_map(|(field, length)| Mutation::OverwriteLength { field, length }),
+    ];
+    vec(mutation, 1..=4)
+}
+
+/// Applies the mutations to `data`.
+///
+/// `length_fields` are the offsets of the big-endian u32 length fields in `data`. Overwriting
+/// them with values near the actual lengths reaches the parser's length checks far more often
+/// than random bytes do.
+pub fn mutate(data: &mut Vec<u8>, mutations: &[Mutation], length_fields: &[usize]) {
+    for mutation in mutations {
+        match mutation {
+            Mutation::FlipBits { index, mask } if !data.is_empty() => {
+                let index = index.index(data.len());
+                data[index] ^= mask;
+            }
+            Mutation::Truncate { length } => data.truncate(length.index(data.len() + 1)),
+            Mutation::Append { bytes } => data.extend_from_slice(bytes),
+            Mutation::Remove { start, length } if !data.is_empty() => {
+                let start = start.index(data.len());
+                let end = data.len().min(start + length);
+                data.drain(start..end);
+            }
+            Mutation::Insert { index, bytes } => {
+                let index = index.index(data.len() + 1);
+                data.splice(index..index, bytes.iter().copied());
+            }
+            Mutation::OverwriteLength { field, length } if !length_fields.is_empty() => {
+                let offset = length_fields[field.index(length_fields.len())];
+                let Some(field) = data.get_mut(offset..offset + 4) else {
+                    continue;
+                };
+                let current = u32::from_be_bytes(field.try_into().unwrap());
+                let length = match length {
+                    LengthOverwrite::Above(delta) => current.wrapping_add(*delta),
+                    LengthOverwrite::Below(delta) => current.wrapping_sub(*delta),
+                    LengthOverwrite::NearMax(delta) => u32::MAX - delta,
+                    LengthOverwrite::Arbitrary(length) => *length,
+                };
+                field.copy_from_slice(&length.to_be_bytes());
+            }
+            _ => {}
+        }
+    }
+}
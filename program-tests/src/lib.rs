@@ -0,0 +1,56 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! In-process SVM for testing the Gateway programs
+//!
+//! The programs are compiled natively and run against account buffers serialized exactly as the
+//! runtime serializes them for an SBF program. Syscalls are provided through `solana_program`'s
+//! syscall stubs, so cross-program invocations, sysvars, and return data behave as on-chain. The
+//! system program, the SPL token and associated token account programs, and the Ed25519
+//! precompile are provided natively, which lets the tests run offline with plain `cargo test`.
+//!
+//! # Scope
+//!
+//! This is not a full SVM. LiteSVM and `solana-program-test` both need an SBF build of the
+//! programs and crates built against the same Solana version as Anchor, neither of which is
+//! available to the offline Rust build. The SVM here models the parts of the runtime the programs
+//! rely on for their behavior:
+//!
+//! - atomic transactions, signer and writable flags merged across instructions, and the
+//!   instructions sysvar
+//! - the privileges passed on through cross-program invocations, and PDA signatures
+//! - the account rules: only writable accounts change and lamports are balanced across each
+//!   instruction, and only the owner of an account changes its data or owner or debits it, which
+//!   is checked around each invocation; accounts are only assigned once their data is cleared
+//! - rent-exempt account balances when a transaction is committed
+//!
+//! It does not model compute units, stack and heap limits, transaction size limits or account
+//! data limits beyond reallocation, and the programs run as native code rather than SBF. Those
+//! are covered by the TypeScript suites under `tests/`, which run the compiled programs in
+//! LiteSVM; the integration suites under `tests/integration` exercise deposits, burns and mints
+//! end to end there.
+
+pub mod builtins;
+pub mod fixtures;
+pub mod fuzz;
+pub mod signers;
+pub mod svm;
+
+mod stubs;
+
+pub use svm::{Account, InnerInstruction, Svm, TransactionError, TransactionMetadata};
@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Ed25519 and EVM signers for the tests
+
+use anchor_lang::{prelude::Pubkey, solana_program::keccak::hash};
+use gateway_shared::ethereum_signed_message_hash;
+use libsecp256k1::{Message, PublicKey, SecretKey};
+
+pub use gateway_client::keypair::Keypair;
+
+/// A secp256k1 key that signs EIP-191 messages, like the attestation and burn signers
+pub struct EvmSigner {
+    secret_key: SecretKey,
+    address: Pubkey,
+}
+
+impl EvmSigner {
+    pub fn new(secret: [u8; 32]) -> Self {
+        let secret_key = SecretKey::parse(&secret).expect("secret is a valid key");
+        let public_key = PublicKey::from_secret_key(&secret_key).serialize();
+        let mut address = hash(&public_key[1..]).0;
+        address[..12].fill(0);
+        Self {
+            secret_key,
+            address: Pubkey::new_from_array(address),
+        }
+    }
+
+    /// Returns the EVM address of the signer, left-padded to 32 bytes
+    pub fn address(&self) -> Pubkey {
+        self.address
+    }
+
+    /// Returns the 65-byte signature over the EIP-191 hash of `keccak256(message)`
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        let eth_signed_hash = ethereum_signed_message_hash(&hash(message).0);
+        let (signature, recovery_id) =
+            libsecp256k1::sign(&Message::parse(&eth_signed_hash), &self.secret_key);
+
+        let mut bytes = signature.serialize().to_vec();
+        bytes.push(recovery_id.serialize() + 27);
+        bytes
+    }
+}
@@ -0,0 +1,271 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Syscall stubs that run cross-program invocations and serve sysvars in-process
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::Once};
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        entrypoint::ProgramResult, instruction::Instruction, program_stubs::SyscallStubs,
+    },
+};
+
+use crate::{
+    builtins,
+    svm::{InnerInstruction, TransactionMetadata},
+};
+
+// The state of an account that the program being run may only change as its owner allows
+#[derive(PartialEq)]
+struct AccountState {
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+}
+
+impl AccountState {
+    fn new(info: &AccountInfo) -> Self {
+        Self {
+            lamports: info.lamports(),
+            data: info.data.borrow().to_vec(),
+            owner: *info.owner,
+        }
+    }
+}
+
+// A program being run, with the state of its accounts as of its last cross-program invocation
+struct Frame {
+    program_id: Pubkey,
+    accounts: HashMap<Pubkey, AccountState>,
+}
+
+// The state of the transaction being processed on the current thread
+#[derive(Default)]
+struct InvokeContext {
+    clock: Clock,
+    instruction_index: usize,
+    program_stack: Vec<Frame>,
+    metadata: TransactionMetadata,
+    // The account a program changed without owning it
+    external_account_modified: Option<Pubkey>,
+}
+
+thread_local! {
+    static CONTEXT: RefCell<InvokeContext> = RefCell::new(InvokeContext::default());
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let (caller, instruction_index, stack_height) = CONTEXT.with_borrow(|context| {
+            (
+                context
+                    .program_stack
+                    .last()
+                    .expect("invoked from a program")
+                    .program_id,
+                context.instruction_index,
+                context.program_stack.len() + 1,
+            )
+        });
+
+        // The caller's changes so far are checked before the callee can see them
+        verify_account_changes(account_infos)?;
+
+        let signers = signers_seeds
+            .iter()
+            .map(|seeds| Pubkey::create_program_address(seeds, &caller))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut callee_infos: Vec<AccountInfo> = Vec::with_capacity(instruction.accounts.len());
+        for meta in &instruction.accounts {
+            let caller_info = account_infos
+                .iter()
+                .find(|info| *info.key == meta.pubkey)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            // A program can only pass on the privileges it was given, plus signatures for its PDAs
+            if meta.is_writable && !caller_info.is_writable {
+                return Err(ProgramError::InvalidArgument);
+            }
+            if meta.is_signer && !caller_info.is_signer && !signers.contains(&meta.pubkey) {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            callee_infos.push(AccountInfo {
+                key: caller_info.key,
+                lamports: Rc::clone(&caller_info.lamports),
+                data: Rc::clone(&caller_info.data),
+                owner: caller_info.owner,
+                rent_epoch: caller_info.rent_epoch,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+                executable: caller_info.executable,
+            });
+        }
+
+        CONTEXT.with_borrow_mut(|context| {
+            context.metadata.inner_instructions.push(InnerInstruction {
+                instruction_index,
+                stack_height,
+                instruction: instruction.clone(),
+            })
+        });
+        execute(&instruction.program_id, &callee_infos, &instruction.data)?;
+
+        // The callee's changes are not the caller's own
+        CONTEXT.with_borrow_mut(|context| {
+            let frame = context
+                .program_stack
+                .last_mut()
+                .expect("invoked from a program");
+            for info in account_infos {
+                frame.accounts.insert(*info.key, AccountState::new(info));
+            }
+        });
+        Ok(())
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = CONTEXT.with_borrow(|context| context.clock.clone());
+        // SAFETY: the caller passes a pointer to a `Clock`
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        // SAFETY: the caller passes a pointer to a `Rent`
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        0
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        CONTEXT.with_borrow(|context| context.metadata.return_data.clone())
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        CONTEXT.with_borrow_mut(|context| {
+            let program_id = context
+                .program_stack
+                .last()
+                .expect("set by a program")
+                .program_id;
+            context.metadata.return_data = (!data.is_empty()).then(|| (program_id, data.to_vec()));
+        })
+    }
+
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        CONTEXT.with_borrow_mut(|context| context.metadata.data_logs.push(fields.concat()));
+    }
+
+    fn sol_get_stack_height(&self) -> u64 {
+        CONTEXT.with_borrow(|context| context.program_stack.len() as u64)
+    }
+}
+
+// Runs a program with the current invocation pushed onto the program stack
+fn execute(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let processor = builtins::processor(program_id).ok_or(ProgramError::IncorrectProgramId)?;
+    CONTEXT.with_borrow_mut(|context| {
+        context.program_stack.push(Frame {
+            program_id: *program_id,
+            accounts: accounts
+                .iter()
+                .map(|info| (*info.key, AccountState::new(info)))
+                .collect(),
+        })
+    });
+    let result =
+        processor(program_id, accounts, data).and_then(|()| verify_account_changes(accounts));
+    CONTEXT.with_borrow_mut(|context| context.program_stack.pop());
+    result
+}
+
+// Checks that the running program only changed accounts as the runtime allows: only the owner of
+// an account can change its data or owner or debit its lamports, and an account can only be
+// assigned once its data is cleared
+fn verify_account_changes(accounts: &[AccountInfo]) -> ProgramResult {
+    CONTEXT.with_borrow_mut(|context| {
+        let frame = context.program_stack.last().expect("run by a program");
+        let modified = accounts.iter().find(|info| {
+            let Some(pre) = frame.accounts.get(info.key) else {
+                return false;
+            };
+            let post = AccountState::new(info);
+            let allowed = if pre.owner == frame.program_id {
+                post.owner == pre.owner || post.data.iter().all(|byte| *byte == 0)
+            } else {
+                post.owner == pre.owner && post.data == pre.data && post.lamports >= pre.lamports
+            };
+            !allowed
+        });
+        match modified {
+            Some(info) => {
+                context.external_account_modified = Some(*info.key);
+                Err(ProgramError::IllegalOwner)
+            }
+            None => Ok(()),
+        }
+    })
+}
+
+/// Installs the syscall stubs, once per process
+pub(crate) fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        anchor_lang::solana_program::program_stubs::set_syscall_stubs(Box::new(Stubs));
+    });
+}
+
+/// Resets the invoke context of the current thread for a new transaction
+pub(crate) fn begin_transaction(clock: Clock) {
+    CONTEXT.set(InvokeContext {
+        clock,
+        ..InvokeContext::default()
+    });
+}
+
+/// Returns the account a program changed without owning it, if it failed the transaction
+pub(crate) fn external_account_modified() -> Option<Pubkey> {
+    CONTEXT.with_borrow(|context| context.external_account_modified)
+}
+
+/// Returns the metadata collected since `begin_transaction`
+pub(crate) fn end_transaction() -> TransactionMetadata {
+    CONTEXT.take().metadata
+}
+
+/// Runs a transaction instruction
+pub(crate) fn process_instruction(
+    instruction_index: usize,
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    CONTEXT.with_borrow_mut(|context| {
+        context.instruction_index = instruction_index;
+        context.metadata.return_data = None;
+    });
+    execute(program_id, accounts, data)
+}
@@ -0,0 +1,564 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Account store and transaction processing
+
+use std::collections::{HashMap, HashSet};
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        bpf_loader_upgradeable, ed25519_program,
+        entrypoint::{deserialize, BPF_ALIGN_OF_U128, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER},
+        instruction::Instruction,
+        pubkey, sysvar,
+    },
+    system_program, Event,
+};
+use solana_instruction::{BorrowedAccountMeta, BorrowedInstruction};
+use solana_instructions_sysvar::{construct_instructions_data, store_current_index_checked};
+
+use crate::{builtins, stubs};
+
+/// The owner of the builtin programs
+pub const NATIVE_LOADER_ID: Pubkey = pubkey!("NativeLoader1111111111111111111111111111111");
+
+/// An account in the SVM
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Account {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+impl Account {
+    /// Returns a rent-exempt account holding `data`
+    pub fn new_rent_exempt(data: Vec<u8>, owner: Pubkey) -> Self {
+        Self {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner,
+            executable: false,
+        }
+    }
+}
+
+/// The reason a transaction failed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionError {
+    /// An account marked as a signer did not sign the transaction
+    MissingSignature(Pubkey),
+    /// The instruction at the index failed
+    InstructionError(usize, ProgramError),
+    /// The instruction at the index changed the total lamports of its accounts
+    UnbalancedInstruction(usize),
+    /// The instruction at the index modified an account that was not passed as writable
+    ReadonlyAccountModified(usize, Pubkey),
+    /// The instruction at the index changed the data or owner of an account, or debited its
+    /// lamports, from a program that does not own it
+    ExternalAccountModified(usize, Pubkey),
+    /// The transaction left an account with lamports but not rent-exempt, which it was not before
+    InsufficientFundsForRent(Pubkey),
+}
+
+impl TransactionError {
+    /// Returns the custom program error code, if an instruction failed with one
+    pub fn custom_error(&self) -> Option<u32> {
+        match self {
+            TransactionError::InstructionError(_, ProgramError::Custom(code)) => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+/// An instruction invoked by a program during a transaction
+#[derive(Clone, Debug)]
+pub struct InnerInstruction {
+    /// The index of the transaction instruction that invoked it
+    pub instruction_index: usize,
+    /// The invocation depth, starting at 2 for instructions invoked by a transaction instruction
+    pub stack_height: usize,
+    pub instruction: Instruction,
+}
+
+/// The result of a successful transaction
+#[derive(Clone, Debug, Default)]
+pub struct TransactionMetadata {
+    pub inner_instructions: Vec<InnerInstruction>,
+    /// The data logged with `sol_log_data`, such as by `emit!`
+    pub data_logs: Vec<Vec<u8>>,
+    /// The return data of the last transaction instruction
+    pub return_data: Option<(Pubkey, Vec<u8>)>,
+}
+
+impl TransactionMetadata {
+    /// Returns the events of type `E` emitted by `program_id` with `emit_cpi!` or `emit!`, in order
+    pub fn events<E: Event>(&self, program_id: &Pubkey) -> Vec<E> {
+        let cpi_events = self
+            .inner_instructions
+            .iter()
+            .filter(|inner| inner.instruction.program_id == *program_id)
+            .filter_map(|inner| {
+                inner
+                    .instruction
+                    .data
+                    .strip_prefix(anchor_lang::event::EVENT_IX_TAG_LE)
+            });
+        let log_events = self.data_logs.iter().map(Vec::as_slice);
+
+        cpi_events
+            .chain(log_events)
+            .filter_map(|data| data.strip_prefix(E::DISCRIMINATOR))
+            .map(|mut data| E::deserialize(&mut data).expect("event data is valid"))
+            .collect()
+    }
+
+    /// Returns the value that `program_id` returned from the last instruction with
+    /// `set_return_data`, if it returned one
+    pub fn return_value<T: AnchorDeserialize>(&self, program_id: &Pubkey) -> Option<T> {
+        self.return_data
+            .as_ref()
+            .filter(|(returned_by, _)| returned_by == program_id)
+            .map(|(_, data)| T::try_from_slice(data).expect("return data is valid"))
+    }
+}
+
+// Where an account was serialized within the input buffer of an instruction
+struct SerializedAccount {
+    key: Pubkey,
+    is_writable: bool,
+    owner_offset: usize,
+    lamports_offset: usize,
+    data_length_offset: usize,
+}
+
+// The input buffer of an instruction, as serialized by the runtime for SBF programs
+struct SerializedInput {
+    // Backed by u128 so the buffer is aligned like the SBF input region
+    buffer: Vec<u128>,
+    accounts: Vec<SerializedAccount>,
+}
+
+impl SerializedInput {
+    fn new(
+        instruction: &Instruction,
+        accounts: &HashMap<Pubkey, Account>,
+        flags: &HashMap<Pubkey, (bool, bool)>,
+    ) -> Self {
+        let mut bytes = Vec::new();
+        let mut serialized_accounts: Vec<SerializedAccount> = Vec::new();
+
+        bytes.extend_from_slice(&(instruction.accounts.len() as u64).to_le_bytes());
+        for (position, meta) in instruction.accounts.iter().enumerate() {
+            // A duplicate refers to the position of the account's first occurrence in the
+            // instruction
+            if let Some(index) = instruction.accounts[..position]
+                .iter()
+                .position(|other| other.pubkey == meta.pubkey)
+            {
+                bytes.push(index as u8);
+                bytes.extend_from_slice(&[0; 7]);
+                continue;
+            }
+
+            let account = accounts.get(&meta.pubkey).cloned().unwrap_or_default();
+            let (is_signer, is_writable) = flags[&meta.pubkey];
+            bytes.extend_from_slice(&[
+                NON_DUP_MARKER,
+                is_signer as u8,
+                is_writable as u8,
+                account.executable as u8,
+            ]);
+            // The original data length, written by `deserialize`
+            bytes.extend_from_slice(&[0; 4]);
+            bytes.extend_from_slice(meta.pubkey.as_ref());
+            let owner_offset = bytes.len();
+            bytes.extend_from_slice(account.owner.as_ref());
+            let lamports_offset = bytes.len();
+            bytes.extend_from_slice(&account.lamports.to_le_bytes());
+            let data_length_offset = bytes.len();
+            bytes.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(&account.data);
+            bytes.resize(bytes.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            bytes.resize(bytes.len().next_multiple_of(BPF_ALIGN_OF_U128), 0);
+            bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+
+            serialized_accounts.push(SerializedAccount {
+                key: meta.pubkey,
+                is_writable,
+                owner_offset,
+                lamports_offset,
+                data_length_offset,
+            });
+        }
+        bytes.extend_from_slice(&(instruction.data.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&instruction.data);
+        bytes.extend_from_slice(instruction.program_id.as_ref());
+
+        let mut buffer = vec![0u128; bytes.len().div_ceil(size_of::<u128>())];
+        // SAFETY: the buffer holds at least `bytes.len()` bytes
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                buffer.as_mut_ptr() as *mut u8,
+                bytes.len(),
+            );
+        }
+
+        Self {
+            buffer,
+            accounts: serialized_accounts,
+        }
+    }
+
+    fn bytes(&self) -> &[u8] {
+        // SAFETY: a u128 buffer can be viewed as bytes
+        unsafe {
+            std::slice::from_raw_parts(
+                self.buffer.as_ptr() as *const u8,
+                self.buffer.len() * size_of::<u128>(),
+            )
+        }
+    }
+
+    fn read_u64(&self, offset: usize) -> u64 {
+        u64::from_le_bytes(self.bytes()[offset..offset + 8].try_into().unwrap())
+    }
+
+    // Returns the state of a serialized account after the instruction
+    fn account(&self, serialized: &SerializedAccount, executable: bool) -> Account {
+        let bytes = self.bytes();
+        let data_offset = serialized.data_length_offset + 8;
+        let data_length = self.read_u64(serialized.data_length_offset) as usize;
+        Account {
+            lamports: self.read_u64(serialized.lamports_offset),
+            data: bytes[data_offset..data_offset + data_length].to_vec(),
+            owner: Pubkey::try_from(&bytes[serialized.owner_offset..serialized.owner_offset + 32])
+                .unwrap(),
+            executable,
+        }
+    }
+}
+
+// Returns the lamports and data length of an account that has lamports but is not rent-exempt
+fn rent_paying_state(account: Option<&Account>) -> Option<(u64, usize)> {
+    account
+        .filter(|account| {
+            account.lamports > 0
+                && account.lamports < Rent::default().minimum_balance(account.data.len())
+        })
+        .map(|account| (account.lamports, account.data.len()))
+}
+
+/// An in-process SVM with the Gateway programs and the builtin programs they use
+pub struct Svm {
+    accounts: HashMap<Pubkey, Account>,
+    clock: Clock,
+}
+
+impl Default for Svm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Svm {
+    pub fn new() -> Self {
+        stubs::install();
+
+        let mut svm = Self {
+            accounts: HashMap::new(),
+            clock: Clock {
+                slot: 1,
+                ..Clock::default()
+            },
+        };
+        for program_id in builtins::NATIVE_PROGRAMS {
+            svm.set_account(
+                program_id,
+                Account {
+                    lamports: 1,
+                    data: vec![],
+                    owner: NATIVE_LOADER_ID,
+                    executable: true,
+                },
+            );
+        }
+        for program_id in [gateway_wallet::ID, gateway_minter::ID] {
+            svm.set_upgradeable_program(program_id, None);
+        }
+        svm.set_sysvars();
+        svm
+    }
+
+    /// Returns the account at `address`, if it exists
+    pub fn get_account(&self, address: &Pubkey) -> Option<&Account> {
+        self.accounts.get(address)
+    }
+
+    /// Returns the Anchor account at `address` deserialized as `T`, if it exists
+    pub fn get_anchor_account<T: AccountDeserialize>(&self, address: &Pubkey) -> Option<T> {
+        self.get_account(address)
+            .map(|account| T::try_deserialize(&mut account.data.as_slice()).unwrap())
+    }
+
+    pub fn set_account(&mut self, address: Pubkey, account: Account) {
+        self.accounts.insert(address, account);
+    }
+
+    /// Adds `lamports` to the account at `address`, creating it if needed
+    pub fn airdrop(&mut self, address: &Pubkey, lamports: u64) {
+        self.accounts
+            .entry(*address)
+            .or_insert_with(|| Account {
+                owner: system_program::ID,
+                ..Account::default()
+            })
+            .lamports += lamports;
+    }
+
+    /// Sets the upgrade authority of an upgradeable program, which is checked by `initialize`
+    pub fn set_upgradeable_program(
+        &mut self,
+        program_id: Pubkey,
+        upgrade_authority: Option<Pubkey>,
+    ) {
+        let program_data =
+            Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID).0;
+
+        // UpgradeableLoaderState::Program
+        let mut program = 2u32.to_le_bytes().to_vec();
+        program.extend_from_slice(program_data.as_ref());
+        self.set_account(
+            program_id,
+            Account {
+                executable: true,
+                ..Account::new_rent_exempt(program, bpf_loader_upgradeable::ID)
+            },
+        );
+
+        // UpgradeableLoaderState::ProgramData
+        let mut data = 3u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&0u64.to_le_bytes());
+        match upgrade_authority {
+            Some(authority) => {
+                data.push(1);
+                data.extend_from_slice(authority.as_ref());
+            }
+            None => data.push(0),
+        }
+        self.set_account(
+            program_data,
+            Account::new_rent_exempt(data, bpf_loader_upgradeable::ID),
+        );
+    }
+
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Moves the clock forward to `slot`
+    pub fn warp_to_slot(&mut self, slot: u64) {
+        self.clock.slot = slot;
+        self.set_sysvars();
+    }
+
+    fn set_sysvars(&mut self) {
+        let clock = &self.clock;
+        let mut data = clock.slot.to_le_bytes().to_vec();
+        data.extend_from_slice(&clock.epoch_start_timestamp.to_le_bytes());
+        data.extend_from_slice(&clock.epoch.to_le_bytes());
+        data.extend_from_slice(&clock.leader_schedule_epoch.to_le_bytes());
+        data.extend_from_slice(&clock.unix_timestamp.to_le_bytes());
+        self.set_account(
+            sysvar::clock::ID,
+            Account::new_rent_exempt(data, sysvar::ID),
+        );
+
+        let rent = Rent::default();
+        let mut data = rent.lamports_per_byte_year.to_le_bytes().to_vec();
+        data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+        data.push(rent.burn_percent);
+        self.set_account(sysvar::rent::ID, Account::new_rent_exempt(data, sysvar::ID));
+    }
+
+    /// Processes the instructions as a single atomic transaction signed by `signers`
+    pub fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[Pubkey],
+    ) -> std::result::Result<TransactionMetadata, TransactionError> {
+        // The signer and writable flags of each account, merged across the instructions as in a
+        // transaction message
+        let mut flags: HashMap<Pubkey, (bool, bool)> = HashMap::new();
+        for meta in instructions.iter().flat_map(|ix| &ix.accounts) {
+            if meta.is_signer && !signers.contains(&meta.pubkey) {
+                return Err(TransactionError::MissingSignature(meta.pubkey));
+            }
+            let entry = flags.entry(meta.pubkey).or_default();
+            entry.0 |= meta.is_signer;
+            entry.1 |= meta.is_writable;
+        }
+
+        // Precompiles are verified before any instruction is executed
+        let instruction_datas: Vec<&[u8]> =
+            instructions.iter().map(|ix| ix.data.as_slice()).collect();
+        for (index, instruction) in instructions.iter().enumerate() {
+            if instruction.program_id == ed25519_program::ID {
+                builtins::verify_ed25519(&instruction.data, &instruction_datas)
+                    .map_err(|error| TransactionError::InstructionError(index, error))?;
+            }
+        }
+
+        let snapshot = self.accounts.clone();
+        stubs::begin_transaction(self.clock.clone());
+        let result = instructions
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, instruction)| {
+                self.process_instruction(instructions, index, instruction, &flags)
+            });
+        let metadata = stubs::end_transaction();
+
+        let result = result.and_then(|()| {
+            // An account can only be left rent-paying if it already was, and was not grown or
+            // credited
+            let rent_paying = flags.iter().find(|(address, (_, is_writable))| {
+                *is_writable
+                    && rent_paying_state(self.accounts.get(address)).is_some_and(|post| {
+                        rent_paying_state(snapshot.get(address))
+                            .is_none_or(|pre| post.1 != pre.1 || post.0 > pre.0)
+                    })
+            });
+            match rent_paying {
+                Some((address, _)) => Err(TransactionError::InsufficientFundsForRent(*address)),
+                None => Ok(()),
+            }
+        });
+
+        match result {
+            Ok(()) => {
+                // Accounts left without lamports are removed, as when a transaction is committed
+                self.accounts.retain(|_, account| account.lamports > 0);
+                Ok(metadata)
+            }
+            Err(error) => {
+                self.accounts = snapshot;
+                Err(error)
+            }
+        }
+    }
+
+    fn process_instruction(
+        &mut self,
+        instructions: &[Instruction],
+        index: usize,
+        instruction: &Instruction,
+        flags: &HashMap<Pubkey, (bool, bool)>,
+    ) -> std::result::Result<(), TransactionError> {
+        if instruction.program_id == ed25519_program::ID {
+            return Ok(());
+        }
+        self.set_instructions_sysvar(instructions, index, flags);
+
+        let mut input = SerializedInput::new(instruction, &self.accounts, flags);
+        let pre_accounts: Vec<Account> = input
+            .accounts
+            .iter()
+            .map(|serialized| {
+                self.accounts
+                    .get(&serialized.key)
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        {
+            // SAFETY: the buffer is serialized in the format `deserialize` expects, and outlives
+            // the account infos
+            let (program_id, account_infos, data) =
+                unsafe { deserialize(input.buffer.as_mut_ptr() as *mut u8) };
+            stubs::process_instruction(index, program_id, &account_infos, data).map_err(
+                |error| match stubs::external_account_modified() {
+                    Some(address) => TransactionError::ExternalAccountModified(index, address),
+                    None => TransactionError::InstructionError(index, error),
+                },
+            )?;
+        }
+
+        let post_accounts: Vec<Account> = input
+            .accounts
+            .iter()
+            .zip(&pre_accounts)
+            .map(|(serialized, pre)| input.account(serialized, pre.executable))
+            .collect();
+
+        let pre_lamports: u128 = pre_accounts.iter().map(|a| a.lamports as u128).sum();
+        let post_lamports: u128 = post_accounts.iter().map(|a| a.lamports as u128).sum();
+        if pre_lamports != post_lamports {
+            return Err(TransactionError::UnbalancedInstruction(index));
+        }
+
+        let mut updated = HashSet::new();
+        for ((serialized, pre), post) in input.accounts.iter().zip(&pre_accounts).zip(post_accounts)
+        {
+            if !serialized.is_writable && *pre != post {
+                return Err(TransactionError::ReadonlyAccountModified(
+                    index,
+                    serialized.key,
+                ));
+            }
+            if updated.insert(serialized.key) {
+                self.accounts.insert(serialized.key, post);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn set_instructions_sysvar(
+        &mut self,
+        instructions: &[Instruction],
+        index: usize,
+        flags: &HashMap<Pubkey, (bool, bool)>,
+    ) {
+        let borrowed: Vec<BorrowedInstruction> = instructions
+            .iter()
+            .map(|instruction| BorrowedInstruction {
+                program_id: &instruction.program_id,
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|meta| BorrowedAccountMeta {
+                        pubkey: &meta.pubkey,
+                        is_signer: flags[&meta.pubkey].0,
+                        is_writable: flags[&meta.pubkey].1,
+                    })
+                    .collect(),
+                data: &instruction.data,
+            })
+            .collect();
+        let mut data = construct_instructions_data(&borrowed);
+        store_current_index_checked(&mut data, index as u16).unwrap();
+        self.set_account(
+            sysvar::instructions::ID,
+            Account::new_rent_exempt(data, sysvar::ID),
+        );
+    }
+}
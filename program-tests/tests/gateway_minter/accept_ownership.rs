@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::minter;
+use gateway_minter::{
+    error::GatewayMinterError, events::OwnershipTransferred, instructions::TransferOwnershipParams,
+};
+use gateway_program_tests::fixtures::{assert_error, MinterFixture};
+
+fn transfer_ownership(fixture: &mut MinterFixture, new_owner: Pubkey) {
+    fixture
+        .svm
+        .process(
+            &[minter::transfer_ownership(
+                fixture.owner,
+                TransferOwnershipParams { new_owner },
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+}
+
+#[test]
+fn makes_the_pending_owner_the_owner() {
+    let mut fixture = MinterFixture::new();
+    let new_owner = Pubkey::new_unique();
+    transfer_ownership(&mut fixture, new_owner);
+
+    let metadata = fixture
+        .svm
+        .process(&[minter::accept_ownership(new_owner)], &[new_owner])
+        .unwrap();
+
+    let state = fixture.state();
+    assert_eq!(state.owner, new_owner);
+    assert_eq!(state.pending_owner, Pubkey::default());
+
+    let events = metadata.events::<OwnershipTransferred>(&gateway_minter::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].previous_owner, fixture.owner);
+    assert_eq!(events[0].new_owner, new_owner);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_pending_owner() {
+    let mut fixture = MinterFixture::new();
+    transfer_ownership(&mut fixture, Pubkey::new_unique());
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture
+        .svm
+        .process(&[minter::accept_ownership(attacker)], &[attacker]);
+
+    assert_error(result, GatewayMinterError::InvalidAuthority);
+}
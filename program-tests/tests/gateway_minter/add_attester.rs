@@ -0,0 +1,96 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::minter;
+use gateway_minter::{
+    error::GatewayMinterError, events::AttestationSignerAdded, instructions::AddAttesterParams,
+    state::MAX_ATTESTERS,
+};
+use gateway_program_tests::fixtures::{assert_error, MinterFixture};
+
+fn add_attester(
+    owner: Pubkey,
+    attester: Pubkey,
+) -> anchor_lang::solana_program::instruction::Instruction {
+    minter::add_attester(owner, AddAttesterParams { attester })
+}
+
+#[test]
+fn enables_the_attester_once() {
+    let mut fixture = MinterFixture::new();
+    let attester = Pubkey::new_unique();
+
+    for _ in 0..2 {
+        let metadata = fixture
+            .svm
+            .process(&[add_attester(fixture.owner, attester)], &[fixture.owner])
+            .unwrap();
+
+        let events = metadata.events::<AttestationSignerAdded>(&gateway_minter::ID);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].signer, attester);
+    }
+
+    assert_eq!(fixture.state().enabled_attesters, vec![attester]);
+}
+
+#[test]
+fn fails_beyond_the_attester_limit() {
+    let mut fixture = MinterFixture::new();
+    for _ in 0..MAX_ATTESTERS {
+        fixture
+            .svm
+            .process(
+                &[add_attester(fixture.owner, Pubkey::new_unique())],
+                &[fixture.owner],
+            )
+            .unwrap();
+    }
+
+    let result = fixture.svm.process(
+        &[add_attester(fixture.owner, Pubkey::new_unique())],
+        &[fixture.owner],
+    );
+
+    assert_error(result, GatewayMinterError::AttesterLimitExceeded);
+}
+
+#[test]
+fn fails_for_the_default_pubkey() {
+    let mut fixture = MinterFixture::new();
+
+    let result = fixture.svm.process(
+        &[add_attester(fixture.owner, Pubkey::default())],
+        &[fixture.owner],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidAttester);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = MinterFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture
+        .svm
+        .process(&[add_attester(attacker, attacker)], &[attacker]);
+
+    assert_error(result, GatewayMinterError::InvalidAuthority);
+}
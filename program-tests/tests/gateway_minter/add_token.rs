@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::{minter, pda};
+use gateway_minter::{
+    error::GatewayMinterError, events::TokenSupported, state::MAX_SUPPORTED_TOKENS,
+};
+use gateway_program_tests::fixtures::{assert_error, create_mint, token_account, MinterFixture};
+
+#[test]
+fn supports_the_token_in_custody_mode() {
+    let mut fixture = MinterFixture::new();
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[minter::add_token(fixture.payer, fixture.owner, token_mint)],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+
+    let state = fixture.state();
+    assert_eq!(state.supported_tokens, vec![fixture.token_mint, token_mint]);
+    // The token uses the default config until the token controller configures it
+    assert!(fixture.token_config(&token_mint).is_none());
+
+    let custody = pda::gateway_minter_custody(&token_mint);
+    let custody_account = token_account(&fixture.svm, &custody);
+    assert_eq!(custody_account.mint, token_mint);
+    assert_eq!(custody_account.owner, pda::gateway_minter());
+
+    let events = metadata.events::<TokenSupported>(&gateway_minter::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].token, token_mint);
+    assert_eq!(events[0].custody_token_account, custody);
+}
+
+#[test]
+fn fails_beyond_the_token_limit() {
+    let mut fixture = MinterFixture::new();
+    for _ in 1..MAX_SUPPORTED_TOKENS {
+        let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+        fixture
+            .svm
+            .process(
+                &[minter::add_token(fixture.payer, fixture.owner, token_mint)],
+                &[fixture.payer, fixture.owner],
+            )
+            .unwrap();
+    }
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+
+    let result = fixture.svm.process(
+        &[minter::add_token(fixture.payer, fixture.owner, token_mint)],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayMinterError::MaxTokensSupported);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_token_controller() {
+    let mut fixture = MinterFixture::new();
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[minter::add_token(fixture.payer, attacker, token_mint)],
+        &[fixture.payer, attacker],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidAuthority);
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::{minter, pda};
+use gateway_minter::{error::GatewayMinterError, events::TokenCustodyBurned};
+use gateway_program_tests::fixtures::{assert_error, mint_supply, token_account, MinterFixture};
+
+#[test]
+fn burns_up_to_the_custody_balance() {
+    let mut fixture = MinterFixture::new();
+    fixture.fund_custody(1_000);
+    let custody = pda::gateway_minter_custody(&fixture.token_mint);
+
+    for (amount, burned, remaining) in [(400, 400, 600), (1_000, 600, 0)] {
+        let metadata = fixture
+            .svm
+            .process(
+                &[minter::burn_token_custody(
+                    fixture.owner,
+                    fixture.token_mint,
+                    amount,
+                )],
+                &[fixture.owner],
+            )
+            .unwrap();
+
+        assert_eq!(token_account(&fixture.svm, &custody).amount, remaining);
+        assert_eq!(mint_supply(&fixture.svm, &fixture.token_mint), remaining);
+
+        let events = metadata.events::<TokenCustodyBurned>(&gateway_minter::ID);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].token, fixture.token_mint);
+        assert_eq!(events[0].custody_token_account, custody);
+        assert_eq!(events[0].amount, burned);
+    }
+}
+
+#[test]
+fn fails_for_a_zero_amount() {
+    let mut fixture = MinterFixture::new();
+    fixture.fund_custody(1_000);
+
+    let result = fixture.svm.process(
+        &[minter::burn_token_custody(
+            fixture.owner,
+            fixture.token_mint,
+            0,
+        )],
+        &[fixture.owner],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidBurnAmount);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_token_controller() {
+    let mut fixture = MinterFixture::new();
+    fixture.fund_custody(1_000);
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[minter::burn_token_custody(
+            attacker,
+            fixture.token_mint,
+            1_000,
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidAuthority);
+}
@@ -0,0 +1,301 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::{prelude::Pubkey, Discriminator};
+use gateway_client::{minter, pda};
+use gateway_minter::{
+    error::GatewayMinterError,
+    events::UsedTransferSpecHashClosed,
+    instructions::CloseUsedTransferSpecHashParams,
+    state::{NonceBucket, ReplayStore, UsedTransferSpecHash},
+};
+use gateway_program_tests::{
+    fixtures::{assert_error, token_account},
+    TransactionError, TransactionMetadata,
+};
+use gateway_shared::replay::{is_nonce_used, ReplayKey};
+use gateway_wallet::burn_data::TransferSpec;
+
+use crate::gateway_mint::{depositor_namespace, MintFixture};
+
+// Returns the fixture, the transfer spec hash and the expiry slot of a completed mint
+fn minted() -> (MintFixture, [u8; 32], u64) {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+    fixture.mint(&attestation).unwrap();
+    (
+        fixture,
+        attestation.elements[0].transfer_spec_hash,
+        attestation.max_block_height,
+    )
+}
+
+#[test]
+fn refunds_the_rent_payer_after_expiry() {
+    let (mut fixture, transfer_spec_hash, expiry_slot) = minted();
+    let svm = &mut fixture.minter.svm;
+    let payer = fixture.minter.payer;
+    let address = pda::used_transfer_spec_hash(&gateway_minter::ID, &transfer_spec_hash);
+    let rent = svm.get_account(&address).unwrap().lamports;
+    let balance = svm.get_account(&payer).unwrap().lamports;
+    svm.warp_to_slot(expiry_slot + 1);
+
+    let metadata = svm
+        .process(
+            &[minter::close_used_transfer_spec_hash(
+                payer,
+                payer,
+                CloseUsedTransferSpecHashParams {
+                    transfer_spec_hash,
+                    transfer_spec: None,
+                },
+            )],
+            &[],
+        )
+        .unwrap();
+
+    // The discriminator stays behind as a tombstone, holding only the rent it needs
+    let tombstone = svm.get_account(&address).unwrap();
+    assert_eq!(tombstone.data, UsedTransferSpecHash::DISCRIMINATOR);
+    assert_eq!(
+        svm.get_account(&payer).unwrap().lamports,
+        balance + rent - tombstone.lamports
+    );
+
+    let events = metadata.events::<UsedTransferSpecHashClosed>(&gateway_minter::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].transfer_spec_hash, transfer_spec_hash);
+    assert_eq!(events[0].rent_payer, payer);
+}
+
+#[test]
+fn fails_before_expiry() {
+    let (mut fixture, transfer_spec_hash, expiry_slot) = minted();
+    fixture.minter.svm.warp_to_slot(expiry_slot);
+
+    let result = fixture.minter.svm.process(
+        &[minter::close_used_transfer_spec_hash(
+            fixture.minter.payer,
+            fixture.minter.payer,
+            CloseUsedTransferSpecHashParams {
+                transfer_spec_hash,
+                transfer_spec: None,
+            },
+        )],
+        &[],
+    );
+
+    assert_error(result, GatewayMinterError::TransferSpecHashNotExpired);
+}
+
+#[test]
+fn fails_for_another_rent_payer() {
+    let (mut fixture, transfer_spec_hash, expiry_slot) = minted();
+    fixture.minter.svm.warp_to_slot(expiry_slot + 1);
+
+    let result = fixture.minter.svm.process(
+        &[minter::close_used_transfer_spec_hash(
+            fixture.minter.payer,
+            Pubkey::new_unique(),
+            CloseUsedTransferSpecHashParams {
+                transfer_spec_hash,
+                transfer_spec: None,
+            },
+        )],
+        &[],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidRentPayer);
+}
+
+#[test]
+fn fails_to_replay_a_later_expiry_attestation_after_closing() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+    fixture.mint(&attestation).unwrap();
+    let expiry_slot = attestation.max_block_height;
+    fixture.minter.svm.warp_to_slot(expiry_slot + 1);
+    fixture
+        .minter
+        .svm
+        .process(
+            &[minter::close_used_transfer_spec_hash(
+                fixture.minter.payer,
+                fixture.minter.payer,
+                CloseUsedTransferSpecHashParams {
+                    transfer_spec_hash: attestation.elements[0].transfer_spec_hash,
+                    transfer_spec: None,
+                },
+            )],
+            &[],
+        )
+        .unwrap();
+
+    // A new attestation of the same transfer spec hash, that has not expired yet
+    let mut replay = attestation.clone();
+    replay.max_block_height = expiry_slot + 100;
+    let result = fixture.mint(&replay);
+
+    assert_error(result, GatewayMinterError::TransferSpecHashAlreadyUsed);
+    assert_eq!(token_account(&fixture.minter.svm, &recipient).amount, 400);
+}
+
+// Returns the fixture, the transfer spec hash and the expiry slot of a completed mint recorded by
+// hash, for a transfer spec with the explicit nonce 7
+fn minted_with_nonce() -> (MintFixture, [u8; 32], u64) {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation =
+        fixture.nonce_attestation(recipient, 400, depositor_namespace(Pubkey::new_unique()), 7);
+    fixture.mint(&attestation).unwrap();
+    (
+        fixture,
+        attestation.elements[0].transfer_spec_hash,
+        attestation.max_block_height,
+    )
+}
+
+fn close_with_transfer_spec(
+    fixture: &mut MintFixture,
+    transfer_spec_hash: [u8; 32],
+    transfer_spec: Vec<u8>,
+) -> Result<TransactionMetadata, TransactionError> {
+    let payer = fixture.minter.payer;
+    fixture.minter.svm.process(
+        &[minter::close_used_transfer_spec_hash(
+            payer,
+            payer,
+            CloseUsedTransferSpecHashParams {
+                transfer_spec_hash,
+                transfer_spec: Some(transfer_spec),
+            },
+        )],
+        &[payer],
+    )
+}
+
+#[test]
+fn closes_fully_once_the_nonce_is_recorded() {
+    let (mut fixture, transfer_spec_hash, expiry_slot) = minted_with_nonce();
+    fixture.set_replay_store(ReplayStore::Migrating);
+    let transfer_spec = fixture.transfer_specs[&transfer_spec_hash].clone();
+    let payer = fixture.minter.payer;
+    let address = pda::used_transfer_spec_hash(&gateway_minter::ID, &transfer_spec_hash);
+    let rent = fixture.minter.svm.get_account(&address).unwrap().lamports;
+    let balance = fixture.minter.svm.get_account(&payer).unwrap().lamports;
+    fixture.minter.svm.warp_to_slot(expiry_slot + 1);
+
+    close_with_transfer_spec(&mut fixture, transfer_spec_hash, transfer_spec.clone()).unwrap();
+
+    // The account is closed, and the transfer is recorded in its nonce bucket instead
+    let svm = &fixture.minter.svm;
+    assert!(svm.get_account(&address).is_none());
+    let replay_key = ReplayKey::from_transfer_spec(transfer_spec_hash, &transfer_spec).unwrap();
+    let nonce_bucket = replay_key.find_address(&gateway_minter::ID).0;
+    let nonce_bucket_account = svm.get_account(&nonce_bucket).unwrap();
+    assert!(is_nonce_used(
+        &nonce_bucket_account.data,
+        NonceBucket::DISCRIMINATOR,
+        7
+    ));
+    assert_eq!(
+        svm.get_account(&payer).unwrap().lamports,
+        balance + rent - nonce_bucket_account.lamports
+    );
+}
+
+#[test]
+fn fails_to_replay_a_later_expiry_attestation_after_closing_fully() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation =
+        fixture.nonce_attestation(recipient, 400, depositor_namespace(Pubkey::new_unique()), 7);
+    fixture.mint(&attestation).unwrap();
+    fixture.set_replay_store(ReplayStore::Migrating);
+    let expiry_slot = attestation.max_block_height;
+    fixture.minter.svm.warp_to_slot(expiry_slot + 1);
+    let transfer_spec_hash = attestation.elements[0].transfer_spec_hash;
+    let transfer_spec = fixture.transfer_specs[&transfer_spec_hash].clone();
+    close_with_transfer_spec(&mut fixture, transfer_spec_hash, transfer_spec).unwrap();
+
+    // A new attestation of the same transfer spec hash, that has not expired yet
+    let mut replay = attestation.clone();
+    replay.max_block_height = expiry_slot + 100;
+    let result = fixture.mint(&replay);
+
+    assert_error(result, GatewayMinterError::TransferSpecHashAlreadyUsed);
+    assert_eq!(token_account(&fixture.minter.svm, &recipient).amount, 400);
+}
+
+#[test]
+fn fails_to_close_fully_while_mints_are_recorded_by_hash() {
+    let (mut fixture, transfer_spec_hash, expiry_slot) = minted_with_nonce();
+    let transfer_spec = fixture.transfer_specs[&transfer_spec_hash].clone();
+    fixture.minter.svm.warp_to_slot(expiry_slot + 1);
+
+    let result = close_with_transfer_spec(&mut fixture, transfer_spec_hash, transfer_spec);
+
+    assert_error(result, GatewayMinterError::TransferNotRecordedByNonce);
+}
+
+#[test]
+fn fails_to_close_fully_without_an_explicit_nonce() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let mut attestation =
+        fixture.nonce_attestation(recipient, 400, depositor_namespace(Pubkey::new_unique()), 7);
+    // Swap in a transfer spec with a random salt
+    let mut transfer_spec =
+        TransferSpec::decode(&fixture.transfer_specs[&attestation.elements[0].transfer_spec_hash])
+            .unwrap();
+    transfer_spec.salt = Pubkey::new_unique().to_bytes();
+    let transfer_spec_hash = transfer_spec.hash();
+    attestation.elements[0].transfer_spec_hash = transfer_spec_hash;
+    fixture.mint(&attestation).unwrap();
+    fixture.set_replay_store(ReplayStore::Migrating);
+    fixture
+        .minter
+        .svm
+        .warp_to_slot(attestation.max_block_height + 1);
+
+    let result = close_with_transfer_spec(&mut fixture, transfer_spec_hash, transfer_spec.encode());
+
+    assert_error(result, GatewayMinterError::TransferNotRecordedByNonce);
+}
+
+#[test]
+fn fails_to_close_fully_for_another_transfer_spec() {
+    let (mut fixture, transfer_spec_hash, expiry_slot) = minted_with_nonce();
+    fixture.set_replay_store(ReplayStore::Migrating);
+    let mut transfer_spec = fixture.transfer_specs[&transfer_spec_hash].clone();
+    // A transfer spec that does not hash to the closed transfer spec hash
+    let last = transfer_spec.len() - 1;
+    transfer_spec[last] ^= 1;
+    fixture.minter.svm.warp_to_slot(expiry_slot + 1);
+
+    let result = close_with_transfer_spec(&mut fixture, transfer_spec_hash, transfer_spec);
+
+    assert_error(result, GatewayMinterError::InvalidTransferSpec);
+}
@@ -0,0 +1,134 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::minter;
+use gateway_minter::{
+    error::GatewayMinterError, events::TokenMinterConfigured,
+    instructions::ConfigureTokenMinterParams, state::TokenMintMode,
+};
+use gateway_program_tests::fixtures::{assert_error, create_mint, MinterFixture};
+
+fn params(mint_mode: TokenMintMode, minting_allowance: u64) -> ConfigureTokenMinterParams {
+    ConfigureTokenMinterParams {
+        mint_mode,
+        minting_allowance,
+    }
+}
+
+#[test]
+fn sets_the_mint_mode_and_allowance() {
+    let mut fixture = MinterFixture::new();
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[minter::configure_token_minter(
+                fixture.payer,
+                fixture.owner,
+                fixture.token_mint,
+                params(TokenMintMode::MintAuthority, 1_000),
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+
+    let config = fixture.token_config(&fixture.token_mint).unwrap();
+    assert_eq!(config.mint_mode, TokenMintMode::MintAuthority);
+    assert_eq!(config.minting_allowance, 1_000);
+
+    let events = metadata.events::<TokenMinterConfigured>(&gateway_minter::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].token, fixture.token_mint);
+    assert_eq!(events[0].mint_mode, TokenMintMode::MintAuthority);
+    assert_eq!(events[0].minting_allowance, 1_000);
+}
+
+#[test]
+fn fails_without_the_mint_authority() {
+    let mut fixture = MinterFixture::new();
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+    fixture
+        .svm
+        .process(
+            &[minter::add_token(fixture.payer, fixture.owner, token_mint)],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+
+    let result = fixture.svm.process(
+        &[minter::configure_token_minter(
+            fixture.payer,
+            fixture.owner,
+            token_mint,
+            params(TokenMintMode::MintAuthority, 1_000),
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+    assert_error(result, GatewayMinterError::InvalidTokenMintAuthority);
+
+    // Custody mode does not need the mint authority
+    fixture
+        .svm
+        .process(
+            &[minter::configure_token_minter(
+                fixture.payer,
+                fixture.owner,
+                token_mint,
+                params(TokenMintMode::Custody, 0),
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+}
+
+#[test]
+fn fails_for_an_unsupported_token() {
+    let mut fixture = MinterFixture::new();
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+
+    let result = fixture.svm.process(
+        &[minter::configure_token_minter(
+            fixture.payer,
+            fixture.owner,
+            token_mint,
+            params(TokenMintMode::Custody, 0),
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayMinterError::TokenNotSupported);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_token_controller() {
+    let mut fixture = MinterFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[minter::configure_token_minter(
+            fixture.payer,
+            attacker,
+            fixture.token_mint,
+            params(TokenMintMode::MintAuthority, u64::MAX),
+        )],
+        &[fixture.payer, attacker],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidAuthority);
+}
@@ -0,0 +1,786 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::HashMap;
+
+use anchor_lang::{
+    prelude::{AccountMeta, ProgramError, Pubkey},
+    solana_program::{instruction::Instruction, system_instruction},
+};
+use anchor_spl::associated_token::get_associated_token_address;
+use gateway_client::{minter, pda};
+use gateway_minter::{
+    attestation::{MintAttestationElementStruct, MintAttestationStruct},
+    error::GatewayMinterError,
+    events::{AttestationUsed, MintRateLimited},
+    hook::MintHook,
+    instructions::{
+        AddAttesterParams, ConfigureTokenMinterParams, GatewayMintParams,
+        GatewayMintReconstructParams, MintAttestationParams, SetMintRateLimitParams,
+        SetReplayStoreParams,
+    },
+    state::{NonceBucket, ReplayStore, TokenMintMode, UsedTransferSpecHash},
+};
+use gateway_program_tests::{
+    fixtures::{
+        assert_error, create_associated_token_account, create_mint, mint_supply, token_account,
+        MinterFixture, LOCAL_DOMAIN,
+    },
+    signers::EvmSigner,
+    TransactionError, TransactionMetadata,
+};
+use gateway_shared::replay::{NonceNamespace, ReplayKey, MAX_TRANSFER_VALIDITY_SLOTS};
+use gateway_wallet::burn_data::TransferSpec;
+
+/// A GatewayMinter with an enabled attester
+pub(crate) struct MintFixture {
+    pub minter: MinterFixture,
+    pub attester: EvmSigner,
+    /// The encoded transfer specs of the attestation elements, by transfer spec hash
+    pub transfer_specs: HashMap<[u8; 32], Vec<u8>>,
+}
+
+impl MintFixture {
+    pub fn new() -> Self {
+        let mut minter = MinterFixture::new();
+        let attester = EvmSigner::new([3; 32]);
+        minter
+            .svm
+            .process(
+                &[minter::add_attester(
+                    minter.owner,
+                    AddAttesterParams {
+                        attester: attester.address(),
+                    },
+                )],
+                &[minter.owner],
+            )
+            .unwrap();
+        Self {
+            minter,
+            attester,
+            transfer_specs: HashMap::new(),
+        }
+    }
+
+    /// Moves the replay store forward to `replay_store`, waiting for the transfers recorded by
+    /// hash to expire before completing the migration
+    pub fn set_replay_store(&mut self, replay_store: ReplayStore) {
+        let minter = &mut self.minter;
+        if replay_store == ReplayStore::NonceBuckets {
+            let slot = minter.svm.clock().slot;
+            minter
+                .svm
+                .warp_to_slot(slot + MAX_TRANSFER_VALIDITY_SLOTS + 1);
+        }
+        minter
+            .svm
+            .process(
+                &[minter::set_replay_store(
+                    minter.owner,
+                    SetReplayStoreParams { replay_store },
+                )],
+                &[minter.owner],
+            )
+            .unwrap();
+    }
+
+    /// Returns a new token account of the token, which the attestation elements mint to
+    pub fn recipient(&mut self) -> Pubkey {
+        let minter = &mut self.minter;
+        create_associated_token_account(
+            &mut minter.svm,
+            &minter.token_mint,
+            &Pubkey::new_unique(),
+            0,
+        )
+    }
+
+    /// Returns an attestation set with one element minting `value` to `recipient`
+    pub fn attestation(&self, recipient: Pubkey, value: u64) -> MintAttestationStruct<'static> {
+        MintAttestationStruct {
+            version: 1,
+            destination_domain: LOCAL_DOMAIN,
+            destination_contract: gateway_minter::ID.to_bytes(),
+            destination_caller: [0; 32],
+            max_block_height: self.minter.svm.clock().slot + 100,
+            elements: vec![MintAttestationElementStruct {
+                destination_token: self.minter.token_mint.to_bytes(),
+                destination_recipient: recipient.to_bytes(),
+                value,
+                transfer_spec_hash: Pubkey::new_unique().to_bytes(),
+                hook_data: &[],
+            }],
+        }
+    }
+
+    /// Returns an attestation set with one element minting `value` to `recipient`, for a transfer
+    /// spec whose salt is the explicit nonce `nonce` of `namespace`
+    pub fn nonce_attestation(
+        &mut self,
+        recipient: Pubkey,
+        value: u64,
+        namespace: NonceNamespace,
+        nonce: u64,
+    ) -> MintAttestationStruct<'static> {
+        let mut salt = [0; 32];
+        salt[24..].copy_from_slice(&nonce.to_be_bytes());
+        let transfer_spec = TransferSpec {
+            version: 1,
+            source_domain: namespace.source_domain,
+            destination_domain: LOCAL_DOMAIN,
+            source_contract: namespace.source_contract,
+            destination_contract: gateway_minter::ID,
+            source_token: Pubkey::new_unique(),
+            destination_token: self.minter.token_mint,
+            source_depositor: namespace.source_depositor,
+            destination_recipient: recipient,
+            source_signer: namespace.source_depositor,
+            destination_caller: Pubkey::default(),
+            value,
+            salt,
+            hook_data: vec![],
+        };
+        self.transfer_specs
+            .insert(transfer_spec.hash(), transfer_spec.encode());
+
+        let mut attestation = self.attestation(recipient, value);
+        attestation.elements[0].transfer_spec_hash = transfer_spec.hash();
+        attestation
+    }
+
+    /// Returns the encoded transfer spec of each attestation element, or none if any is unknown
+    pub fn transfer_specs(&self, attestation: &MintAttestationStruct) -> Vec<Vec<u8>> {
+        attestation
+            .elements
+            .iter()
+            .map(|element| {
+                self.transfer_specs
+                    .get(&element.transfer_spec_hash)
+                    .cloned()
+            })
+            .collect::<Option<_>>()
+            .unwrap_or_default()
+    }
+
+    pub fn elements(
+        &self,
+        attestation: &MintAttestationStruct,
+        recipient_is_owner: bool,
+    ) -> Vec<minter::GatewayMintElement> {
+        let replay_store = self.minter.state().replay_store;
+        attestation
+            .elements
+            .iter()
+            .map(|element| {
+                let destination_token = Pubkey::new_from_array(element.destination_token);
+                minter::GatewayMintElement {
+                    destination_token,
+                    destination_recipient: Pubkey::new_from_array(element.destination_recipient),
+                    transfer_spec_hash: element.transfer_spec_hash,
+                    transfer_spec: self
+                        .transfer_specs
+                        .get(&element.transfer_spec_hash)
+                        .cloned(),
+                    replay_store,
+                    mint_mode: self
+                        .minter
+                        .token_config(&destination_token)
+                        .map(|config| config.mint_mode)
+                        .unwrap_or_default(),
+                    recipient_is_owner,
+                    hook: minter::MintHookAccounts::from_hook_data(element.hook_data),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the `gateway_mint` instruction for an attestation signed by `attester`
+    pub fn instruction(
+        &self,
+        attestation: &MintAttestationStruct,
+        attester: &EvmSigner,
+        recipient_is_owner: bool,
+    ) -> Instruction {
+        let encoded_attestation = attestation.encode_attestation();
+        minter::gateway_mint(
+            self.minter.payer,
+            self.minter.payer,
+            GatewayMintParams {
+                signature: attester.sign(&encoded_attestation),
+                attestation: encoded_attestation,
+                transfer_specs: self.transfer_specs(attestation),
+            },
+            &self.elements(attestation, recipient_is_owner),
+        )
+    }
+
+    /// Submits an attestation signed by the attester
+    pub fn mint(
+        &mut self,
+        attestation: &MintAttestationStruct,
+    ) -> Result<TransactionMetadata, TransactionError> {
+        let instruction = self.instruction(attestation, &self.attester, false);
+        self.minter
+            .svm
+            .process(&[instruction], &[self.minter.payer])
+    }
+}
+
+/// Returns the nonce namespace of a depositor of the GatewayWallet on domain 0
+pub(crate) fn depositor_namespace(source_depositor: Pubkey) -> NonceNamespace {
+    NonceNamespace {
+        source_domain: 0,
+        source_contract: gateway_wallet::ID,
+        source_depositor,
+    }
+}
+
+#[test]
+fn mints_from_custody() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+
+    let metadata = fixture.mint(&attestation).unwrap();
+
+    let svm = &fixture.minter.svm;
+    let token_mint = fixture.minter.token_mint;
+    assert_eq!(token_account(svm, &recipient).amount, 400);
+    assert_eq!(
+        token_account(svm, &pda::gateway_minter_custody(&token_mint)).amount,
+        600
+    );
+
+    let transfer_spec_hash = attestation.elements[0].transfer_spec_hash;
+    let used_transfer_spec_hash: UsedTransferSpecHash = svm
+        .get_anchor_account(&pda::used_transfer_spec_hash(
+            &gateway_minter::ID,
+            &transfer_spec_hash,
+        ))
+        .unwrap();
+    assert_eq!(
+        used_transfer_spec_hash.expiry_slot,
+        attestation.max_block_height
+    );
+    assert_eq!(used_transfer_spec_hash.rent_payer, fixture.minter.payer);
+    assert_eq!(used_transfer_spec_hash.receipt, None);
+
+    let events = metadata.events::<AttestationUsed>(&gateway_minter::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].token, token_mint);
+    assert_eq!(events[0].recipient, recipient);
+    assert_eq!(events[0].transfer_spec_hash, transfer_spec_hash);
+    assert_eq!(events[0].value, 400);
+    assert!(events[0].hook_data.is_empty());
+}
+
+#[test]
+fn mints_with_the_mint_authority_within_the_allowance() {
+    let mut fixture = MintFixture::new();
+    let minter = &mut fixture.minter;
+    minter
+        .svm
+        .process(
+            &[minter::configure_token_minter(
+                minter.payer,
+                minter.owner,
+                minter.token_mint,
+                ConfigureTokenMinterParams {
+                    mint_mode: TokenMintMode::MintAuthority,
+                    minting_allowance: 1_000,
+                },
+            )],
+            &[minter.payer, minter.owner],
+        )
+        .unwrap();
+    let recipient = fixture.recipient();
+
+    fixture.mint(&fixture.attestation(recipient, 400)).unwrap();
+
+    let minter = &fixture.minter;
+    assert_eq!(token_account(&minter.svm, &recipient).amount, 400);
+    assert_eq!(mint_supply(&minter.svm, &minter.token_mint), 400);
+    let config = minter.token_config(&minter.token_mint).unwrap();
+    assert_eq!(config.minting_allowance, 600);
+
+    let attestation = fixture.attestation(recipient, 601);
+    assert_error(
+        fixture.mint(&attestation),
+        GatewayMinterError::MintingAllowanceExceeded,
+    );
+}
+
+#[test]
+fn creates_the_token_account_of_a_wallet_owner() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let owner = Pubkey::new_unique();
+    let attestation = fixture.attestation(owner, 400);
+    let instruction = fixture.instruction(&attestation, &fixture.attester, true);
+
+    fixture
+        .minter
+        .svm
+        .process(&[instruction], &[fixture.minter.payer])
+        .unwrap();
+
+    let recipient = get_associated_token_address(&owner, &fixture.minter.token_mint);
+    let recipient_account = token_account(&fixture.minter.svm, &recipient);
+    assert_eq!(recipient_account.owner, owner);
+    assert_eq!(recipient_account.amount, 400);
+}
+
+#[test]
+fn mints_a_reconstructed_attestation() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+    let element = &attestation.elements[0];
+
+    let instruction = minter::gateway_mint_with_params(
+        fixture.minter.payer,
+        fixture.minter.payer,
+        GatewayMintReconstructParams {
+            is_default_destination_caller: true,
+            max_block_height: attestation.max_block_height,
+            elements: vec![MintAttestationParams {
+                value: element.value,
+                transfer_spec_hash: element.transfer_spec_hash,
+                hook_data: vec![],
+                recipient_owner: None,
+            }],
+            signature: fixture.attester.sign(&attestation.encode_attestation()),
+            transfer_specs: vec![],
+        },
+        &fixture.elements(&attestation, false),
+    );
+    fixture
+        .minter
+        .svm
+        .process(&[instruction], &[fixture.minter.payer])
+        .unwrap();
+
+    assert_eq!(token_account(&fixture.minter.svm, &recipient).amount, 400);
+}
+
+/// Returns a hook instruction that asks the token program to format an amount of the token,
+/// which only reads the token mint, and its encoding as hook data
+fn amount_to_ui_amount_hook(fixture: &MintFixture) -> (Instruction, Vec<u8>) {
+    let hook = spl_token::instruction::amount_to_ui_amount(
+        &spl_token::ID,
+        &fixture.minter.token_mint,
+        1_000,
+    )
+    .unwrap();
+    let hook_data = MintHook::encode(&hook.program_id, &hook.accounts, &hook.data);
+    (hook, hook_data)
+}
+
+#[test]
+fn executes_the_hook_with_the_attested_accounts() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let (hook, hook_data) = amount_to_ui_amount_hook(&fixture);
+    let mut attestation = fixture.attestation(recipient, 400);
+    attestation.elements[0].hook_data = &hook_data;
+
+    let metadata = fixture.mint(&attestation).unwrap();
+
+    let hook_invocations: Vec<_> = metadata
+        .inner_instructions
+        .iter()
+        .filter(|inner| inner.instruction.program_id == spl_token::ID)
+        .filter(|inner| inner.instruction.data == hook.data)
+        .collect();
+    assert_eq!(hook_invocations.len(), 1);
+    assert_eq!(
+        hook_invocations[0].instruction.accounts,
+        [AccountMeta::new_readonly(fixture.minter.token_mint, false)]
+    );
+    let events = metadata.events::<AttestationUsed>(&gateway_minter::ID);
+    assert_eq!(events[0].hook_data, hook_data);
+}
+
+#[test]
+fn fails_for_a_hook_account_other_than_the_attested_one() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let (_, hook_data) = amount_to_ui_amount_hook(&fixture);
+    let mut attestation = fixture.attestation(recipient, 400);
+    attestation.elements[0].hook_data = &hook_data;
+    let mut instruction = fixture.instruction(&attestation, &fixture.attester, false);
+    // Replace the attested token mint with another token's mint
+    let other_mint = create_mint(&mut fixture.minter.svm, &Pubkey::new_unique());
+    instruction.accounts.last_mut().unwrap().pubkey = other_mint;
+
+    let result = fixture
+        .minter
+        .svm
+        .process(&[instruction], &[fixture.minter.payer]);
+
+    assert_error(result, GatewayMinterError::InvalidHookAccount);
+}
+
+#[test]
+fn does_not_pass_signers_to_the_hook() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    // A hook that would move lamports from the payer, who signs the gateway_mint transaction
+    let hook = system_instruction::transfer(&fixture.minter.payer, &Pubkey::new_unique(), 1);
+    let hook_data = MintHook::encode(&hook.program_id, &hook.accounts, &hook.data);
+    let mut attestation = fixture.attestation(recipient, 400);
+    attestation.elements[0].hook_data = &hook_data;
+
+    let result = fixture.mint(&attestation);
+
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionError::InstructionError(0, ProgramError::MissingRequiredSignature)
+    );
+}
+
+#[test]
+fn fails_for_a_replayed_attestation() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+    fixture.mint(&attestation).unwrap();
+
+    let result = fixture.mint(&attestation);
+
+    assert_error(result, GatewayMinterError::TransferSpecHashAlreadyUsed);
+}
+
+#[test]
+fn records_an_explicit_nonce_in_its_nonce_bucket() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    fixture.set_replay_store(ReplayStore::Migrating);
+    fixture.set_replay_store(ReplayStore::NonceBuckets);
+    let recipient = fixture.recipient();
+    let namespace = depositor_namespace(Pubkey::new_unique());
+    let attestation = fixture.nonce_attestation(recipient, 400, namespace, 7);
+
+    fixture.mint(&attestation).unwrap();
+
+    let svm = &fixture.minter.svm;
+    assert_eq!(token_account(svm, &recipient).amount, 400);
+    let replay_key = ReplayKey::Nonce {
+        namespace,
+        nonce: 7,
+    };
+    let nonce_bucket: NonceBucket = svm
+        .get_anchor_account(&replay_key.find_address(&gateway_minter::ID).0)
+        .unwrap();
+    assert_eq!(nonce_bucket.source_domain, namespace.source_domain);
+    assert_eq!(nonce_bucket.source_contract, namespace.source_contract);
+    assert_eq!(nonce_bucket.source_depositor, namespace.source_depositor);
+    assert_eq!(nonce_bucket.bucket_index, 0);
+    assert!(svm
+        .get_account(&pda::used_transfer_spec_hash(
+            &gateway_minter::ID,
+            &attestation.elements[0].transfer_spec_hash,
+        ))
+        .is_none());
+
+    // Another transfer spec with the same nonce is a replay
+    let attestation = fixture.nonce_attestation(recipient, 300, namespace, 7);
+    let result = fixture.mint(&attestation);
+    assert_error(result, GatewayMinterError::TransferSpecHashAlreadyUsed);
+}
+
+#[test]
+fn scopes_explicit_nonces_to_the_source_domain_and_contract() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    fixture.set_replay_store(ReplayStore::Migrating);
+    let recipient = fixture.recipient();
+    let namespace = depositor_namespace(Pubkey::new_unique());
+    // The same depositor key and nonce on another domain, or of another source contract
+    let other_domain = NonceNamespace {
+        source_domain: 1,
+        ..namespace
+    };
+    let other_contract = NonceNamespace {
+        source_contract: Pubkey::new_unique(),
+        ..namespace
+    };
+
+    for (value, namespace) in [(100, namespace), (200, other_domain), (300, other_contract)] {
+        let attestation = fixture.nonce_attestation(recipient, value, namespace, 7);
+        fixture.mint(&attestation).unwrap();
+    }
+
+    assert_eq!(token_account(&fixture.minter.svm, &recipient).amount, 600);
+}
+
+#[test]
+fn fails_while_migrating_for_a_transfer_recorded_by_hash() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation =
+        fixture.nonce_attestation(recipient, 400, depositor_namespace(Pubkey::new_unique()), 7);
+    fixture.mint(&attestation).unwrap();
+    fixture.set_replay_store(ReplayStore::Migrating);
+
+    let result = fixture.mint(&attestation);
+
+    assert_error(result, GatewayMinterError::TransferSpecHashAlreadyUsed);
+}
+
+#[test]
+fn fails_for_a_transfer_spec_other_than_the_attested_one() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    fixture.set_replay_store(ReplayStore::Migrating);
+    let recipient = fixture.recipient();
+    let namespace = depositor_namespace(Pubkey::new_unique());
+    let attestation = fixture.nonce_attestation(recipient, 400, namespace, 7);
+    let other_attestation = fixture.nonce_attestation(recipient, 400, namespace, 8);
+    let other_transfer_spec =
+        fixture.transfer_specs[&other_attestation.elements[0].transfer_spec_hash].clone();
+    fixture.transfer_specs.insert(
+        attestation.elements[0].transfer_spec_hash,
+        other_transfer_spec,
+    );
+
+    let result = fixture.mint(&attestation);
+
+    assert_error(result, GatewayMinterError::InvalidTransferSpec);
+}
+
+#[test]
+fn fails_for_an_unknown_attester() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+    let instruction = fixture.instruction(&attestation, &EvmSigner::new([4; 32]), false);
+
+    let result = fixture
+        .minter
+        .svm
+        .process(&[instruction], &[fixture.minter.payer]);
+
+    assert_error(result, GatewayMinterError::InvalidAttesterSignature);
+}
+
+#[test]
+fn fails_for_an_expired_attestation() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+    fixture
+        .minter
+        .svm
+        .warp_to_slot(attestation.max_block_height + 1);
+
+    let result = fixture.mint(&attestation);
+
+    assert_error(result, GatewayMinterError::AttestationExpired);
+}
+
+#[test]
+fn fails_for_an_attestation_valid_for_too_long_while_recorded_by_hash() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let mut attestation = fixture.attestation(recipient, 400);
+    attestation.max_block_height =
+        fixture.minter.svm.clock().slot + MAX_TRANSFER_VALIDITY_SLOTS + 1;
+
+    let result = fixture.mint(&attestation);
+
+    assert_error(result, GatewayMinterError::AttestationValidityTooLong);
+}
+
+#[test]
+fn fails_for_a_mismatched_attestation() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    type Mutation = fn(&mut MintAttestationStruct);
+    let cases: [(Mutation, GatewayMinterError); 4] = [
+        (
+            |attestation| attestation.destination_domain = LOCAL_DOMAIN + 1,
+            GatewayMinterError::DestinationDomainMismatch,
+        ),
+        (
+            |attestation| attestation.destination_contract = Pubkey::new_unique().to_bytes(),
+            GatewayMinterError::DestinationContractMismatch,
+        ),
+        (
+            |attestation| attestation.destination_caller = Pubkey::new_unique().to_bytes(),
+            GatewayMinterError::DestinationCallerMismatch,
+        ),
+        (
+            |attestation| attestation.version = 2,
+            GatewayMinterError::VersionMismatch,
+        ),
+    ];
+
+    for (mutate, error) in cases {
+        let mut attestation = fixture.attestation(recipient, 400);
+        mutate(&mut attestation);
+        assert_error(fixture.mint(&attestation), error);
+    }
+}
+
+#[test]
+fn fails_for_a_zero_value() {
+    let mut fixture = MintFixture::new();
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 0);
+
+    let result = fixture.mint(&attestation);
+
+    assert_error(result, GatewayMinterError::InvalidAttestationValue);
+}
+
+#[test]
+fn skips_mints_beyond_the_rate_limit() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let minter = &mut fixture.minter;
+    minter
+        .svm
+        .process(
+            &[minter::set_mint_rate_limit(
+                minter.payer,
+                minter.owner,
+                minter.token_mint,
+                SetMintRateLimitParams {
+                    capacity: 500,
+                    refill_per_slot: 10,
+                },
+            )],
+            &[minter.payer, minter.owner],
+        )
+        .unwrap();
+    let recipient = fixture.recipient();
+    fixture.mint(&fixture.attestation(recipient, 400)).unwrap();
+
+    let attestation = fixture.attestation(recipient, 200);
+    let metadata = fixture.mint(&attestation).unwrap();
+
+    // The element is skipped without minting or recording the transfer
+    let transfer_spec_hash = attestation.elements[0].transfer_spec_hash;
+    let events = metadata.events::<MintRateLimited>(&gateway_minter::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].token, fixture.minter.token_mint);
+    assert_eq!(events[0].recipient, recipient);
+    assert_eq!(events[0].transfer_spec_hash, transfer_spec_hash);
+    assert_eq!(events[0].value, 200);
+    assert!(events[0].available < 200);
+    assert!(metadata
+        .events::<AttestationUsed>(&gateway_minter::ID)
+        .is_empty());
+    let svm = &fixture.minter.svm;
+    assert_eq!(token_account(svm, &recipient).amount, 400);
+    assert!(svm
+        .get_account(&pda::used_transfer_spec_hash(
+            &gateway_minter::ID,
+            &transfer_spec_hash,
+        ))
+        .is_none());
+
+    // The rate limit refills over time, after which the same attestation mints
+    let slot = fixture.minter.svm.clock().slot;
+    fixture.minter.svm.warp_to_slot(slot + 10);
+    let metadata = fixture.mint(&attestation).unwrap();
+    assert!(metadata
+        .events::<MintRateLimited>(&gateway_minter::ID)
+        .is_empty());
+    assert_eq!(token_account(&fixture.minter.svm, &recipient).amount, 600);
+}
+
+#[test]
+fn does_not_write_lock_the_gateway_minter() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+
+    let instruction = fixture.instruction(&attestation, &fixture.attester, false);
+
+    // Mints of different tokens only write-lock their own token config accounts
+    let gateway_minter = pda::gateway_minter();
+    assert!(instruction
+        .accounts
+        .iter()
+        .all(|meta| meta.pubkey != gateway_minter || !meta.is_writable));
+    assert!(instruction.accounts.iter().any(
+        |meta| meta.is_writable && meta.pubkey == pda::token_config(&fixture.minter.token_mint)
+    ));
+    fixture
+        .minter
+        .svm
+        .process(&[instruction], &[fixture.minter.payer])
+        .unwrap();
+}
+
+#[test]
+fn fails_for_another_token_config_account() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+    let mut instruction = fixture.instruction(&attestation, &fixture.attester, false);
+    // Swap in the unconfigured token config of another token
+    let token_config = pda::token_config(&fixture.minter.token_mint);
+    let meta = instruction
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == token_config)
+        .unwrap();
+    meta.pubkey = pda::token_config(&Pubkey::new_unique());
+
+    let result = fixture
+        .minter
+        .svm
+        .process(&[instruction], &[fixture.minter.payer]);
+
+    assert_error(result, GatewayMinterError::InvalidTokenConfigAccount);
+}
+
+#[test]
+fn fails_while_paused() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let owner = fixture.minter.owner;
+    fixture
+        .minter
+        .svm
+        .process(&[minter::pause(owner)], &[owner])
+        .unwrap();
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+
+    let result = fixture.mint(&attestation);
+
+    assert_error(result, GatewayMinterError::ProgramPaused);
+}
@@ -0,0 +1,109 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::{error::ErrorCode, prelude::Pubkey};
+use gateway_client::{minter, pda};
+use gateway_minter::{
+    events::GatewayMinterInitialized, instructions::InitializeParams, state::GatewayMinter,
+};
+use gateway_program_tests::{
+    fixtures::{assert_error, LOCAL_DOMAIN, PAYER_LAMPORTS},
+    Svm, TransactionError, TransactionMetadata,
+};
+
+struct Setup {
+    svm: Svm,
+    payer: Pubkey,
+    upgrade_authority: Pubkey,
+}
+
+fn setup() -> Setup {
+    let mut svm = Svm::new();
+    let payer = Pubkey::new_unique();
+    let upgrade_authority = Pubkey::new_unique();
+    svm.set_upgradeable_program(gateway_minter::ID, Some(upgrade_authority));
+    svm.airdrop(&payer, PAYER_LAMPORTS);
+    Setup {
+        svm,
+        payer,
+        upgrade_authority,
+    }
+}
+
+fn initialize(
+    setup: &mut Setup,
+    upgrade_authority: Pubkey,
+) -> Result<TransactionMetadata, TransactionError> {
+    setup.svm.process(
+        &[minter::initialize(
+            setup.payer,
+            upgrade_authority,
+            InitializeParams {
+                local_domain: LOCAL_DOMAIN,
+            },
+        )],
+        &[setup.payer, upgrade_authority],
+    )
+}
+
+#[test]
+fn makes_the_upgrade_authority_every_role() {
+    let mut setup = setup();
+    let upgrade_authority = setup.upgrade_authority;
+
+    let metadata = initialize(&mut setup, upgrade_authority).unwrap();
+
+    let state: GatewayMinter = setup
+        .svm
+        .get_anchor_account(&pda::gateway_minter())
+        .unwrap();
+    for role in [state.owner, state.pauser, state.token_controller] {
+        assert_eq!(role, upgrade_authority);
+    }
+    assert_eq!(state.pending_owner, Pubkey::default());
+    assert_eq!(state.local_domain, LOCAL_DOMAIN);
+    assert_eq!(state.version, 1);
+    assert!(!state.paused);
+    assert!(state.enabled_attesters.is_empty());
+    assert!(state.supported_tokens.is_empty());
+    assert!(!state.transfer_receipts_enabled);
+
+    let events = metadata.events::<GatewayMinterInitialized>(&gateway_minter::ID);
+    assert_eq!(events.len(), 1);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_upgrade_authority() {
+    let mut setup = setup();
+
+    let result = initialize(&mut setup, Pubkey::new_unique());
+
+    assert_error(result, ErrorCode::ConstraintRaw);
+}
+
+#[test]
+fn fails_if_already_initialized() {
+    let mut setup = setup();
+    let upgrade_authority = setup.upgrade_authority;
+    initialize(&mut setup, upgrade_authority).unwrap();
+
+    let result = initialize(&mut setup, upgrade_authority);
+
+    // The system program's `AccountAlreadyInUse`
+    assert_error(result, 0u32);
+}
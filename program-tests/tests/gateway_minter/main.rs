@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GatewayMinter instruction tests
+
+mod accept_ownership;
+mod add_attester;
+mod add_token;
+mod burn_token_custody;
+mod close_used_transfer_spec_hash;
+mod configure_token_minter;
+mod gateway_mint;
+mod initialize;
+mod migrate_gateway_minter;
+mod pause;
+mod remove_attester;
+mod set_mint_rate_limit;
+mod set_replay_store;
+mod set_transfer_receipts;
+mod transfer_ownership;
+mod unpause;
+mod update_pauser;
+mod update_token_controller;
+mod views;
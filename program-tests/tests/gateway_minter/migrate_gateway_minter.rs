@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::{error::ErrorCode, prelude::*};
+use gateway_client::{minter, pda};
+use gateway_minter::{
+    error::GatewayMinterError,
+    events::GatewayMinterMigrated,
+    state::{GatewayMinter, ReplayStore, MAX_ATTESTERS, MAX_SUPPORTED_TOKENS},
+    utils::DISCRIMINATOR_SIZE,
+};
+use gateway_program_tests::{
+    fixtures::{assert_error, MinterFixture},
+    TransactionError, TransactionMetadata,
+};
+
+/// The size of a GatewayMinter state account created before the transfer receipts, replay store
+/// and replay migration settings were appended
+const LEGACY_SPACE: usize =
+    DISCRIMINATOR_SIZE + GatewayMinter::INIT_SPACE - 1 - ReplayStore::INIT_SPACE - 8;
+
+/// Replaces the GatewayMinter state account with one in the legacy layout holding `state`
+fn set_legacy_state(fixture: &mut MinterFixture, state: &GatewayMinter) {
+    let address = pda::gateway_minter();
+    let mut account = fixture.svm.get_account(&address).unwrap().clone();
+    let mut data = Vec::new();
+    state.try_serialize(&mut data).unwrap();
+    data.resize(LEGACY_SPACE, 0);
+    account.data = data;
+    account.lamports = Rent::default().minimum_balance(LEGACY_SPACE);
+    fixture.svm.set_account(address, account);
+}
+
+fn migrate_gateway_minter(
+    fixture: &mut MinterFixture,
+    owner: Pubkey,
+) -> std::result::Result<TransactionMetadata, TransactionError> {
+    fixture.svm.process(
+        &[minter::migrate_gateway_minter(fixture.payer, owner)],
+        &[fixture.payer, owner],
+    )
+}
+
+#[test]
+fn grows_a_legacy_gateway_minter_with_full_lists() {
+    let mut fixture = MinterFixture::new();
+    let mut state = fixture.state();
+    state.enabled_attesters = (0..MAX_ATTESTERS).map(|_| Pubkey::new_unique()).collect();
+    state.supported_tokens = (0..MAX_SUPPORTED_TOKENS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    state.custody_token_account_bumps = vec![255; MAX_SUPPORTED_TOKENS];
+    set_legacy_state(&mut fixture, &state);
+
+    // The legacy fields fill the account, so the new fields cannot be read
+    let result = fixture
+        .svm
+        .process(&[minter::pause(fixture.owner)], &[fixture.owner]);
+    assert_error(result, ErrorCode::AccountDidNotDeserialize);
+
+    let owner = fixture.owner;
+
+    let metadata = migrate_gateway_minter(&mut fixture, owner).unwrap();
+
+    let new_space = DISCRIMINATOR_SIZE + GatewayMinter::INIT_SPACE;
+    let account = fixture.svm.get_account(&pda::gateway_minter()).unwrap();
+    assert_eq!(account.data.len(), new_space);
+    assert_eq!(account.lamports, Rent::default().minimum_balance(new_space));
+    assert_eq!(
+        metadata.events::<GatewayMinterMigrated>(&gateway_minter::ID),
+        vec![GatewayMinterMigrated {
+            previous_space: LEGACY_SPACE as u64,
+            new_space: new_space as u64,
+        }]
+    );
+
+    let migrated = fixture.state();
+    assert_eq!(migrated.owner, state.owner);
+    assert_eq!(migrated.enabled_attesters, state.enabled_attesters);
+    assert_eq!(migrated.supported_tokens, state.supported_tokens);
+    assert!(!migrated.transfer_receipts_enabled);
+    assert_eq!(migrated.replay_store, ReplayStore::HashAccounts);
+    assert_eq!(migrated.replay_migration_started_slot, 0);
+
+    fixture
+        .svm
+        .process(&[minter::pause(fixture.owner)], &[fixture.owner])
+        .unwrap();
+    assert!(fixture.state().paused);
+}
+
+#[test]
+fn leaves_a_current_gateway_minter_unchanged() {
+    let mut fixture = MinterFixture::new();
+    let account = fixture
+        .svm
+        .get_account(&pda::gateway_minter())
+        .unwrap()
+        .clone();
+
+    let owner = fixture.owner;
+
+    let metadata = migrate_gateway_minter(&mut fixture, owner).unwrap();
+
+    assert_eq!(
+        fixture.svm.get_account(&pda::gateway_minter()).unwrap(),
+        &account
+    );
+    let events = metadata.events::<GatewayMinterMigrated>(&gateway_minter::ID);
+    assert_eq!(events[0].previous_space, events[0].new_space);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = MinterFixture::new();
+    let state = fixture.state();
+    set_legacy_state(&mut fixture, &state);
+    let attacker = Pubkey::new_unique();
+
+    let result = migrate_gateway_minter(&mut fixture, attacker);
+
+    assert_error(result, GatewayMinterError::InvalidAuthority);
+}
@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::minter;
+use gateway_minter::{
+    error::GatewayMinterError,
+    events::AttestationSignerRemoved,
+    instructions::{AddAttesterParams, RemoveAttesterParams},
+};
+use gateway_program_tests::fixtures::{assert_error, MinterFixture};
+
+#[test]
+fn disables_the_attester() {
+    let mut fixture = MinterFixture::new();
+    let attester = Pubkey::new_unique();
+    fixture
+        .svm
+        .process(
+            &[minter::add_attester(
+                fixture.owner,
+                AddAttesterParams { attester },
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[minter::remove_attester(
+                fixture.owner,
+                RemoveAttesterParams { attester },
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+
+    assert!(fixture.state().enabled_attesters.is_empty());
+    let events = metadata.events::<AttestationSignerRemoved>(&gateway_minter::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].signer, attester);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = MinterFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[minter::remove_attester(
+            attacker,
+            RemoveAttesterParams { attester: attacker },
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidAuthority);
+}
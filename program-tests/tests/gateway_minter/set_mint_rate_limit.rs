@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::minter;
+use gateway_minter::{
+    error::GatewayMinterError, events::MintRateLimitSet, instructions::SetMintRateLimitParams,
+};
+use gateway_program_tests::fixtures::{assert_error, MinterFixture};
+
+#[test]
+fn configures_a_full_rate_limit() {
+    let mut fixture = MinterFixture::new();
+    fixture.svm.warp_to_slot(42);
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[minter::set_mint_rate_limit(
+                fixture.payer,
+                fixture.owner,
+                fixture.token_mint,
+                SetMintRateLimitParams {
+                    capacity: 500,
+                    refill_per_slot: 10,
+                },
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+
+    let rate_limit = fixture
+        .token_config(&fixture.token_mint)
+        .unwrap()
+        .rate_limit;
+    assert_eq!(rate_limit.capacity, 500);
+    assert_eq!(rate_limit.refill_per_slot, 10);
+    assert_eq!(rate_limit.available, 500);
+    assert_eq!(rate_limit.last_refill_slot, 42);
+
+    let events = metadata.events::<MintRateLimitSet>(&gateway_minter::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].token, fixture.token_mint);
+    assert_eq!(events[0].capacity, 500);
+    assert_eq!(events[0].refill_per_slot, 10);
+}
+
+#[test]
+fn fails_to_refill_a_disabled_rate_limit() {
+    let mut fixture = MinterFixture::new();
+
+    let result = fixture.svm.process(
+        &[minter::set_mint_rate_limit(
+            fixture.payer,
+            fixture.owner,
+            fixture.token_mint,
+            SetMintRateLimitParams {
+                capacity: 0,
+                refill_per_slot: 10,
+            },
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidMintRateLimit);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_token_controller() {
+    let mut fixture = MinterFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[minter::set_mint_rate_limit(
+            fixture.payer,
+            attacker,
+            fixture.token_mint,
+            SetMintRateLimitParams {
+                capacity: 0,
+                refill_per_slot: 0,
+            },
+        )],
+        &[fixture.payer, attacker],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidAuthority);
+}
@@ -0,0 +1,108 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::{minter, pda};
+use gateway_minter::{
+    error::GatewayMinterError,
+    events::TransferReceiptsSet,
+    instructions::SetTransferReceiptsParams,
+    state::{MintReceipt, UsedTransferSpecHash},
+};
+use gateway_program_tests::fixtures::{assert_error, MinterFixture};
+
+use crate::gateway_mint::MintFixture;
+
+#[test]
+fn toggles_transfer_receipts() {
+    let mut fixture = MinterFixture::new();
+
+    for enabled in [true, false] {
+        let metadata = fixture
+            .svm
+            .process(
+                &[minter::set_transfer_receipts(
+                    fixture.owner,
+                    SetTransferReceiptsParams { enabled },
+                )],
+                &[fixture.owner],
+            )
+            .unwrap();
+
+        assert_eq!(fixture.state().transfer_receipts_enabled, enabled);
+        let events = metadata.events::<TransferReceiptsSet>(&gateway_minter::ID);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].enabled, enabled);
+    }
+}
+
+#[test]
+fn mints_store_a_receipt_while_enabled() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let owner = fixture.minter.owner;
+    fixture
+        .minter
+        .svm
+        .process(
+            &[minter::set_transfer_receipts(
+                owner,
+                SetTransferReceiptsParams { enabled: true },
+            )],
+            &[owner],
+        )
+        .unwrap();
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+
+    fixture.mint(&attestation).unwrap();
+
+    let used_transfer_spec_hash: UsedTransferSpecHash = fixture
+        .minter
+        .svm
+        .get_anchor_account(&pda::used_transfer_spec_hash(
+            &gateway_minter::ID,
+            &attestation.elements[0].transfer_spec_hash,
+        ))
+        .unwrap();
+    assert_eq!(
+        used_transfer_spec_hash.receipt,
+        Some(MintReceipt {
+            slot: fixture.minter.svm.clock().slot,
+            token: fixture.minter.token_mint,
+            recipient,
+            value: 400,
+        })
+    );
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = MinterFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[minter::set_transfer_receipts(
+            attacker,
+            SetTransferReceiptsParams { enabled: true },
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidAuthority);
+}
@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::minter;
+use gateway_minter::{
+    error::GatewayMinterError, events::TokenControllerUpdated,
+    instructions::UpdateTokenControllerParams,
+};
+use gateway_program_tests::fixtures::{assert_error, MinterFixture};
+
+#[test]
+fn updates_the_token_controller() {
+    let mut fixture = MinterFixture::new();
+    let new_token_controller = Pubkey::new_unique();
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[minter::update_token_controller(
+                fixture.owner,
+                UpdateTokenControllerParams {
+                    new_token_controller,
+                },
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+
+    assert_eq!(fixture.state().token_controller, new_token_controller);
+
+    let events = metadata.events::<TokenControllerUpdated>(&gateway_minter::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].previous_token_controller, fixture.owner);
+    assert_eq!(events[0].new_token_controller, new_token_controller);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = MinterFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[minter::update_token_controller(
+            attacker,
+            UpdateTokenControllerParams {
+                new_token_controller: attacker,
+            },
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidAuthority);
+}
+
+#[test]
+fn fails_for_the_default_pubkey() {
+    let mut fixture = MinterFixture::new();
+
+    let result = fixture.svm.process(
+        &[minter::update_token_controller(
+            fixture.owner,
+            UpdateTokenControllerParams {
+                new_token_controller: Pubkey::default(),
+            },
+        )],
+        &[fixture.owner],
+    );
+
+    assert_error(result, GatewayMinterError::InvalidTokenController);
+}
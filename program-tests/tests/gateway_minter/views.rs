@@ -0,0 +1,110 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::minter;
+use gateway_minter::{
+    error::GatewayMinterError,
+    instructions::{MinterConfig, SetMintRateLimitParams},
+    state::{TokenConfig, TokenMintMode},
+};
+use gateway_program_tests::fixtures::{assert_error, MinterFixture, LOCAL_DOMAIN};
+
+use crate::gateway_mint::MintFixture;
+
+#[test]
+fn is_transfer_spec_hash_used_once_minted() {
+    let mut fixture = MintFixture::new();
+    fixture.minter.fund_custody(1_000);
+    let recipient = fixture.recipient();
+    let attestation = fixture.attestation(recipient, 400);
+    let transfer_spec_hash = attestation.elements[0].transfer_spec_hash;
+    let view = minter::is_transfer_spec_hash_used(transfer_spec_hash);
+
+    let metadata = fixture.minter.svm.process(&[view.clone()], &[]).unwrap();
+    assert_eq!(metadata.return_value(&gateway_minter::ID), Some(false));
+
+    fixture.mint(&attestation).unwrap();
+
+    let metadata = fixture.minter.svm.process(&[view], &[]).unwrap();
+    assert_eq!(metadata.return_value(&gateway_minter::ID), Some(true));
+}
+
+#[test]
+fn get_config_returns_the_minter_config() {
+    let mut fixture = MinterFixture::new();
+
+    let config: MinterConfig = fixture
+        .svm
+        .process(&[minter::get_config()], &[])
+        .unwrap()
+        .return_value(&gateway_minter::ID)
+        .unwrap();
+
+    assert_eq!(config.owner, fixture.owner);
+    assert_eq!(config.pauser, fixture.owner);
+    assert_eq!(config.local_domain, LOCAL_DOMAIN);
+    assert_eq!(config.supported_tokens, vec![fixture.token_mint]);
+    assert_eq!(config.enabled_attesters, fixture.state().enabled_attesters);
+    assert!(!config.paused);
+}
+
+#[test]
+fn get_token_config_refills_the_rate_limit() {
+    let mut fixture = MinterFixture::new();
+    fixture.svm.warp_to_slot(42);
+    fixture
+        .svm
+        .process(
+            &[minter::set_mint_rate_limit(
+                fixture.payer,
+                fixture.owner,
+                fixture.token_mint,
+                SetMintRateLimitParams {
+                    capacity: 500,
+                    refill_per_slot: 10,
+                },
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+    fixture.svm.warp_to_slot(50);
+
+    let config: TokenConfig = fixture
+        .svm
+        .process(&[minter::get_token_config(fixture.token_mint)], &[])
+        .unwrap()
+        .return_value(&gateway_minter::ID)
+        .unwrap();
+
+    assert_eq!(config.mint_mode, TokenMintMode::Custody);
+    assert_eq!(config.rate_limit.capacity, 500);
+    assert_eq!(config.rate_limit.available, 500);
+    assert_eq!(config.rate_limit.last_refill_slot, 50);
+}
+
+#[test]
+fn get_token_config_fails_for_an_unsupported_token() {
+    let mut fixture = MinterFixture::new();
+
+    let result = fixture
+        .svm
+        .process(&[minter::get_token_config(Pubkey::new_unique())], &[]);
+
+    assert_error(result, GatewayMinterError::TokenNotSupported);
+}
@@ -0,0 +1,92 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::wallet;
+use gateway_program_tests::{
+    fixtures::{assert_error, WalletFixture},
+    TransactionError, TransactionMetadata,
+};
+use gateway_wallet::{
+    error::GatewayWalletError, events::BurnSignerAdded, instructions::AddBurnSignerParams,
+    state::MAX_BURN_SIGNERS,
+};
+
+fn add_burn_signer(
+    fixture: &mut WalletFixture,
+    signer: Pubkey,
+) -> Result<TransactionMetadata, TransactionError> {
+    fixture.svm.process(
+        &[wallet::add_burn_signer(
+            fixture.owner,
+            AddBurnSignerParams { signer },
+        )],
+        &[fixture.owner],
+    )
+}
+
+#[test]
+fn adds_a_burn_signer_once() {
+    let mut fixture = WalletFixture::new();
+    let signer = Pubkey::new_unique();
+
+    let metadata = add_burn_signer(&mut fixture, signer).unwrap();
+    add_burn_signer(&mut fixture, signer).unwrap();
+
+    assert_eq!(fixture.state().burn_signers, vec![signer]);
+    let events = metadata.events::<BurnSignerAdded>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].signer, signer);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = WalletFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::add_burn_signer(
+            attacker,
+            AddBurnSignerParams { signer: attacker },
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
+
+#[test]
+fn fails_for_the_default_pubkey() {
+    let mut fixture = WalletFixture::new();
+
+    let result = add_burn_signer(&mut fixture, Pubkey::default());
+
+    assert_error(result, GatewayWalletError::InvalidBurnSigner);
+}
+
+#[test]
+fn fails_past_the_signer_limit() {
+    let mut fixture = WalletFixture::new();
+    for _ in 0..MAX_BURN_SIGNERS {
+        add_burn_signer(&mut fixture, Pubkey::new_unique()).unwrap();
+    }
+
+    let result = add_burn_signer(&mut fixture, Pubkey::new_unique());
+
+    assert_error(result, GatewayWalletError::BurnSignerLimitExceeded);
+}
@@ -0,0 +1,150 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, create_mint, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::DelegateAdded,
+    state::{DelegateStatus, GatewayDelegate},
+};
+
+#[test]
+fn authorizes_the_delegate() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let delegate = Pubkey::new_unique();
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::add_delegate(
+                fixture.payer,
+                depositor,
+                fixture.token_mint,
+                delegate,
+            )],
+            &[fixture.payer, depositor],
+        )
+        .unwrap();
+
+    let delegate_account: GatewayDelegate = fixture
+        .svm
+        .get_anchor_account(&pda::gateway_delegate(
+            &fixture.token_mint,
+            &depositor,
+            &delegate,
+        ))
+        .unwrap();
+    assert_eq!(delegate_account.status, DelegateStatus::Authorized);
+    assert_eq!(delegate_account.token, fixture.token_mint);
+    assert_eq!(delegate_account.depositor, depositor);
+    assert_eq!(delegate_account.delegate, delegate);
+
+    let events = metadata.events::<DelegateAdded>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].depositor, depositor);
+    assert_eq!(events[0].delegate, delegate);
+}
+
+#[test]
+fn fails_for_an_invalid_delegate() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+
+    for (delegate, error) in [
+        (Pubkey::default(), GatewayWalletError::InvalidDelegate),
+        (depositor, GatewayWalletError::CannotDelegateToSelf),
+    ] {
+        let result = fixture.svm.process(
+            &[wallet::add_delegate(
+                fixture.payer,
+                depositor,
+                fixture.token_mint,
+                delegate,
+            )],
+            &[fixture.payer, depositor],
+        );
+        assert_error(result, error);
+    }
+}
+
+#[test]
+fn fails_if_the_depositor_or_delegate_is_denylisted() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let delegate = Pubkey::new_unique();
+
+    for denylisted in [delegate, depositor] {
+        fixture.denylist(denylisted);
+
+        let result = fixture.svm.process(
+            &[wallet::add_delegate(
+                fixture.payer,
+                depositor,
+                fixture.token_mint,
+                delegate,
+            )],
+            &[fixture.payer, depositor],
+        );
+
+        assert_error(result, GatewayWalletError::AccountDenylisted);
+    }
+}
+
+#[test]
+fn fails_for_an_unsupported_token() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+
+    let result = fixture.svm.process(
+        &[wallet::add_delegate(
+            fixture.payer,
+            depositor,
+            token_mint,
+            Pubkey::new_unique(),
+        )],
+        &[fixture.payer, depositor],
+    );
+
+    assert_error(result, GatewayWalletError::TokenNotSupported);
+}
+
+#[test]
+fn fails_while_paused() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture
+        .svm
+        .process(&[wallet::pause(fixture.owner)], &[fixture.owner])
+        .unwrap();
+
+    let result = fixture.svm.process(
+        &[wallet::add_delegate(
+            fixture.payer,
+            depositor,
+            fixture.token_mint,
+            Pubkey::new_unique(),
+        )],
+        &[fixture.payer, depositor],
+    );
+
+    assert_error(result, GatewayWalletError::ProgramPaused);
+}
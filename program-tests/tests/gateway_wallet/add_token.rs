@@ -0,0 +1,117 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, create_mint, token_account, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::TokenSupported,
+    state::{TokenTotals, MAX_SUPPORTED_TOKENS},
+};
+
+#[test]
+fn supports_the_token_and_creates_its_custody_account() {
+    let mut fixture = WalletFixture::new();
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::add_token(fixture.payer, fixture.owner, token_mint)],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+
+    let state = fixture.state();
+    assert_eq!(state.supported_tokens, vec![fixture.token_mint, token_mint]);
+    assert_eq!(state.custody_token_account_bumps.len(), 2);
+
+    let custody = pda::gateway_wallet_custody(&token_mint);
+    let custody_account = token_account(&fixture.svm, &custody);
+    assert_eq!(custody_account.mint, token_mint);
+    assert_eq!(custody_account.owner, pda::gateway_wallet());
+    assert_eq!(custody_account.amount, 0);
+    let token_totals: TokenTotals = fixture
+        .svm
+        .get_anchor_account(&pda::token_totals(&token_mint))
+        .unwrap();
+    assert_eq!(token_totals.token, token_mint);
+    assert_eq!(token_totals.total_deposits, 0);
+
+    let events = metadata.events::<TokenSupported>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].token, token_mint);
+    assert_eq!(events[0].custody_token_account, custody);
+}
+
+#[test]
+fn does_nothing_for_a_supported_token() {
+    let mut fixture = WalletFixture::new();
+
+    fixture
+        .svm
+        .process(
+            &[wallet::add_token(
+                fixture.payer,
+                fixture.owner,
+                fixture.token_mint,
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+
+    assert_eq!(fixture.state().supported_tokens, vec![fixture.token_mint]);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_token_controller() {
+    let mut fixture = WalletFixture::new();
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::add_token(fixture.payer, attacker, token_mint)],
+        &[fixture.payer, attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
+
+#[test]
+fn fails_past_the_token_limit() {
+    let mut fixture = WalletFixture::new();
+    for _ in 1..MAX_SUPPORTED_TOKENS {
+        let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+        fixture
+            .svm
+            .process(
+                &[wallet::add_token(fixture.payer, fixture.owner, token_mint)],
+                &[fixture.payer, fixture.owner],
+            )
+            .unwrap();
+    }
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+
+    let result = fixture.svm.process(
+        &[wallet::add_token(fixture.payer, fixture.owner, token_mint)],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayWalletError::MaxTokensSupported);
+}
@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::AccountMeta};
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError, events::Denylisted, instructions::BatchDenylistParams,
+    state::Denylist,
+};
+
+#[test]
+fn denylists_every_account() {
+    let mut fixture = WalletFixture::new();
+    let already_denylisted = Pubkey::new_unique();
+    fixture.denylist(already_denylisted);
+    let accounts = vec![
+        Pubkey::new_unique(),
+        already_denylisted,
+        Pubkey::new_unique(),
+    ];
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::batch_denylist(
+                fixture.payer,
+                fixture.owner,
+                BatchDenylistParams {
+                    accounts: accounts.clone(),
+                },
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+
+    for account in &accounts {
+        assert!(fixture
+            .svm
+            .get_anchor_account::<Denylist>(&pda::denylist(account))
+            .is_some());
+    }
+    let events = metadata.events::<Denylisted>(&gateway_wallet::ID);
+    let denylisted: Vec<Pubkey> = events.iter().map(|event| event.addr).collect();
+    assert_eq!(denylisted, accounts);
+}
+
+#[test]
+fn fails_for_an_empty_batch() {
+    let mut fixture = WalletFixture::new();
+
+    let result = fixture.svm.process(
+        &[wallet::batch_denylist(
+            fixture.payer,
+            fixture.owner,
+            BatchDenylistParams { accounts: vec![] },
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayWalletError::EmptyDenylistBatch);
+}
+
+#[test]
+fn fails_for_missing_or_mismatched_denylist_accounts() {
+    let mut fixture = WalletFixture::new();
+    let params = BatchDenylistParams {
+        accounts: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+    };
+    let instruction = wallet::batch_denylist(fixture.payer, fixture.owner, params);
+
+    let mut missing = instruction.clone();
+    missing.accounts.pop();
+    let result = fixture
+        .svm
+        .process(&[missing], &[fixture.payer, fixture.owner]);
+    assert_error(result, GatewayWalletError::RemainingAccountsLengthMismatch);
+
+    let mut mismatched = instruction;
+    let last = mismatched.accounts.len() - 1;
+    mismatched.accounts[last] = AccountMeta::new(Pubkey::new_unique(), false);
+    let result = fixture
+        .svm
+        .process(&[mismatched], &[fixture.payer, fixture.owner]);
+    assert_error(result, GatewayWalletError::InvalidDenylistAccount);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_denylister() {
+    let mut fixture = WalletFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::batch_denylist(
+            fixture.payer,
+            attacker,
+            BatchDenylistParams {
+                accounts: vec![Pubkey::new_unique()],
+            },
+        )],
+        &[fixture.payer, attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
@@ -0,0 +1,115 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::{error::ErrorCode, prelude::Pubkey};
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError, events::UnDenylisted, instructions::BatchUndenylistParams,
+};
+
+#[test]
+fn removes_every_account_from_the_denylist() {
+    let mut fixture = WalletFixture::new();
+    let accounts = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+    for account in &accounts {
+        fixture.denylist(*account);
+    }
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::batch_undenylist(
+                fixture.payer,
+                fixture.owner,
+                BatchUndenylistParams {
+                    accounts: accounts.clone(),
+                },
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+
+    for account in &accounts {
+        assert!(fixture.svm.get_account(&pda::denylist(account)).is_none());
+    }
+    let events = metadata.events::<UnDenylisted>(&gateway_wallet::ID);
+    let undenylisted: Vec<Pubkey> = events.iter().map(|event| event.addr).collect();
+    assert_eq!(undenylisted, accounts);
+}
+
+#[test]
+fn fails_if_an_account_is_not_denylisted() {
+    let mut fixture = WalletFixture::new();
+    let denylisted = Pubkey::new_unique();
+    fixture.denylist(denylisted);
+
+    let result = fixture.svm.process(
+        &[wallet::batch_undenylist(
+            fixture.payer,
+            fixture.owner,
+            BatchUndenylistParams {
+                accounts: vec![denylisted, Pubkey::new_unique()],
+            },
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, ErrorCode::AccountNotInitialized);
+    assert!(fixture
+        .svm
+        .get_account(&pda::denylist(&denylisted))
+        .is_some());
+}
+
+#[test]
+fn fails_for_an_empty_batch() {
+    let mut fixture = WalletFixture::new();
+
+    let result = fixture.svm.process(
+        &[wallet::batch_undenylist(
+            fixture.payer,
+            fixture.owner,
+            BatchUndenylistParams { accounts: vec![] },
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayWalletError::EmptyDenylistBatch);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_denylister() {
+    let mut fixture = WalletFixture::new();
+    let account = Pubkey::new_unique();
+    fixture.denylist(account);
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::batch_undenylist(
+            fixture.payer,
+            attacker,
+            BatchUndenylistParams {
+                accounts: vec![account],
+            },
+        )],
+        &[fixture.payer, attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
@@ -0,0 +1,293 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::{prelude::Pubkey, Discriminator};
+use gateway_client::{pda, wallet};
+use gateway_program_tests::{fixtures::assert_error, TransactionError, TransactionMetadata};
+use gateway_shared::replay::{is_nonce_used, ReplayKey};
+use gateway_wallet::{
+    burn_data::BurnIntent,
+    error::GatewayWalletError,
+    events::UsedTransferSpecHashClosed,
+    instructions::{CloseUsedTransferSpecHashParams, SetReplayStoreParams},
+    state::{NonceBucket, ReplayStore, UsedTransferSpecHash},
+};
+
+use crate::gateway_burn::BurnFixture;
+
+// Returns the fixture, the transfer spec hash and the expiry slot of a completed burn
+fn burned() -> (BurnFixture, [u8; 32], u64) {
+    let mut fixture = BurnFixture::new(1_000);
+    let burn_intent = fixture.burn_intent(100);
+    fixture.burn(&burn_intent, 10).unwrap();
+    (
+        fixture,
+        burn_intent.transfer_spec.hash(),
+        burn_intent.max_block_height,
+    )
+}
+
+#[test]
+fn refunds_the_rent_payer_after_expiry() {
+    let (mut fixture, transfer_spec_hash, expiry_slot) = burned();
+    let svm = &mut fixture.wallet.svm;
+    let payer = fixture.wallet.payer;
+    let address = pda::used_transfer_spec_hash(&gateway_wallet::ID, &transfer_spec_hash);
+    let rent = svm.get_account(&address).unwrap().lamports;
+    let balance = svm.get_account(&payer).unwrap().lamports;
+    svm.warp_to_slot(expiry_slot + 1);
+
+    // Anyone can submit the close, the rent always goes to the rent payer
+    let metadata = svm
+        .process(
+            &[wallet::close_used_transfer_spec_hash(
+                payer,
+                payer,
+                CloseUsedTransferSpecHashParams {
+                    transfer_spec_hash,
+                    transfer_spec: None,
+                },
+            )],
+            &[],
+        )
+        .unwrap();
+
+    // The discriminator stays behind as a tombstone, holding only the rent it needs
+    let tombstone = svm.get_account(&address).unwrap();
+    assert_eq!(tombstone.data, UsedTransferSpecHash::DISCRIMINATOR);
+    assert_eq!(
+        svm.get_account(&payer).unwrap().lamports,
+        balance + rent - tombstone.lamports
+    );
+
+    let events = metadata.events::<UsedTransferSpecHashClosed>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].transfer_spec_hash, transfer_spec_hash);
+    assert_eq!(events[0].rent_payer, payer);
+}
+
+#[test]
+fn fails_before_expiry() {
+    let (mut fixture, transfer_spec_hash, expiry_slot) = burned();
+    fixture.wallet.svm.warp_to_slot(expiry_slot);
+
+    let result = fixture.wallet.svm.process(
+        &[wallet::close_used_transfer_spec_hash(
+            fixture.wallet.payer,
+            fixture.wallet.payer,
+            CloseUsedTransferSpecHashParams {
+                transfer_spec_hash,
+                transfer_spec: None,
+            },
+        )],
+        &[],
+    );
+
+    assert_error(result, GatewayWalletError::TransferSpecHashNotExpired);
+}
+
+#[test]
+fn fails_for_another_rent_payer() {
+    let (mut fixture, transfer_spec_hash, expiry_slot) = burned();
+    fixture.wallet.svm.warp_to_slot(expiry_slot + 1);
+
+    let result = fixture.wallet.svm.process(
+        &[wallet::close_used_transfer_spec_hash(
+            fixture.wallet.payer,
+            Pubkey::new_unique(),
+            CloseUsedTransferSpecHashParams {
+                transfer_spec_hash,
+                transfer_spec: None,
+            },
+        )],
+        &[],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidRentPayer);
+}
+
+#[test]
+fn fails_to_replay_a_later_expiry_burn_intent_after_closing() {
+    let mut fixture = BurnFixture::new(1_000);
+    let burn_intent = fixture.burn_intent(100);
+    fixture.burn(&burn_intent, 10).unwrap();
+    let expiry_slot = burn_intent.max_block_height;
+    fixture.wallet.svm.warp_to_slot(expiry_slot + 1);
+    fixture
+        .wallet
+        .svm
+        .process(
+            &[wallet::close_used_transfer_spec_hash(
+                fixture.wallet.payer,
+                fixture.wallet.payer,
+                CloseUsedTransferSpecHashParams {
+                    transfer_spec_hash: burn_intent.transfer_spec.hash(),
+                    transfer_spec: None,
+                },
+            )],
+            &[],
+        )
+        .unwrap();
+
+    // A new burn intent of the same transfer spec, that has not expired yet
+    let mut replay = burn_intent.clone();
+    replay.max_block_height = expiry_slot + 100;
+    let result = fixture.burn(&replay, 10);
+
+    assert_error(result, GatewayWalletError::TransferSpecHashAlreadyUsed);
+    assert_eq!(
+        fixture
+            .wallet
+            .deposit(&fixture.depositor())
+            .available_amount,
+        890
+    );
+}
+
+// Returns the fixture and a completed burn recorded by hash, for a transfer spec with the
+// explicit nonce 7
+fn burned_with_nonce() -> (BurnFixture, BurnIntent) {
+    let mut fixture = BurnFixture::new(1_000);
+    let mut burn_intent = fixture.burn_intent(100);
+    burn_intent.transfer_spec.salt = [0; 32];
+    burn_intent.transfer_spec.salt[31] = 7;
+    fixture.burn(&burn_intent, 10).unwrap();
+    (fixture, burn_intent)
+}
+
+fn start_migrating(fixture: &mut BurnFixture) {
+    let owner = fixture.wallet.owner;
+    fixture
+        .wallet
+        .svm
+        .process(
+            &[wallet::set_replay_store(
+                owner,
+                SetReplayStoreParams {
+                    replay_store: ReplayStore::Migrating,
+                },
+            )],
+            &[owner],
+        )
+        .unwrap();
+}
+
+fn close_with_transfer_spec(
+    fixture: &mut BurnFixture,
+    burn_intent: &BurnIntent,
+) -> Result<TransactionMetadata, TransactionError> {
+    let payer = fixture.wallet.payer;
+    fixture.wallet.svm.process(
+        &[wallet::close_used_transfer_spec_hash(
+            payer,
+            payer,
+            CloseUsedTransferSpecHashParams {
+                transfer_spec_hash: burn_intent.transfer_spec.hash(),
+                transfer_spec: Some(burn_intent.transfer_spec.encode()),
+            },
+        )],
+        &[payer],
+    )
+}
+
+#[test]
+fn closes_fully_once_the_nonce_is_recorded() {
+    let (mut fixture, burn_intent) = burned_with_nonce();
+    start_migrating(&mut fixture);
+    let transfer_spec_hash = burn_intent.transfer_spec.hash();
+    let payer = fixture.wallet.payer;
+    let address = pda::used_transfer_spec_hash(&gateway_wallet::ID, &transfer_spec_hash);
+    let rent = fixture.wallet.svm.get_account(&address).unwrap().lamports;
+    let balance = fixture.wallet.svm.get_account(&payer).unwrap().lamports;
+    fixture
+        .wallet
+        .svm
+        .warp_to_slot(burn_intent.max_block_height + 1);
+
+    close_with_transfer_spec(&mut fixture, &burn_intent).unwrap();
+
+    // The account is closed, and the transfer is recorded in its nonce bucket instead
+    let svm = &fixture.wallet.svm;
+    assert!(svm.get_account(&address).is_none());
+    let replay_key =
+        ReplayKey::from_transfer_spec(transfer_spec_hash, &burn_intent.transfer_spec.encode())
+            .unwrap();
+    let nonce_bucket = replay_key.find_address(&gateway_wallet::ID).0;
+    let nonce_bucket_account = svm.get_account(&nonce_bucket).unwrap();
+    assert!(is_nonce_used(
+        &nonce_bucket_account.data,
+        NonceBucket::DISCRIMINATOR,
+        7
+    ));
+    assert_eq!(
+        svm.get_account(&payer).unwrap().lamports,
+        balance + rent - nonce_bucket_account.lamports
+    );
+}
+
+#[test]
+fn fails_to_replay_a_later_expiry_burn_intent_after_closing_fully() {
+    let (mut fixture, burn_intent) = burned_with_nonce();
+    start_migrating(&mut fixture);
+    let expiry_slot = burn_intent.max_block_height;
+    fixture.wallet.svm.warp_to_slot(expiry_slot + 1);
+    close_with_transfer_spec(&mut fixture, &burn_intent).unwrap();
+
+    // A new burn intent of the same transfer spec, that has not expired yet
+    let mut replay = burn_intent.clone();
+    replay.max_block_height = expiry_slot + 100;
+    let result = fixture.burn(&replay, 10);
+
+    assert_error(result, GatewayWalletError::TransferSpecHashAlreadyUsed);
+    assert_eq!(
+        fixture
+            .wallet
+            .deposit(&fixture.depositor())
+            .available_amount,
+        890
+    );
+}
+
+#[test]
+fn fails_to_close_fully_while_burns_are_recorded_by_hash() {
+    let (mut fixture, burn_intent) = burned_with_nonce();
+    fixture
+        .wallet
+        .svm
+        .warp_to_slot(burn_intent.max_block_height + 1);
+
+    let result = close_with_transfer_spec(&mut fixture, &burn_intent);
+
+    assert_error(result, GatewayWalletError::TransferNotRecordedByNonce);
+}
+
+#[test]
+fn fails_to_close_fully_without_an_explicit_nonce() {
+    let mut fixture = BurnFixture::new(1_000);
+    let burn_intent = fixture.burn_intent(100);
+    fixture.burn(&burn_intent, 10).unwrap();
+    start_migrating(&mut fixture);
+    fixture
+        .wallet
+        .svm
+        .warp_to_slot(burn_intent.max_block_height + 1);
+
+    let result = close_with_transfer_spec(&mut fixture, &burn_intent);
+
+    assert_error(result, GatewayWalletError::TransferNotRecordedByNonce);
+}
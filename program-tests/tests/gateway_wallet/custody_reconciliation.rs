@@ -0,0 +1,392 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::{
+    error::ErrorCode,
+    prelude::{AccountDeserialize, AccountSerialize, Pubkey},
+    solana_program::system_instruction::SystemError,
+};
+use anchor_spl::associated_token::get_associated_token_address;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::{
+    fixtures::{
+        assert_error, create_associated_token_account, set_token_account, token_account,
+        WalletFixture,
+    },
+    TransactionError, TransactionMetadata,
+};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::{CustodyReconciled, CustodySurplusSwept, TokenTotalsInitialized},
+    instructions::InitializeTokenTotalsParams,
+    state::TokenTotals,
+};
+
+fn reconcile(fixture: &mut WalletFixture) -> CustodyReconciled {
+    let metadata = fixture
+        .svm
+        .process(&[wallet::reconcile(fixture.token_mint)], &[fixture.payer])
+        .unwrap();
+    let mut events = metadata.events::<CustodyReconciled>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    events.remove(0)
+}
+
+fn sweep_custody_surplus(
+    fixture: &mut WalletFixture,
+    destination: Pubkey,
+) -> Result<TransactionMetadata, TransactionError> {
+    fixture.svm.process(
+        &[wallet::sweep_custody_surplus(
+            fixture.owner,
+            fixture.token_mint,
+            destination,
+        )],
+        &[fixture.owner],
+    )
+}
+
+fn initialize_token_totals(
+    fixture: &mut WalletFixture,
+    total_deposits: u64,
+) -> Result<TransactionMetadata, TransactionError> {
+    fixture.svm.process(
+        &[wallet::initialize_token_totals(
+            fixture.payer,
+            fixture.owner,
+            fixture.token_mint,
+            InitializeTokenTotalsParams { total_deposits },
+        )],
+        &[fixture.payer, fixture.owner],
+    )
+}
+
+/// Overwrites the deposit totals of the token, as if they had drifted from the deposits
+fn set_total_deposits(fixture: &mut WalletFixture, total_deposits: u64) {
+    let address = pda::token_totals(&fixture.token_mint);
+    let mut account = fixture.svm.get_account(&address).unwrap().clone();
+    let mut token_totals = TokenTotals::try_deserialize(&mut account.data.as_slice()).unwrap();
+    token_totals.total_deposits = total_deposits;
+    account.data.clear();
+    token_totals.try_serialize(&mut account.data).unwrap();
+    fixture.svm.set_account(address, account);
+}
+
+/// Sends `amount` of the token straight to the custody token account, bypassing a deposit
+fn send_to_custody(fixture: &mut WalletFixture, amount: u64) {
+    let sender = Pubkey::new_unique();
+    let sender_token_account =
+        create_associated_token_account(&mut fixture.svm, &fixture.token_mint, &sender, amount);
+    fixture
+        .svm
+        .process(
+            &[spl_token::instruction::transfer(
+                &spl_token::ID,
+                &sender_token_account,
+                &pda::gateway_wallet_custody(&fixture.token_mint),
+                &sender,
+                &[],
+                amount,
+            )
+            .unwrap()],
+            &[fixture.payer, sender],
+        )
+        .unwrap();
+}
+
+#[test]
+fn reconcile_reports_a_balanced_custody() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.initiate_withdrawal(depositor, 400);
+
+    let event = reconcile(&mut fixture);
+    assert_eq!(event.token, fixture.token_mint);
+    assert_eq!(event.custody_balance, 1_000);
+    assert_eq!(event.total_deposits, 1_000);
+    assert_eq!(event.surplus, 0);
+    assert_eq!(event.deficit, 0);
+}
+
+#[test]
+fn reconcile_reports_a_surplus_or_deficit() {
+    let mut fixture = WalletFixture::new();
+    fixture.depositor(1_000);
+    send_to_custody(&mut fixture, 25);
+
+    let event = reconcile(&mut fixture);
+    assert_eq!(event.custody_balance, 1_025);
+    assert_eq!(event.total_deposits, 1_000);
+    assert_eq!(event.surplus, 25);
+    assert_eq!(event.deficit, 0);
+
+    let custody = pda::gateway_wallet_custody(&fixture.token_mint);
+    let token_mint = fixture.token_mint;
+    set_token_account(
+        &mut fixture.svm,
+        custody,
+        &token_mint,
+        &pda::gateway_wallet(),
+        990,
+    );
+
+    let event = reconcile(&mut fixture);
+    assert_eq!(event.custody_balance, 990);
+    assert_eq!(event.surplus, 0);
+    assert_eq!(event.deficit, 10);
+}
+
+#[test]
+fn sweep_transfers_only_the_surplus() {
+    let mut fixture = WalletFixture::new();
+    fixture.depositor(1_000);
+    send_to_custody(&mut fixture, 25);
+    let destination = create_associated_token_account(
+        &mut fixture.svm,
+        &fixture.token_mint,
+        &Pubkey::new_unique(),
+        0,
+    );
+
+    let metadata = sweep_custody_surplus(&mut fixture, destination).unwrap();
+
+    assert_eq!(token_account(&fixture.svm, &destination).amount, 25);
+    let custody = pda::gateway_wallet_custody(&fixture.token_mint);
+    assert_eq!(token_account(&fixture.svm, &custody).amount, 1_000);
+    assert_eq!(fixture.total_deposits(), 1_000);
+
+    let events = metadata.events::<CustodySurplusSwept>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].token, fixture.token_mint);
+    assert_eq!(events[0].destination_token_account, destination);
+    assert_eq!(events[0].amount, 25);
+
+    // Nothing is left to sweep
+    let result = sweep_custody_surplus(&mut fixture, destination);
+    assert_error(result, GatewayWalletError::NoCustodySurplus);
+}
+
+#[test]
+fn sweep_fails_if_not_signed_by_the_token_controller() {
+    let mut fixture = WalletFixture::new();
+    send_to_custody(&mut fixture, 25);
+    let attacker = Pubkey::new_unique();
+    let destination =
+        create_associated_token_account(&mut fixture.svm, &fixture.token_mint, &attacker, 0);
+
+    let result = fixture.svm.process(
+        &[wallet::sweep_custody_surplus(
+            attacker,
+            fixture.token_mint,
+            destination,
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
+
+#[test]
+fn add_token_fails_for_a_supported_token_without_totals() {
+    let mut fixture = WalletFixture::new();
+    fixture.depositor(1_000);
+    fixture.remove_token_totals();
+
+    let result = fixture.svm.process(
+        &[wallet::add_token(
+            fixture.payer,
+            fixture.owner,
+            fixture.token_mint,
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayWalletError::TokenTotalsNotInitialized);
+}
+
+#[test]
+fn tracks_no_totals_for_a_token_without_them() {
+    let mut fixture = WalletFixture::new();
+    fixture.remove_token_totals();
+
+    let depositor = fixture.depositor(1_000);
+    fixture.initiate_withdrawal(depositor, 400);
+    let withdrawal_block = fixture.deposit(&depositor).withdrawal_block;
+    fixture.svm.warp_to_slot(withdrawal_block);
+    fixture
+        .svm
+        .process(
+            &[wallet::withdraw(
+                depositor,
+                get_associated_token_address(&depositor, &fixture.token_mint),
+                fixture.token_mint,
+            )],
+            &[depositor],
+        )
+        .unwrap();
+
+    assert_eq!(fixture.deposit(&depositor).available_amount, 600);
+    assert!(fixture
+        .svm
+        .get_account(&pda::token_totals(&fixture.token_mint))
+        .is_none());
+}
+
+#[test]
+fn initialize_token_totals_starts_from_the_audited_total_deposits() {
+    let mut fixture = WalletFixture::new();
+    fixture.depositor(1_000);
+    fixture.remove_token_totals();
+
+    let metadata = initialize_token_totals(&mut fixture, 1_000).unwrap();
+
+    let token_totals: TokenTotals = fixture
+        .svm
+        .get_anchor_account(&pda::token_totals(&fixture.token_mint))
+        .unwrap();
+    assert_eq!(token_totals.total_deposits, 1_000);
+    assert!(token_totals.initialized_from_audit);
+    let events = metadata.events::<TokenTotalsInitialized>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].token, fixture.token_mint);
+    assert_eq!(events[0].total_deposits, 1_000);
+    assert_eq!(reconcile(&mut fixture).surplus, 0);
+
+    // The totals are tracked from then on
+    fixture.depositor(500);
+    assert_eq!(fixture.total_deposits(), 1_500);
+}
+
+#[test]
+fn initialize_token_totals_fails_below_the_custody_balance() {
+    let mut fixture = WalletFixture::new();
+    fixture.depositor(1_000);
+    fixture.remove_token_totals();
+    let destination = create_associated_token_account(
+        &mut fixture.svm,
+        &fixture.token_mint,
+        &Pubkey::new_unique(),
+        0,
+    );
+
+    // An understated total would let the deposits it leaves out be swept as a surplus
+    let result = initialize_token_totals(&mut fixture, 0);
+    assert_error(result, GatewayWalletError::TotalDepositsBelowCustody);
+    let result = initialize_token_totals(&mut fixture, 999);
+    assert_error(result, GatewayWalletError::TotalDepositsBelowCustody);
+
+    let result = sweep_custody_surplus(&mut fixture, destination);
+    assert_error(result, ErrorCode::AccountNotInitialized);
+    assert_eq!(token_account(&fixture.svm, &destination).amount, 0);
+    let custody = pda::gateway_wallet_custody(&fixture.token_mint);
+    assert_eq!(token_account(&fixture.svm, &custody).amount, 1_000);
+}
+
+#[test]
+fn sweep_fails_for_audited_total_deposits() {
+    let mut fixture = WalletFixture::new();
+    fixture.depositor(1_000);
+    send_to_custody(&mut fixture, 25);
+    fixture.remove_token_totals();
+    initialize_token_totals(&mut fixture, 1_025).unwrap();
+    send_to_custody(&mut fixture, 10);
+    let destination = create_associated_token_account(
+        &mut fixture.svm,
+        &fixture.token_mint,
+        &Pubkey::new_unique(),
+        0,
+    );
+
+    let result = sweep_custody_surplus(&mut fixture, destination);
+
+    assert_error(result, GatewayWalletError::CustodySurplusUnverifiable);
+    let custody = pda::gateway_wallet_custody(&fixture.token_mint);
+    assert_eq!(token_account(&fixture.svm, &custody).amount, 1_035);
+}
+
+#[test]
+fn initialize_token_totals_fails_once_initialized() {
+    let mut fixture = WalletFixture::new();
+    fixture.depositor(1_000);
+
+    let result = initialize_token_totals(&mut fixture, 0);
+
+    assert_error(result, SystemError::AccountAlreadyInUse as u32);
+    assert_eq!(fixture.total_deposits(), 1_000);
+}
+
+#[test]
+fn initialize_token_totals_fails_if_not_signed_by_the_token_controller() {
+    let mut fixture = WalletFixture::new();
+    fixture.remove_token_totals();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::initialize_token_totals(
+            fixture.payer,
+            attacker,
+            fixture.token_mint,
+            InitializeTokenTotalsParams { total_deposits: 0 },
+        )],
+        &[fixture.payer, attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
+
+#[test]
+fn deposit_fails_if_the_total_deposits_overflow() {
+    let mut fixture = WalletFixture::new();
+    fixture.remove_token_totals();
+    initialize_token_totals(&mut fixture, u64::MAX - 10).unwrap();
+    let (depositor, token_account) = fixture.funded_depositor(11);
+
+    let result = fixture.svm.process(
+        &[wallet::deposit(
+            fixture.payer,
+            depositor,
+            token_account,
+            fixture.token_mint,
+            11,
+        )],
+        &[fixture.payer, depositor],
+    );
+
+    assert_error(result, GatewayWalletError::TotalDepositsOverflow);
+}
+
+#[test]
+fn withdrawal_fails_if_the_total_deposits_underflow() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    set_total_deposits(&mut fixture, 100);
+    fixture.initiate_withdrawal(depositor, 400);
+    let withdrawal_block = fixture.deposit(&depositor).withdrawal_block;
+    fixture.svm.warp_to_slot(withdrawal_block);
+
+    let result = fixture.svm.process(
+        &[wallet::withdraw(
+            depositor,
+            get_associated_token_address(&depositor, &fixture.token_mint),
+            fixture.token_mint,
+        )],
+        &[depositor],
+    );
+
+    assert_error(result, GatewayWalletError::TotalDepositsUnderflow);
+}
@@ -0,0 +1,72 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError, events::Denylisted, instructions::DenylistParams, state::Denylist,
+};
+
+#[test]
+fn creates_the_denylist_account() {
+    let mut fixture = WalletFixture::new();
+    let account = Pubkey::new_unique();
+
+    for _ in 0..2 {
+        let metadata = fixture
+            .svm
+            .process(
+                &[wallet::denylist(
+                    fixture.payer,
+                    fixture.owner,
+                    DenylistParams { account },
+                )],
+                &[fixture.payer, fixture.owner],
+            )
+            .unwrap();
+
+        let events = metadata.events::<Denylisted>(&gateway_wallet::ID);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].addr, account);
+    }
+
+    assert!(fixture
+        .svm
+        .get_anchor_account::<Denylist>(&pda::denylist(&account))
+        .is_some());
+}
+
+#[test]
+fn fails_if_not_signed_by_the_denylister() {
+    let mut fixture = WalletFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::denylist(
+            fixture.payer,
+            attacker,
+            DenylistParams {
+                account: Pubkey::new_unique(),
+            },
+        )],
+        &[fixture.payer, attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
@@ -0,0 +1,259 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::{Pubkey, Rent, Space};
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, token_account, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError, events::Deposited, state::GatewayDeposit, utils::DISCRIMINATOR_SIZE,
+};
+use spl_token::error::TokenError;
+
+#[test]
+fn deposit_moves_tokens_into_custody() {
+    let mut fixture = WalletFixture::new();
+    let (depositor, token_account_address) = fixture.funded_depositor(1_000);
+
+    for amount in [600, 400] {
+        let metadata = fixture
+            .svm
+            .process(
+                &[wallet::deposit(
+                    fixture.payer,
+                    depositor,
+                    token_account_address,
+                    fixture.token_mint,
+                    amount,
+                )],
+                &[fixture.payer, depositor],
+            )
+            .unwrap();
+
+        let events = metadata.events::<Deposited>(&gateway_wallet::ID);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].token, fixture.token_mint);
+        assert_eq!(events[0].depositor, depositor);
+        assert_eq!(events[0].sender, depositor);
+        assert_eq!(events[0].value, amount);
+    }
+
+    let deposit = fixture.deposit(&depositor);
+    assert_eq!(deposit.depositor, depositor);
+    assert_eq!(deposit.token_mint, fixture.token_mint);
+    assert_eq!(deposit.available_amount, 1_000);
+    assert_eq!(deposit.withdrawing_amount, 0);
+    assert_eq!(
+        token_account(&fixture.svm, &token_account_address).amount,
+        0
+    );
+    let custody = pda::gateway_wallet_custody(&fixture.token_mint);
+    assert_eq!(token_account(&fixture.svm, &custody).amount, 1_000);
+    assert_eq!(fixture.total_deposits(), 1_000);
+}
+
+#[test]
+fn deposit_grows_a_deposit_created_before_deposits_could_be_frozen() {
+    let mut fixture = WalletFixture::new();
+    let (depositor, token_account_address) = fixture.funded_depositor(1_000);
+    let deposit = |fixture: &mut WalletFixture, amount| {
+        fixture.svm.process(
+            &[wallet::deposit(
+                fixture.payer,
+                depositor,
+                token_account_address,
+                fixture.token_mint,
+                amount,
+            )],
+            &[fixture.payer, depositor],
+        )
+    };
+    deposit(&mut fixture, 600).unwrap();
+    fixture.set_legacy_deposit(&depositor);
+
+    deposit(&mut fixture, 400).unwrap();
+
+    let space = DISCRIMINATOR_SIZE + GatewayDeposit::INIT_SPACE;
+    let account = fixture
+        .svm
+        .get_account(&pda::gateway_deposit(&fixture.token_mint, &depositor))
+        .unwrap();
+    assert_eq!(account.data.len(), space);
+    assert_eq!(account.lamports, Rent::default().minimum_balance(space));
+    let deposit = fixture.deposit(&depositor);
+    assert_eq!(deposit.available_amount, 1_000);
+    assert!(!deposit.frozen);
+}
+
+#[test]
+fn deposit_fails_for_a_zero_amount() {
+    let mut fixture = WalletFixture::new();
+    let (depositor, token_account) = fixture.funded_depositor(1_000);
+
+    let result = fixture.svm.process(
+        &[wallet::deposit(
+            fixture.payer,
+            depositor,
+            token_account,
+            fixture.token_mint,
+            0,
+        )],
+        &[fixture.payer, depositor],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidDepositAmount);
+}
+
+#[test]
+fn deposit_fails_for_more_than_the_token_balance() {
+    let mut fixture = WalletFixture::new();
+    let (depositor, token_account) = fixture.funded_depositor(1_000);
+
+    let result = fixture.svm.process(
+        &[wallet::deposit(
+            fixture.payer,
+            depositor,
+            token_account,
+            fixture.token_mint,
+            1_001,
+        )],
+        &[fixture.payer, depositor],
+    );
+
+    assert_error(result, TokenError::InsufficientFunds as u32);
+}
+
+#[test]
+fn deposit_fails_for_a_denylisted_depositor() {
+    let mut fixture = WalletFixture::new();
+    let (depositor, token_account) = fixture.funded_depositor(1_000);
+    fixture.denylist(depositor);
+
+    let result = fixture.svm.process(
+        &[wallet::deposit(
+            fixture.payer,
+            depositor,
+            token_account,
+            fixture.token_mint,
+            1_000,
+        )],
+        &[fixture.payer, depositor],
+    );
+
+    assert_error(result, GatewayWalletError::AccountDenylisted);
+}
+
+#[test]
+fn deposit_for_credits_the_depositor() {
+    let mut fixture = WalletFixture::new();
+    let (sender, token_account) = fixture.funded_depositor(1_000);
+    let depositor = Pubkey::new_unique();
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::deposit_for(
+                fixture.payer,
+                sender,
+                token_account,
+                fixture.token_mint,
+                depositor,
+                1_000,
+            )],
+            &[fixture.payer, sender],
+        )
+        .unwrap();
+
+    let deposit = fixture.deposit(&depositor);
+    assert_eq!(deposit.depositor, depositor);
+    assert_eq!(deposit.available_amount, 1_000);
+    assert_eq!(fixture.total_deposits(), 1_000);
+
+    let events = metadata.events::<Deposited>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].depositor, depositor);
+    assert_eq!(events[0].sender, sender);
+}
+
+#[test]
+fn deposit_for_fails_for_the_default_depositor() {
+    let mut fixture = WalletFixture::new();
+    let (sender, token_account) = fixture.funded_depositor(1_000);
+
+    let result = fixture.svm.process(
+        &[wallet::deposit_for(
+            fixture.payer,
+            sender,
+            token_account,
+            fixture.token_mint,
+            Pubkey::default(),
+            1_000,
+        )],
+        &[fixture.payer, sender],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidDepositor);
+}
+
+#[test]
+fn deposit_for_fails_if_the_sender_or_depositor_is_denylisted() {
+    let mut fixture = WalletFixture::new();
+    let (sender, token_account) = fixture.funded_depositor(1_000);
+    let depositor = Pubkey::new_unique();
+
+    for denylisted in [depositor, sender] {
+        fixture.denylist(denylisted);
+
+        let result = fixture.svm.process(
+            &[wallet::deposit_for(
+                fixture.payer,
+                sender,
+                token_account,
+                fixture.token_mint,
+                depositor,
+                1_000,
+            )],
+            &[fixture.payer, sender],
+        );
+
+        assert_error(result, GatewayWalletError::AccountDenylisted);
+    }
+}
+
+#[test]
+fn deposit_for_fails_while_paused() {
+    let mut fixture = WalletFixture::new();
+    let (sender, token_account) = fixture.funded_depositor(1_000);
+    fixture
+        .svm
+        .process(&[wallet::pause(fixture.owner)], &[fixture.owner])
+        .unwrap();
+
+    let result = fixture.svm.process(
+        &[wallet::deposit_for(
+            fixture.payer,
+            sender,
+            token_account,
+            fixture.token_mint,
+            Pubkey::new_unique(),
+            1_000,
+        )],
+        &[fixture.payer, sender],
+    );
+
+    assert_error(result, GatewayWalletError::ProgramPaused);
+}
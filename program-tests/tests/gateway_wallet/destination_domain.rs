@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::{
+        DestinationDomainDisabled, DestinationDomainEnabled, DestinationDomainEnforcementSet,
+    },
+    instructions::{EnableDestinationDomainParams, SetDestinationDomainEnforcementParams},
+    state::DestinationDomain,
+};
+
+const DOMAIN: u32 = 6;
+
+fn destination_domain(fixture: &WalletFixture) -> DestinationDomain {
+    fixture
+        .svm
+        .get_anchor_account(&pda::destination_domain(DOMAIN))
+        .unwrap()
+}
+
+#[test]
+fn enables_disables_and_reenables_a_domain() {
+    let mut fixture = WalletFixture::new();
+    let destination_contract = Pubkey::new_unique();
+    let enable = wallet::enable_destination_domain(
+        fixture.payer,
+        fixture.owner,
+        EnableDestinationDomainParams {
+            domain: DOMAIN,
+            destination_contract,
+        },
+    );
+
+    let metadata = fixture
+        .svm
+        .process(&[enable.clone()], &[fixture.payer, fixture.owner])
+        .unwrap();
+    let domain = destination_domain(&fixture);
+    assert_eq!(domain.domain, DOMAIN);
+    assert_eq!(domain.destination_contract, destination_contract);
+    assert!(domain.enabled);
+    let events = metadata.events::<DestinationDomainEnabled>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].domain, DOMAIN);
+    assert_eq!(events[0].destination_contract, destination_contract);
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::disable_destination_domain(fixture.owner, DOMAIN)],
+            &[fixture.owner],
+        )
+        .unwrap();
+    assert!(!destination_domain(&fixture).enabled);
+    let events = metadata.events::<DestinationDomainDisabled>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].domain, DOMAIN);
+
+    fixture
+        .svm
+        .process(&[enable], &[fixture.payer, fixture.owner])
+        .unwrap();
+    assert!(destination_domain(&fixture).enabled);
+}
+
+#[test]
+fn enable_fails_for_the_default_destination_contract() {
+    let mut fixture = WalletFixture::new();
+
+    let result = fixture.svm.process(
+        &[wallet::enable_destination_domain(
+            fixture.payer,
+            fixture.owner,
+            EnableDestinationDomainParams {
+                domain: DOMAIN,
+                destination_contract: Pubkey::default(),
+            },
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidDestinationContract);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = WalletFixture::new();
+    fixture
+        .svm
+        .process(
+            &[wallet::enable_destination_domain(
+                fixture.payer,
+                fixture.owner,
+                EnableDestinationDomainParams {
+                    domain: DOMAIN,
+                    destination_contract: Pubkey::new_unique(),
+                },
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::enable_destination_domain(
+            fixture.payer,
+            attacker,
+            EnableDestinationDomainParams {
+                domain: DOMAIN,
+                destination_contract: attacker,
+            },
+        )],
+        &[fixture.payer, attacker],
+    );
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+
+    let result = fixture.svm.process(
+        &[wallet::disable_destination_domain(attacker, DOMAIN)],
+        &[attacker],
+    );
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+
+    let result = fixture.svm.process(
+        &[wallet::set_destination_domain_enforcement(
+            attacker,
+            SetDestinationDomainEnforcementParams { enforced: true },
+        )],
+        &[attacker],
+    );
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
+
+#[test]
+fn sets_the_destination_domain_enforcement() {
+    let mut fixture = WalletFixture::new();
+
+    for enforced in [true, false] {
+        let metadata = fixture
+            .svm
+            .process(
+                &[wallet::set_destination_domain_enforcement(
+                    fixture.owner,
+                    SetDestinationDomainEnforcementParams { enforced },
+                )],
+                &[fixture.owner],
+            )
+            .unwrap();
+
+        assert_eq!(fixture.state().destination_domains_enforced, enforced);
+        assert_eq!(
+            metadata.events::<DestinationDomainEnforcementSet>(&gateway_wallet::ID),
+            vec![DestinationDomainEnforcementSet { enforced }]
+        );
+    }
+}
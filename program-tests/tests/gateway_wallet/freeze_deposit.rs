@@ -0,0 +1,104 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::wallet;
+use gateway_program_tests::fixtures::{assert_error, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::{DepositFrozen, DepositUnfrozen},
+};
+
+#[test]
+fn freezes_and_unfreezes_a_deposit() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::freeze_deposit(
+                fixture.owner,
+                fixture.token_mint,
+                depositor,
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+    assert!(fixture.deposit(&depositor).frozen);
+    let events = metadata.events::<DepositFrozen>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].token, fixture.token_mint);
+    assert_eq!(events[0].depositor, depositor);
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::unfreeze_deposit(
+                fixture.owner,
+                fixture.token_mint,
+                depositor,
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+    assert!(!fixture.deposit(&depositor).frozen);
+    let events = metadata.events::<DepositUnfrozen>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].depositor, depositor);
+
+    fixture.initiate_withdrawal(depositor, 1_000);
+}
+
+#[test]
+fn freezes_a_deposit_created_before_deposits_could_be_frozen() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.set_legacy_deposit(&depositor);
+
+    fixture
+        .svm
+        .process(
+            &[wallet::freeze_deposit(
+                fixture.owner,
+                fixture.token_mint,
+                depositor,
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+
+    let deposit = fixture.deposit(&depositor);
+    assert!(deposit.frozen);
+    assert_eq!(deposit.available_amount, 1_000);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_denylister() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let attacker = Pubkey::new_unique();
+
+    for instruction in [
+        wallet::freeze_deposit(attacker, fixture.token_mint, depositor),
+        wallet::unfreeze_deposit(attacker, fixture.token_mint, depositor),
+    ] {
+        let result = fixture.svm.process(&[instruction], &[attacker]);
+        assert_error(result, GatewayWalletError::InvalidAuthority);
+    }
+}
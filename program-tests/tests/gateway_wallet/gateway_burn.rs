@@ -0,0 +1,692 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::{
+    fixtures::{
+        assert_error, create_associated_token_account, mint_supply, token_account, WalletFixture,
+        LOCAL_DOMAIN, PAYER_LAMPORTS,
+    },
+    signers::{EvmSigner, Keypair},
+    TransactionError, TransactionMetadata,
+};
+use gateway_shared::replay::MAX_TRANSFER_VALIDITY_SLOTS;
+use gateway_wallet::{
+    burn_data::{BurnIntent, TransferSpec},
+    error::GatewayWalletError,
+    events::{GatewayBurned, InsufficientBalance},
+    instructions::{
+        AddBurnSignerParams, EnableDestinationDomainParams, SetDestinationDomainEnforcementParams,
+        SetFeeScheduleParams, UpdateFeeSplitsParams,
+    },
+    state::{FeeSplit, UsedTransferSpecHash},
+};
+
+pub(crate) const DESTINATION_DOMAIN: u32 = 6;
+
+/// A GatewayWallet with an authorized burn signer, an enabled destination domain and a depositor
+/// whose key signs burn intents
+pub(crate) struct BurnFixture {
+    pub wallet: WalletFixture,
+    pub user: Keypair,
+    pub burn_signer: EvmSigner,
+    pub destination_contract: Pubkey,
+}
+
+impl BurnFixture {
+    pub fn new(deposit_amount: u64) -> Self {
+        let mut wallet = WalletFixture::new();
+        let burn_signer = EvmSigner::new([1; 32]);
+        let destination_contract = Pubkey::new_unique();
+        wallet
+            .svm
+            .process(
+                &[
+                    wallet::add_burn_signer(
+                        wallet.owner,
+                        AddBurnSignerParams {
+                            signer: burn_signer.address(),
+                        },
+                    ),
+                    wallet::enable_destination_domain(
+                        wallet.payer,
+                        wallet.owner,
+                        EnableDestinationDomainParams {
+                            domain: DESTINATION_DOMAIN,
+                            destination_contract,
+                        },
+                    ),
+                ],
+                &[wallet.payer, wallet.owner],
+            )
+            .unwrap();
+        create_associated_token_account(&mut wallet.svm, &wallet.token_mint, &wallet.owner, 0);
+
+        let mut fixture = Self {
+            wallet,
+            user: Keypair::new_unique(),
+            burn_signer,
+            destination_contract,
+        };
+        fixture.deposit(fixture.user.pubkey(), deposit_amount);
+        fixture
+    }
+
+    pub fn depositor(&self) -> Pubkey {
+        self.user.pubkey()
+    }
+
+    /// Deposits `amount` of the token for `depositor`
+    pub fn deposit(&mut self, depositor: Pubkey, amount: u64) {
+        let wallet = &mut self.wallet;
+        wallet.svm.airdrop(&depositor, PAYER_LAMPORTS);
+        let token_account = create_associated_token_account(
+            &mut wallet.svm,
+            &wallet.token_mint,
+            &depositor,
+            amount,
+        );
+        wallet
+            .svm
+            .process(
+                &[wallet::deposit(
+                    wallet.payer,
+                    depositor,
+                    token_account,
+                    wallet.token_mint,
+                    amount,
+                )],
+                &[wallet.payer, depositor],
+            )
+            .unwrap();
+    }
+
+    /// Returns a burn intent of `value` from the depositor's balance, signed by the depositor
+    pub fn burn_intent(&self, value: u64) -> BurnIntent {
+        BurnIntent {
+            max_block_height: self.wallet.svm.clock().slot + 100,
+            max_fee: 100,
+            transfer_spec: TransferSpec {
+                version: 1,
+                source_domain: LOCAL_DOMAIN,
+                destination_domain: DESTINATION_DOMAIN,
+                source_contract: gateway_wallet::ID,
+                destination_contract: self.destination_contract,
+                source_token: self.wallet.token_mint,
+                destination_token: Pubkey::new_unique(),
+                source_depositor: self.depositor(),
+                destination_recipient: Pubkey::new_unique(),
+                source_signer: self.depositor(),
+                destination_caller: Pubkey::default(),
+                value,
+                salt: Pubkey::new_unique().to_bytes(),
+                hook_data: vec![],
+            },
+        }
+    }
+
+    pub fn accounts(&self, burn_intent: &BurnIntent) -> wallet::GatewayBurnAccounts {
+        let state = self.wallet.state();
+        let transfer_spec = &burn_intent.transfer_spec;
+        wallet::GatewayBurnAccounts {
+            payer: self.wallet.payer,
+            source_domain: transfer_spec.source_domain,
+            token_mint: transfer_spec.source_token,
+            depositor: transfer_spec.source_depositor,
+            delegate: (transfer_spec.source_signer != transfer_spec.source_depositor)
+                .then_some(transfer_spec.source_signer),
+            destination_domain: transfer_spec.destination_domain,
+            transfer_spec_hash: transfer_spec.hash(),
+            salt: transfer_spec.salt,
+            fee_recipient: state.fee_recipient,
+            fee_split_recipients: state
+                .fee_splits
+                .iter()
+                .map(|split| split.recipient)
+                .collect(),
+            replay_store: state.replay_store,
+        }
+    }
+
+    /// Returns the Ed25519 and `gateway_burn` instructions for a burn intent signed by `user`
+    pub fn instructions(
+        &self,
+        user: &Keypair,
+        burn_signer: &EvmSigner,
+        burn_intent: &BurnIntent,
+        fee: u64,
+    ) -> [anchor_lang::solana_program::instruction::Instruction; 2] {
+        let burn_data = wallet::sign_burn_intent(user, fee, burn_intent).unwrap();
+        let burn_signature = burn_signer.sign(&burn_data.encode());
+        wallet::gateway_burn_with_user_signature(
+            &self.accounts(burn_intent),
+            user,
+            fee,
+            burn_intent,
+            burn_signature,
+            1,
+        )
+        .unwrap()
+    }
+
+    /// Submits a burn intent signed by the depositor and the burn signer
+    pub fn burn(
+        &mut self,
+        burn_intent: &BurnIntent,
+        fee: u64,
+    ) -> Result<TransactionMetadata, TransactionError> {
+        let instructions = self.instructions(&self.user, &self.burn_signer, burn_intent, fee);
+        self.wallet.svm.process(&instructions, &[self.wallet.payer])
+    }
+}
+
+#[test]
+fn burns_the_value_and_pays_the_fee() {
+    let mut fixture = BurnFixture::new(1_000);
+    let token_mint = fixture.wallet.token_mint;
+    let burn_intent = fixture.burn_intent(600);
+    let supply = mint_supply(&fixture.wallet.svm, &token_mint);
+
+    let metadata = fixture.burn(&burn_intent, 10).unwrap();
+
+    let wallet = &fixture.wallet;
+    assert_eq!(wallet.deposit(&fixture.depositor()).available_amount, 390);
+    assert_eq!(mint_supply(&wallet.svm, &token_mint), supply - 600);
+    assert_eq!(
+        token_account(
+            &wallet.svm,
+            &get_associated_token_address(&wallet.owner, &token_mint)
+        )
+        .amount,
+        10
+    );
+    assert_eq!(
+        token_account(&wallet.svm, &pda::gateway_wallet_custody(&token_mint)).amount,
+        390
+    );
+    assert_eq!(wallet.total_deposits(), 390);
+
+    let transfer_spec_hash = burn_intent.transfer_spec.hash();
+    let used_transfer_spec_hash: UsedTransferSpecHash = wallet
+        .svm
+        .get_anchor_account(&pda::used_transfer_spec_hash(
+            &gateway_wallet::ID,
+            &transfer_spec_hash,
+        ))
+        .unwrap();
+    assert_eq!(
+        used_transfer_spec_hash.expiry_slot,
+        burn_intent.max_block_height
+    );
+    assert_eq!(used_transfer_spec_hash.rent_payer, wallet.payer);
+    assert_eq!(used_transfer_spec_hash.receipt, None);
+
+    let events = metadata.events::<GatewayBurned>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.token, token_mint);
+    assert_eq!(event.depositor, fixture.depositor());
+    assert_eq!(event.transfer_spec_hash, transfer_spec_hash);
+    assert_eq!(event.destination_domain, DESTINATION_DOMAIN);
+    assert_eq!(
+        event.destination_recipient,
+        burn_intent.transfer_spec.destination_recipient.to_bytes()
+    );
+    assert_eq!(event.signer, fixture.depositor());
+    assert_eq!(event.value, 600);
+    assert_eq!(event.fee, 10);
+    assert_eq!(event.from_available, 610);
+    assert_eq!(event.from_withdrawing, 0);
+    assert_eq!(event.fee_shares.len(), 1);
+    assert_eq!(event.fee_shares[0].recipient, wallet.owner);
+    assert_eq!(event.fee_shares[0].amount, 10);
+}
+
+#[test]
+fn burns_without_totals_for_a_token_without_them() {
+    let mut fixture = BurnFixture::new(1_000);
+    fixture.wallet.remove_token_totals();
+    let burn_intent = fixture.burn_intent(600);
+
+    fixture.burn(&burn_intent, 10).unwrap();
+
+    let wallet = &fixture.wallet;
+    assert_eq!(wallet.deposit(&fixture.depositor()).available_amount, 390);
+    assert!(wallet
+        .svm
+        .get_account(&pda::token_totals(&wallet.token_mint))
+        .is_none());
+}
+
+/// Splits fees between the owner, as the fee recipient, and two partners, the first of which has
+/// an associated token account for the token
+fn set_fee_splits(fixture: &mut BurnFixture) -> (Pubkey, Pubkey) {
+    let wallet = &mut fixture.wallet;
+    let partner = Pubkey::new_unique();
+    let partner_without_account = Pubkey::new_unique();
+    wallet
+        .svm
+        .process(
+            &[wallet::update_fee_splits(
+                wallet.owner,
+                UpdateFeeSplitsParams {
+                    fee_splits: vec![
+                        FeeSplit {
+                            recipient: partner,
+                            bps: 3_000,
+                        },
+                        FeeSplit {
+                            recipient: partner_without_account,
+                            bps: 2_000,
+                        },
+                    ],
+                },
+            )],
+            &[wallet.owner],
+        )
+        .unwrap();
+    create_associated_token_account(&mut wallet.svm, &wallet.token_mint, &partner, 0);
+    (partner, partner_without_account)
+}
+
+#[test]
+fn splits_the_fee_between_the_fee_recipients() {
+    let mut fixture = BurnFixture::new(1_000);
+    let (partner, other_partner) = set_fee_splits(&mut fixture);
+    let token_mint = fixture.wallet.token_mint;
+    create_associated_token_account(&mut fixture.wallet.svm, &token_mint, &other_partner, 0);
+    let burn_intent = fixture.burn_intent(100);
+
+    let metadata = fixture.burn(&burn_intent, 15).unwrap();
+
+    let svm = &fixture.wallet.svm;
+    let balance = |owner: &Pubkey| {
+        token_account(svm, &get_associated_token_address(owner, &token_mint)).amount
+    };
+    // Partner shares are rounded down and the remainder goes to the fee recipient
+    assert_eq!(balance(&partner), 4);
+    assert_eq!(balance(&other_partner), 3);
+    assert_eq!(balance(&fixture.wallet.owner), 8);
+
+    let events = metadata.events::<GatewayBurned>(&gateway_wallet::ID);
+    let shares: Vec<_> = events[0]
+        .fee_shares
+        .iter()
+        .map(|share| (share.recipient, share.amount))
+        .collect();
+    assert_eq!(
+        shares,
+        [(fixture.wallet.owner, 8), (partner, 4), (other_partner, 3)]
+    );
+}
+
+#[test]
+fn pays_the_share_of_a_partner_without_a_token_account_to_the_fee_recipient() {
+    let mut fixture = BurnFixture::new(1_000);
+    let (partner, partner_without_account) = set_fee_splits(&mut fixture);
+    let token_mint = fixture.wallet.token_mint;
+    let burn_intent = fixture.burn_intent(100);
+
+    let metadata = fixture.burn(&burn_intent, 15).unwrap();
+
+    let svm = &fixture.wallet.svm;
+    let balance = |owner: &Pubkey| {
+        token_account(svm, &get_associated_token_address(owner, &token_mint)).amount
+    };
+    assert_eq!(balance(&partner), 4);
+    assert_eq!(balance(&fixture.wallet.owner), 11);
+    assert!(svm
+        .get_account(&get_associated_token_address(
+            &partner_without_account,
+            &token_mint
+        ))
+        .is_none());
+
+    let events = metadata.events::<GatewayBurned>(&gateway_wallet::ID);
+    let shares: Vec<_> = events[0]
+        .fee_shares
+        .iter()
+        .map(|share| (share.recipient, share.amount))
+        .collect();
+    assert_eq!(
+        shares,
+        [
+            (fixture.wallet.owner, 11),
+            (partner, 4),
+            (partner_without_account, 0)
+        ]
+    );
+}
+
+#[test]
+fn burns_what_remains_of_an_insufficient_balance() {
+    let mut fixture = BurnFixture::new(500);
+    // Another deposit keeps the custody balance sufficient
+    fixture.deposit(Pubkey::new_unique(), 1_000);
+    let burn_intent = fixture.burn_intent(600);
+
+    let metadata = fixture.burn(&burn_intent, 10).unwrap();
+
+    let events = metadata.events::<InsufficientBalance>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].value, 610);
+    assert_eq!(events[0].available_balance, 500);
+    assert_eq!(events[0].withdrawing_balance, 0);
+
+    let events = metadata.events::<GatewayBurned>(&gateway_wallet::ID);
+    assert_eq!(events[0].value, 500);
+    assert_eq!(events[0].fee, 0);
+    assert_eq!(
+        fixture
+            .wallet
+            .deposit(&fixture.depositor())
+            .available_amount,
+        0
+    );
+    assert_eq!(fixture.wallet.total_deposits(), 1_000);
+}
+
+#[test]
+fn a_delegate_can_sign_the_burn_intent() {
+    let mut fixture = BurnFixture::new(1_000);
+    let delegate = Keypair::new_unique();
+    fixture
+        .wallet
+        .svm
+        .process(
+            &[wallet::add_delegate(
+                fixture.wallet.payer,
+                fixture.depositor(),
+                fixture.wallet.token_mint,
+                delegate.pubkey(),
+            )],
+            &[fixture.wallet.payer, fixture.depositor()],
+        )
+        .unwrap();
+    let mut burn_intent = fixture.burn_intent(600);
+    burn_intent.transfer_spec.source_signer = delegate.pubkey();
+
+    let instructions = fixture.instructions(&delegate, &fixture.burn_signer, &burn_intent, 10);
+    let metadata = fixture
+        .wallet
+        .svm
+        .process(&instructions, &[fixture.wallet.payer])
+        .unwrap();
+
+    let events = metadata.events::<GatewayBurned>(&gateway_wallet::ID);
+    assert_eq!(events[0].signer, delegate.pubkey());
+    assert_eq!(events[0].depositor, fixture.depositor());
+}
+
+#[test]
+fn fails_for_an_unauthorized_signer() {
+    let mut fixture = BurnFixture::new(1_000);
+    let attacker = Keypair::new_unique();
+    let mut burn_intent = fixture.burn_intent(600);
+    burn_intent.transfer_spec.source_signer = attacker.pubkey();
+    let burn_data = wallet::sign_burn_intent(&attacker, 10, &burn_intent).unwrap();
+    // Without a delegate account the signer can only be the depositor
+    let accounts = wallet::GatewayBurnAccounts {
+        delegate: None,
+        ..fixture.accounts(&burn_intent)
+    };
+    let instructions = wallet::gateway_burn_with_user_signature(
+        &accounts,
+        &attacker,
+        10,
+        &burn_intent,
+        fixture.burn_signer.sign(&burn_data.encode()),
+        1,
+    )
+    .unwrap();
+
+    let result = fixture
+        .wallet
+        .svm
+        .process(&instructions, &[fixture.wallet.payer]);
+
+    assert_error(result, GatewayWalletError::InvalidDelegateAccount);
+}
+
+#[test]
+fn fails_for_a_replayed_burn_intent() {
+    let mut fixture = BurnFixture::new(1_000);
+    let burn_intent = fixture.burn_intent(100);
+    fixture.burn(&burn_intent, 10).unwrap();
+
+    let result = fixture.burn(&burn_intent, 10);
+
+    assert_error(result, GatewayWalletError::TransferSpecHashAlreadyUsed);
+}
+
+#[test]
+fn fails_for_an_unauthorized_burn_signer() {
+    let mut fixture = BurnFixture::new(1_000);
+    let burn_intent = fixture.burn_intent(100);
+    let instructions =
+        fixture.instructions(&fixture.user, &EvmSigner::new([2; 32]), &burn_intent, 10);
+
+    let result = fixture
+        .wallet
+        .svm
+        .process(&instructions, &[fixture.wallet.payer]);
+
+    assert_error(result, GatewayWalletError::BurnSignerNotAuthorized);
+}
+
+#[test]
+fn fails_without_the_ed25519_instruction() {
+    let mut fixture = BurnFixture::new(1_000);
+    let burn_intent = fixture.burn_intent(100);
+    let [_, gateway_burn] =
+        fixture.instructions(&fixture.user, &fixture.burn_signer, &burn_intent, 10);
+
+    let result = fixture
+        .wallet
+        .svm
+        .process(&[gateway_burn], &[fixture.wallet.payer]);
+
+    assert_error(
+        result,
+        GatewayWalletError::PreviousInstructionNotEd25519Program,
+    );
+}
+
+#[test]
+fn fails_for_an_expired_burn_intent() {
+    let mut fixture = BurnFixture::new(1_000);
+    let burn_intent = fixture.burn_intent(100);
+    fixture
+        .wallet
+        .svm
+        .warp_to_slot(burn_intent.max_block_height + 1);
+
+    let result = fixture.burn(&burn_intent, 10);
+
+    assert_error(result, GatewayWalletError::BurnIntentExpired);
+}
+
+#[test]
+fn fails_for_a_burn_intent_valid_for_too_long_while_recorded_by_hash() {
+    let mut fixture = BurnFixture::new(1_000);
+    let mut burn_intent = fixture.burn_intent(100);
+    burn_intent.max_block_height =
+        fixture.wallet.svm.clock().slot + MAX_TRANSFER_VALIDITY_SLOTS + 1;
+
+    let result = fixture.burn(&burn_intent, 10);
+
+    assert_error(result, GatewayWalletError::BurnIntentValidityTooLong);
+}
+
+#[test]
+fn fails_for_a_mismatched_transfer_spec() {
+    let mut fixture = BurnFixture::new(1_000);
+    type Mutation = fn(&mut TransferSpec);
+    let cases: [(Mutation, GatewayWalletError); 3] = [
+        (
+            |spec| spec.source_domain = LOCAL_DOMAIN + 1,
+            GatewayWalletError::SourceDomainMismatch,
+        ),
+        (
+            |spec| spec.source_contract = Pubkey::new_unique(),
+            GatewayWalletError::SourceContractMismatch,
+        ),
+        (
+            |spec| spec.destination_contract = Pubkey::new_unique(),
+            GatewayWalletError::DestinationContractMismatch,
+        ),
+    ];
+
+    for (mutate, error) in cases {
+        let mut burn_intent = fixture.burn_intent(100);
+        mutate(&mut burn_intent.transfer_spec);
+        assert_error(fixture.burn(&burn_intent, 10), error);
+    }
+}
+
+#[test]
+fn fails_for_a_disabled_destination_domain() {
+    let mut fixture = BurnFixture::new(1_000);
+    let owner = fixture.wallet.owner;
+    fixture
+        .wallet
+        .svm
+        .process(
+            &[wallet::disable_destination_domain(
+                owner,
+                DESTINATION_DOMAIN,
+            )],
+            &[owner],
+        )
+        .unwrap();
+    let burn_intent = fixture.burn_intent(100);
+
+    let result = fixture.burn(&burn_intent, 10);
+
+    assert_error(result, GatewayWalletError::DestinationDomainNotEnabled);
+}
+
+#[test]
+fn burns_to_an_unregistered_destination_domain_until_the_registry_is_enforced() {
+    let mut fixture = BurnFixture::new(1_000);
+    let unregistered = |fixture: &BurnFixture| {
+        let mut burn_intent = fixture.burn_intent(100);
+        burn_intent.transfer_spec.destination_domain = DESTINATION_DOMAIN + 1;
+        burn_intent
+    };
+
+    fixture.burn(&unregistered(&fixture), 10).unwrap();
+
+    let owner = fixture.wallet.owner;
+    fixture
+        .wallet
+        .svm
+        .process(
+            &[wallet::set_destination_domain_enforcement(
+                owner,
+                SetDestinationDomainEnforcementParams { enforced: true },
+            )],
+            &[owner],
+        )
+        .unwrap();
+
+    let result = fixture.burn(&unregistered(&fixture), 10);
+    assert_error(result, GatewayWalletError::DestinationDomainNotEnabled);
+
+    // Registered domains are unaffected
+    fixture.burn(&fixture.burn_intent(100), 10).unwrap();
+}
+
+#[test]
+fn fails_for_a_fee_above_the_max_fee() {
+    let mut fixture = BurnFixture::new(1_000);
+    let burn_intent = fixture.burn_intent(100);
+
+    let result = fixture.burn(&burn_intent, burn_intent.max_fee + 1);
+
+    assert_error(result, GatewayWalletError::BurnFeeExceedsMaxFee);
+}
+
+#[test]
+fn fails_for_a_fee_below_the_minimum() {
+    let mut fixture = BurnFixture::new(1_000);
+    let wallet = &mut fixture.wallet;
+    wallet
+        .svm
+        .process(
+            &[wallet::set_fee_schedule(
+                wallet.payer,
+                wallet.owner,
+                wallet.token_mint,
+                SetFeeScheduleParams {
+                    destination_domain: DESTINATION_DOMAIN,
+                    min_fee: 20,
+                    fee_bps: 0,
+                },
+            )],
+            &[wallet.payer, wallet.owner],
+        )
+        .unwrap();
+    let burn_intent = fixture.burn_intent(100);
+
+    assert_error(
+        fixture.burn(&burn_intent, 10),
+        GatewayWalletError::BurnFeeBelowMinimum,
+    );
+    fixture.burn(&burn_intent, 20).unwrap();
+}
+
+#[test]
+fn fails_for_a_frozen_deposit() {
+    let mut fixture = BurnFixture::new(1_000);
+    let wallet = &mut fixture.wallet;
+    wallet
+        .svm
+        .process(
+            &[wallet::freeze_deposit(
+                wallet.owner,
+                wallet.token_mint,
+                fixture.user.pubkey(),
+            )],
+            &[wallet.owner],
+        )
+        .unwrap();
+    let burn_intent = fixture.burn_intent(100);
+
+    let result = fixture.burn(&burn_intent, 10);
+
+    assert_error(result, GatewayWalletError::DepositFrozen);
+}
+
+#[test]
+fn fails_while_paused() {
+    let mut fixture = BurnFixture::new(1_000);
+    let owner = fixture.wallet.owner;
+    fixture
+        .wallet
+        .svm
+        .process(&[wallet::pause(owner)], &[owner])
+        .unwrap();
+    let burn_intent = fixture.burn_intent(100);
+
+    let result = fixture.burn(&burn_intent, 10);
+
+    assert_error(result, GatewayWalletError::ProgramPaused);
+}
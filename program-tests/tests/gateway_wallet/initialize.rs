@@ -0,0 +1,132 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::{error::ErrorCode, prelude::Pubkey};
+use gateway_client::{pda, wallet};
+use gateway_program_tests::{
+    fixtures::{assert_error, LOCAL_DOMAIN, PAYER_LAMPORTS, WITHDRAWAL_DELAY},
+    Svm, TransactionError, TransactionMetadata,
+};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::GatewayWalletInitialized,
+    instructions::InitializeParams,
+    state::{GatewayWallet, ReplayStore},
+};
+
+struct Setup {
+    svm: Svm,
+    payer: Pubkey,
+    upgrade_authority: Pubkey,
+}
+
+fn setup() -> Setup {
+    let mut svm = Svm::new();
+    let payer = Pubkey::new_unique();
+    let upgrade_authority = Pubkey::new_unique();
+    svm.set_upgradeable_program(gateway_wallet::ID, Some(upgrade_authority));
+    svm.airdrop(&payer, PAYER_LAMPORTS);
+    Setup {
+        svm,
+        payer,
+        upgrade_authority,
+    }
+}
+
+fn initialize(
+    setup: &mut Setup,
+    upgrade_authority: Pubkey,
+    withdrawal_delay: u64,
+) -> Result<TransactionMetadata, TransactionError> {
+    setup.svm.process(
+        &[wallet::initialize(
+            setup.payer,
+            upgrade_authority,
+            InitializeParams {
+                local_domain: LOCAL_DOMAIN,
+                withdrawal_delay,
+            },
+        )],
+        &[setup.payer, upgrade_authority],
+    )
+}
+
+#[test]
+fn makes_the_upgrade_authority_every_role() {
+    let mut setup = setup();
+    let upgrade_authority = setup.upgrade_authority;
+
+    let metadata = initialize(&mut setup, upgrade_authority, WITHDRAWAL_DELAY).unwrap();
+
+    let state: GatewayWallet = setup
+        .svm
+        .get_anchor_account(&pda::gateway_wallet())
+        .unwrap();
+    for role in [
+        state.owner,
+        state.pauser,
+        state.denylister,
+        state.token_controller,
+        state.fee_recipient,
+    ] {
+        assert_eq!(role, upgrade_authority);
+    }
+    assert_eq!(state.pending_owner, Pubkey::default());
+    assert_eq!(state.local_domain, LOCAL_DOMAIN);
+    assert_eq!(state.version, 1);
+    assert_eq!(state.withdrawal_delay, WITHDRAWAL_DELAY);
+    assert!(!state.paused);
+    assert!(state.supported_tokens.is_empty());
+    assert_eq!(state.replay_store, ReplayStore::HashAccounts);
+    assert!(!state.transfer_receipts_enabled);
+    assert!(!state.destination_domains_enforced);
+
+    let events = metadata.events::<GatewayWalletInitialized>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_upgrade_authority() {
+    let mut setup = setup();
+
+    let result = initialize(&mut setup, Pubkey::new_unique(), WITHDRAWAL_DELAY);
+
+    assert_error(result, ErrorCode::ConstraintRaw);
+}
+
+#[test]
+fn fails_for_a_zero_withdrawal_delay() {
+    let mut setup = setup();
+    let upgrade_authority = setup.upgrade_authority;
+
+    let result = initialize(&mut setup, upgrade_authority, 0);
+
+    assert_error(result, GatewayWalletError::InvalidWithdrawalDelay);
+}
+
+#[test]
+fn fails_if_already_initialized() {
+    let mut setup = setup();
+    let upgrade_authority = setup.upgrade_authority;
+    initialize(&mut setup, upgrade_authority, WITHDRAWAL_DELAY).unwrap();
+
+    let result = initialize(&mut setup, upgrade_authority, WITHDRAWAL_DELAY);
+
+    // The system program's `AccountAlreadyInUse`
+    assert_error(result, 0u32);
+}
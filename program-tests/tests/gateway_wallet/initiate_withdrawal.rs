@@ -0,0 +1,152 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use gateway_client::wallet;
+use gateway_program_tests::fixtures::{assert_error, WalletFixture, WITHDRAWAL_DELAY};
+use gateway_wallet::{error::GatewayWalletError, events::WithdrawalInitiated};
+
+#[test]
+fn moves_the_amount_to_the_withdrawing_balance() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.svm.warp_to_slot(50);
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::initiate_withdrawal(
+                depositor,
+                fixture.token_mint,
+                300,
+            )],
+            &[depositor],
+        )
+        .unwrap();
+
+    let deposit = fixture.deposit(&depositor);
+    assert_eq!(deposit.available_amount, 700);
+    assert_eq!(deposit.withdrawing_amount, 300);
+    assert_eq!(deposit.withdrawal_block, 50 + WITHDRAWAL_DELAY);
+
+    let events = metadata.events::<WithdrawalInitiated>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].depositor, depositor);
+    assert_eq!(events[0].value, 300);
+    assert_eq!(events[0].remaining_available, 700);
+    assert_eq!(events[0].total_withdrawing, 300);
+    assert_eq!(events[0].withdrawal_block, 50 + WITHDRAWAL_DELAY);
+}
+
+#[test]
+fn grows_a_deposit_created_before_deposits_could_be_frozen() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.set_legacy_deposit(&depositor);
+
+    fixture.initiate_withdrawal(depositor, 300);
+
+    let deposit = fixture.deposit(&depositor);
+    assert_eq!(deposit.available_amount, 700);
+    assert_eq!(deposit.withdrawing_amount, 300);
+    assert!(!deposit.frozen);
+}
+
+#[test]
+fn adds_to_a_withdrawal_in_progress_and_restarts_the_delay() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.initiate_withdrawal(depositor, 300);
+    fixture.svm.warp_to_slot(80);
+
+    fixture.initiate_withdrawal(depositor, 200);
+
+    let deposit = fixture.deposit(&depositor);
+    assert_eq!(deposit.available_amount, 500);
+    assert_eq!(deposit.withdrawing_amount, 500);
+    assert_eq!(deposit.withdrawal_block, 80 + WITHDRAWAL_DELAY);
+}
+
+#[test]
+fn fails_for_invalid_amounts() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+
+    for (amount, error) in [
+        (0, GatewayWalletError::InvalidWithdrawalAmount),
+        (1_001, GatewayWalletError::InsufficientDepositBalance),
+    ] {
+        let result = fixture.svm.process(
+            &[wallet::initiate_withdrawal(
+                depositor,
+                fixture.token_mint,
+                amount,
+            )],
+            &[depositor],
+        );
+        assert_error(result, error);
+    }
+}
+
+#[test]
+fn fails_while_paused() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture
+        .svm
+        .process(&[wallet::pause(fixture.owner)], &[fixture.owner])
+        .unwrap();
+
+    let result = fixture.svm.process(
+        &[wallet::initiate_withdrawal(
+            depositor,
+            fixture.token_mint,
+            1,
+        )],
+        &[depositor],
+    );
+
+    assert_error(result, GatewayWalletError::ProgramPaused);
+}
+
+#[test]
+fn fails_for_a_frozen_deposit() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture
+        .svm
+        .process(
+            &[wallet::freeze_deposit(
+                fixture.owner,
+                fixture.token_mint,
+                depositor,
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+
+    let result = fixture.svm.process(
+        &[wallet::initiate_withdrawal(
+            depositor,
+            fixture.token_mint,
+            1,
+        )],
+        &[depositor],
+    );
+
+    assert_error(result, GatewayWalletError::DepositFrozen);
+}
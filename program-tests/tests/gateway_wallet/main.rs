@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GatewayWallet instruction tests
+
+mod accept_ownership;
+mod add_burn_signer;
+mod add_delegate;
+mod add_token;
+mod batch_denylist;
+mod batch_undenylist;
+mod close_used_transfer_spec_hash;
+mod custody_reconciliation;
+mod denylist;
+mod deposit;
+mod destination_domain;
+mod freeze_deposit;
+mod gateway_burn;
+mod initialize;
+mod initiate_withdrawal;
+mod migrate_gateway_wallet;
+mod outflow_limit;
+mod pause;
+mod remove_burn_signer;
+mod remove_delegate;
+mod set_fee_schedule;
+mod set_replay_store;
+mod set_transfer_receipts;
+mod transfer_balance;
+mod transfer_ownership;
+mod undenylist;
+mod unpause;
+mod update_denylister;
+mod update_fee_recipient;
+mod update_fee_splits;
+mod update_pauser;
+mod update_token_controller;
+mod update_withdrawal_delay;
+mod views;
+mod withdrawal;
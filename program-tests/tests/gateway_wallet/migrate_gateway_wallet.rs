@@ -0,0 +1,183 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::{error::ErrorCode, prelude::*};
+use gateway_client::{pda, wallet};
+use gateway_program_tests::{
+    fixtures::{assert_error, WalletFixture},
+    TransactionError, TransactionMetadata,
+};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::GatewayWalletMigrated,
+    instructions::UpdateFeeSplitsParams,
+    state::{
+        FeeSplit, GatewayWallet, ReplayStore, MAX_BURN_SIGNERS, MAX_FEE_SPLITS,
+        MAX_SUPPORTED_TOKENS,
+    },
+    utils::DISCRIMINATOR_SIZE,
+};
+
+/// The size of a GatewayWallet state account created before fee splits were added, which also
+/// predates the replay store, transfer receipts, destination domain enforcement and replay
+/// migration settings appended after them
+const LEGACY_SPACE: usize = DISCRIMINATOR_SIZE + GatewayWallet::INIT_SPACE
+    - (4 + MAX_FEE_SPLITS * FeeSplit::INIT_SPACE)
+    - ReplayStore::INIT_SPACE
+    - 1
+    - 1
+    - 8;
+
+/// Replaces the GatewayWallet state account with one in the legacy layout holding `state`
+fn set_legacy_state(fixture: &mut WalletFixture, state: &GatewayWallet) {
+    let address = pda::gateway_wallet();
+    let mut account = fixture.svm.get_account(&address).unwrap().clone();
+    let mut data = Vec::new();
+    state.try_serialize(&mut data).unwrap();
+    data.resize(LEGACY_SPACE, 0);
+    account.data = data;
+    account.lamports = Rent::default().minimum_balance(LEGACY_SPACE);
+    fixture.svm.set_account(address, account);
+}
+
+fn migrate_gateway_wallet(
+    fixture: &mut WalletFixture,
+    owner: Pubkey,
+) -> std::result::Result<TransactionMetadata, TransactionError> {
+    fixture.svm.process(
+        &[wallet::migrate_gateway_wallet(fixture.payer, owner)],
+        &[fixture.payer, owner],
+    )
+}
+
+fn update_fee_splits(
+    fixture: &mut WalletFixture,
+    fee_splits: Vec<FeeSplit>,
+) -> std::result::Result<TransactionMetadata, TransactionError> {
+    fixture.svm.process(
+        &[wallet::update_fee_splits(
+            fixture.owner,
+            UpdateFeeSplitsParams { fee_splits },
+        )],
+        &[fixture.owner],
+    )
+}
+
+#[test]
+fn grows_a_legacy_gateway_wallet() {
+    let mut fixture = WalletFixture::new();
+    let state = fixture.state();
+    set_legacy_state(&mut fixture, &state);
+    let owner = fixture.owner;
+
+    let metadata = migrate_gateway_wallet(&mut fixture, owner).unwrap();
+
+    let new_space = DISCRIMINATOR_SIZE + GatewayWallet::INIT_SPACE;
+    let account = fixture.svm.get_account(&pda::gateway_wallet()).unwrap();
+    assert_eq!(account.data.len(), new_space);
+    assert_eq!(account.lamports, Rent::default().minimum_balance(new_space));
+    assert_eq!(
+        metadata.events::<GatewayWalletMigrated>(&gateway_wallet::ID),
+        vec![GatewayWalletMigrated {
+            previous_space: LEGACY_SPACE as u64,
+            new_space: new_space as u64,
+        }]
+    );
+
+    let fee_splits: Vec<_> = (0..MAX_FEE_SPLITS)
+        .map(|_| FeeSplit {
+            recipient: Pubkey::new_unique(),
+            bps: 1_000,
+        })
+        .collect();
+    update_fee_splits(&mut fixture, fee_splits.clone()).unwrap();
+    assert_eq!(fixture.state().fee_splits, fee_splits);
+}
+
+#[test]
+fn grows_a_legacy_gateway_wallet_with_full_lists() {
+    let mut fixture = WalletFixture::new();
+    let mut state = fixture.state();
+    state.supported_tokens = (0..MAX_SUPPORTED_TOKENS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    state.custody_token_account_bumps = vec![255; MAX_SUPPORTED_TOKENS];
+    state.burn_signers = (0..MAX_BURN_SIGNERS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    set_legacy_state(&mut fixture, &state);
+
+    // The legacy fields fill the account, so the new fields cannot be read
+    let result = fixture
+        .svm
+        .process(&[wallet::pause(fixture.owner)], &[fixture.owner]);
+    assert_error(result, ErrorCode::AccountDidNotDeserialize);
+
+    let owner = fixture.owner;
+
+    migrate_gateway_wallet(&mut fixture, owner).unwrap();
+
+    let migrated = fixture.state();
+    assert_eq!(migrated.owner, state.owner);
+    assert_eq!(migrated.supported_tokens, state.supported_tokens);
+    assert_eq!(migrated.burn_signers, state.burn_signers);
+    assert!(migrated.fee_splits.is_empty());
+    assert_eq!(migrated.replay_store, ReplayStore::HashAccounts);
+    assert!(!migrated.transfer_receipts_enabled);
+    assert!(!migrated.destination_domains_enforced);
+    assert_eq!(migrated.replay_migration_started_slot, 0);
+
+    fixture
+        .svm
+        .process(&[wallet::pause(fixture.owner)], &[fixture.owner])
+        .unwrap();
+    assert!(fixture.state().paused);
+}
+
+#[test]
+fn leaves_a_current_gateway_wallet_unchanged() {
+    let mut fixture = WalletFixture::new();
+    let account = fixture
+        .svm
+        .get_account(&pda::gateway_wallet())
+        .unwrap()
+        .clone();
+
+    let owner = fixture.owner;
+
+    let metadata = migrate_gateway_wallet(&mut fixture, owner).unwrap();
+
+    assert_eq!(
+        fixture.svm.get_account(&pda::gateway_wallet()).unwrap(),
+        &account
+    );
+    let events = metadata.events::<GatewayWalletMigrated>(&gateway_wallet::ID);
+    assert_eq!(events[0].previous_space, events[0].new_space);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = WalletFixture::new();
+    let state = fixture.state();
+    set_legacy_state(&mut fixture, &state);
+    let attacker = Pubkey::new_unique();
+
+    let result = migrate_gateway_wallet(&mut fixture, attacker);
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
@@ -0,0 +1,231 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::{
+    fixtures::{assert_error, create_mint, WalletFixture},
+    TransactionError, TransactionMetadata,
+};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::{OutflowLimitSet, OutflowOverrideSet},
+    instructions::{SetOutflowLimitParams, SetOutflowOverrideParams},
+    state::OutflowLimit,
+};
+
+const WINDOW_SLOTS: u64 = 100;
+
+fn set_outflow_limit(fixture: &mut WalletFixture, window_slots: u64, max_outflow: u64) {
+    fixture
+        .svm
+        .process(
+            &[wallet::set_outflow_limit(
+                fixture.payer,
+                fixture.owner,
+                fixture.token_mint,
+                SetOutflowLimitParams {
+                    window_slots,
+                    max_outflow,
+                },
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+}
+
+fn outflow_limit(fixture: &WalletFixture) -> OutflowLimit {
+    fixture
+        .svm
+        .get_anchor_account(&pda::outflow_limit(&fixture.token_mint))
+        .unwrap()
+}
+
+// Returns a depositor whose withdrawal of `amount` can be completed
+fn withdrawable_depositor(fixture: &mut WalletFixture, amount: u64) -> Pubkey {
+    let depositor = fixture.depositor(amount);
+    fixture.initiate_withdrawal(depositor, amount);
+    depositor
+}
+
+fn withdraw(
+    fixture: &mut WalletFixture,
+    depositor: Pubkey,
+) -> Result<TransactionMetadata, TransactionError> {
+    let token_account = get_associated_token_address(&depositor, &fixture.token_mint);
+    fixture.svm.process(
+        &[wallet::withdraw(
+            depositor,
+            token_account,
+            fixture.token_mint,
+        )],
+        &[depositor],
+    )
+}
+
+#[test]
+fn set_outflow_limit_configures_the_limit() {
+    let mut fixture = WalletFixture::new();
+    fixture.svm.warp_to_slot(42);
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::set_outflow_limit(
+                fixture.payer,
+                fixture.owner,
+                fixture.token_mint,
+                SetOutflowLimitParams {
+                    window_slots: WINDOW_SLOTS,
+                    max_outflow: 1_000,
+                },
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+
+    let limit = outflow_limit(&fixture);
+    assert_eq!(limit.token, fixture.token_mint);
+    assert_eq!(limit.window_slots, WINDOW_SLOTS);
+    assert_eq!(limit.max_outflow, 1_000);
+    assert_eq!(limit.current_window_start, 42);
+
+    let events = metadata.events::<OutflowLimitSet>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].window_slots, WINDOW_SLOTS);
+    assert_eq!(events[0].max_outflow, 1_000);
+}
+
+#[test]
+fn withdrawals_are_bounded_by_the_limit() {
+    let mut fixture = WalletFixture::new();
+    set_outflow_limit(&mut fixture, WINDOW_SLOTS, 1_000);
+    let first = withdrawable_depositor(&mut fixture, 600);
+    let second = withdrawable_depositor(&mut fixture, 600);
+    let slot = fixture.deposit(&second).withdrawal_block;
+    fixture.svm.warp_to_slot(slot);
+
+    withdraw(&mut fixture, first).unwrap();
+    assert_error(
+        withdraw(&mut fixture, second),
+        GatewayWalletError::OutflowLimitExceeded,
+    );
+    assert_eq!(outflow_limit(&fixture).current_window_outflow, 600);
+
+    // The previous window's outflow no longer counts once the sliding window has moved past it
+    fixture.svm.warp_to_slot(slot + 2 * WINDOW_SLOTS);
+    withdraw(&mut fixture, second).unwrap();
+}
+
+#[test]
+fn the_override_allowance_covers_outflow_beyond_the_limit() {
+    let mut fixture = WalletFixture::new();
+    set_outflow_limit(&mut fixture, WINDOW_SLOTS, 100);
+    let depositor = withdrawable_depositor(&mut fixture, 600);
+    let slot = fixture.deposit(&depositor).withdrawal_block;
+    fixture.svm.warp_to_slot(slot);
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::set_outflow_override(
+                fixture.owner,
+                fixture.token_mint,
+                SetOutflowOverrideParams {
+                    override_allowance: 500,
+                },
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+    let events = metadata.events::<OutflowOverrideSet>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].override_allowance, 500);
+
+    withdraw(&mut fixture, depositor).unwrap();
+
+    let limit = outflow_limit(&fixture);
+    assert_eq!(limit.current_window_outflow, 100);
+    assert_eq!(limit.override_allowance, 0);
+}
+
+#[test]
+fn a_zero_window_disables_the_limit() {
+    let mut fixture = WalletFixture::new();
+    set_outflow_limit(&mut fixture, 0, 0);
+    let depositor = withdrawable_depositor(&mut fixture, 600);
+    let slot = fixture.deposit(&depositor).withdrawal_block;
+    fixture.svm.warp_to_slot(slot);
+
+    withdraw(&mut fixture, depositor).unwrap();
+}
+
+#[test]
+fn set_outflow_limit_fails_for_an_unsupported_token() {
+    let mut fixture = WalletFixture::new();
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+
+    let result = fixture.svm.process(
+        &[wallet::set_outflow_limit(
+            fixture.payer,
+            fixture.owner,
+            token_mint,
+            SetOutflowLimitParams {
+                window_slots: WINDOW_SLOTS,
+                max_outflow: 1_000,
+            },
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayWalletError::TokenNotSupported);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = WalletFixture::new();
+    set_outflow_limit(&mut fixture, WINDOW_SLOTS, 1_000);
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::set_outflow_limit(
+            fixture.payer,
+            attacker,
+            fixture.token_mint,
+            SetOutflowLimitParams {
+                window_slots: 0,
+                max_outflow: 0,
+            },
+        )],
+        &[fixture.payer, attacker],
+    );
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+
+    let result = fixture.svm.process(
+        &[wallet::set_outflow_override(
+            attacker,
+            fixture.token_mint,
+            SetOutflowOverrideParams {
+                override_allowance: u64::MAX,
+            },
+        )],
+        &[attacker],
+    );
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
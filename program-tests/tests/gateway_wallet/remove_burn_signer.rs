@@ -0,0 +1,96 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::wallet;
+use gateway_program_tests::fixtures::{assert_error, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::BurnSignerRemoved,
+    instructions::{AddBurnSignerParams, RemoveBurnSignerParams},
+};
+
+#[test]
+fn removes_a_burn_signer() {
+    let mut fixture = WalletFixture::new();
+    let [first, second] = [Pubkey::new_unique(), Pubkey::new_unique()];
+    for signer in [first, second] {
+        fixture
+            .svm
+            .process(
+                &[wallet::add_burn_signer(
+                    fixture.owner,
+                    AddBurnSignerParams { signer },
+                )],
+                &[fixture.owner],
+            )
+            .unwrap();
+    }
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::remove_burn_signer(
+                fixture.owner,
+                RemoveBurnSignerParams { signer: first },
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+
+    assert_eq!(fixture.state().burn_signers, vec![second]);
+    let events = metadata.events::<BurnSignerRemoved>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].signer, first);
+}
+
+#[test]
+fn succeeds_for_an_unknown_signer() {
+    let mut fixture = WalletFixture::new();
+
+    fixture
+        .svm
+        .process(
+            &[wallet::remove_burn_signer(
+                fixture.owner,
+                RemoveBurnSignerParams {
+                    signer: Pubkey::new_unique(),
+                },
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+
+    assert!(fixture.state().burn_signers.is_empty());
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = WalletFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::remove_burn_signer(
+            attacker,
+            RemoveBurnSignerParams { signer: attacker },
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
@@ -0,0 +1,118 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::DelegateRemoved,
+    state::{DelegateStatus, GatewayDelegate},
+};
+
+fn add_delegate(fixture: &mut WalletFixture, depositor: Pubkey, delegate: Pubkey) {
+    fixture
+        .svm
+        .process(
+            &[wallet::add_delegate(
+                fixture.payer,
+                depositor,
+                fixture.token_mint,
+                delegate,
+            )],
+            &[fixture.payer, depositor],
+        )
+        .unwrap();
+}
+
+#[test]
+fn revokes_the_delegate_once() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let delegate = Pubkey::new_unique();
+    add_delegate(&mut fixture, depositor, delegate);
+    let remove = wallet::remove_delegate(depositor, fixture.token_mint, delegate);
+
+    let metadata = fixture
+        .svm
+        .process(&[remove.clone()], &[depositor])
+        .unwrap();
+    let events = metadata.events::<DelegateRemoved>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].depositor, depositor);
+    assert_eq!(events[0].delegate, delegate);
+
+    let delegate_account: GatewayDelegate = fixture
+        .svm
+        .get_anchor_account(&pda::gateway_delegate(
+            &fixture.token_mint,
+            &depositor,
+            &delegate,
+        ))
+        .unwrap();
+    assert_eq!(delegate_account.status, DelegateStatus::Revoked);
+
+    // Revoking again does nothing
+    let metadata = fixture.svm.process(&[remove], &[depositor]).unwrap();
+    assert!(metadata
+        .events::<DelegateRemoved>(&gateway_wallet::ID)
+        .is_empty());
+}
+
+#[test]
+fn fails_for_a_denylisted_depositor() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let delegate = Pubkey::new_unique();
+    add_delegate(&mut fixture, depositor, delegate);
+    fixture.denylist(depositor);
+
+    let result = fixture.svm.process(
+        &[wallet::remove_delegate(
+            depositor,
+            fixture.token_mint,
+            delegate,
+        )],
+        &[depositor],
+    );
+
+    assert_error(result, GatewayWalletError::AccountDenylisted);
+}
+
+#[test]
+fn fails_while_paused() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let delegate = Pubkey::new_unique();
+    add_delegate(&mut fixture, depositor, delegate);
+    fixture
+        .svm
+        .process(&[wallet::pause(fixture.owner)], &[fixture.owner])
+        .unwrap();
+
+    let result = fixture.svm.process(
+        &[wallet::remove_delegate(
+            depositor,
+            fixture.token_mint,
+            delegate,
+        )],
+        &[depositor],
+    );
+
+    assert_error(result, GatewayWalletError::ProgramPaused);
+}
@@ -0,0 +1,124 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, create_mint, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::FeeScheduleSet,
+    instructions::SetFeeScheduleParams,
+    state::{FeeSchedule, BPS_DENOMINATOR},
+};
+
+const DESTINATION_DOMAIN: u32 = 6;
+
+fn params(min_fee: u64, fee_bps: u16) -> SetFeeScheduleParams {
+    SetFeeScheduleParams {
+        destination_domain: DESTINATION_DOMAIN,
+        min_fee,
+        fee_bps,
+    }
+}
+
+#[test]
+fn creates_and_updates_the_fee_schedule() {
+    let mut fixture = WalletFixture::new();
+
+    for (min_fee, fee_bps) in [(10, 5), (20, BPS_DENOMINATOR)] {
+        let metadata = fixture
+            .svm
+            .process(
+                &[wallet::set_fee_schedule(
+                    fixture.payer,
+                    fixture.owner,
+                    fixture.token_mint,
+                    params(min_fee, fee_bps),
+                )],
+                &[fixture.payer, fixture.owner],
+            )
+            .unwrap();
+
+        let fee_schedule: FeeSchedule = fixture
+            .svm
+            .get_anchor_account(&pda::fee_schedule(&fixture.token_mint, DESTINATION_DOMAIN))
+            .unwrap();
+        assert_eq!(fee_schedule.token, fixture.token_mint);
+        assert_eq!(fee_schedule.destination_domain, DESTINATION_DOMAIN);
+        assert_eq!(fee_schedule.min_fee, min_fee);
+        assert_eq!(fee_schedule.fee_bps, fee_bps);
+
+        let events = metadata.events::<FeeScheduleSet>(&gateway_wallet::ID);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].min_fee, min_fee);
+        assert_eq!(events[0].fee_bps, fee_bps);
+    }
+}
+
+#[test]
+fn fails_for_more_than_the_whole_value() {
+    let mut fixture = WalletFixture::new();
+
+    let result = fixture.svm.process(
+        &[wallet::set_fee_schedule(
+            fixture.payer,
+            fixture.owner,
+            fixture.token_mint,
+            params(0, BPS_DENOMINATOR + 1),
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidFeeSchedule);
+}
+
+#[test]
+fn fails_for_an_unsupported_token() {
+    let mut fixture = WalletFixture::new();
+    let token_mint = create_mint(&mut fixture.svm, &Pubkey::new_unique());
+
+    let result = fixture.svm.process(
+        &[wallet::set_fee_schedule(
+            fixture.payer,
+            fixture.owner,
+            token_mint,
+            params(10, 5),
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, GatewayWalletError::TokenNotSupported);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = WalletFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::set_fee_schedule(
+            fixture.payer,
+            attacker,
+            fixture.token_mint,
+            params(0, 0),
+        )],
+        &[fixture.payer, attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
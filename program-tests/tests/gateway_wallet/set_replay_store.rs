@@ -0,0 +1,114 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::wallet;
+use gateway_program_tests::{
+    fixtures::{assert_error, WalletFixture},
+    TransactionError, TransactionMetadata,
+};
+use gateway_shared::replay::MAX_TRANSFER_VALIDITY_SLOTS;
+use gateway_wallet::{
+    error::GatewayWalletError, events::ReplayStoreSet, instructions::SetReplayStoreParams,
+    state::ReplayStore,
+};
+
+fn set_replay_store(
+    fixture: &mut WalletFixture,
+    replay_store: ReplayStore,
+) -> Result<TransactionMetadata, TransactionError> {
+    fixture.svm.process(
+        &[wallet::set_replay_store(
+            fixture.owner,
+            SetReplayStoreParams { replay_store },
+        )],
+        &[fixture.owner],
+    )
+}
+
+#[test]
+fn moves_forward_through_the_migration() {
+    let mut fixture = WalletFixture::new();
+    assert_eq!(fixture.state().replay_store, ReplayStore::HashAccounts);
+
+    let metadata = set_replay_store(&mut fixture, ReplayStore::Migrating).unwrap();
+    assert_eq!(fixture.state().replay_store, ReplayStore::Migrating);
+    let events = metadata.events::<ReplayStoreSet>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].previous_replay_store, ReplayStore::HashAccounts);
+    assert_eq!(events[0].new_replay_store, ReplayStore::Migrating);
+
+    // Every transfer recorded by hash before the migration has expired once the longest validity
+    // has passed
+    let started_slot = fixture.svm.clock().slot;
+    assert_eq!(fixture.state().replay_migration_started_slot, started_slot);
+    fixture
+        .svm
+        .warp_to_slot(started_slot + MAX_TRANSFER_VALIDITY_SLOTS + 1);
+    set_replay_store(&mut fixture, ReplayStore::NonceBuckets).unwrap();
+    assert_eq!(fixture.state().replay_store, ReplayStore::NonceBuckets);
+}
+
+#[test]
+fn fails_to_complete_the_migration_before_transfers_recorded_by_hash_expire() {
+    let mut fixture = WalletFixture::new();
+    set_replay_store(&mut fixture, ReplayStore::Migrating).unwrap();
+    let started_slot = fixture.svm.clock().slot;
+    fixture
+        .svm
+        .warp_to_slot(started_slot + MAX_TRANSFER_VALIDITY_SLOTS);
+
+    let result = set_replay_store(&mut fixture, ReplayStore::NonceBuckets);
+
+    assert_error(result, GatewayWalletError::ReplayStoreMigrationIncomplete);
+    assert_eq!(fixture.state().replay_store, ReplayStore::Migrating);
+}
+
+#[test]
+fn fails_to_skip_or_reverse_the_migration() {
+    let mut fixture = WalletFixture::new();
+
+    for replay_store in [ReplayStore::HashAccounts, ReplayStore::NonceBuckets] {
+        let result = set_replay_store(&mut fixture, replay_store);
+        assert_error(result, GatewayWalletError::InvalidReplayStoreTransition);
+    }
+
+    set_replay_store(&mut fixture, ReplayStore::Migrating).unwrap();
+    for replay_store in [ReplayStore::HashAccounts, ReplayStore::Migrating] {
+        let result = set_replay_store(&mut fixture, replay_store);
+        assert_error(result, GatewayWalletError::InvalidReplayStoreTransition);
+    }
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = WalletFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::set_replay_store(
+            attacker,
+            SetReplayStoreParams {
+                replay_store: ReplayStore::Migrating,
+            },
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
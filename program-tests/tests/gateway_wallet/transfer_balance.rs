@@ -0,0 +1,256 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, token_account, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError, events::BalanceTransferred, instructions::TransferBalanceParams,
+};
+
+fn transfer(
+    fixture: &WalletFixture,
+    signer: Pubkey,
+    depositor: Pubkey,
+    recipient: Pubkey,
+    amount: u64,
+) -> Instruction {
+    wallet::transfer_balance(
+        fixture.payer,
+        signer,
+        TransferBalanceParams {
+            token: fixture.token_mint,
+            depositor,
+            recipient,
+            amount,
+        },
+    )
+}
+
+fn add_delegate(fixture: &mut WalletFixture, depositor: Pubkey, delegate: Pubkey) {
+    fixture
+        .svm
+        .process(
+            &[wallet::add_delegate(
+                fixture.payer,
+                depositor,
+                fixture.token_mint,
+                delegate,
+            )],
+            &[fixture.payer, depositor],
+        )
+        .unwrap();
+}
+
+#[test]
+fn moves_the_available_balance_to_the_recipient() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let recipient = Pubkey::new_unique();
+
+    for amount in [300, 200] {
+        let instruction = transfer(&fixture, depositor, depositor, recipient, amount);
+        let metadata = fixture
+            .svm
+            .process(&[instruction], &[fixture.payer, depositor])
+            .unwrap();
+
+        let events = metadata.events::<BalanceTransferred>(&gateway_wallet::ID);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].token, fixture.token_mint);
+        assert_eq!(events[0].depositor, depositor);
+        assert_eq!(events[0].recipient, recipient);
+        assert_eq!(events[0].signer, depositor);
+        assert_eq!(events[0].value, amount);
+    }
+
+    assert_eq!(fixture.deposit(&depositor).available_amount, 500);
+    let recipient_deposit = fixture.deposit(&recipient);
+    assert_eq!(recipient_deposit.depositor, recipient);
+    assert_eq!(recipient_deposit.token_mint, fixture.token_mint);
+    assert_eq!(recipient_deposit.available_amount, 500);
+    // No tokens leave custody
+    let custody = pda::gateway_wallet_custody(&fixture.token_mint);
+    assert_eq!(token_account(&fixture.svm, &custody).amount, 1_000);
+    assert_eq!(fixture.total_deposits(), 1_000);
+}
+
+#[test]
+fn an_authorized_delegate_can_transfer() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let delegate = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    add_delegate(&mut fixture, depositor, delegate);
+
+    let instruction = transfer(&fixture, delegate, depositor, recipient, 400);
+    let metadata = fixture
+        .svm
+        .process(&[instruction], &[fixture.payer, delegate])
+        .unwrap();
+
+    assert_eq!(fixture.deposit(&recipient).available_amount, 400);
+    let events = metadata.events::<BalanceTransferred>(&gateway_wallet::ID);
+    assert_eq!(events[0].signer, delegate);
+
+    // A revoked delegate can no longer transfer
+    fixture
+        .svm
+        .process(
+            &[wallet::remove_delegate(
+                depositor,
+                fixture.token_mint,
+                delegate,
+            )],
+            &[depositor],
+        )
+        .unwrap();
+    let instruction = transfer(&fixture, delegate, depositor, recipient, 100);
+    let result = fixture
+        .svm
+        .process(&[instruction], &[fixture.payer, delegate]);
+    assert_error(result, GatewayWalletError::DelegateSignerNotAuthorized);
+}
+
+#[test]
+fn fails_for_a_signer_without_a_delegate_account() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let attacker = Pubkey::new_unique();
+    let mut instruction = transfer(&fixture, attacker, depositor, attacker, 1_000);
+    // Passing the program ID leaves the optional delegate account out
+    instruction.accounts[5].pubkey = gateway_wallet::ID;
+
+    let result = fixture
+        .svm
+        .process(&[instruction], &[fixture.payer, attacker]);
+
+    assert_error(result, GatewayWalletError::InvalidDelegateAccount);
+}
+
+#[test]
+fn fails_for_an_invalid_amount_or_recipient() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.initiate_withdrawal(depositor, 400);
+    let recipient = Pubkey::new_unique();
+
+    // The withdrawing balance can't be transferred
+    let cases = [
+        (
+            recipient,
+            601,
+            GatewayWalletError::InsufficientDepositBalance,
+        ),
+        (recipient, 0, GatewayWalletError::InvalidTransferAmount),
+        (depositor, 100, GatewayWalletError::CannotTransferToSelf),
+        (Pubkey::default(), 100, GatewayWalletError::InvalidDepositor),
+    ];
+    for (recipient, amount, error) in cases {
+        let instruction = transfer(&fixture, depositor, depositor, recipient, amount);
+        let result = fixture
+            .svm
+            .process(&[instruction], &[fixture.payer, depositor]);
+        assert_error(result, error);
+    }
+}
+
+#[test]
+fn fails_for_a_denylisted_depositor_recipient_or_delegate() {
+    for denylisted in 0..3 {
+        let mut fixture = WalletFixture::new();
+        let depositor = fixture.depositor(1_000);
+        let delegate = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        add_delegate(&mut fixture, depositor, delegate);
+        fixture.denylist([depositor, delegate, recipient][denylisted]);
+
+        let instruction = transfer(&fixture, delegate, depositor, recipient, 100);
+        let result = fixture
+            .svm
+            .process(&[instruction], &[fixture.payer, delegate]);
+
+        assert_error(result, GatewayWalletError::AccountDenylisted);
+    }
+}
+
+#[test]
+fn fails_for_a_frozen_deposit_or_while_paused() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let recipient = Pubkey::new_unique();
+    let instruction = transfer(&fixture, depositor, depositor, recipient, 100);
+
+    fixture
+        .svm
+        .process(
+            &[wallet::freeze_deposit(
+                fixture.owner,
+                fixture.token_mint,
+                depositor,
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+    let result = fixture
+        .svm
+        .process(&[instruction.clone()], &[fixture.payer, depositor]);
+    assert_error(result, GatewayWalletError::DepositFrozen);
+
+    fixture
+        .svm
+        .process(
+            &[
+                wallet::unfreeze_deposit(fixture.owner, fixture.token_mint, depositor),
+                wallet::pause(fixture.owner),
+            ],
+            &[fixture.owner],
+        )
+        .unwrap();
+    let result = fixture
+        .svm
+        .process(&[instruction], &[fixture.payer, depositor]);
+    assert_error(result, GatewayWalletError::ProgramPaused);
+}
+
+#[test]
+fn fails_for_a_frozen_recipient_deposit() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let recipient = fixture.depositor(100);
+    fixture
+        .svm
+        .process(
+            &[wallet::freeze_deposit(
+                fixture.owner,
+                fixture.token_mint,
+                recipient,
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+
+    let instruction = transfer(&fixture, depositor, depositor, recipient, 400);
+    let result = fixture
+        .svm
+        .process(&[instruction], &[fixture.payer, depositor]);
+
+    assert_error(result, GatewayWalletError::DepositFrozen);
+    assert_eq!(fixture.deposit(&recipient).available_amount, 100);
+}
@@ -0,0 +1,113 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::{error::ErrorCode, prelude::Pubkey};
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{assert_error, WalletFixture, PAYER_LAMPORTS};
+use gateway_wallet::{
+    error::GatewayWalletError, events::UnDenylisted, instructions::UndenylistParams,
+};
+
+#[test]
+fn closes_the_denylist_account_and_refunds_the_payer() {
+    let mut fixture = WalletFixture::new();
+    let (depositor, token_account) = fixture.funded_depositor(1_000);
+    fixture.denylist(depositor);
+    let payer = Pubkey::new_unique();
+    fixture.svm.airdrop(&payer, PAYER_LAMPORTS);
+    let rent = fixture
+        .svm
+        .get_account(&pda::denylist(&depositor))
+        .unwrap()
+        .lamports;
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::undenylist(
+                payer,
+                fixture.owner,
+                UndenylistParams { account: depositor },
+            )],
+            &[payer, fixture.owner],
+        )
+        .unwrap();
+
+    assert!(fixture
+        .svm
+        .get_account(&pda::denylist(&depositor))
+        .is_none());
+    assert_eq!(
+        fixture.svm.get_account(&payer).unwrap().lamports,
+        PAYER_LAMPORTS + rent
+    );
+    let events = metadata.events::<UnDenylisted>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].addr, depositor);
+
+    fixture
+        .svm
+        .process(
+            &[wallet::deposit(
+                fixture.payer,
+                depositor,
+                token_account,
+                fixture.token_mint,
+                1_000,
+            )],
+            &[fixture.payer, depositor],
+        )
+        .unwrap();
+}
+
+#[test]
+fn fails_if_not_signed_by_the_denylister() {
+    let mut fixture = WalletFixture::new();
+    let account = Pubkey::new_unique();
+    fixture.denylist(account);
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::undenylist(
+            fixture.payer,
+            attacker,
+            UndenylistParams { account },
+        )],
+        &[fixture.payer, attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
+
+#[test]
+fn fails_for_an_account_that_is_not_denylisted() {
+    let mut fixture = WalletFixture::new();
+
+    let result = fixture.svm.process(
+        &[wallet::undenylist(
+            fixture.payer,
+            fixture.owner,
+            UndenylistParams {
+                account: Pubkey::new_unique(),
+            },
+        )],
+        &[fixture.payer, fixture.owner],
+    );
+
+    assert_error(result, ErrorCode::AccountNotInitialized);
+}
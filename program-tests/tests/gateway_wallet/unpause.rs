@@ -0,0 +1,56 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::wallet;
+use gateway_program_tests::fixtures::{assert_error, WalletFixture};
+use gateway_wallet::{error::GatewayWalletError, events::Unpaused};
+
+#[test]
+fn resumes_deposits() {
+    let mut fixture = WalletFixture::new();
+    fixture
+        .svm
+        .process(&[wallet::pause(fixture.owner)], &[fixture.owner])
+        .unwrap();
+
+    let metadata = fixture
+        .svm
+        .process(&[wallet::unpause(fixture.owner)], &[fixture.owner])
+        .unwrap();
+
+    assert!(!fixture.state().paused);
+    let events = metadata.events::<Unpaused>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].account, fixture.owner);
+
+    let depositor = fixture.depositor(100);
+    assert_eq!(fixture.deposit(&depositor).available_amount, 100);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_pauser() {
+    let mut fixture = WalletFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture
+        .svm
+        .process(&[wallet::unpause(attacker)], &[attacker]);
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
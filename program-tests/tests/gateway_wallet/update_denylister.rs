@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::wallet;
+use gateway_program_tests::fixtures::{assert_error, WalletFixture};
+use gateway_wallet::{
+    error::GatewayWalletError, events::DenylisterChanged, instructions::UpdateDenylisterParams,
+};
+
+#[test]
+fn updates_the_denylister() {
+    let mut fixture = WalletFixture::new();
+    let new_denylister = Pubkey::new_unique();
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::update_denylister(
+                fixture.owner,
+                UpdateDenylisterParams { new_denylister },
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+
+    assert_eq!(fixture.state().denylister, new_denylister);
+
+    let events = metadata.events::<DenylisterChanged>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].old_denylister, fixture.owner);
+    assert_eq!(events[0].new_denylister, new_denylister);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = WalletFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::update_denylister(
+            attacker,
+            UpdateDenylisterParams {
+                new_denylister: attacker,
+            },
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
+
+#[test]
+fn fails_for_the_default_pubkey() {
+    let mut fixture = WalletFixture::new();
+
+    let result = fixture.svm.process(
+        &[wallet::update_denylister(
+            fixture.owner,
+            UpdateDenylisterParams {
+                new_denylister: Pubkey::default(),
+            },
+        )],
+        &[fixture.owner],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidDenylister);
+}
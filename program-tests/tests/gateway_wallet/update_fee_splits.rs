@@ -0,0 +1,130 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::wallet;
+use gateway_program_tests::{
+    fixtures::{assert_error, WalletFixture},
+    TransactionError, TransactionMetadata,
+};
+use gateway_wallet::{
+    error::GatewayWalletError,
+    events::FeeSplitsChanged,
+    instructions::UpdateFeeSplitsParams,
+    state::{FeeSplit, MAX_FEE_SPLITS},
+};
+
+fn update_fee_splits(
+    fixture: &mut WalletFixture,
+    fee_splits: Vec<FeeSplit>,
+) -> Result<TransactionMetadata, TransactionError> {
+    fixture.svm.process(
+        &[wallet::update_fee_splits(
+            fixture.owner,
+            UpdateFeeSplitsParams { fee_splits },
+        )],
+        &[fixture.owner],
+    )
+}
+
+fn split(bps: u16) -> FeeSplit {
+    FeeSplit {
+        recipient: Pubkey::new_unique(),
+        bps,
+    }
+}
+
+#[test]
+fn replaces_the_fee_splits() {
+    let mut fixture = WalletFixture::new();
+    let first = vec![split(1_000)];
+    let second = vec![split(2_500), split(7_500)];
+    update_fee_splits(&mut fixture, first.clone()).unwrap();
+
+    let metadata = update_fee_splits(&mut fixture, second.clone()).unwrap();
+
+    assert_eq!(fixture.state().fee_splits, second);
+    let events = metadata.events::<FeeSplitsChanged>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].old_fee_splits, first);
+    assert_eq!(events[0].new_fee_splits, second);
+}
+
+#[test]
+fn clears_the_fee_splits() {
+    let mut fixture = WalletFixture::new();
+    update_fee_splits(&mut fixture, vec![split(1_000)]).unwrap();
+
+    update_fee_splits(&mut fixture, vec![]).unwrap();
+
+    assert!(fixture.state().fee_splits.is_empty());
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = WalletFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::update_fee_splits(
+            attacker,
+            UpdateFeeSplitsParams {
+                fee_splits: vec![split(1_000)],
+            },
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
+
+#[test]
+fn fails_past_the_split_limit() {
+    let mut fixture = WalletFixture::new();
+
+    let result = update_fee_splits(&mut fixture, vec![split(1); MAX_FEE_SPLITS + 1]);
+
+    assert_error(result, GatewayWalletError::FeeSplitLimitExceeded);
+}
+
+#[test]
+fn fails_for_invalid_splits() {
+    let mut fixture = WalletFixture::new();
+    let duplicate = split(100);
+
+    for fee_splits in [
+        vec![FeeSplit {
+            recipient: Pubkey::default(),
+            bps: 100,
+        }],
+        vec![split(0)],
+        vec![duplicate, duplicate],
+    ] {
+        let result = update_fee_splits(&mut fixture, fee_splits);
+        assert_error(result, GatewayWalletError::InvalidFeeSplit);
+    }
+}
+
+#[test]
+fn fails_if_the_splits_exceed_the_fee() {
+    let mut fixture = WalletFixture::new();
+
+    let result = update_fee_splits(&mut fixture, vec![split(5_000), split(5_001)]);
+
+    assert_error(result, GatewayWalletError::FeeSplitBpsExceedsMax);
+}
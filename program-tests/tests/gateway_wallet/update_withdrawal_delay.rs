@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::wallet;
+use gateway_program_tests::fixtures::{assert_error, WalletFixture, WITHDRAWAL_DELAY};
+use gateway_wallet::{
+    error::GatewayWalletError, events::WithdrawalDelayChanged,
+    instructions::UpdateWithdrawalDelayParams,
+};
+
+#[test]
+fn updates_the_withdrawal_delay() {
+    let mut fixture = WalletFixture::new();
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::update_withdrawal_delay(
+                fixture.owner,
+                UpdateWithdrawalDelayParams { new_delay: 100 },
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+
+    assert_eq!(fixture.state().withdrawal_delay, 100);
+
+    let events = metadata.events::<WithdrawalDelayChanged>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].old_delay, WITHDRAWAL_DELAY);
+    assert_eq!(events[0].new_delay, 100);
+}
+
+#[test]
+fn fails_if_not_signed_by_the_owner() {
+    let mut fixture = WalletFixture::new();
+    let attacker = Pubkey::new_unique();
+
+    let result = fixture.svm.process(
+        &[wallet::update_withdrawal_delay(
+            attacker,
+            UpdateWithdrawalDelayParams { new_delay: 100 },
+        )],
+        &[attacker],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidAuthority);
+}
+
+#[test]
+fn fails_for_a_zero_delay() {
+    let mut fixture = WalletFixture::new();
+
+    let result = fixture.svm.process(
+        &[wallet::update_withdrawal_delay(
+            fixture.owner,
+            UpdateWithdrawalDelayParams { new_delay: 0 },
+        )],
+        &[fixture.owner],
+    );
+
+    assert_error(result, GatewayWalletError::InvalidWithdrawalDelay);
+}
@@ -0,0 +1,228 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_client::wallet;
+use gateway_program_tests::fixtures::{WalletFixture, LOCAL_DOMAIN, WITHDRAWAL_DELAY};
+use gateway_wallet::{
+    instructions::{DepositBalance, SetOutflowLimitParams, SetOutflowOverrideParams, WalletConfig},
+    state::ReplayStore,
+};
+
+fn balance(fixture: &mut WalletFixture, depositor: Pubkey) -> DepositBalance {
+    fixture
+        .svm
+        .process(&[wallet::get_balance(fixture.token_mint, depositor)], &[])
+        .unwrap()
+        .return_value(&gateway_wallet::ID)
+        .unwrap()
+}
+
+fn withdrawable(fixture: &mut WalletFixture, depositor: Pubkey) -> u64 {
+    fixture
+        .svm
+        .process(
+            &[wallet::get_withdrawable(fixture.token_mint, depositor)],
+            &[],
+        )
+        .unwrap()
+        .return_value(&gateway_wallet::ID)
+        .unwrap()
+}
+
+fn set_outflow_limit(fixture: &mut WalletFixture, max_outflow: u64) {
+    fixture
+        .svm
+        .process(
+            &[wallet::set_outflow_limit(
+                fixture.payer,
+                fixture.owner,
+                fixture.token_mint,
+                SetOutflowLimitParams {
+                    window_slots: 100,
+                    max_outflow,
+                },
+            )],
+            &[fixture.payer, fixture.owner],
+        )
+        .unwrap();
+}
+
+fn is_authorized(fixture: &mut WalletFixture, depositor: Pubkey, addr: Pubkey) -> bool {
+    fixture
+        .svm
+        .process(
+            &[wallet::is_authorized_for_balance(
+                fixture.token_mint,
+                depositor,
+                addr,
+            )],
+            &[],
+        )
+        .unwrap()
+        .return_value(&gateway_wallet::ID)
+        .unwrap()
+}
+
+#[test]
+fn get_balance_returns_the_deposit_balances() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.initiate_withdrawal(depositor, 400);
+
+    assert_eq!(
+        balance(&mut fixture, depositor),
+        DepositBalance {
+            available_amount: 600,
+            withdrawing_amount: 400,
+            withdrawal_block: fixture.deposit(&depositor).withdrawal_block,
+            frozen: false,
+        }
+    );
+    assert_eq!(
+        balance(&mut fixture, Pubkey::new_unique()),
+        DepositBalance::default()
+    );
+
+    // A deposit created before deposits could be frozen reads as not frozen
+    fixture.set_legacy_deposit(&depositor);
+    let legacy_balance = balance(&mut fixture, depositor);
+    assert_eq!(legacy_balance.available_amount, 600);
+    assert!(!legacy_balance.frozen);
+}
+
+#[test]
+fn get_withdrawable_returns_the_balance_withdraw_would_transfer() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    assert_eq!(withdrawable(&mut fixture, depositor), 0);
+
+    fixture.initiate_withdrawal(depositor, 400);
+    let slot = fixture.svm.clock().slot;
+    fixture.svm.warp_to_slot(slot + WITHDRAWAL_DELAY - 1);
+    assert_eq!(withdrawable(&mut fixture, depositor), 0);
+    fixture.svm.warp_to_slot(slot + WITHDRAWAL_DELAY);
+    assert_eq!(withdrawable(&mut fixture, depositor), 400);
+
+    fixture
+        .svm
+        .process(
+            &[wallet::freeze_deposit(
+                fixture.owner,
+                fixture.token_mint,
+                depositor,
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+    assert_eq!(withdrawable(&mut fixture, depositor), 0);
+    assert_eq!(withdrawable(&mut fixture, Pubkey::new_unique()), 0);
+}
+
+#[test]
+fn get_withdrawable_is_capped_by_the_outflow_limit() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.initiate_withdrawal(depositor, 400);
+    let slot = fixture.svm.clock().slot;
+    fixture.svm.warp_to_slot(slot + WITHDRAWAL_DELAY);
+
+    set_outflow_limit(&mut fixture, 250);
+    assert_eq!(withdrawable(&mut fixture, depositor), 250);
+
+    // The override allowance adds to what the limit allows
+    fixture
+        .svm
+        .process(
+            &[wallet::set_outflow_override(
+                fixture.owner,
+                fixture.token_mint,
+                SetOutflowOverrideParams {
+                    override_allowance: 100,
+                },
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+    assert_eq!(withdrawable(&mut fixture, depositor), 350);
+
+    set_outflow_limit(&mut fixture, 1_000);
+    assert_eq!(withdrawable(&mut fixture, depositor), 400);
+}
+
+#[test]
+fn is_authorized_for_balance_follows_the_delegate_status() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let delegate = Pubkey::new_unique();
+
+    assert!(is_authorized(&mut fixture, depositor, depositor));
+    assert!(!is_authorized(&mut fixture, depositor, delegate));
+
+    fixture
+        .svm
+        .process(
+            &[wallet::add_delegate(
+                fixture.payer,
+                depositor,
+                fixture.token_mint,
+                delegate,
+            )],
+            &[fixture.payer, depositor],
+        )
+        .unwrap();
+    assert!(is_authorized(&mut fixture, depositor, delegate));
+    // The delegate is scoped to the depositor
+    assert!(!is_authorized(&mut fixture, Pubkey::new_unique(), delegate));
+
+    fixture
+        .svm
+        .process(
+            &[wallet::remove_delegate(
+                depositor,
+                fixture.token_mint,
+                delegate,
+            )],
+            &[depositor],
+        )
+        .unwrap();
+    assert!(!is_authorized(&mut fixture, depositor, delegate));
+}
+
+#[test]
+fn get_config_returns_the_wallet_config() {
+    let mut fixture = WalletFixture::new();
+
+    let config: WalletConfig = fixture
+        .svm
+        .process(&[wallet::get_config()], &[])
+        .unwrap()
+        .return_value(&gateway_wallet::ID)
+        .unwrap();
+
+    let state = fixture.state();
+    assert_eq!(config.owner, fixture.owner);
+    assert_eq!(config.token_controller, fixture.owner);
+    assert_eq!(config.local_domain, LOCAL_DOMAIN);
+    assert_eq!(config.withdrawal_delay, WITHDRAWAL_DELAY);
+    assert!(!config.paused);
+    assert_eq!(config.supported_tokens, vec![fixture.token_mint]);
+    assert_eq!(config.fee_recipient, state.fee_recipient);
+    assert_eq!(config.replay_store, ReplayStore::HashAccounts);
+    assert!(!config.destination_domains_enforced);
+}
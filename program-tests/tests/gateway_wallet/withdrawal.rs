@@ -0,0 +1,134 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_spl::associated_token::get_associated_token_address;
+use gateway_client::{pda, wallet};
+use gateway_program_tests::fixtures::{
+    assert_error, token_account, WalletFixture, WITHDRAWAL_DELAY,
+};
+use gateway_wallet::{error::GatewayWalletError, events::WithdrawalCompleted};
+
+#[test]
+fn transfers_the_withdrawing_balance_after_the_delay() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.initiate_withdrawal(depositor, 400);
+    let withdrawal_block = fixture.deposit(&depositor).withdrawal_block;
+    fixture.svm.warp_to_slot(withdrawal_block);
+    let depositor_token_account = get_associated_token_address(&depositor, &fixture.token_mint);
+
+    let metadata = fixture
+        .svm
+        .process(
+            &[wallet::withdraw(
+                depositor,
+                depositor_token_account,
+                fixture.token_mint,
+            )],
+            &[depositor],
+        )
+        .unwrap();
+
+    let deposit = fixture.deposit(&depositor);
+    assert_eq!(deposit.available_amount, 600);
+    assert_eq!(deposit.withdrawing_amount, 0);
+    assert_eq!(deposit.withdrawal_block, 0);
+    assert_eq!(
+        token_account(&fixture.svm, &depositor_token_account).amount,
+        400
+    );
+    let custody = pda::gateway_wallet_custody(&fixture.token_mint);
+    assert_eq!(token_account(&fixture.svm, &custody).amount, 600);
+    assert_eq!(fixture.total_deposits(), 600);
+
+    let events = metadata.events::<WithdrawalCompleted>(&gateway_wallet::ID);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].depositor, depositor);
+    assert_eq!(events[0].value, 400);
+}
+
+#[test]
+fn fails_without_a_withdrawal_in_progress() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    let depositor_token_account = get_associated_token_address(&depositor, &fixture.token_mint);
+
+    let result = fixture.svm.process(
+        &[wallet::withdraw(
+            depositor,
+            depositor_token_account,
+            fixture.token_mint,
+        )],
+        &[depositor],
+    );
+
+    assert_error(result, GatewayWalletError::NoWithdrawalInProgress);
+}
+
+#[test]
+fn fails_before_the_delay_has_elapsed() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.initiate_withdrawal(depositor, 400);
+    let slot = fixture.svm.clock().slot;
+    fixture.svm.warp_to_slot(slot + WITHDRAWAL_DELAY - 1);
+    let depositor_token_account = get_associated_token_address(&depositor, &fixture.token_mint);
+
+    let result = fixture.svm.process(
+        &[wallet::withdraw(
+            depositor,
+            depositor_token_account,
+            fixture.token_mint,
+        )],
+        &[depositor],
+    );
+
+    assert_error(result, GatewayWalletError::WithdrawalDelayNotElapsed);
+}
+
+#[test]
+fn fails_for_a_frozen_deposit_or_while_paused() {
+    let mut fixture = WalletFixture::new();
+    let depositor = fixture.depositor(1_000);
+    fixture.initiate_withdrawal(depositor, 400);
+    let withdrawal_block = fixture.deposit(&depositor).withdrawal_block;
+    fixture.svm.warp_to_slot(withdrawal_block);
+    let depositor_token_account = get_associated_token_address(&depositor, &fixture.token_mint);
+    let withdraw = wallet::withdraw(depositor, depositor_token_account, fixture.token_mint);
+
+    fixture
+        .svm
+        .process(
+            &[wallet::freeze_deposit(
+                fixture.owner,
+                fixture.token_mint,
+                depositor,
+            )],
+            &[fixture.owner],
+        )
+        .unwrap();
+    let result = fixture.svm.process(&[withdraw.clone()], &[depositor]);
+    assert_error(result, GatewayWalletError::DepositFrozen);
+
+    fixture
+        .svm
+        .process(&[wallet::pause(fixture.owner)], &[fixture.owner])
+        .unwrap();
+    let result = fixture.svm.process(&[withdraw], &[depositor]);
+    assert_error(result, GatewayWalletError::ProgramPaused);
+}
@@ -0,0 +1,197 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_program_tests::fuzz;
+use gateway_wallet::burn_data::{BurnData, BurnDataStruct, BurnIntent, TransferSpec};
+use proptest::prelude::*;
+
+// The offsets of the transfer spec length and hook data length within the burn data
+const TRANSFER_SPEC_LENGTH_OFFSET: usize = BurnData::BURN_INTENT_OFFSET + 68;
+const HOOK_DATA_LENGTH_OFFSET: usize = BurnData::BURN_INTENT_OFFSET + 72 + 336;
+
+fn pubkey() -> impl Strategy<Value = Pubkey> {
+    any::<[u8; 32]>().prop_map(Pubkey::new_from_array)
+}
+
+fn transfer_spec() -> impl Strategy<Value = TransferSpec> {
+    (
+        (any::<u32>(), any::<u32>(), any::<u32>()),
+        [pubkey(), pubkey(), pubkey(), pubkey()],
+        [pubkey(), pubkey(), pubkey(), pubkey()],
+        (fuzz::u64(), any::<[u8; 32]>(), fuzz::bytes(256)),
+    )
+        .prop_map(
+            |(
+                (version, source_domain, destination_domain),
+                [source_contract, destination_contract, source_token, destination_token],
+                [source_depositor, destination_recipient, source_signer, destination_caller],
+                (value, salt, hook_data),
+            )| TransferSpec {
+                version,
+                source_domain,
+                destination_domain,
+                source_contract,
+                destination_contract,
+                source_token,
+                destination_token,
+                source_depositor,
+                destination_recipient,
+                source_signer,
+                destination_caller,
+                value: value.max(1),
+                salt,
+                hook_data,
+            },
+        )
+}
+
+fn burn_data() -> impl Strategy<Value = BurnDataStruct> {
+    (
+        fuzz::u64(),
+        any::<[u8; 64]>(),
+        fuzz::u64(),
+        fuzz::u64(),
+        transfer_spec(),
+    )
+        .prop_map(
+            |(fee, user_signature, max_block_height, max_fee, transfer_spec)| BurnDataStruct {
+                fee,
+                user_signature,
+                burn_intent: BurnIntent {
+                    max_block_height,
+                    max_fee,
+                    transfer_spec,
+                },
+            },
+        )
+}
+
+/// Parses `data` and reads every field of the parsed burn data
+fn parse(data: &[u8]) {
+    let Ok(burn_data) = BurnData::new(data) else {
+        return;
+    };
+
+    let _ = burn_data.fee();
+    let _ = burn_data.user_signature();
+    let _ = burn_data.burn_intent_message_prefix();
+    let _ = burn_data.magic();
+    let _ = burn_data.max_block_height();
+    let _ = burn_data.max_fee();
+    let _ = burn_data.transfer_spec_length();
+    let _ = burn_data.transfer_spec_magic();
+    let _ = burn_data.encoded_transfer_spec();
+    let _ = burn_data.transfer_spec_hash();
+    let _ = burn_data.version();
+    let _ = burn_data.source_domain();
+    let _ = burn_data.destination_domain();
+    let _ = burn_data.source_contract();
+    let _ = burn_data.destination_contract();
+    let _ = burn_data.source_token();
+    let _ = burn_data.destination_token();
+    let _ = burn_data.source_depositor();
+    let _ = burn_data.destination_recipient();
+    let _ = burn_data.source_signer();
+    let _ = burn_data.destination_caller();
+    let _ = burn_data.value();
+    let _ = burn_data.salt();
+    let _ = burn_data.hook_data_length();
+    let _ = burn_data.hook_data();
+    let _ = burn_data.burn_intent_message_length();
+}
+
+proptest! {
+    #![proptest_config(fuzz::config())]
+
+    #[test]
+    fn arbitrary_bytes_never_panic(
+        mut data in fuzz::bytes(1_024),
+        // Give most inputs a valid message prefix, so that they reach the later checks
+        with_prefix in proptest::bool::weighted(0.75),
+    ) {
+        if data.len() >= BurnData::BURN_INTENT_OFFSET && with_prefix {
+            data[BurnData::BURN_INTENT_MESSAGE_PREFIX_OFFSET..BurnData::BURN_INTENT_OFFSET]
+                .copy_from_slice(&BurnData::BURN_INTENT_MESSAGE_PREFIX);
+        }
+        parse(&data);
+    }
+
+    #[test]
+    fn mutated_burn_data_never_panics(
+        burn_data in burn_data(),
+        mutations in fuzz::mutations(),
+    ) {
+        let mut data = burn_data.encode();
+        fuzz::mutate(
+            &mut data,
+            &mutations,
+            &[TRANSFER_SPEC_LENGTH_OFFSET, HOOK_DATA_LENGTH_OFFSET],
+        );
+        parse(&data);
+    }
+
+    #[test]
+    fn accepted_burn_data_reencodes_to_the_same_bytes(
+        burn_data in burn_data(),
+        mutations in fuzz::mutations(),
+    ) {
+        let mut data = burn_data.encode();
+        fuzz::mutate(
+            &mut data,
+            &mutations,
+            &[TRANSFER_SPEC_LENGTH_OFFSET, HOOK_DATA_LENGTH_OFFSET],
+        );
+
+        if let Ok(decoded) = BurnDataStruct::decode(&data) {
+            prop_assert_eq!(decoded.encode(), data);
+        }
+    }
+
+    #[test]
+    fn encode_then_decode_round_trips(expected in burn_data()) {
+        let encoded = expected.encode();
+
+        prop_assert_eq!(&BurnDataStruct::decode(&encoded).unwrap(), &expected);
+
+        let burn_intent = &expected.burn_intent;
+        prop_assert_eq!(
+            &BurnIntent::decode(&burn_intent.encode()).unwrap(),
+            burn_intent
+        );
+        let transfer_spec = &burn_intent.transfer_spec;
+        prop_assert_eq!(
+            &TransferSpec::decode(&transfer_spec.encode()).unwrap(),
+            transfer_spec
+        );
+
+        let parsed = BurnData::new(&encoded).unwrap();
+        prop_assert_eq!(parsed.transfer_spec_hash().unwrap(), transfer_spec.hash());
+        prop_assert_eq!(
+            parsed.burn_intent_message_length().unwrap(),
+            burn_intent.message().len()
+        );
+    }
+
+    #[test]
+    fn rejects_a_zero_value(mut burn_data in burn_data()) {
+        burn_data.burn_intent.transfer_spec.value = 0;
+
+        prop_assert!(BurnData::new(&burn_data.encode()).is_err());
+    }
+}
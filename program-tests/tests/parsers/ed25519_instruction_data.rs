@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use gateway_program_tests::fuzz;
+use gateway_wallet::{
+    burn_data::BurnData,
+    ed25519::{
+        gateway_burn_ed25519_instruction_data, Ed25519InstructionData, ED25519_NUM_SIGNATURES,
+        ED25519_PADDING, GATEWAY_BURN_DATA_OFFSET,
+    },
+};
+use proptest::prelude::*;
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+proptest! {
+    #![proptest_config(fuzz::config())]
+
+    #[test]
+    fn arbitrary_bytes_never_panic(data in fuzz::bytes(2 * Ed25519InstructionData::HEADER_SIZE)) {
+        let Ok(instruction) = Ed25519InstructionData::new(&data) else {
+            prop_assert_ne!(data.len(), Ed25519InstructionData::HEADER_SIZE);
+            return Ok(());
+        };
+
+        prop_assert_eq!(instruction.data(), &data[..]);
+        prop_assert_eq!(instruction.num_signatures().unwrap(), data[0]);
+        prop_assert_eq!(instruction.padding().unwrap(), data[1]);
+        for (offset, field) in [
+            (2, instruction.signature_offset()),
+            (4, instruction.signature_instruction_index()),
+            (6, instruction.public_key_offset()),
+            (8, instruction.public_key_instruction_index()),
+            (10, instruction.message_data_offset()),
+            (12, instruction.message_data_size()),
+            (14, instruction.message_instruction_index()),
+        ] {
+            prop_assert_eq!(field.unwrap(), read_u16(&data, offset));
+        }
+    }
+
+    #[test]
+    fn gateway_burn_instruction_data_round_trips(
+        burn_instruction_index in any::<u16>(),
+        burn_intent_message_length in any::<u16>(),
+    ) {
+        let data = gateway_burn_ed25519_instruction_data(
+            burn_instruction_index,
+            burn_intent_message_length,
+        );
+        let instruction = Ed25519InstructionData::new(&data).unwrap();
+
+        prop_assert_eq!(
+            instruction.num_signatures().unwrap(),
+            ED25519_NUM_SIGNATURES
+        );
+        prop_assert_eq!(instruction.padding().unwrap(), ED25519_PADDING);
+        prop_assert_eq!(
+            instruction.signature_offset().unwrap(),
+            GATEWAY_BURN_DATA_OFFSET + BurnData::BURN_DATA_USER_SIGNATURE_OFFSET as u16
+        );
+        prop_assert_eq!(
+            instruction.public_key_offset().unwrap(),
+            GATEWAY_BURN_DATA_OFFSET + BurnData::TS_SOURCE_SIGNER_OFFSET as u16
+        );
+        prop_assert_eq!(
+            instruction.message_data_offset().unwrap(),
+            GATEWAY_BURN_DATA_OFFSET + BurnData::BURN_INTENT_MESSAGE_PREFIX_OFFSET as u16
+        );
+        prop_assert_eq!(
+            instruction.message_data_size().unwrap(),
+            burn_intent_message_length
+        );
+        for index in [
+            instruction.signature_instruction_index(),
+            instruction.public_key_instruction_index(),
+            instruction.message_instruction_index(),
+        ] {
+            prop_assert_eq!(index.unwrap(), burn_instruction_index);
+        }
+    }
+}
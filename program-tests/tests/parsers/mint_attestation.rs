@@ -0,0 +1,202 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use gateway_minter::attestation::{
+    MintAttestation, MintAttestationElementStruct, MintAttestationStruct,
+};
+use gateway_program_tests::fuzz;
+use proptest::prelude::*;
+
+// The offsets within an encoded attestation set
+const NUM_ATTESTATIONS_OFFSET: usize = 84;
+const ATTESTATIONS_OFFSET: usize = 88;
+// The offsets within an encoded attestation element
+const HOOK_DATA_LENGTH_OFFSET: usize = 104;
+const HOOK_DATA_OFFSET: usize = 108;
+
+/// An attestation element with owned hook data
+#[derive(Debug, PartialEq)]
+struct Element {
+    destination_token: [u8; 32],
+    destination_recipient: [u8; 32],
+    value: u64,
+    transfer_spec_hash: [u8; 32],
+    hook_data: Vec<u8>,
+}
+
+/// The header fields of an attestation set
+#[derive(Debug, PartialEq)]
+struct Header {
+    version: u32,
+    destination_domain: u32,
+    destination_contract: [u8; 32],
+    destination_caller: [u8; 32],
+    max_block_height: u64,
+}
+
+fn elements() -> impl Strategy<Value = Vec<Element>> {
+    let element = (
+        any::<[u8; 32]>(),
+        any::<[u8; 32]>(),
+        fuzz::u64(),
+        any::<[u8; 32]>(),
+        fuzz::bytes(128),
+    )
+        .prop_map(
+            |(destination_token, destination_recipient, value, transfer_spec_hash, hook_data)| {
+                Element {
+                    destination_token,
+                    destination_recipient,
+                    value,
+                    transfer_spec_hash,
+                    hook_data,
+                }
+            },
+        );
+    proptest::collection::vec(element, 1..=4)
+}
+
+fn header() -> impl Strategy<Value = Header> {
+    (
+        any::<u32>(),
+        any::<u32>(),
+        any::<[u8; 32]>(),
+        any::<[u8; 32]>(),
+        fuzz::u64(),
+    )
+        .prop_map(
+            |(
+                version,
+                destination_domain,
+                destination_contract,
+                destination_caller,
+                max_block_height,
+            )| Header {
+                version,
+                destination_domain,
+                destination_contract,
+                destination_caller,
+                max_block_height,
+            },
+        )
+}
+
+fn encode(header: &Header, elements: &[Element]) -> Vec<u8> {
+    MintAttestationStruct {
+        version: header.version,
+        destination_domain: header.destination_domain,
+        destination_contract: header.destination_contract,
+        destination_caller: header.destination_caller,
+        max_block_height: header.max_block_height,
+        elements: elements
+            .iter()
+            .map(|element| MintAttestationElementStruct {
+                destination_token: element.destination_token,
+                destination_recipient: element.destination_recipient,
+                value: element.value,
+                transfer_spec_hash: element.transfer_spec_hash,
+                hook_data: &element.hook_data,
+            })
+            .collect(),
+    }
+    .encode_attestation()
+}
+
+/// Returns the offsets of the length fields of an encoded attestation set
+fn length_fields(elements: &[Element]) -> Vec<usize> {
+    let mut length_fields = vec![NUM_ATTESTATIONS_OFFSET];
+    let mut offset = ATTESTATIONS_OFFSET;
+    for element in elements {
+        length_fields.push(offset + HOOK_DATA_LENGTH_OFFSET);
+        offset += HOOK_DATA_OFFSET + element.hook_data.len();
+    }
+    length_fields
+}
+
+/// Parses `data` the way gateway_mint does, reading every field of every element
+fn parse(data: &[u8]) -> anchor_lang::Result<(Header, Vec<Element>)> {
+    let mut attestation = MintAttestation::new(data)?;
+    let header = Header {
+        version: attestation.version()?,
+        destination_domain: attestation.destination_domain()?,
+        destination_contract: attestation.destination_contract()?.to_bytes(),
+        destination_caller: attestation.destination_caller()?.to_bytes(),
+        max_block_height: attestation.max_block_height()?,
+    };
+
+    let mut elements = Vec::new();
+    while attestation.next()? {
+        elements.push(Element {
+            destination_token: attestation.destination_token()?.to_bytes(),
+            destination_recipient: attestation.destination_recipient()?.to_bytes(),
+            value: attestation.value()?,
+            transfer_spec_hash: attestation.transfer_spec_hash()?,
+            hook_data: attestation.hook_data()?.to_vec(),
+        });
+    }
+    Ok((header, elements))
+}
+
+proptest! {
+    #![proptest_config(fuzz::config())]
+
+    #[test]
+    fn arbitrary_bytes_never_panic(
+        mut data in fuzz::bytes(1_024),
+        // Give most inputs the set magic, so that they reach the element checks
+        with_magic in proptest::bool::weighted(0.75),
+    ) {
+        if data.len() >= 4 && with_magic {
+            data[..4].copy_from_slice(&MintAttestation::ATTESTATION_SET_MAGIC.to_be_bytes());
+        }
+        let _ = parse(&data);
+    }
+
+    #[test]
+    fn mutated_attestations_never_panic(
+        header in header(),
+        elements in elements(),
+        mutations in fuzz::mutations(),
+    ) {
+        let mut data = encode(&header, &elements);
+        fuzz::mutate(&mut data, &mutations, &length_fields(&elements));
+        let _ = parse(&data);
+    }
+
+    #[test]
+    fn accepted_attestations_reencode_to_the_same_bytes(
+        header in header(),
+        elements in elements(),
+        mutations in fuzz::mutations(),
+    ) {
+        let mut data = encode(&header, &elements);
+        fuzz::mutate(&mut data, &mutations, &length_fields(&elements));
+
+        if let Ok((header, elements)) = parse(&data) {
+            prop_assert_eq!(encode(&header, &elements), data);
+        }
+    }
+
+    #[test]
+    fn encode_then_parse_round_trips(header in header(), elements in elements()) {
+        let (parsed_header, parsed_elements) = parse(&encode(&header, &elements)).unwrap();
+
+        prop_assert_eq!(parsed_header, header);
+        prop_assert_eq!(parsed_elements, elements);
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Tests of the runtime rules enforced by the in-process SVM
+
+use anchor_lang::{prelude::*, solana_program::system_instruction};
+use gateway_program_tests::{Svm, TransactionError};
+
+const MINIMUM_BALANCE: u64 = 890_880;
+
+#[test]
+fn empties_an_account() {
+    let mut svm = Svm::new();
+    let from = Pubkey::new_unique();
+    let to = Pubkey::new_unique();
+    svm.airdrop(&from, 1_000_000_000);
+
+    svm.process(
+        &[system_instruction::transfer(&from, &to, 1_000_000_000)],
+        &[from],
+    )
+    .unwrap();
+
+    assert!(svm.get_account(&from).is_none());
+    assert_eq!(svm.get_account(&to).unwrap().lamports, 1_000_000_000);
+}
+
+#[test]
+fn fails_to_leave_an_account_rent_paying() {
+    let mut svm = Svm::new();
+    let from = Pubkey::new_unique();
+    let to = Pubkey::new_unique();
+    svm.airdrop(&from, 1_000_000_000);
+    assert_eq!(Rent::default().minimum_balance(0), MINIMUM_BALANCE);
+
+    let result = svm.process(
+        &[system_instruction::transfer(
+            &from,
+            &to,
+            1_000_000_000 - MINIMUM_BALANCE + 1,
+        )],
+        &[from],
+    );
+
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionError::InsufficientFundsForRent(from)
+    );
+    assert_eq!(svm.get_account(&from).unwrap().lamports, 1_000_000_000);
+}
+
+#[test]
+fn fails_to_create_a_rent_paying_account() {
+    let mut svm = Svm::new();
+    let from = Pubkey::new_unique();
+    let to = Pubkey::new_unique();
+    svm.airdrop(&from, 1_000_000_000);
+
+    let result = svm.process(
+        &[system_instruction::transfer(
+            &from,
+            &to,
+            MINIMUM_BALANCE - 1,
+        )],
+        &[from],
+    );
+
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionError::InsufficientFundsForRent(to)
+    );
+    assert!(svm.get_account(&to).is_none());
+}
@@ -25,7 +25,7 @@
 //! - Set magic: `0x10cbb1ec` (bytes4(keccak256("circle.gateway.ReducedAttestationSet")))
 //!
 //! Attestation set layout:
-//! ```
+//! ```text
 //! offset  size  field
 //! 0       4     magic (0x10cbb1ec)
 //! 4       4     version
@@ -38,7 +38,7 @@
 //! ```
 //!
 //! Attestation element layout:
-//! ```
+//! ```text
 //! offset  size  field
 //! 0       32    destination_token
 //! 32      32    destination_recipient
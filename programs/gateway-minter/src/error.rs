@@ -17,6 +17,7 @@
  */
 
 use anchor_lang::prelude::*;
+use gateway_shared::admin::AdminError;
 
 #[error_code]
 pub enum GatewayMinterError {
@@ -89,10 +90,71 @@ pub enum GatewayMinterError {
     InvalidTransferSpecHashAccount,
     #[msg("Transfer spec hash already used")]
     TransferSpecHashAlreadyUsed,
+    #[msg("Transfer spec does not match the attested transfer spec hash")]
+    InvalidTransferSpec,
+    #[msg("Invalid nonce bucket account")]
+    InvalidNonceBucketAccount,
+    #[msg("Invalid replay store transition")]
+    InvalidReplayStoreTransition,
 
     // Token Account Validation
     #[msg("Invalid custody token account")]
     InvalidCustodyTokenAccount,
     #[msg("Invalid destination token account")]
     InvalidDestinationTokenAccount,
+    #[msg("Invalid token config account")]
+    InvalidTokenConfigAccount,
+
+    // Hooks
+    #[msg("Malformed hook data")]
+    MalformedHookData,
+    #[msg("Invalid hook program")]
+    InvalidHookProgram,
+    #[msg("Hook account does not match the attested hook account")]
+    InvalidHookAccount,
+
+    // Recipient Token Account Creation
+    #[msg("Invalid destination recipient owner")]
+    InvalidDestinationRecipientOwner,
+    #[msg("Invalid associated token program")]
+    InvalidAssociatedTokenProgram,
+
+    // Mint Authority Mode
+    #[msg("Invalid token mint account")]
+    InvalidTokenMint,
+    #[msg("Gateway minter is not the token mint authority")]
+    InvalidTokenMintAuthority,
+    #[msg("Minting allowance exceeded")]
+    MintingAllowanceExceeded,
+
+    // Rate Limiting
+    #[msg("Invalid mint rate limit")]
+    InvalidMintRateLimit,
+    #[msg("Mint rate limit exceeded")]
+    MintRateLimitExceeded,
+
+    // Used Transfer Spec Hashes
+    #[msg("Transfer spec hash has not expired")]
+    TransferSpecHashNotExpired,
+    #[msg("Invalid rent payer")]
+    InvalidRentPayer,
+    #[msg("Transfer is not recorded by nonce")]
+    TransferNotRecordedByNonce,
+
+    // Replay Store Migration
+    #[msg("Transfers recorded by hash before the migration may not have expired yet")]
+    ReplayStoreMigrationIncomplete,
+    #[msg("Attestation is valid for too long")]
+    AttestationValidityTooLong,
+}
+
+impl From<AdminError> for GatewayMinterError {
+    fn from(error: AdminError) -> Self {
+        match error {
+            AdminError::InvalidPauser => GatewayMinterError::InvalidPauser,
+            AdminError::InvalidTokenController => GatewayMinterError::InvalidTokenController,
+            AdminError::MaxTokensSupported => GatewayMinterError::MaxTokensSupported,
+            AdminError::TokenNotSupported => GatewayMinterError::TokenNotSupported,
+        }
+    }
 }
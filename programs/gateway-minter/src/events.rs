@@ -16,52 +16,71 @@
  * limitations under the License.
  */
 
+use crate::state::{ReplayStore, TokenMintMode};
 use anchor_lang::prelude::*;
 
 #[event(discriminator = [10, 0])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OwnershipTransferStarted {
     pub previous_owner: Pubkey,
     pub new_owner: Pubkey,
 }
 
 #[event(discriminator = [10, 1])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OwnershipTransferred {
     pub previous_owner: Pubkey,
     pub new_owner: Pubkey,
 }
 
 #[event(discriminator = [10, 2])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GatewayMinterInitialized {}
 
 #[event(discriminator = [10, 3])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PauserChanged {
     pub old_pauser: Pubkey,
     pub new_pauser: Pubkey,
 }
 
 #[event(discriminator = [10, 4])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttestationSignerAdded {
     pub signer: Pubkey,
 }
 
 #[event(discriminator = [10, 5])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttestationSignerRemoved {
     pub signer: Pubkey,
 }
 
 #[event(discriminator = [10, 6])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenControllerUpdated {
     pub previous_token_controller: Pubkey,
     pub new_token_controller: Pubkey,
 }
 
 #[event(discriminator = [10, 7])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenSupported {
     pub token: Pubkey,
     pub custody_token_account: Pubkey,
 }
 
 #[event(discriminator = [10, 8])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenCustodyBurned {
     pub token: Pubkey,
     pub custody_token_account: Pubkey,
@@ -69,19 +88,86 @@ pub struct TokenCustodyBurned {
 }
 
 #[event(discriminator = [10, 9])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Paused {
     pub account: Pubkey,
 }
 
 #[event(discriminator = [10, 10])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Unpaused {
     pub account: Pubkey,
 }
 
 #[event(discriminator = [10, 11])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttestationUsed {
     pub token: Pubkey,
     pub recipient: Pubkey,
     pub transfer_spec_hash: [u8; 32],
     pub value: u64,
+    pub hook_data: Vec<u8>,
+}
+
+#[event(discriminator = [10, 12])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenMinterConfigured {
+    pub token: Pubkey,
+    pub mint_mode: TokenMintMode,
+    pub minting_allowance: u64,
+}
+
+#[event(discriminator = [10, 13])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MintRateLimitSet {
+    pub token: Pubkey,
+    pub capacity: u64,
+    pub refill_per_slot: u64,
+}
+
+#[event(discriminator = [10, 14])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MintRateLimited {
+    pub token: Pubkey,
+    pub recipient: Pubkey,
+    pub transfer_spec_hash: [u8; 32],
+    pub value: u64,
+    pub available: u64,
+}
+
+#[event(discriminator = [10, 15])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UsedTransferSpecHashClosed {
+    pub transfer_spec_hash: [u8; 32],
+    pub rent_payer: Pubkey,
+}
+
+#[event(discriminator = [10, 16])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransferReceiptsSet {
+    pub enabled: bool,
+}
+
+#[event(discriminator = [10, 17])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayStoreSet {
+    pub previous_replay_store: ReplayStore,
+    pub new_replay_store: ReplayStore,
+}
+
+#[event(discriminator = [10, 18])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GatewayMinterMigrated {
+    pub previous_space: u64,
+    pub new_space: u64,
 }
@@ -0,0 +1,162 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! MintHook
+//!
+//! This module decodes the optional hook encoded in an attestation element's `hook_data`.
+//! Hook data that does not start with the hook magic is not a hook, and is ignored by
+//! `gateway_mint`. All message encodings use **big-endian**.
+//!
+//! Constants:
+//! - Hook magic: `0x0e64dc89` (bytes4(keccak256("circle.gateway.MintHook")))
+//!
+//! Hook layout:
+//! ```text
+//! offset  size  field
+//! 0       4     magic (0x0e64dc89)
+//! 4       32    program_id
+//! 36      4     num_accounts
+//! 40      33*A  accounts (A = num_accounts)
+//! 40+33*A N     instruction_data
+//! ```
+//!
+//! Hook account layout:
+//! ```text
+//! offset  size  field
+//! 0       32    pubkey
+//! 32      1     is_writable (0 or 1)
+//! ```
+//!
+//! The accounts are attested along with the rest of the hook, so the caller of `gateway_mint`
+//! cannot choose which accounts the hook program is invoked with. No account is passed to the
+//! hook program as a signer.
+
+use crate::error::GatewayMinterError;
+use anchor_lang::{prelude::*, solana_program::instruction::AccountMeta};
+
+#[derive(Clone, Debug)]
+pub struct MintHook<'a> {
+    pub program_id: Pubkey,
+    pub accounts: Vec<AccountMeta>,
+    pub instruction_data: &'a [u8],
+}
+
+impl<'a> MintHook<'a> {
+    pub const MAGIC: u32 = 0x0e64dc89;
+
+    // Byte offsets of each field in the hook
+    const MAGIC_OFFSET: usize = 0;
+    const PROGRAM_ID_OFFSET: usize = 4;
+    const NUM_ACCOUNTS_OFFSET: usize = 36;
+    const ACCOUNTS_OFFSET: usize = 40;
+
+    // Byte offsets of each field in a hook account
+    const ACCOUNT_PUBKEY_OFFSET: usize = 0;
+    const ACCOUNT_IS_WRITABLE_OFFSET: usize = 32;
+    const ACCOUNT_LENGTH: usize = 33;
+
+    /// Decodes a hook from an attestation element's hook data
+    ///
+    /// # Arguments
+    /// * `hook_data` - The element's hook data
+    ///
+    /// # Returns
+    /// * `Ok(Some(hook))` if the hook data starts with the hook magic
+    /// * `Ok(None)` if the hook data is not a hook
+    /// * `Err(GatewayMinterError::MalformedHookData)` if the hook data starts with the
+    ///   hook magic but is too short to hold the hook header and accounts, or an account's
+    ///   writable flag is neither 0 nor 1
+    pub fn decode(hook_data: &'a [u8]) -> Result<Option<Self>> {
+        let magic = hook_data.get(Self::MAGIC_OFFSET..Self::PROGRAM_ID_OFFSET);
+        if magic != Some(&Self::MAGIC.to_be_bytes()[..]) {
+            return Ok(None);
+        }
+
+        require_gte!(
+            hook_data.len(),
+            Self::ACCOUNTS_OFFSET,
+            GatewayMinterError::MalformedHookData
+        );
+
+        let program_id =
+            Pubkey::try_from(&hook_data[Self::PROGRAM_ID_OFFSET..Self::NUM_ACCOUNTS_OFFSET])
+                .map_err(|_| error!(GatewayMinterError::MalformedHookData))?;
+        let num_accounts = u32::from_be_bytes(
+            hook_data[Self::NUM_ACCOUNTS_OFFSET..Self::ACCOUNTS_OFFSET]
+                .try_into()
+                .map_err(|_| error!(GatewayMinterError::MalformedHookData))?,
+        );
+
+        let instruction_data_offset = usize::try_from(num_accounts)
+            .ok()
+            .and_then(|num_accounts| num_accounts.checked_mul(Self::ACCOUNT_LENGTH))
+            .and_then(|accounts_length| accounts_length.checked_add(Self::ACCOUNTS_OFFSET))
+            .ok_or(GatewayMinterError::MalformedHookData)?;
+        require_gte!(
+            hook_data.len(),
+            instruction_data_offset,
+            GatewayMinterError::MalformedHookData
+        );
+
+        let accounts = hook_data[Self::ACCOUNTS_OFFSET..instruction_data_offset]
+            .chunks_exact(Self::ACCOUNT_LENGTH)
+            .map(Self::decode_account)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Some(Self {
+            program_id,
+            accounts,
+            instruction_data: &hook_data[instruction_data_offset..],
+        }))
+    }
+
+    fn decode_account(account: &[u8]) -> Result<AccountMeta> {
+        let pubkey = Pubkey::try_from(
+            &account[Self::ACCOUNT_PUBKEY_OFFSET..Self::ACCOUNT_IS_WRITABLE_OFFSET],
+        )
+        .map_err(|_| error!(GatewayMinterError::MalformedHookData))?;
+
+        match account[Self::ACCOUNT_IS_WRITABLE_OFFSET] {
+            0 => Ok(AccountMeta::new_readonly(pubkey, false)),
+            1 => Ok(AccountMeta::new(pubkey, false)),
+            _ => err!(GatewayMinterError::MalformedHookData),
+        }
+    }
+
+    /// Encodes a hook into the hook data of an attestation element
+    ///
+    /// The signer flags of `accounts` are ignored, as hook accounts are never signers.
+    pub fn encode(
+        program_id: &Pubkey,
+        accounts: &[AccountMeta],
+        instruction_data: &[u8],
+    ) -> Vec<u8> {
+        let mut hook_data = Vec::with_capacity(
+            Self::ACCOUNTS_OFFSET + accounts.len() * Self::ACCOUNT_LENGTH + instruction_data.len(),
+        );
+        hook_data.extend_from_slice(&Self::MAGIC.to_be_bytes());
+        hook_data.extend_from_slice(program_id.as_ref());
+        hook_data.extend_from_slice(&(accounts.len() as u32).to_be_bytes());
+        for account in accounts {
+            hook_data.extend_from_slice(account.pubkey.as_ref());
+            hook_data.push(account.is_writable as u8);
+        }
+        hook_data.extend_from_slice(instruction_data);
+        hook_data
+    }
+}
@@ -22,10 +22,19 @@ pub mod accept_ownership;
 pub mod add_attester;
 pub mod add_token;
 pub mod burn_token_custody;
+pub mod close_used_transfer_spec_hash;
+pub mod configure_token_minter;
 pub mod gateway_mint;
+pub mod get_config;
+pub mod get_token_config;
 pub mod initialize;
+pub mod is_transfer_spec_hash_used;
+pub mod migrate_gateway_minter;
 pub mod pause;
 pub mod remove_attester;
+pub mod set_mint_rate_limit;
+pub mod set_replay_store;
+pub mod set_transfer_receipts;
 pub mod transfer_ownership;
 pub mod unpause;
 pub mod update_pauser;
@@ -35,10 +44,19 @@ pub use accept_ownership::*;
 pub use add_attester::*;
 pub use add_token::*;
 pub use burn_token_custody::*;
+pub use close_used_transfer_spec_hash::*;
+pub use configure_token_minter::*;
 pub use gateway_mint::*;
+pub use get_config::*;
+pub use get_token_config::*;
 pub use initialize::*;
+pub use is_transfer_spec_hash_used::*;
+pub use migrate_gateway_minter::*;
 pub use pause::*;
 pub use remove_attester::*;
+pub use set_mint_rate_limit::*;
+pub use set_replay_store::*;
+pub use set_transfer_receipts::*;
 pub use transfer_ownership::*;
 pub use unpause::*;
 pub use update_pauser::*;
@@ -27,6 +27,7 @@ use {
     },
     anchor_lang::prelude::*,
     anchor_spl::token::{Mint, Token, TokenAccount},
+    gateway_shared::admin::TokenRegistry,
 };
 
 #[event_cpi]
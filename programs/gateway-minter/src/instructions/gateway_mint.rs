@@ -19,20 +19,30 @@
 //! Gateway mint instruction handler
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
 use anchor_lang::solana_program::keccak::hash;
+use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::sysvar::clock::Clock;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use gateway_shared::admin::TokenRegistry;
+use gateway_shared::replay::{mark_nonce_used, ReplayKey, MAX_TRANSFER_VALIDITY_SLOTS};
 use gateway_shared::{
     create_used_transfer_spec_hash_account, ethereum_signed_message_hash,
-    is_transfer_spec_hash_used, USED_TRANSFER_SPEC_HASH_SEED_PREFIX,
+    is_transfer_spec_hash_used, write_used_transfer_spec_hash_receipt,
 };
 
 use crate::{
     attestation::{MintAttestation, MintAttestationElementStruct, MintAttestationStruct},
     error::GatewayMinterError,
-    events::AttestationUsed,
-    seeds::{GATEWAY_MINTER_CUSTODY_SEED, GATEWAY_MINTER_SEED},
-    state::{GatewayMinter, UsedTransferSpecHash},
+    events::{AttestationUsed, MintRateLimited},
+    hook::MintHook,
+    seeds::{GATEWAY_MINTER_CUSTODY_SEED, GATEWAY_MINTER_SEED, TOKEN_CONFIG_SEED},
+    state::{
+        GatewayMinter, MintReceipt, NonceBucket, ReplayStore, TokenConfig, TokenMintMode,
+        UsedTransferSpecHash,
+    },
+    utils,
 };
 
 #[event_cpi]
@@ -53,10 +63,26 @@ pub struct GatewayMintContext<'info> {
     pub system_program: Program<'info, System>,
 
     pub token_program: Program<'info, Token>,
-    // Additional account triplets for each attestation element
-    //   0. `[writable]` The custody token account PDA (seeds = [GATEWAY_MINTER_CUSTODY_SEED, destination_token])
-    //   1. `[writable]` The destination recipient token account.
-    //   2. `[writable]` The used transfer spec hash account PDA (seeds = [USED_TRANSFER_SPEC_HASH_SEED_PREFIX, transfer_spec_hash])
+    // Additional account quadruplets for each attestation element
+    //   0. `[writable]` The custody token account PDA (seeds = [GATEWAY_MINTER_CUSTODY_SEED, destination_token]),
+    //      or the destination token mint if the token is in mint-authority mode
+    //   1. `[writable]` The destination recipient token account. If this is the associated token account of
+    //      the attested destination recipient, the destination recipient is treated as a wallet owner and the
+    //      associated token account is created if it does not exist.
+    //   2. `[writable]` The used transfer spec hash account PDA (seeds = [USED_TRANSFER_SPEC_HASH_SEED_PREFIX, transfer_spec_hash]),
+    //      or the nonce bucket account PDA if the transfer is recorded by nonce
+    //   3. `[writable]` The token config account PDA (seeds = [TOKEN_CONFIG_SEED, destination_token])
+    // Followed by extra accounts for each attestation element, in element order
+    // If the transfer is recorded by nonce while migrating to nonce buckets:
+    //   0. `[]` The used transfer spec hash account PDA
+    // If the destination recipient is a wallet owner:
+    //   0. `[]` The destination recipient (wallet owner)
+    //   1. `[]` The destination token mint
+    //   2. `[]` The associated token program
+    // If the element's hook_data encodes a hook:
+    //   0. `[]` The hook program
+    //   1..=num_accounts. The accounts attested in the hook, in the same order. Accounts the hook
+    //      attests as writable must be writable.
 }
 
 /// Mode 1: Full attestation bytes with signature
@@ -64,6 +90,9 @@ pub struct GatewayMintContext<'info> {
 pub struct GatewayMintParams {
     pub attestation: Vec<u8>,
     pub signature: Vec<u8>,
+    /// The encoded transfer spec of each attestation element, which keys its replay record.
+    /// Required unless the replay store is `HashAccounts`.
+    pub transfer_specs: Vec<Vec<u8>>,
 }
 
 /// Mode 2: Parameter reconstruction mode with elements
@@ -73,6 +102,8 @@ pub struct GatewayMintReconstructParams {
     pub max_block_height: u64,
     pub elements: Vec<MintAttestationParams>,
     pub signature: Vec<u8>,
+    /// The encoded transfer spec of each attestation element, as in `GatewayMintParams`
+    pub transfer_specs: Vec<Vec<u8>>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -80,6 +111,26 @@ pub struct MintAttestationParams {
     pub value: u64,
     pub transfer_spec_hash: [u8; 32],
     pub hook_data: Vec<u8>,
+    /// The wallet owner of the destination recipient token account, if the attested destination
+    /// recipient is a wallet owner rather than a token account
+    pub recipient_owner: Option<Pubkey>,
+}
+
+/// The account a token is minted from
+enum TokenSource<'mint> {
+    /// The custody token account, for tokens in custody mode
+    Custody(Account<'mint, TokenAccount>),
+    /// The token mint, for tokens in mint-authority mode
+    Mint(Account<'mint, Mint>),
+}
+
+impl TokenSource<'_> {
+    fn mint(&self) -> Pubkey {
+        match self {
+            TokenSource::Custody(custody_token_account) => custody_token_account.mint,
+            TokenSource::Mint(mint) => mint.key(),
+        }
+    }
 }
 
 pub fn gateway_mint<'mint>(
@@ -106,12 +157,24 @@ pub fn gateway_mint<'mint>(
     // Verify attestation is not expired
     // Note: the field is called max_block_height for consistency with EVM,
     // but in Solana context it refers to the slot height expiration
+    let current_slot = Clock::get()?.slot;
+    let max_block_height = attestation.max_block_height()?;
     require_gte!(
-        attestation.max_block_height()?,
-        Clock::get()?.slot,
+        max_block_height,
+        current_slot,
         GatewayMinterError::AttestationExpired
     );
 
+    // While transfers are recorded by hash, they must expire in time for a migration to nonce
+    // buckets
+    if ctx.accounts.gateway_minter.replay_store == ReplayStore::HashAccounts {
+        require_gte!(
+            current_slot.saturating_add(MAX_TRANSFER_VALIDITY_SLOTS),
+            max_block_height,
+            GatewayMinterError::AttestationValidityTooLong
+        );
+    }
+
     // If destination caller is not zero address, verify it matches the signer
     let attestation_destination_caller = attestation.destination_caller()?;
     if attestation_destination_caller != Pubkey::default() {
@@ -136,43 +199,117 @@ pub fn gateway_mint<'mint>(
         GatewayMinterError::DestinationContractMismatch
     );
 
-    // Check that remaining accounts length is exactly the number of attestation elements times 3
-    // It is possible that num_attestations is encoded incorrectly. In this case we expect the
-    // attestation iterator to return an error.
-    require_eq!(
+    // Check that remaining accounts hold at least the account quadruplets for the attestation
+    // elements. Any accounts after the quadruplets are extra accounts used for recipient token account creation
+    // and hooks. It is possible that num_attestations is encoded incorrectly. In this case we expect
+    // the attestation iterator to return an error.
+    let extra_accounts_start = (attestation.num_attestations()? as usize)
+        .checked_mul(4)
+        .ok_or(GatewayMinterError::RemainingAccountsLengthMismatch)?;
+    require_gte!(
         ctx.remaining_accounts.len(),
-        (attestation.num_attestations()? * 3) as usize,
+        extra_accounts_start,
         GatewayMinterError::RemainingAccountsLengthMismatch
     );
+    let mut extra_account_index = extra_accounts_start;
+
+    // Transfers are keyed by their transfer spec, which the attestation only holds the hash of
+    let replay_store = ctx.accounts.gateway_minter.replay_store;
+    if replay_store != ReplayStore::HashAccounts {
+        require_eq!(
+            params.transfer_specs.len(),
+            attestation.num_attestations()? as usize,
+            GatewayMinterError::InvalidTransferSpec
+        );
+    }
 
-    // Each attestation element requires 3 accounts:
-    // 0. Custody token account
+    // Each attestation element requires 4 accounts:
+    // 0. Custody token account, or token mint in mint-authority mode
     // 1. Destination recipient account
-    // 2. Used transfer spec hash account
+    // 2. Used transfer spec hash account, or nonce bucket account
+    // 3. Token config account of the destination token
+    let receipt_space = if ctx.accounts.gateway_minter.transfer_receipts_enabled {
+        MintReceipt::INIT_SPACE
+    } else {
+        0
+    };
     let mut account_index = 0;
+    let mut element_index = 0;
     while attestation.next()? {
-        let custody_token_account = validate_custody_token_account(
-            &ctx.remaining_accounts[account_index],
-            gateway_minter,
-            &ctx.accounts.gateway_minter.key(),
-            ctx.program_id,
-        )?;
+        let destination_token = attestation.destination_token()?;
+        let destination_recipient = attestation.destination_recipient()?;
+
+        // Determine where the transfer is recorded. Explicit nonces are scoped to the source
+        // depositor of the source contract on the source domain.
+        let transfer_spec_hash = attestation.transfer_spec_hash()?;
+        let replay_key = match replay_store {
+            ReplayStore::HashAccounts => ReplayKey::TransferSpecHash(transfer_spec_hash),
+            ReplayStore::Migrating | ReplayStore::NonceBuckets => {
+                let transfer_spec = &params.transfer_specs[element_index];
+                require!(
+                    hash(transfer_spec).0 == transfer_spec_hash,
+                    GatewayMinterError::InvalidTransferSpec
+                );
+                ReplayKey::from_transfer_spec(transfer_spec_hash, transfer_spec)
+                    .ok_or(GatewayMinterError::InvalidTransferSpec)?
+            }
+        };
+        let migration_hash_account = if replay_store == ReplayStore::Migrating
+            && matches!(replay_key, ReplayKey::Nonce { .. })
+        {
+            let account = ctx
+                .remaining_accounts
+                .get(extra_account_index)
+                .ok_or(GatewayMinterError::RemainingAccountsLengthMismatch)?;
+            extra_account_index += 1;
+            Some(account)
+        } else {
+            None
+        };
+
+        // Tokens that are not configured fall back to custody mode, and unsupported tokens fail
+        // custody validation
+        let token_config_info = &ctx.remaining_accounts[account_index + 3];
+        let mut token_config =
+            load_token_config(token_config_info, &destination_token, ctx.program_id)?;
+        let token_source = match token_config.mint_mode {
+            TokenMintMode::Custody => TokenSource::Custody(validate_custody_token_account(
+                &ctx.remaining_accounts[account_index],
+                &ctx.accounts.gateway_minter,
+                &ctx.accounts.gateway_minter.key(),
+                ctx.program_id,
+            )?),
+            TokenMintMode::MintAuthority => {
+                TokenSource::Mint(validate_token_mint(&ctx.remaining_accounts[account_index])?)
+            }
+        };
+
+        // If the destination recipient account is the associated token account of the attested
+        // recipient, the attested recipient is a wallet owner. Create its associated token account
+        // if it does not exist yet.
+        let destination_account_info = &ctx.remaining_accounts[account_index + 1];
+        let is_recipient_owner = destination_account_info.key() != destination_recipient
+            && destination_account_info.key()
+                == get_associated_token_address(&destination_recipient, &destination_token);
+        if is_recipient_owner {
+            extra_account_index = create_recipient_token_account(
+                &destination_recipient,
+                &destination_token,
+                destination_account_info,
+                ctx.remaining_accounts,
+                extra_account_index,
+                &ctx.accounts.payer,
+                &ctx.accounts.system_program,
+                &ctx.accounts.token_program,
+            )?;
+        }
 
         let destination_recipient_account =
-            validate_destination_token_account(&ctx.remaining_accounts[account_index + 1])?;
-
-        let transfer_spec_hash = process_used_transfer_spec_hash(
-            attestation.transfer_spec_hash()?,
-            &ctx.remaining_accounts[account_index + 2],
-            &ctx.accounts.payer,
-            &ctx.accounts.system_program,
-            ctx.program_id,
-        )?;
+            validate_destination_token_account(destination_account_info)?;
 
         // Verify token account mints match the expected destination token
-        let destination_token = attestation.destination_token()?;
         require_keys_eq!(
-            custody_token_account.mint,
+            token_source.mint(),
             destination_token,
             GatewayMinterError::DestinationTokenMismatch
         );
@@ -182,10 +319,15 @@ pub fn gateway_mint<'mint>(
             GatewayMinterError::DestinationTokenMismatch
         );
 
-        // Verify destination account matches expected recipient
+        // Verify destination account matches expected recipient, or is owned by it
+        let recipient_key = if is_recipient_owner {
+            destination_recipient_account.owner
+        } else {
+            destination_recipient_account.key()
+        };
         require_keys_eq!(
-            destination_recipient_account.key(),
-            attestation.destination_recipient()?,
+            recipient_key,
+            destination_recipient,
             GatewayMinterError::DestinationRecipientMismatch
         );
 
@@ -193,30 +335,116 @@ pub fn gateway_mint<'mint>(
         let value = attestation.value()?;
         require_gt!(value, 0, GatewayMinterError::InvalidAttestationValue);
 
-        // Mint token
-        gateway_minter.mint_token(
-            &ctx.accounts.token_program,
-            &custody_token_account,
-            &destination_recipient_account,
-            &ctx.accounts.gateway_minter,
-            gateway_minter.bump,
-            value,
+        // Skip a mint beyond the token's rate limit without recording the transfer, so that the
+        // attestation can be submitted again once the rate limit has refilled
+        let hook_data = attestation.hook_data()?;
+        let hook = MintHook::decode(hook_data)?;
+        if !token_config.rate_limit.consume(value, current_slot) {
+            emit_cpi!(MintRateLimited {
+                token: destination_token,
+                recipient: destination_recipient,
+                transfer_spec_hash,
+                value,
+                available: token_config.rate_limit.available,
+            });
+
+            // The hook accounts of the skipped element are still passed
+            if let Some(hook) = &hook {
+                extra_account_index = extra_account_index
+                    .checked_add(1 + hook.accounts.len())
+                    .ok_or(GatewayMinterError::RemainingAccountsLengthMismatch)?;
+            }
+            account_index += 4;
+            element_index += 1;
+            continue;
+        }
+
+        mark_transfer_used(
+            replay_key,
+            transfer_spec_hash,
+            &ctx.remaining_accounts[account_index + 2],
+            migration_hash_account,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            ctx.program_id,
+            max_block_height,
+            receipt_space,
         )?;
 
+        // Mint token
+        match &token_source {
+            TokenSource::Custody(custody_token_account) => {
+                ctx.accounts.gateway_minter.mint_token(
+                    &ctx.accounts.token_program,
+                    custody_token_account,
+                    &destination_recipient_account,
+                    &ctx.accounts.gateway_minter,
+                    ctx.accounts.gateway_minter.bump,
+                    value,
+                )?;
+            }
+            TokenSource::Mint(mint) => {
+                token_config.use_minting_allowance(value)?;
+                ctx.accounts.gateway_minter.mint_to_token(
+                    &ctx.accounts.token_program,
+                    mint,
+                    &destination_recipient_account,
+                    &ctx.accounts.gateway_minter,
+                    ctx.accounts.gateway_minter.bump,
+                    value,
+                )?;
+            }
+        }
+
+        // Persist the consumed rate limit and allowance. Only configured tokens have either.
+        if token_config_info.owner == ctx.program_id {
+            token_config.try_serialize(&mut &mut token_config_info.try_borrow_mut_data()?[..])?;
+        }
+
+        // Record how the mint was settled, unless it is recorded in a nonce bucket
+        if receipt_space > 0 && matches!(replay_key, ReplayKey::TransferSpecHash(_)) {
+            write_used_transfer_spec_hash_receipt(
+                &ctx.remaining_accounts[account_index + 2],
+                &MintReceipt {
+                    slot: current_slot,
+                    token: destination_token,
+                    recipient: destination_recipient,
+                    value,
+                },
+            )?;
+        }
+
+        // Execute the hook, if the element's hook data encodes one
+        if let Some(hook) = hook {
+            extra_account_index = execute_hook(
+                &hook,
+                ctx.remaining_accounts,
+                extra_account_index,
+                ctx.program_id,
+            )?;
+        }
+
         // Emit attestation used event
         emit_cpi!(AttestationUsed {
-            token: attestation.destination_token()?,
-            recipient: attestation.destination_recipient()?,
+            token: destination_token,
+            recipient: destination_recipient,
             transfer_spec_hash,
             value,
+            hook_data: hook_data.to_vec(),
         });
 
-        account_index += 3;
+        account_index += 4;
+        element_index += 1;
     }
 
     // Ensure no extra accounts were provided
     require_eq!(
         account_index,
+        extra_accounts_start,
+        GatewayMinterError::RemainingAccountsLengthMismatch
+    );
+    require_eq!(
+        extra_account_index,
         ctx.remaining_accounts.len(),
         GatewayMinterError::RemainingAccountsLengthMismatch
     );
@@ -224,6 +452,155 @@ pub fn gateway_mint<'mint>(
     Ok(())
 }
 
+/// Creates the associated token account of a destination recipient wallet owner, if it does not exist
+///
+/// # Arguments
+/// * `recipient_owner` - The attested destination recipient, which owns the token account
+/// * `token_mint` - The attested destination token
+/// * `recipient_token_account` - The destination recipient token account
+/// * `remaining_accounts` - The gateway_mint remaining accounts
+/// * `start` - The index of the recipient owner account in the remaining accounts
+/// * `payer` - The payer for the token account rent
+/// * `system_program` - The system program
+/// * `token_program` - The token program
+///
+/// # Returns
+/// * The index of the account following the token account creation accounts
+#[allow(clippy::too_many_arguments)]
+fn create_recipient_token_account<'mint>(
+    recipient_owner: &Pubkey,
+    token_mint: &Pubkey,
+    recipient_token_account: &AccountInfo<'mint>,
+    remaining_accounts: &[AccountInfo<'mint>],
+    start: usize,
+    payer: &Signer<'mint>,
+    system_program: &Program<'mint, System>,
+    token_program: &Program<'mint, Token>,
+) -> Result<usize> {
+    let end = start
+        .checked_add(3)
+        .ok_or(GatewayMinterError::RemainingAccountsLengthMismatch)?;
+    require_gte!(
+        remaining_accounts.len(),
+        end,
+        GatewayMinterError::RemainingAccountsLengthMismatch
+    );
+
+    let owner = &remaining_accounts[start];
+    let mint = &remaining_accounts[start + 1];
+    let associated_token_program = &remaining_accounts[start + 2];
+
+    require_keys_eq!(
+        owner.key(),
+        *recipient_owner,
+        GatewayMinterError::DestinationRecipientMismatch
+    );
+    // A token account cannot own another token account, so an attested recipient that is a
+    // token account must be minted to directly
+    require_keys_neq!(
+        *owner.owner,
+        token_program.key(),
+        GatewayMinterError::InvalidDestinationRecipientOwner
+    );
+    require_keys_eq!(
+        mint.key(),
+        *token_mint,
+        GatewayMinterError::DestinationTokenMismatch
+    );
+    require_keys_eq!(
+        associated_token_program.key(),
+        AssociatedToken::id(),
+        GatewayMinterError::InvalidAssociatedTokenProgram
+    );
+    associated_token::create_idempotent(CpiContext::new(
+        associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: payer.to_account_info(),
+            associated_token: recipient_token_account.to_account_info(),
+            authority: owner.to_account_info(),
+            mint: mint.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+    ))?;
+
+    Ok(end)
+}
+
+/// Invokes a hook program with the hook's accounts from the remaining accounts
+///
+/// The remaining accounts must match the accounts attested in the hook, with at least the
+/// attested writable privileges. No account is passed to the hook as a signer, so the hook
+/// cannot act with the signatures given to this instruction, and the hook is invoked without
+/// the GatewayMinter PDA as a signer, so it cannot move custody funds.
+///
+/// # Arguments
+/// * `hook` - The decoded hook
+/// * `remaining_accounts` - The gateway_mint remaining accounts
+/// * `start` - The index of the hook program account in the remaining accounts
+/// * `program_id` - The GatewayMinter program ID
+///
+/// # Returns
+/// * The index of the account following the hook's accounts
+fn execute_hook<'mint>(
+    hook: &MintHook,
+    remaining_accounts: &[AccountInfo<'mint>],
+    start: usize,
+    program_id: &Pubkey,
+) -> Result<usize> {
+    require_keys_neq!(
+        hook.program_id,
+        *program_id,
+        GatewayMinterError::InvalidHookProgram
+    );
+
+    let end = start
+        .checked_add(1)
+        .and_then(|index| index.checked_add(hook.accounts.len()))
+        .ok_or(GatewayMinterError::RemainingAccountsLengthMismatch)?;
+    require_gte!(
+        remaining_accounts.len(),
+        end,
+        GatewayMinterError::RemainingAccountsLengthMismatch
+    );
+
+    let hook_program = &remaining_accounts[start];
+    require_keys_eq!(
+        hook_program.key(),
+        hook.program_id,
+        GatewayMinterError::InvalidHookProgram
+    );
+    require!(
+        hook_program.executable,
+        GatewayMinterError::InvalidHookProgram
+    );
+
+    let hook_accounts = &remaining_accounts[start + 1..end];
+    for (meta, account) in hook.accounts.iter().zip(hook_accounts) {
+        require_keys_eq!(
+            account.key(),
+            meta.pubkey,
+            GatewayMinterError::InvalidHookAccount
+        );
+        require!(
+            account.is_writable || !meta.is_writable,
+            GatewayMinterError::InvalidHookAccount
+        );
+    }
+
+    let instruction = Instruction {
+        program_id: hook.program_id,
+        accounts: hook.accounts.clone(),
+        data: hook.instruction_data.to_vec(),
+    };
+
+    let mut account_infos = hook_accounts.to_vec();
+    account_infos.push(hook_program.clone());
+    invoke(&instruction, &account_infos)?;
+
+    Ok(end)
+}
+
 fn validate_custody_token_account<'mint>(
     account_info: &'mint AccountInfo<'mint>, // UncheckedAccount
     gateway_minter: &GatewayMinter,
@@ -262,6 +639,16 @@ fn validate_custody_token_account<'mint>(
     Ok(custody_account)
 }
 
+fn validate_token_mint<'mint>(
+    account_info: &'mint AccountInfo<'mint>, // UncheckedAccount
+) -> Result<Account<'mint, Mint>> {
+    // Deserialize the token mint
+    let token_mint = Account::<'mint, Mint>::try_from(account_info)
+        .map_err(|_| GatewayMinterError::InvalidTokenMint)?;
+
+    Ok(token_mint)
+}
+
 fn validate_destination_token_account<'mint>(
     account_info: &'mint AccountInfo<'mint>, // UncheckedAccount
 ) -> Result<Account<'mint, TokenAccount>> {
@@ -272,48 +659,128 @@ fn validate_destination_token_account<'mint>(
     Ok(destination_account)
 }
 
-fn process_used_transfer_spec_hash<'mint>(
-    transfer_spec_hash: [u8; 32],
-    hash_account: &AccountInfo<'mint>, // UncheckedAccount
-    payer: &Signer<'mint>,
-    system_program: &Program<'mint, System>,
+/// Loads the config of a destination token from its token config PDA
+///
+/// # Arguments
+/// * `account_info` - The token config account
+/// * `token` - The attested destination token
+/// * `program_id` - The GatewayMinter program ID
+///
+/// # Returns
+/// * The token config, or the default config if the token was never configured
+fn load_token_config(
+    account_info: &AccountInfo, // UncheckedAccount
+    token: &Pubkey,
     program_id: &Pubkey,
-) -> Result<[u8; 32]> {
-    // Derive the expected PDA using the parsed hash
-    let (expected_pda, bump) = Pubkey::find_program_address(
-        &[USED_TRANSFER_SPEC_HASH_SEED_PREFIX, &transfer_spec_hash],
-        program_id,
-    );
-
-    // Verify the provided account matches the expected PDA
+) -> Result<TokenConfig> {
+    let (expected_pda, bump) =
+        Pubkey::find_program_address(&[TOKEN_CONFIG_SEED, token.as_ref()], program_id);
     require_keys_eq!(
         expected_pda,
-        hash_account.key(),
-        GatewayMinterError::InvalidTransferSpecHashAccount
+        account_info.key(),
+        GatewayMinterError::InvalidTokenConfigAccount
     );
 
-    // Check if the hash is already used
-    let is_used = {
-        let account_data = hash_account.try_borrow_data()?;
-        is_transfer_spec_hash_used(&account_data, UsedTransferSpecHash::DISCRIMINATOR)?
-    };
+    Ok(
+        utils::load_if_initialized::<TokenConfig>(account_info, program_id)?.unwrap_or_else(|| {
+            TokenConfig {
+                bump,
+                token: *token,
+                ..TokenConfig::default()
+            }
+        }),
+    )
+}
 
-    if is_used {
-        return Err(GatewayMinterError::TransferSpecHashAlreadyUsed.into());
+/// Marks a transfer as used in its replay protection account, failing if it was already used.
+///
+/// While migrating to nonce buckets, a transfer recorded by nonce must also have an unused
+/// transfer spec hash account, as it may have been recorded there before the migration began.
+#[allow(clippy::too_many_arguments)]
+fn mark_transfer_used<'mint>(
+    replay_key: ReplayKey,
+    transfer_spec_hash: [u8; 32],
+    replay_account: &AccountInfo<'mint>, // UncheckedAccount
+    migration_hash_account: Option<&AccountInfo<'mint>>,
+    payer: &Signer<'mint>,
+    system_program: &Program<'mint, System>,
+    program_id: &Pubkey,
+    expiry_slot: u64,
+    receipt_space: usize,
+) -> Result<()> {
+    let (expected_pda, bump) = replay_key.find_address(program_id);
+
+    match replay_key {
+        ReplayKey::TransferSpecHash(_) => {
+            require_keys_eq!(
+                expected_pda,
+                replay_account.key(),
+                GatewayMinterError::InvalidTransferSpecHashAccount
+            );
+
+            let is_used = {
+                let account_data = replay_account.try_borrow_data()?;
+                is_transfer_spec_hash_used(&account_data, UsedTransferSpecHash::DISCRIMINATOR)?
+            };
+
+            if is_used {
+                return Err(GatewayMinterError::TransferSpecHashAlreadyUsed.into());
+            }
+
+            // Create and initialize the used transfer spec hash account
+            create_used_transfer_spec_hash_account(
+                replay_account,
+                &transfer_spec_hash,
+                bump,
+                payer,
+                system_program,
+                program_id,
+                UsedTransferSpecHash::DISCRIMINATOR,
+                expiry_slot,
+                receipt_space,
+            )
+        }
+        ReplayKey::Nonce { namespace, nonce } => {
+            require_keys_eq!(
+                expected_pda,
+                replay_account.key(),
+                GatewayMinterError::InvalidNonceBucketAccount
+            );
+
+            if let Some(hash_account) = migration_hash_account {
+                let (expected_hash_pda, _) =
+                    ReplayKey::TransferSpecHash(transfer_spec_hash).find_address(program_id);
+                require_keys_eq!(
+                    expected_hash_pda,
+                    hash_account.key(),
+                    GatewayMinterError::InvalidTransferSpecHashAccount
+                );
+
+                let is_used = {
+                    let account_data = hash_account.try_borrow_data()?;
+                    is_transfer_spec_hash_used(&account_data, UsedTransferSpecHash::DISCRIMINATOR)?
+                };
+
+                if is_used {
+                    return Err(GatewayMinterError::TransferSpecHashAlreadyUsed.into());
+                }
+            }
+
+            let is_marked = mark_nonce_used(
+                replay_account,
+                &namespace,
+                nonce,
+                bump,
+                payer,
+                system_program,
+                program_id,
+                NonceBucket::DISCRIMINATOR,
+            )?;
+            require!(is_marked, GatewayMinterError::TransferSpecHashAlreadyUsed);
+
+            Ok(())
+        }
     }
-
-    // Create and initialize the used transfer spec hash account
-    create_used_transfer_spec_hash_account(
-        hash_account,
-        &transfer_spec_hash,
-        bump,
-        payer,
-        system_program,
-        program_id,
-        UsedTransferSpecHash::DISCRIMINATOR,
-    )?;
-
-    Ok(transfer_spec_hash)
 }
 
 pub fn gateway_mint_with_params<'mint>(
@@ -330,6 +797,7 @@ pub fn gateway_mint_with_params<'mint>(
     let gateway_mint_params = GatewayMintParams {
         attestation: attestation_bytes,
         signature: params.signature,
+        transfer_specs: params.transfer_specs,
     };
 
     gateway_mint(ctx, &gateway_mint_params)
@@ -339,10 +807,11 @@ fn reconstruct_attestation_bytes<'mint>(
     ctx: &Context<'_, '_, 'mint, 'mint, GatewayMintContext<'mint>>,
     params: &GatewayMintReconstructParams,
 ) -> Result<Vec<u8>> {
-    // Check that remaining accounts length is exactly the number of attestation elements times 3
-    require_eq!(
+    // Check that remaining accounts hold at least the account quadruplets for the attestation
+    // elements
+    require_gte!(
         ctx.remaining_accounts.len(),
-        params.elements.len() * 3,
+        params.elements.len() * 4,
         GatewayMinterError::RemainingAccountsLengthMismatch
     );
 
@@ -350,20 +819,38 @@ fn reconstruct_attestation_bytes<'mint>(
     let mut account_index = 0;
     let mut elements = Vec::with_capacity(params.elements.len());
     for element in &params.elements {
-        // Parse the destination recipient token account
-        let destination_account =
-            validate_destination_token_account(&ctx.remaining_accounts[account_index + 1])?;
+        let (destination_token, destination_recipient) = match element.recipient_owner {
+            // The destination recipient token account may not exist yet, so take the destination
+            // token from the custody token account, or the token mint in mint-authority mode
+            Some(recipient_owner) => {
+                let token_source = &ctx.remaining_accounts[account_index];
+                let destination_token = match Account::<'mint, Mint>::try_from(token_source) {
+                    Ok(token_mint) => token_mint.key(),
+                    Err(_) => {
+                        Account::<'mint, TokenAccount>::try_from(token_source)
+                            .map_err(|_| GatewayMinterError::InvalidCustodyTokenAccount)?
+                            .mint
+                    }
+                };
+                (destination_token, recipient_owner)
+            }
+            // Assume that the destination token is the same as the destination recipient token
+            None => {
+                let destination_account =
+                    validate_destination_token_account(&ctx.remaining_accounts[account_index + 1])?;
+                (destination_account.mint, destination_account.key())
+            }
+        };
 
-        // Assume that the destination token is the same as the destination recipient token
         elements.push(MintAttestationElementStruct {
-            destination_token: destination_account.mint.to_bytes(),
-            destination_recipient: destination_account.key().to_bytes(),
+            destination_token: destination_token.to_bytes(),
+            destination_recipient: destination_recipient.to_bytes(),
             value: element.value,
             transfer_spec_hash: element.transfer_spec_hash,
             hook_data: element.hook_data.as_slice(),
         });
 
-        account_index += 3;
+        account_index += 4;
     }
 
     // Determine how the destination caller should be encoded
@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GetConfig view instruction handler
+
+use {
+    crate::{
+        seeds::GATEWAY_MINTER_SEED,
+        state::{GatewayMinter, ReplayStore, MAX_ATTESTERS, MAX_SUPPORTED_TOKENS},
+    },
+    anchor_lang::{prelude::*, solana_program::program::MAX_RETURN_DATA},
+};
+
+#[derive(Accounts)]
+pub struct GetConfigContext<'info> {
+    #[account(
+        seeds = [GATEWAY_MINTER_SEED],
+        bump = gateway_minter.bump
+    )]
+    pub gateway_minter: Box<Account<'info, GatewayMinter>>,
+}
+
+/// The GatewayMinter configuration, returned by `get_config`
+///
+/// The token configs are returned per token by `get_token_config`, so that the result always
+/// fits in the return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct MinterConfig {
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub pauser: Pubkey,
+    pub token_controller: Pubkey,
+    pub paused: bool,
+    #[max_len(MAX_ATTESTERS)]
+    pub enabled_attesters: Vec<Pubkey>,
+    pub local_domain: u32,
+    pub version: u32,
+    #[max_len(MAX_SUPPORTED_TOKENS)]
+    pub supported_tokens: Vec<Pubkey>,
+    pub transfer_receipts_enabled: bool,
+    pub replay_store: ReplayStore,
+    pub replay_migration_started_slot: u64,
+}
+
+const _: () = assert!(MinterConfig::INIT_SPACE <= MAX_RETURN_DATA);
+
+pub fn get_config(ctx: Context<GetConfigContext>) -> Result<MinterConfig> {
+    let gateway_minter = &ctx.accounts.gateway_minter;
+
+    Ok(MinterConfig {
+        owner: gateway_minter.owner,
+        pending_owner: gateway_minter.pending_owner,
+        pauser: gateway_minter.pauser,
+        token_controller: gateway_minter.token_controller,
+        paused: gateway_minter.paused,
+        enabled_attesters: gateway_minter.enabled_attesters.clone(),
+        local_domain: gateway_minter.local_domain,
+        version: gateway_minter.version,
+        supported_tokens: gateway_minter.supported_tokens.clone(),
+        transfer_receipts_enabled: gateway_minter.transfer_receipts_enabled,
+        replay_store: gateway_minter.replay_store,
+        replay_migration_started_slot: gateway_minter.replay_migration_started_slot,
+    })
+}
@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GetTokenConfig view instruction handler
+
+use {
+    crate::{
+        error::GatewayMinterError,
+        seeds::{GATEWAY_MINTER_SEED, TOKEN_CONFIG_SEED},
+        state::{GatewayMinter, TokenConfig},
+        utils,
+    },
+    anchor_lang::prelude::*,
+    gateway_shared::admin::TokenRegistry,
+};
+
+#[derive(Accounts)]
+#[instruction(params: GetTokenConfigParams)]
+pub struct GetTokenConfigContext<'info> {
+    #[account(
+        seeds = [GATEWAY_MINTER_SEED],
+        bump = gateway_minter.bump
+    )]
+    pub gateway_minter: Box<Account<'info, GatewayMinter>>,
+
+    /// CHECK: The token config PDA, which does not exist until the token is configured
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, params.token.as_ref()],
+        bump
+    )]
+    pub token_config: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct GetTokenConfigParams {
+    /// The token mint key
+    pub token: Pubkey,
+}
+
+/// Returns the minting configuration of a supported token, with its rate limit refilled to the
+/// current slot
+pub fn get_token_config(
+    ctx: Context<GetTokenConfigContext>,
+    params: &GetTokenConfigParams,
+) -> Result<TokenConfig> {
+    require!(
+        ctx.accounts.gateway_minter.is_token_supported(params.token),
+        GatewayMinterError::TokenNotSupported
+    );
+
+    // A token that was never configured uses the default config
+    let mut config =
+        utils::load_if_initialized::<TokenConfig>(&ctx.accounts.token_config, ctx.program_id)?
+            .unwrap_or_else(|| TokenConfig {
+                bump: ctx.bumps.token_config,
+                token: params.token,
+                ..TokenConfig::default()
+            });
+
+    if config.rate_limit.is_enabled() {
+        config.rate_limit.refill(Clock::get()?.slot);
+    }
+
+    Ok(config)
+}
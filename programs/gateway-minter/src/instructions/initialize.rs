@@ -20,7 +20,10 @@
 
 use {
     crate::{
-        events::GatewayMinterInitialized, seeds::GATEWAY_MINTER_SEED, state::GatewayMinter, utils,
+        events::GatewayMinterInitialized,
+        seeds::GATEWAY_MINTER_SEED,
+        state::{GatewayMinter, ReplayStore},
+        utils,
     },
     anchor_lang::prelude::*,
 };
@@ -74,6 +77,9 @@ pub fn initialize(ctx: Context<InitializeContext>, params: &InitializeParams) ->
     gateway_minter_state.pauser = upgrade_authority;
     gateway_minter_state.token_controller = upgrade_authority;
     gateway_minter_state.paused = false;
+    gateway_minter_state.transfer_receipts_enabled = false;
+    gateway_minter_state.replay_store = ReplayStore::HashAccounts;
+    gateway_minter_state.replay_migration_started_slot = 0;
 
     emit_cpi!(GatewayMinterInitialized {});
 
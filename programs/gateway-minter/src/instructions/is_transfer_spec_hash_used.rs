@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! IsTransferSpecHashUsed view instruction handler
+
+use {
+    crate::state::UsedTransferSpecHash, anchor_lang::prelude::*,
+    gateway_shared::USED_TRANSFER_SPEC_HASH_SEED_PREFIX,
+};
+
+#[derive(Accounts)]
+#[instruction(params: IsTransferSpecHashUsedParams)]
+pub struct IsTransferSpecHashUsedContext<'info> {
+    /// CHECK: Used transfer spec hash PDA of the transfer spec hash. The hash is unused if it
+    /// does not exist.
+    #[account(
+        seeds = [USED_TRANSFER_SPEC_HASH_SEED_PREFIX, params.transfer_spec_hash.as_ref()],
+        bump
+    )]
+    pub used_transfer_spec_hash: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct IsTransferSpecHashUsedParams {
+    /// The transfer spec hash to check
+    pub transfer_spec_hash: [u8; 32],
+}
+
+/// Returns whether a mint has used the transfer spec hash
+///
+/// A used transfer spec hash account closed after its expiry is reported as unused, as its
+/// attestation can no longer be minted.
+pub fn is_transfer_spec_hash_used(
+    ctx: Context<IsTransferSpecHashUsedContext>,
+    _params: &IsTransferSpecHashUsedParams,
+) -> Result<bool> {
+    let account = &ctx.accounts.used_transfer_spec_hash;
+    if account.owner != ctx.program_id {
+        return Ok(false);
+    }
+
+    gateway_shared::is_transfer_spec_hash_used(
+        &account.try_borrow_data()?,
+        UsedTransferSpecHash::DISCRIMINATOR,
+    )
+}
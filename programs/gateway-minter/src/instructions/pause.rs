@@ -23,6 +23,7 @@ use {
         error::GatewayMinterError, events::Paused, seeds::GATEWAY_MINTER_SEED, state::GatewayMinter,
     },
     anchor_lang::prelude::*,
+    gateway_shared::admin::Pausable,
 };
 
 #[event_cpi]
@@ -40,7 +41,7 @@ pub struct PauseContext<'info> {
 }
 
 pub fn pause(ctx: Context<PauseContext>) -> Result<()> {
-    ctx.accounts.gateway_minter.paused = true;
+    ctx.accounts.gateway_minter.pause();
 
     emit_cpi!(Paused {
         account: ctx.accounts.pauser.key(),
@@ -0,0 +1,100 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! SetMintRateLimit instruction handler
+
+use {
+    crate::{
+        error::GatewayMinterError,
+        events::MintRateLimitSet,
+        seeds::{GATEWAY_MINTER_SEED, TOKEN_CONFIG_SEED},
+        state::{GatewayMinter, TokenConfig},
+        utils,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::Mint,
+    gateway_shared::admin::TokenRegistry,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMintRateLimitContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_controller: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_MINTER_SEED],
+        bump = gateway_minter.bump,
+        has_one = token_controller @ GatewayMinterError::InvalidAuthority
+    )]
+    pub gateway_minter: Box<Account<'info, GatewayMinter>>,
+
+    #[account(
+        constraint = gateway_minter.is_token_supported(token_mint.key()) @ GatewayMinterError::TokenNotSupported
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = utils::DISCRIMINATOR_SIZE + TokenConfig::INIT_SPACE,
+        seeds = [TOKEN_CONFIG_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetMintRateLimitParams {
+    /// The maximum amount that can be minted at once. Zero disables the rate limit.
+    pub capacity: u64,
+    /// The amount added back to the rate limit each slot
+    pub refill_per_slot: u64,
+}
+
+pub fn set_mint_rate_limit(
+    ctx: Context<SetMintRateLimitContext>,
+    params: &SetMintRateLimitParams,
+) -> Result<()> {
+    let token = ctx.accounts.token_mint.key();
+
+    // A disabled rate limit cannot refill
+    require!(
+        params.capacity > 0 || params.refill_per_slot == 0,
+        GatewayMinterError::InvalidMintRateLimit
+    );
+
+    let token_config = &mut ctx.accounts.token_config;
+    token_config.bump = ctx.bumps.token_config;
+    token_config.token = token;
+    token_config
+        .rate_limit
+        .configure(params.capacity, params.refill_per_slot, Clock::get()?.slot);
+
+    emit_cpi!(MintRateLimitSet {
+        token,
+        capacity: params.capacity,
+        refill_per_slot: params.refill_per_slot,
+    });
+
+    Ok(())
+}
@@ -24,6 +24,7 @@ use {
         state::GatewayMinter,
     },
     anchor_lang::prelude::*,
+    gateway_shared::admin::Ownable,
 };
 
 #[event_cpi]
@@ -49,12 +50,14 @@ pub fn transfer_ownership(
     ctx: Context<TransferOwnershipContext>,
     params: &TransferOwnershipParams,
 ) -> Result<()> {
-    let state = ctx.accounts.gateway_minter.as_mut();
-    state.pending_owner = params.new_owner;
+    let previous_owner = ctx
+        .accounts
+        .gateway_minter
+        .transfer_ownership(params.new_owner);
 
     emit_cpi!(OwnershipTransferStarted {
-        previous_owner: state.owner,
-        new_owner: state.pending_owner,
+        previous_owner,
+        new_owner: params.new_owner,
     });
 
     Ok(())
@@ -24,6 +24,7 @@ use {
         state::GatewayMinter,
     },
     anchor_lang::prelude::*,
+    gateway_shared::admin::Pausable,
 };
 
 #[event_cpi]
@@ -41,7 +42,7 @@ pub struct UnpauseContext<'info> {
 }
 
 pub fn unpause(ctx: Context<UnpauseContext>) -> Result<()> {
-    ctx.accounts.gateway_minter.paused = false;
+    ctx.accounts.gateway_minter.unpause();
 
     emit_cpi!(Unpaused {
         account: ctx.accounts.pauser.key(),
@@ -24,6 +24,7 @@ use {
         state::GatewayMinter,
     },
     anchor_lang::prelude::*,
+    gateway_shared::admin::Pausable,
 };
 
 #[event_cpi]
@@ -46,20 +47,14 @@ pub struct UpdatePauserParams {
 }
 
 pub fn update_pauser(ctx: Context<UpdatePauserContext>, params: &UpdatePauserParams) -> Result<()> {
-    let state = ctx.accounts.gateway_minter.as_mut();
-
-    require_keys_neq!(
-        params.new_pauser,
-        Pubkey::default(),
-        GatewayMinterError::InvalidPauser
-    );
-
-    let old_pauser = state.pauser;
-    state.pauser = params.new_pauser;
+    let old_pauser = ctx
+        .accounts
+        .gateway_minter
+        .update_pauser(params.new_pauser)?;
 
     emit_cpi!(PauserChanged {
         old_pauser,
-        new_pauser: state.pauser,
+        new_pauser: params.new_pauser,
     });
     Ok(())
 }
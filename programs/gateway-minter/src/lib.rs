@@ -23,6 +23,7 @@
 pub mod attestation;
 pub mod error;
 pub mod events;
+pub mod hook;
 pub mod instructions;
 pub mod seeds;
 pub mod state;
@@ -129,4 +130,70 @@ pub mod gateway_minter {
     pub fn unpause(ctx: Context<UnpauseContext>) -> Result<()> {
         instructions::unpause(ctx)
     }
+
+    #[instruction(discriminator = [12, 13])]
+    pub fn configure_token_minter(
+        ctx: Context<ConfigureTokenMinterContext>,
+        params: ConfigureTokenMinterParams,
+    ) -> Result<()> {
+        instructions::configure_token_minter(ctx, &params)
+    }
+
+    #[instruction(discriminator = [12, 14])]
+    pub fn set_mint_rate_limit(
+        ctx: Context<SetMintRateLimitContext>,
+        params: SetMintRateLimitParams,
+    ) -> Result<()> {
+        instructions::set_mint_rate_limit(ctx, &params)
+    }
+
+    #[instruction(discriminator = [12, 15])]
+    pub fn close_used_transfer_spec_hash(
+        ctx: Context<CloseUsedTransferSpecHashContext>,
+        params: CloseUsedTransferSpecHashParams,
+    ) -> Result<()> {
+        instructions::close_used_transfer_spec_hash(ctx, &params)
+    }
+
+    #[instruction(discriminator = [12, 16])]
+    pub fn set_transfer_receipts(
+        ctx: Context<SetTransferReceiptsContext>,
+        params: SetTransferReceiptsParams,
+    ) -> Result<()> {
+        instructions::set_transfer_receipts(ctx, &params)
+    }
+
+    #[instruction(discriminator = [12, 17])]
+    pub fn is_transfer_spec_hash_used(
+        ctx: Context<IsTransferSpecHashUsedContext>,
+        params: IsTransferSpecHashUsedParams,
+    ) -> Result<bool> {
+        instructions::is_transfer_spec_hash_used(ctx, &params)
+    }
+
+    #[instruction(discriminator = [12, 18])]
+    pub fn get_config(ctx: Context<GetConfigContext>) -> Result<MinterConfig> {
+        instructions::get_config(ctx)
+    }
+
+    #[instruction(discriminator = [12, 19])]
+    pub fn get_token_config(
+        ctx: Context<GetTokenConfigContext>,
+        params: GetTokenConfigParams,
+    ) -> Result<state::TokenConfig> {
+        instructions::get_token_config(ctx, &params)
+    }
+
+    #[instruction(discriminator = [12, 20])]
+    pub fn set_replay_store(
+        ctx: Context<SetReplayStoreContext>,
+        params: SetReplayStoreParams,
+    ) -> Result<()> {
+        instructions::set_replay_store(ctx, &params)
+    }
+
+    #[instruction(discriminator = [12, 21])]
+    pub fn migrate_gateway_minter(ctx: Context<MigrateGatewayMinterContext>) -> Result<()> {
+        instructions::migrate_gateway_minter(ctx)
+    }
 }
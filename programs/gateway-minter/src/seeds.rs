@@ -20,3 +20,4 @@
 
 pub const GATEWAY_MINTER_SEED: &[u8] = b"gateway_minter";
 pub const GATEWAY_MINTER_CUSTODY_SEED: &[u8] = b"gateway_minter_custody";
+pub const TOKEN_CONFIG_SEED: &[u8] = b"token_config";
@@ -18,6 +18,8 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use gateway_shared::admin::{AdminState, Ownable, Pausable, TokenRegistry};
+use gateway_shared::replay::NONCE_BUCKET_BITMAP_SIZE;
 
 use crate::error::GatewayMinterError;
 use crate::seeds::GATEWAY_MINTER_SEED;
@@ -43,40 +45,230 @@ pub struct GatewayMinter {
     pub supported_tokens: Vec<Pubkey>,
     #[max_len(MAX_SUPPORTED_TOKENS)]
     pub custody_token_account_bumps: Vec<u8>,
+    pub transfer_receipts_enabled: bool,
+    pub replay_store: ReplayStore,
+    /// The slot at which mints started migrating to the `Migrating` replay store
+    pub replay_migration_started_slot: u64,
 }
 
-#[account(discriminator = [11, 1])]
-/// Used transfer spec hash state for a transfer spec hash
-pub struct UsedTransferSpecHash;
+/// Where gateway mints record used transfers to prevent replay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplayStore {
+    /// Every transfer is recorded in its own used transfer spec hash account
+    HashAccounts,
+    /// Transfers with an explicit nonce are recorded in nonce buckets, after checking that they
+    /// were not recorded in a used transfer spec hash account before the migration
+    Migrating,
+    /// Transfers with an explicit nonce are recorded in nonce buckets only
+    NonceBuckets,
+}
 
-impl GatewayMinter {
-    /// The length in bytes of attestation signature (64 bytes signature + 1 byte recovery id)
-    const ATTESTATION_SIGNATURE_LENGTH: usize = 65;
+/// How gateway_mint delivers a supported token to recipients
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TokenMintMode {
+    /// Tokens are transferred from the pre-funded custody token account
+    #[default]
+    Custody,
+    /// Tokens are minted with the GatewayMinter PDA as the mint authority
+    MintAuthority,
+}
+
+#[account(discriminator = [11, 2])]
+#[derive(Debug, Default, PartialEq, Eq, InitSpace)]
+/// Minting configuration for a supported token
+///
+/// A token without a token config account is minted from custody without a rate limit.
+pub struct TokenConfig {
+    /// The bump of the token config account
+    pub bump: u8,
+    /// The token mint key
+    pub token: Pubkey,
+    pub mint_mode: TokenMintMode,
+    /// The remaining amount that may be minted in mint-authority mode
+    pub minting_allowance: u64,
+    pub rate_limit: MintRateLimit,
+}
+
+/// A token bucket bounding how much of a token gateway_mint can release over time
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct MintRateLimit {
+    /// The maximum amount that can be minted at once. Zero disables the rate limit.
+    pub capacity: u64,
+    /// The amount added back to the bucket each slot
+    pub refill_per_slot: u64,
+    /// The amount that can currently be minted
+    pub available: u64,
+    /// The slot at which `available` was last refilled
+    pub last_refill_slot: u64,
+}
 
-    pub fn is_token_supported(&self, token_mint: Pubkey) -> bool {
-        self.supported_tokens.contains(&token_mint)
+impl MintRateLimit {
+    pub fn is_enabled(&self) -> bool {
+        self.capacity > 0
     }
 
-    pub fn get_token_index(&self, token_mint: Pubkey) -> Option<usize> {
-        self.supported_tokens
-            .iter()
-            .position(|token| token == &token_mint)
+    /// Refills the bucket for the slots elapsed since the last refill, up to its capacity
+    pub fn refill(&mut self, slot: u64) {
+        let elapsed_slots = slot.saturating_sub(self.last_refill_slot);
+        self.available = self
+            .available
+            .saturating_add(elapsed_slots.saturating_mul(self.refill_per_slot))
+            .min(self.capacity);
+        self.last_refill_slot = slot;
     }
 
-    pub fn add_token(&mut self, token_mint: Pubkey, bump: u8) -> Result<()> {
-        if self.is_token_supported(token_mint) {
-            return Ok(());
+    /// Updates the bucket's capacity and refill rate
+    ///
+    /// A bucket that was disabled starts full. Otherwise the bucket is refilled at the old
+    /// rate and its available amount is capped at the new capacity.
+    pub fn configure(&mut self, capacity: u64, refill_per_slot: u64, slot: u64) {
+        if self.is_enabled() {
+            self.refill(slot);
+            self.available = self.available.min(capacity);
+        } else {
+            self.available = capacity;
+        }
+        self.capacity = capacity;
+        self.refill_per_slot = refill_per_slot;
+        self.last_refill_slot = slot;
+    }
+
+    /// Refills the bucket and takes `amount` from it
+    ///
+    /// # Returns
+    /// * `true` if the amount was available, or the rate limit is disabled
+    /// * `false` if the amount exceeds the available amount, in which case nothing is taken
+    pub fn consume(&mut self, amount: u64, slot: u64) -> bool {
+        if !self.is_enabled() {
+            return true;
         }
 
-        if self.supported_tokens.len() >= MAX_SUPPORTED_TOKENS {
-            return err!(GatewayMinterError::MaxTokensSupported);
+        self.refill(slot);
+        match self.available.checked_sub(amount) {
+            Some(available) => {
+                self.available = available;
+                true
+            }
+            None => false,
         }
+    }
+}
 
-        self.supported_tokens.push(token_mint);
-        self.custody_token_account_bumps.push(bump);
+impl TokenConfig {
+    /// Decreases the minting allowance in mint-authority mode
+    ///
+    /// # Arguments
+    /// * `amount` - The amount being minted
+    ///
+    /// # Errors
+    /// Returns `MintingAllowanceExceeded` if the amount exceeds the remaining allowance
+    pub fn use_minting_allowance(&mut self, amount: u64) -> Result<()> {
+        self.minting_allowance = self
+            .minting_allowance
+            .checked_sub(amount)
+            .ok_or(GatewayMinterError::MintingAllowanceExceeded)?;
 
         Ok(())
     }
+}
+
+#[account(discriminator = [11, 3])]
+#[derive(Debug, InitSpace)]
+/// Bitmap of used nonces, shared by the transfers of a namespace with nonces in the same range
+pub struct NonceBucket {
+    /// The source domain of the transfers
+    pub source_domain: u32,
+    /// The source contract of the transfers
+    pub source_contract: Pubkey,
+    /// The source depositor of the transfers
+    pub source_depositor: Pubkey,
+    /// The index of the bucket, which records nonces from `bucket_index * NONCES_PER_BUCKET`
+    pub bucket_index: u64,
+    /// One bit per nonce, set once the nonce is used
+    pub bitmap: [u8; NONCE_BUCKET_BITMAP_SIZE],
+}
+
+#[account(discriminator = [11, 1])]
+#[derive(Debug, InitSpace)]
+/// Used transfer spec hash state for a transfer spec hash
+pub struct UsedTransferSpecHash {
+    /// The last slot at which the transfer spec hash can be used
+    pub expiry_slot: u64,
+    /// The account that paid the rent, refunded when the account is closed
+    pub rent_payer: Pubkey,
+    /// How the mint was settled, if transfer receipts were enabled when it was executed
+    pub receipt: Option<MintReceipt>,
+}
+
+/// Settlement details of a mint, stored in its used transfer spec hash account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+pub struct MintReceipt {
+    /// The slot in which the mint was executed
+    pub slot: u64,
+    /// The token mint key
+    pub token: Pubkey,
+    /// The attested recipient
+    pub recipient: Pubkey,
+    /// The amount minted
+    pub value: u64,
+}
+
+impl AdminState for GatewayMinter {
+    type Error = GatewayMinterError;
+}
+
+impl Ownable for GatewayMinter {
+    fn owner_mut(&mut self) -> &mut Pubkey {
+        &mut self.owner
+    }
+
+    fn pending_owner_mut(&mut self) -> &mut Pubkey {
+        &mut self.pending_owner
+    }
+}
+
+impl Pausable for GatewayMinter {
+    fn pauser_mut(&mut self) -> &mut Pubkey {
+        &mut self.pauser
+    }
+
+    fn paused_mut(&mut self) -> &mut bool {
+        &mut self.paused
+    }
+}
+
+impl TokenRegistry for GatewayMinter {
+    const MAX_SUPPORTED_TOKENS: usize = MAX_SUPPORTED_TOKENS;
+
+    fn token_controller_mut(&mut self) -> &mut Pubkey {
+        &mut self.token_controller
+    }
+
+    fn supported_tokens(&self) -> &[Pubkey] {
+        &self.supported_tokens
+    }
+
+    fn custody_token_account_bumps(&self) -> &[u8] {
+        &self.custody_token_account_bumps
+    }
+
+    fn token_registry_mut(&mut self) -> (&mut Vec<Pubkey>, &mut Vec<u8>) {
+        (
+            &mut self.supported_tokens,
+            &mut self.custody_token_account_bumps,
+        )
+    }
+}
+
+impl GatewayMinter {
+    /// The length in bytes of attestation signature (64 bytes signature + 1 byte recovery id)
+    const ATTESTATION_SIGNATURE_LENGTH: usize = 65;
 
     pub fn is_attester_enabled(&self, attester: Pubkey) -> bool {
         self.enabled_attesters.contains(&attester)
@@ -133,15 +325,6 @@ impl GatewayMinter {
         Ok(())
     }
 
-    pub fn get_custody_token_account_bump(&self, token_mint: Pubkey) -> Result<u8> {
-        let index = self.get_token_index(token_mint);
-        if index.is_none() {
-            return err!(GatewayMinterError::TokenNotSupported);
-        }
-
-        Ok(self.custody_token_account_bumps[index.unwrap()])
-    }
-
     /// Mints tokens from the custody account to a destination account
     ///
     /// This function transfers tokens from a custody account controlled by the gateway
@@ -183,6 +366,47 @@ impl GatewayMinter {
         Ok(())
     }
 
+    /// Mints new tokens to a destination account
+    ///
+    /// This function mints tokens with the gateway minter as the token's mint authority,
+    /// for tokens in mint-authority mode.
+    ///
+    /// # Arguments
+    /// * `token_program` - The token program
+    /// * `mint` - The token mint
+    /// * `destination_account` - The destination token account to mint to
+    /// * `authority` - The mint authority account (gateway minter)
+    /// * `authority_bump` - The authority PDA bump seed
+    /// * `amount` - The amount to mint
+    ///
+    /// # Errors
+    /// Returns an error if the mint fails or if any account constraints are violated
+    pub fn mint_to_token<'info>(
+        &self,
+        token_program: &Program<'info, Token>,
+        mint: &Account<'info, Mint>,
+        destination_account: &Account<'info, TokenAccount>,
+        authority: &Account<'info, GatewayMinter>,
+        authority_bump: u8,
+        amount: u64,
+    ) -> Result<()> {
+        let authority_seeds: &[&[&[u8]]] = &[&[GATEWAY_MINTER_SEED, &[authority_bump]]];
+
+        let mint_to_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::MintTo {
+                mint: mint.to_account_info(),
+                to: destination_account.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+            authority_seeds,
+        );
+
+        token::mint_to(mint_to_ctx, amount)?;
+
+        Ok(())
+    }
+
     /// Verifies attestation signatures against the message hash
     ///
     /// This function recovers the signer from each signature and verifies they are enabled attesters.
@@ -19,4 +19,4 @@
 //! Common utility functions.
 
 // Re-export from shared library for convenience
-pub use gateway_shared::DISCRIMINATOR_SIZE;
+pub use gateway_shared::{grow_account, load_if_initialized, DISCRIMINATOR_SIZE};
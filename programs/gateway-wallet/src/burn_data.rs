@@ -25,13 +25,17 @@
 //! The user signs the BurnIntent message, which consists of a 16-byte prefix and the BurnIntent.
 //! The BurnIntent includes the expiration height, max fee, and TransferSpec.
 //!
+//! `BurnData` is a zero-copy parser used on-chain. `BurnDataStruct`, `BurnIntent`, and
+//! `TransferSpec` are owned equivalents for building messages off-chain; they decode through
+//! the parser so both always agree on the layout.
+//!
 //! Constants:
 //! - BurnIntent magic: `0x070afbc2` (bytes4(keccak256("circle.gateway.BurnIntent")))
 //! - TransferSpec magic: `0xca85def7` (bytes4(keccak256("circle.gateway.TransferSpec")))
 //! - BurnIntent message prefix: `0xff` followed by 15 zero bytes
 //!
 //! BurnData layout:
-//! ```
+//! ```text
 //! offset  size  field
 //! 0       8     fee (u64)
 //! 8       64    user_signature
@@ -40,7 +44,7 @@
 //! ```
 //!
 //! BurnIntent layout:
-//! ```
+//! ```text
 //! offset  size  field
 //! 0       4     magic (0x070afbc2)
 //! 4       32    max_block_height (u256, only last 8 bytes used as u64)
@@ -50,7 +54,7 @@
 //! ```
 //!
 //! TransferSpec layout:
-//! ```
+//! ```text
 //! offset  size  field
 //! 0       4     magic (0xca85def7)
 //! 4       4     version
@@ -359,3 +363,171 @@ impl<'a> BurnData<'a> {
             .ok_or_else(|| error!(GatewayWalletError::MalformedBurnData))
     }
 }
+
+/// An owned TransferSpec
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferSpec {
+    pub version: u32,
+    pub source_domain: u32,
+    pub destination_domain: u32,
+    pub source_contract: Pubkey,
+    pub destination_contract: Pubkey,
+    pub source_token: Pubkey,
+    pub destination_token: Pubkey,
+    pub source_depositor: Pubkey,
+    pub destination_recipient: Pubkey,
+    pub source_signer: Pubkey,
+    pub destination_caller: Pubkey,
+    pub value: u64,
+    pub salt: [u8; 32],
+    pub hook_data: Vec<u8>,
+}
+
+/// An owned BurnIntent
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BurnIntent {
+    pub max_block_height: u64,
+    pub max_fee: u64,
+    pub transfer_spec: TransferSpec,
+}
+
+/// An owned BurnData
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BurnDataStruct {
+    pub fee: u64,
+    pub user_signature: [u8; 64],
+    pub burn_intent: BurnIntent,
+}
+
+impl TransferSpec {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(
+            BurnData::TS_HOOK_DATA_OFFSET - BurnData::TRANSFER_SPEC_OFFSET + self.hook_data.len(),
+        );
+
+        buffer.extend_from_slice(&BurnData::TRANSFER_SPEC_MAGIC.to_be_bytes());
+        buffer.extend_from_slice(&self.version.to_be_bytes());
+        buffer.extend_from_slice(&self.source_domain.to_be_bytes());
+        buffer.extend_from_slice(&self.destination_domain.to_be_bytes());
+        buffer.extend_from_slice(self.source_contract.as_ref());
+        buffer.extend_from_slice(self.destination_contract.as_ref());
+        buffer.extend_from_slice(self.source_token.as_ref());
+        buffer.extend_from_slice(self.destination_token.as_ref());
+        buffer.extend_from_slice(self.source_depositor.as_ref());
+        buffer.extend_from_slice(self.destination_recipient.as_ref());
+        buffer.extend_from_slice(self.source_signer.as_ref());
+        buffer.extend_from_slice(self.destination_caller.as_ref());
+        extend_with_u256(&mut buffer, self.value);
+        buffer.extend_from_slice(&self.salt);
+        buffer.extend_from_slice(&(self.hook_data.len() as u32).to_be_bytes());
+        buffer.extend_from_slice(&self.hook_data);
+
+        buffer
+    }
+
+    /// Decodes an encoded TransferSpec with the `BurnData` parser
+    pub fn decode(encoded_transfer_spec: &[u8]) -> Result<Self> {
+        let burn_intent = BurnIntent::encode_with_transfer_spec(0, 0, encoded_transfer_spec);
+        Ok(BurnIntent::decode(&burn_intent)?.transfer_spec)
+    }
+
+    /// Returns the keccak256 hash of the encoded TransferSpec
+    pub fn hash(&self) -> [u8; 32] {
+        keccak::hash(&self.encode()).to_bytes()
+    }
+
+    fn from_burn_data(burn_data: &BurnData) -> Result<Self> {
+        Ok(Self {
+            version: burn_data.version()?,
+            source_domain: burn_data.source_domain()?,
+            destination_domain: burn_data.destination_domain()?,
+            source_contract: burn_data.source_contract()?,
+            destination_contract: burn_data.destination_contract()?,
+            source_token: burn_data.source_token()?,
+            destination_token: burn_data.destination_token()?,
+            source_depositor: burn_data.source_depositor()?,
+            destination_recipient: burn_data.destination_recipient()?,
+            source_signer: burn_data.source_signer()?,
+            destination_caller: burn_data.destination_caller()?,
+            value: burn_data.value()?,
+            salt: burn_data.salt()?,
+            hook_data: burn_data.hook_data()?.to_vec(),
+        })
+    }
+}
+
+impl BurnIntent {
+    pub fn encode(&self) -> Vec<u8> {
+        Self::encode_with_transfer_spec(
+            self.max_block_height,
+            self.max_fee,
+            &self.transfer_spec.encode(),
+        )
+    }
+
+    /// Decodes an encoded BurnIntent with the `BurnData` parser
+    pub fn decode(encoded_burn_intent: &[u8]) -> Result<Self> {
+        let mut burn_data = vec![0; BurnData::BURN_INTENT_MESSAGE_PREFIX_OFFSET];
+        burn_data.extend_from_slice(&BurnData::BURN_INTENT_MESSAGE_PREFIX);
+        burn_data.extend_from_slice(encoded_burn_intent);
+        Ok(BurnDataStruct::decode(&burn_data)?.burn_intent)
+    }
+
+    /// Returns the message signed by the user: the burn intent message prefix followed by the
+    /// encoded BurnIntent
+    pub fn message(&self) -> Vec<u8> {
+        let mut message = BurnData::BURN_INTENT_MESSAGE_PREFIX.to_vec();
+        message.extend_from_slice(&self.encode());
+        message
+    }
+
+    fn encode_with_transfer_spec(
+        max_block_height: u64,
+        max_fee: u64,
+        encoded_transfer_spec: &[u8],
+    ) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(
+            BurnData::TRANSFER_SPEC_OFFSET - BurnData::MAGIC_OFFSET + encoded_transfer_spec.len(),
+        );
+
+        buffer.extend_from_slice(&BurnData::BURN_INTENT_MAGIC.to_be_bytes());
+        extend_with_u256(&mut buffer, max_block_height);
+        extend_with_u256(&mut buffer, max_fee);
+        buffer.extend_from_slice(&(encoded_transfer_spec.len() as u32).to_be_bytes());
+        buffer.extend_from_slice(encoded_transfer_spec);
+
+        buffer
+    }
+}
+
+impl BurnDataStruct {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+
+        buffer.extend_from_slice(&self.fee.to_be_bytes());
+        buffer.extend_from_slice(&self.user_signature);
+        buffer.extend_from_slice(&self.burn_intent.message());
+
+        buffer
+    }
+
+    /// Decodes an encoded BurnData with the `BurnData` parser
+    pub fn decode(encoded_burn_data: &[u8]) -> Result<Self> {
+        let burn_data = BurnData::new(encoded_burn_data)?;
+        Ok(Self {
+            fee: burn_data.fee()?,
+            user_signature: burn_data.user_signature()?,
+            burn_intent: BurnIntent {
+                max_block_height: burn_data.max_block_height()?,
+                max_fee: burn_data.max_fee()?,
+                transfer_spec: TransferSpec::from_burn_data(&burn_data)?,
+            },
+        })
+    }
+}
+
+/// Appends a u64 as an EVM u256, left-padded with zero bytes
+fn extend_with_u256(buffer: &mut Vec<u8>, value: u64) {
+    buffer.extend_from_slice(&[0; BurnData::U256_TO_U64_OFFSET]);
+    buffer.extend_from_slice(&value.to_be_bytes());
+}
@@ -18,8 +18,67 @@
 
 //! Ed25519 instruction extraction and verification
 
-use crate::error::GatewayWalletError;
+use crate::{burn_data::BurnData, error::GatewayWalletError};
 use anchor_lang::prelude::*;
+use gateway_shared::DISCRIMINATOR_SIZE;
+
+/// The offset of the start of the burn data relative to the start of the gateway_burn instruction data
+/// This includes the discriminator and a 4-byte size field for the size of the encoded_burn_data
+pub const GATEWAY_BURN_DATA_OFFSET: u16 = (DISCRIMINATOR_SIZE + 4) as u16;
+
+/// Required values for the Ed25519 instruction
+pub const ED25519_NUM_SIGNATURES: u8 = 1;
+pub const ED25519_PADDING: u8 = 0;
+
+/// Returns the only Ed25519 instruction data accepted for a gateway_burn instruction
+///
+/// The signature, public key, and message are all read from the gateway_burn instruction at
+/// `burn_instruction_index`: the user signature within the burn data, the burn intent source
+/// signer, and the burn intent message of `burn_intent_message_length` bytes.
+pub fn gateway_burn_ed25519_instruction_data(
+    burn_instruction_index: u16,
+    burn_intent_message_length: u16,
+) -> [u8; Ed25519InstructionData::HEADER_SIZE] {
+    let signature_offset =
+        GATEWAY_BURN_DATA_OFFSET + BurnData::BURN_DATA_USER_SIGNATURE_OFFSET as u16;
+    let source_signer_offset = GATEWAY_BURN_DATA_OFFSET + BurnData::TS_SOURCE_SIGNER_OFFSET as u16;
+    let burn_intent_message_offset =
+        GATEWAY_BURN_DATA_OFFSET + BurnData::BURN_INTENT_MESSAGE_PREFIX_OFFSET as u16;
+
+    let mut data = [0; Ed25519InstructionData::HEADER_SIZE];
+    data[Ed25519InstructionData::NUM_SIGNATURES_OFFSET] = ED25519_NUM_SIGNATURES;
+    data[Ed25519InstructionData::PADDING_OFFSET] = ED25519_PADDING;
+    for (offset, value) in [
+        (Ed25519InstructionData::SIGNATURE_OFFSET, signature_offset),
+        (
+            Ed25519InstructionData::SIGNATURE_INSTRUCTION_INDEX_OFFSET,
+            burn_instruction_index,
+        ),
+        (
+            Ed25519InstructionData::PUBLIC_KEY_OFFSET,
+            source_signer_offset,
+        ),
+        (
+            Ed25519InstructionData::PUBLIC_KEY_INSTRUCTION_INDEX_OFFSET,
+            burn_instruction_index,
+        ),
+        (
+            Ed25519InstructionData::MESSAGE_DATA_OFFSET,
+            burn_intent_message_offset,
+        ),
+        (
+            Ed25519InstructionData::MESSAGE_DATA_SIZE_OFFSET,
+            burn_intent_message_length,
+        ),
+        (
+            Ed25519InstructionData::MESSAGE_INSTRUCTION_INDEX_OFFSET,
+            burn_instruction_index,
+        ),
+    ] {
+        data[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+    }
+    data
+}
 
 /// Ed25519 instruction header parser
 ///
@@ -61,7 +120,7 @@ impl<'a> Ed25519InstructionData<'a> {
     const MESSAGE_INSTRUCTION_INDEX_OFFSET: usize = 14;
 
     // Total header size
-    const HEADER_SIZE: usize = 16;
+    pub const HEADER_SIZE: usize = 16;
 
     pub fn new(data: &'a [u8]) -> Result<Self> {
         let instruction = Self { data };
@@ -17,6 +17,7 @@
  */
 
 use anchor_lang::prelude::*;
+use gateway_shared::admin::AdminError;
 
 #[error_code]
 pub enum GatewayWalletError {
@@ -137,4 +138,103 @@ pub enum GatewayWalletError {
     InvalidTransferSpecHashAccount,
     #[msg("Transfer spec hash already used")]
     TransferSpecHashAlreadyUsed,
+
+    // Denylist Batches
+    #[msg("Denylist batch is empty")]
+    EmptyDenylistBatch,
+    #[msg("Invalid denylist account")]
+    InvalidDenylistAccount,
+
+    // Deposit Freeze
+    #[msg("Deposit is frozen")]
+    DepositFrozen,
+
+    // Fee Splits
+    #[msg("Fee split limit exceeded")]
+    FeeSplitLimitExceeded,
+    #[msg("Invalid fee split")]
+    InvalidFeeSplit,
+    #[msg("Fee split basis points exceed the maximum")]
+    FeeSplitBpsExceedsMax,
+    #[msg("Invalid fee split token account")]
+    InvalidFeeSplitTokenAccount,
+
+    // Fee Schedules
+    #[msg("Invalid fee schedule")]
+    InvalidFeeSchedule,
+    #[msg("Invalid fee schedule account")]
+    InvalidFeeScheduleAccount,
+    #[msg("Burn fee is below the minimum for the destination domain")]
+    BurnFeeBelowMinimum,
+
+    // Destination Domains
+    #[msg("Invalid destination contract")]
+    InvalidDestinationContract,
+    #[msg("Invalid destination domain account")]
+    InvalidDestinationDomainAccount,
+    #[msg("Destination domain is not enabled")]
+    DestinationDomainNotEnabled,
+    #[msg("Destination contract does not match the destination domain")]
+    DestinationContractMismatch,
+
+    // Outflow Limits
+    #[msg("Outflow limit exceeded")]
+    OutflowLimitExceeded,
+
+    // Used Transfer Spec Hashes
+    #[msg("Transfer spec hash has not expired")]
+    TransferSpecHashNotExpired,
+    #[msg("Invalid rent payer")]
+    InvalidRentPayer,
+
+    // Nonce Buckets
+    #[msg("Invalid nonce bucket account")]
+    InvalidNonceBucketAccount,
+    #[msg("Invalid replay store transition")]
+    InvalidReplayStoreTransition,
+
+    // Custody Reconciliation
+    #[msg("Custody holds no surplus over the total deposits")]
+    NoCustodySurplus,
+    #[msg("Total deposits overflow")]
+    TotalDepositsOverflow,
+    #[msg("Total deposits underflow")]
+    TotalDepositsUnderflow,
+    #[msg("Token totals must be initialized with the audited total deposits")]
+    TokenTotalsNotInitialized,
+
+    // Balance Transfers
+    #[msg("Invalid transfer amount")]
+    InvalidTransferAmount,
+    #[msg("Cannot transfer a balance to the same depositor")]
+    CannotTransferToSelf,
+
+    // Used Transfer Spec Hashes
+    #[msg("Invalid transfer spec")]
+    InvalidTransferSpec,
+    #[msg("Transfer is not recorded by nonce")]
+    TransferNotRecordedByNonce,
+
+    // Replay Store Migration
+    #[msg("Transfers recorded by hash before the migration may not have expired yet")]
+    ReplayStoreMigrationIncomplete,
+    #[msg("Burn intent is valid for too long")]
+    BurnIntentValidityTooLong,
+
+    // Audited Token Totals
+    #[msg("Total deposits are below the custody balance")]
+    TotalDepositsBelowCustody,
+    #[msg("Custody surplus cannot be verified against audited total deposits")]
+    CustodySurplusUnverifiable,
+}
+
+impl From<AdminError> for GatewayWalletError {
+    fn from(error: AdminError) -> Self {
+        match error {
+            AdminError::InvalidPauser => GatewayWalletError::InvalidPauser,
+            AdminError::InvalidTokenController => GatewayWalletError::InvalidTokenController,
+            AdminError::MaxTokensSupported => GatewayWalletError::MaxTokensSupported,
+            AdminError::TokenNotSupported => GatewayWalletError::TokenNotSupported,
+        }
+    }
 }
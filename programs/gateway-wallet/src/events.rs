@@ -16,48 +16,65 @@
  * limitations under the License.
  */
 
+use crate::state::{FeeSplit, ReplayStore};
 use anchor_lang::prelude::*;
 
 #[event(discriminator = [20, 0])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GatewayWalletInitialized {}
 
 #[event(discriminator = [20, 1])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OwnershipTransferStarted {
     pub previous_owner: Pubkey,
     pub new_owner: Pubkey,
 }
 
 #[event(discriminator = [20, 2])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OwnershipTransferred {
     pub previous_owner: Pubkey,
     pub new_owner: Pubkey,
 }
 
 #[event(discriminator = [20, 3])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PauserChanged {
     pub old_pauser: Pubkey,
     pub new_pauser: Pubkey,
 }
 
 #[event(discriminator = [20, 4])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DenylisterChanged {
     pub old_denylister: Pubkey,
     pub new_denylister: Pubkey,
 }
 
 #[event(discriminator = [20, 5])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenControllerUpdated {
     pub previous_token_controller: Pubkey,
     pub new_token_controller: Pubkey,
 }
 
 #[event(discriminator = [20, 6])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenSupported {
     pub token: Pubkey,
     pub custody_token_account: Pubkey,
 }
 
 #[event(discriminator = [20, 7])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Deposited {
     pub token: Pubkey,
     pub depositor: Pubkey,
@@ -66,6 +83,8 @@ pub struct Deposited {
 }
 
 #[event(discriminator = [20, 8])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DelegateAdded {
     pub token: Pubkey,
     pub depositor: Pubkey,
@@ -73,6 +92,8 @@ pub struct DelegateAdded {
 }
 
 #[event(discriminator = [20, 9])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DelegateRemoved {
     pub token: Pubkey,
     pub depositor: Pubkey,
@@ -80,16 +101,22 @@ pub struct DelegateRemoved {
 }
 
 #[event(discriminator = [20, 10])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Denylisted {
     pub addr: Pubkey,
 }
 
 #[event(discriminator = [20, 11])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnDenylisted {
     pub addr: Pubkey,
 }
 
 #[event(discriminator = [20, 12])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawalInitiated {
     pub token: Pubkey,
     pub depositor: Pubkey,
@@ -100,16 +127,22 @@ pub struct WithdrawalInitiated {
 }
 
 #[event(discriminator = [20, 13])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BurnSignerAdded {
     pub signer: Pubkey,
 }
 
 #[event(discriminator = [20, 14])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BurnSignerRemoved {
     pub signer: Pubkey,
 }
 
 #[event(discriminator = [20, 15])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawalCompleted {
     pub token: Pubkey,
     pub depositor: Pubkey,
@@ -117,28 +150,38 @@ pub struct WithdrawalCompleted {
 }
 
 #[event(discriminator = [20, 16])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawalDelayChanged {
     pub old_delay: u64,
     pub new_delay: u64,
 }
 
 #[event(discriminator = [20, 17])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeeRecipientChanged {
     pub old_fee_recipient: Pubkey,
     pub new_fee_recipient: Pubkey,
 }
 
 #[event(discriminator = [20, 18])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Paused {
     pub account: Pubkey,
 }
 
 #[event(discriminator = [20, 19])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Unpaused {
     pub account: Pubkey,
 }
 
 #[event(discriminator = [20, 20])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GatewayBurned {
     pub token: Pubkey,
     pub depositor: Pubkey,
@@ -150,9 +193,12 @@ pub struct GatewayBurned {
     pub fee: u64,
     pub from_available: u64,
     pub from_withdrawing: u64,
+    pub fee_shares: Vec<FeeShare>,
 }
 
 #[event(discriminator = [20, 21])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsufficientBalance {
     pub token: Pubkey,
     pub depositor: Pubkey,
@@ -160,3 +206,154 @@ pub struct InsufficientBalance {
     pub available_balance: u64,
     pub withdrawing_balance: u64,
 }
+
+#[event(discriminator = [20, 22])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositFrozen {
+    pub token: Pubkey,
+    pub depositor: Pubkey,
+}
+
+#[event(discriminator = [20, 23])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositUnfrozen {
+    pub token: Pubkey,
+    pub depositor: Pubkey,
+}
+
+#[event(discriminator = [20, 24])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeSplitsChanged {
+    pub old_fee_splits: Vec<FeeSplit>,
+    pub new_fee_splits: Vec<FeeSplit>,
+}
+
+#[event(discriminator = [20, 25])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeScheduleSet {
+    pub token: Pubkey,
+    pub destination_domain: u32,
+    pub min_fee: u64,
+    pub fee_bps: u16,
+}
+
+#[event(discriminator = [20, 26])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DestinationDomainEnabled {
+    pub domain: u32,
+    pub destination_contract: Pubkey,
+}
+
+#[event(discriminator = [20, 27])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DestinationDomainDisabled {
+    pub domain: u32,
+}
+
+#[event(discriminator = [20, 28])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutflowLimitSet {
+    pub token: Pubkey,
+    pub window_slots: u64,
+    pub max_outflow: u64,
+}
+
+#[event(discriminator = [20, 29])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutflowOverrideSet {
+    pub token: Pubkey,
+    pub override_allowance: u64,
+}
+
+#[event(discriminator = [20, 30])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UsedTransferSpecHashClosed {
+    pub transfer_spec_hash: [u8; 32],
+    pub rent_payer: Pubkey,
+}
+
+#[event(discriminator = [20, 31])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayStoreSet {
+    pub previous_replay_store: ReplayStore,
+    pub new_replay_store: ReplayStore,
+}
+
+#[event(discriminator = [20, 32])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransferReceiptsSet {
+    pub enabled: bool,
+}
+
+#[event(discriminator = [20, 33])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustodyReconciled {
+    pub token: Pubkey,
+    pub custody_balance: u64,
+    pub total_deposits: u64,
+    pub surplus: u64,
+    pub deficit: u64,
+}
+
+#[event(discriminator = [20, 34])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustodySurplusSwept {
+    pub token: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[event(discriminator = [20, 35])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BalanceTransferred {
+    pub token: Pubkey,
+    pub depositor: Pubkey,
+    pub recipient: Pubkey,
+    pub signer: Pubkey,
+    pub value: u64,
+}
+
+#[event(discriminator = [20, 36])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenTotalsInitialized {
+    pub token: Pubkey,
+    pub total_deposits: u64,
+}
+
+#[event(discriminator = [20, 37])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GatewayWalletMigrated {
+    pub previous_space: u64,
+    pub new_space: u64,
+}
+
+#[event(discriminator = [20, 38])]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DestinationDomainEnforcementSet {
+    pub enforced: bool,
+}
+
+/// The amount of a burn fee paid to a single recipient
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeShare {
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
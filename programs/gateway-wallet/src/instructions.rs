@@ -22,20 +22,43 @@ pub mod accept_ownership;
 pub mod add_burn_signer;
 pub mod add_delegate;
 pub mod add_token;
+pub mod batch_denylist;
+pub mod batch_undenylist;
+pub mod close_used_transfer_spec_hash;
 pub mod denylist;
 pub mod deposit;
 pub mod deposit_for;
+pub mod disable_destination_domain;
+pub mod enable_destination_domain;
+pub mod freeze_deposit;
 pub mod gateway_burn;
+pub mod get_balance;
+pub mod get_config;
+pub mod get_withdrawable;
 pub mod initialize;
+pub mod initialize_token_totals;
 pub mod initiate_withdrawal;
+pub mod is_authorized_for_balance;
+pub mod migrate_gateway_wallet;
 pub mod pause;
+pub mod reconcile;
 pub mod remove_burn_signer;
 pub mod remove_delegate;
+pub mod set_destination_domain_enforcement;
+pub mod set_fee_schedule;
+pub mod set_outflow_limit;
+pub mod set_outflow_override;
+pub mod set_replay_store;
+pub mod set_transfer_receipts;
+pub mod sweep_custody_surplus;
+pub mod transfer_balance;
 pub mod transfer_ownership;
 pub mod undenylist;
+pub mod unfreeze_deposit;
 pub mod unpause;
 pub mod update_denylister;
 pub mod update_fee_recipient;
+pub mod update_fee_splits;
 pub mod update_pauser;
 pub mod update_token_controller;
 pub mod update_withdrawal_delay;
@@ -45,20 +68,43 @@ pub use accept_ownership::*;
 pub use add_burn_signer::*;
 pub use add_delegate::*;
 pub use add_token::*;
+pub use batch_denylist::*;
+pub use batch_undenylist::*;
+pub use close_used_transfer_spec_hash::*;
 pub use denylist::*;
 pub use deposit::*;
 pub use deposit_for::*;
+pub use disable_destination_domain::*;
+pub use enable_destination_domain::*;
+pub use freeze_deposit::*;
 pub use gateway_burn::*;
+pub use get_balance::*;
+pub use get_config::*;
+pub use get_withdrawable::*;
 pub use initialize::*;
+pub use initialize_token_totals::*;
 pub use initiate_withdrawal::*;
+pub use is_authorized_for_balance::*;
+pub use migrate_gateway_wallet::*;
 pub use pause::*;
+pub use reconcile::*;
 pub use remove_burn_signer::*;
 pub use remove_delegate::*;
+pub use set_destination_domain_enforcement::*;
+pub use set_fee_schedule::*;
+pub use set_outflow_limit::*;
+pub use set_outflow_override::*;
+pub use set_replay_store::*;
+pub use set_transfer_receipts::*;
+pub use sweep_custody_surplus::*;
+pub use transfer_balance::*;
 pub use transfer_ownership::*;
 pub use undenylist::*;
+pub use unfreeze_deposit::*;
 pub use unpause::*;
 pub use update_denylister::*;
 pub use update_fee_recipient::*;
+pub use update_fee_splits::*;
 pub use update_pauser::*;
 pub use update_token_controller::*;
 pub use update_withdrawal_delay::*;
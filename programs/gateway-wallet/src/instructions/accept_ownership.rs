@@ -24,6 +24,7 @@ use {
         state::GatewayWallet,
     },
     anchor_lang::prelude::*,
+    gateway_shared::admin::Ownable,
 };
 
 #[event_cpi]
@@ -47,16 +48,11 @@ pub fn accept_ownership(
     ctx: Context<AcceptOwnershipContext>,
     _params: &AcceptOwnershipParams,
 ) -> Result<()> {
-    let state = ctx.accounts.gateway_wallet.as_mut();
-
-    let previous_owner = state.owner;
-
-    state.owner = state.pending_owner;
-    state.pending_owner = Pubkey::default();
+    let previous_owner = ctx.accounts.gateway_wallet.accept_ownership();
 
     emit_cpi!(OwnershipTransferred {
         previous_owner,
-        new_owner: state.owner,
+        new_owner: ctx.accounts.pending_owner.key(),
     });
 
     Ok(())
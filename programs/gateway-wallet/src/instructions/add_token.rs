@@ -22,11 +22,13 @@ use {
     crate::{
         error::GatewayWalletError,
         events::TokenSupported,
-        seeds::{GATEWAY_WALLET_CUSTODY_SEED, GATEWAY_WALLET_SEED},
-        state::GatewayWallet,
+        seeds::{GATEWAY_WALLET_CUSTODY_SEED, GATEWAY_WALLET_SEED, TOKEN_TOTALS_SEED},
+        state::{GatewayWallet, TokenTotals},
+        utils,
     },
     anchor_lang::prelude::*,
     anchor_spl::token::{Mint, Token, TokenAccount},
+    gateway_shared::admin::TokenRegistry,
 };
 
 #[event_cpi]
@@ -60,18 +62,45 @@ pub struct AddTokenContext<'info> {
     )]
     pub custody_token_account: Account<'info, TokenAccount>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = utils::DISCRIMINATOR_SIZE + TokenTotals::INIT_SPACE,
+        seeds = [TOKEN_TOTALS_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_totals: Account<'info, TokenTotals>,
+
     pub token_program: Program<'info, Token>,
 
     pub system_program: Program<'info, System>,
 }
 
 pub fn add_token(ctx: Context<AddTokenContext>) -> Result<()> {
+    let is_new_token = !ctx
+        .accounts
+        .gateway_wallet
+        .is_token_supported(ctx.accounts.token_mint.key());
+
     // Add the token and custody bump to the supported list
     ctx.accounts.gateway_wallet.add_token(
         ctx.accounts.token_mint.key(),
         ctx.bumps.custody_token_account,
     )?;
 
+    // Start the running deposit totals of a newly supported token, which has no deposits yet. The
+    // custody balance of a token supported before the totals existed may include tokens that
+    // were never deposited, so its totals are initialized from the audited total deposits with
+    // `initialize_token_totals` instead.
+    let token_totals = &mut ctx.accounts.token_totals;
+    if token_totals.token == Pubkey::default() {
+        require!(is_new_token, GatewayWalletError::TokenTotalsNotInitialized);
+        token_totals.bump = ctx.bumps.token_totals;
+        token_totals.token = ctx.accounts.token_mint.key();
+        token_totals.total_deposits = 0;
+        token_totals.initialized_from_audit = false;
+    }
+
     // Emit TokenSupported event
     emit_cpi!(TokenSupported {
         token: ctx.accounts.token_mint.key(),
@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Batch undenylist instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::UnDenylisted,
+        seeds::{DENYLIST_SEED, GATEWAY_WALLET_SEED},
+        state::{Denylist, GatewayWallet},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BatchUndenylistContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(address = gateway_wallet.denylister @ GatewayWalletError::InvalidAuthority)]
+    pub denylister: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+    // Remaining accounts, one for each address in `params.accounts` and in the same order:
+    //   0. `[writable]` The denylist PDA (seeds = [DENYLIST_SEED, account])
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchUndenylistParams {
+    pub accounts: Vec<Pubkey>,
+}
+
+pub fn batch_undenylist<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchUndenylistContext<'info>>,
+    params: &BatchUndenylistParams,
+) -> Result<()> {
+    require!(
+        !params.accounts.is_empty(),
+        GatewayWalletError::EmptyDenylistBatch
+    );
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        params.accounts.len(),
+        GatewayWalletError::RemainingAccountsLengthMismatch
+    );
+
+    for (account, denylist_account) in params.accounts.iter().zip(ctx.remaining_accounts) {
+        let (expected_pda, _) =
+            Pubkey::find_program_address(&[DENYLIST_SEED, account.as_ref()], ctx.program_id);
+        require_keys_eq!(
+            expected_pda,
+            denylist_account.key(),
+            GatewayWalletError::InvalidDenylistAccount
+        );
+
+        // As in `undenylist`, the address must currently be denylisted
+        let denylist = Account::<Denylist>::try_from(denylist_account)?;
+        denylist.close(ctx.accounts.payer.to_account_info())?;
+
+        emit_cpi!(UnDenylisted { addr: *account });
+    }
+
+    Ok(())
+}
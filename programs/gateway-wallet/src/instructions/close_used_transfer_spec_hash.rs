@@ -0,0 +1,186 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! CloseUsedTransferSpecHash instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::UsedTransferSpecHashClosed,
+        seeds::GATEWAY_WALLET_SEED,
+        state::{GatewayWallet, NonceBucket, ReplayStore, UsedTransferSpecHash},
+        utils,
+    },
+    anchor_lang::{prelude::*, solana_program::keccak::hash},
+    gateway_shared::{
+        close_used_transfer_spec_hash_account,
+        replay::{mark_nonce_used, ReplayKey},
+        tombstone_used_transfer_spec_hash_account, USED_TRANSFER_SPEC_HASH_SEED_PREFIX,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: CloseUsedTransferSpecHashParams)]
+pub struct CloseUsedTransferSpecHashContext<'info> {
+    /// CHECK: Must match the rent payer recorded in the used transfer spec hash account
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// CHECK: The used transfer spec hash PDA, deserialized in the handler because it may be
+    /// shrunk to a tombstone rather than closed
+    #[account(
+        mut,
+        seeds = [USED_TRANSFER_SPEC_HASH_SEED_PREFIX, params.transfer_spec_hash.as_ref()],
+        bump
+    )]
+    pub used_transfer_spec_hash: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    /// Pays for the nonce bucket when closing the account fully
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// CHECK: The nonce bucket PDA of the transfer spec's nonce, verified in the handler. Only
+    /// needed when closing the account fully.
+    #[account(mut)]
+    pub nonce_bucket: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CloseUsedTransferSpecHashParams {
+    pub transfer_spec_hash: [u8; 32],
+    /// The encoded transfer spec of the hash, to close the account fully by recording the
+    /// transfer in the nonce bucket of its explicit nonce instead
+    pub transfer_spec: Option<Vec<u8>>,
+}
+
+/// Refunds the rent of a used transfer spec hash account to the rent payer once its expiry slot
+/// has passed.
+///
+/// A new burn intent with a later expiry may sign the same transfer spec hash, so the account
+/// can't simply be closed:
+/// - By default, it is shrunk to a tombstone that keeps the hash used, refunding the rent it no
+///   longer needs.
+/// - Once burns record explicit nonces in nonce buckets, a transfer spec with an explicit nonce
+///   can be passed instead. Its nonce is marked used, which rejects any replay of the transfer,
+///   and the account is closed with all of its rent refunded.
+///
+/// Transfer specs with a random salt are only ever recorded by hash, so they always leave a
+/// tombstone behind.
+pub fn close_used_transfer_spec_hash(
+    ctx: Context<CloseUsedTransferSpecHashContext>,
+    params: &CloseUsedTransferSpecHashParams,
+) -> Result<()> {
+    // A tombstone no longer deserializes, so it can't be closed again
+    let used_transfer_spec_hash = utils::load_if_initialized::<UsedTransferSpecHash>(
+        &ctx.accounts.used_transfer_spec_hash,
+        ctx.program_id,
+    )?
+    .ok_or(ErrorCode::AccountNotInitialized)?;
+    require_keys_eq!(
+        used_transfer_spec_hash.rent_payer,
+        ctx.accounts.rent_payer.key(),
+        GatewayWalletError::InvalidRentPayer
+    );
+    require_gt!(
+        Clock::get()?.slot,
+        used_transfer_spec_hash.expiry_slot,
+        GatewayWalletError::TransferSpecHashNotExpired
+    );
+
+    match &params.transfer_spec {
+        Some(transfer_spec) => {
+            record_nonce(&ctx, params.transfer_spec_hash, transfer_spec)?;
+            close_used_transfer_spec_hash_account(
+                &ctx.accounts.used_transfer_spec_hash,
+                &ctx.accounts.rent_payer,
+            )?;
+        }
+        None => tombstone_used_transfer_spec_hash_account(
+            &ctx.accounts.used_transfer_spec_hash,
+            &ctx.accounts.rent_payer,
+        )?,
+    }
+
+    emit_cpi!(UsedTransferSpecHashClosed {
+        transfer_spec_hash: params.transfer_spec_hash,
+        rent_payer: used_transfer_spec_hash.rent_payer,
+    });
+
+    Ok(())
+}
+
+/// Marks the explicit nonce of a transfer spec used, so that the transfer stays protected from
+/// replay without its used transfer spec hash account
+fn record_nonce(
+    ctx: &Context<CloseUsedTransferSpecHashContext>,
+    transfer_spec_hash: [u8; 32],
+    transfer_spec: &[u8],
+) -> Result<()> {
+    require!(
+        hash(transfer_spec).0 == transfer_spec_hash,
+        GatewayWalletError::InvalidTransferSpec
+    );
+
+    // While burns still record every transfer by hash, a replay would not check the nonce
+    require!(
+        ctx.accounts.gateway_wallet.replay_store != ReplayStore::HashAccounts,
+        GatewayWalletError::TransferNotRecordedByNonce
+    );
+    let replay_key = ReplayKey::from_transfer_spec(transfer_spec_hash, transfer_spec)
+        .ok_or(GatewayWalletError::InvalidTransferSpec)?;
+    let ReplayKey::Nonce { namespace, nonce } = replay_key else {
+        return err!(GatewayWalletError::TransferNotRecordedByNonce);
+    };
+
+    let (Some(payer), Some(nonce_bucket), Some(system_program)) = (
+        &ctx.accounts.payer,
+        &ctx.accounts.nonce_bucket,
+        &ctx.accounts.system_program,
+    ) else {
+        return err!(ErrorCode::AccountNotEnoughKeys);
+    };
+    let (expected_pda, bump) = replay_key.find_address(ctx.program_id);
+    require_keys_eq!(
+        expected_pda,
+        nonce_bucket.key(),
+        GatewayWalletError::InvalidNonceBucketAccount
+    );
+
+    // The nonce may already be marked by another transfer spec, which is rejected all the same
+    mark_nonce_used(
+        nonce_bucket,
+        &namespace,
+        nonce,
+        bump,
+        payer,
+        system_program,
+        ctx.program_id,
+        NonceBucket::DISCRIMINATOR,
+    )?;
+
+    Ok(())
+}
@@ -24,12 +24,14 @@ use {
         events::Deposited,
         seeds::{
             DENYLIST_SEED, GATEWAY_DEPOSIT_SEED, GATEWAY_WALLET_CUSTODY_SEED, GATEWAY_WALLET_SEED,
+            TOKEN_TOTALS_SEED,
         },
-        state::{GatewayDeposit, GatewayWallet},
+        state::GatewayWallet,
         utils,
     },
     anchor_lang::prelude::*,
     anchor_spl::token::{Token, TokenAccount},
+    gateway_shared::admin::TokenRegistry,
 };
 
 #[event_cpi]
@@ -63,15 +65,24 @@ pub struct DepositForContext<'info> {
     )]
     pub custody_token_account: Account<'info, TokenAccount>,
 
-    // The deposit account for the specified depositor
+    /// CHECK: Deposit PDA of the specified depositor for the token. Created on the depositor's
+    /// first deposit of the token, and grown if it was created before fields were appended to its
+    /// layout.
     #[account(
-        init_if_needed,
-        payer = payer,
-        space = utils::DISCRIMINATOR_SIZE + GatewayDeposit::INIT_SPACE,
+        mut,
         seeds = [GATEWAY_DEPOSIT_SEED, custody_token_account.mint.key().as_ref(), depositor.as_ref()],
         bump
     )]
-    pub deposit: Account<'info, GatewayDeposit>,
+    pub deposit: UncheckedAccount<'info>,
+
+    /// CHECK: Deposit totals PDA for the token. The totals of a token supported before they
+    /// existed are not tracked until they are initialized.
+    #[account(
+        mut,
+        seeds = [TOKEN_TOTALS_SEED, custody_token_account.mint.as_ref()],
+        bump
+    )]
+    pub token_totals: UncheckedAccount<'info>,
 
     /// CHECK: Sender denylist PDA. Account is denylisted if it exists at the expected PDA.
     #[account(
@@ -111,19 +122,30 @@ pub fn deposit_for(ctx: Context<DepositForContext>, amount: u64, depositor: Pubk
         GatewayWalletError::AccountDenylisted
     );
 
-    ctx.accounts.deposit.initialize_if_needed(
+    let mut deposit = utils::load_or_create_deposit(
+        &ctx.accounts.deposit,
         ctx.bumps.deposit,
-        depositor,
         ctx.accounts.custody_token_account.mint,
-    );
+        depositor,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.program_id,
+    )?;
 
-    ctx.accounts.deposit.deposit(
+    deposit.deposit(
         &ctx.accounts.token_program,
         &ctx.accounts.owner_token_account,
         &ctx.accounts.custody_token_account,
         &ctx.accounts.owner,
         amount,
     )?;
+    utils::save_deposit(
+        &ctx.accounts.deposit,
+        &deposit,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+    utils::add_token_deposits(&ctx.accounts.token_totals, amount, ctx.program_id)?;
 
     emit_cpi!(Deposited {
         token: ctx.accounts.custody_token_account.mint,
@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! DisableDestinationDomain instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::DestinationDomainDisabled,
+        seeds::{DESTINATION_DOMAIN_SEED, GATEWAY_WALLET_SEED},
+        state::{DestinationDomain, GatewayWallet},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DisableDestinationDomainContext<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        has_one = owner @ GatewayWalletError::InvalidAuthority
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    #[account(
+        mut,
+        seeds = [DESTINATION_DOMAIN_SEED, &destination_domain.domain.to_be_bytes()],
+        bump = destination_domain.bump
+    )]
+    pub destination_domain: Account<'info, DestinationDomain>,
+}
+
+pub fn disable_destination_domain(ctx: Context<DisableDestinationDomainContext>) -> Result<()> {
+    let destination_domain = &mut ctx.accounts.destination_domain;
+    destination_domain.enabled = false;
+
+    emit_cpi!(DestinationDomainDisabled {
+        domain: destination_domain.domain,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! EnableDestinationDomain instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::DestinationDomainEnabled,
+        seeds::{DESTINATION_DOMAIN_SEED, GATEWAY_WALLET_SEED},
+        state::{DestinationDomain, GatewayWallet},
+        utils,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: EnableDestinationDomainParams)]
+pub struct EnableDestinationDomainContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        has_one = owner @ GatewayWalletError::InvalidAuthority
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = utils::DISCRIMINATOR_SIZE + DestinationDomain::INIT_SPACE,
+        seeds = [DESTINATION_DOMAIN_SEED, &params.domain.to_be_bytes()],
+        bump
+    )]
+    pub destination_domain: Account<'info, DestinationDomain>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct EnableDestinationDomainParams {
+    pub domain: u32,
+    pub destination_contract: Pubkey,
+}
+
+pub fn enable_destination_domain(
+    ctx: Context<EnableDestinationDomainContext>,
+    params: &EnableDestinationDomainParams,
+) -> Result<()> {
+    require_keys_neq!(
+        params.destination_contract,
+        Pubkey::default(),
+        GatewayWalletError::InvalidDestinationContract
+    );
+
+    let destination_domain = &mut ctx.accounts.destination_domain;
+    destination_domain.bump = ctx.bumps.destination_domain;
+    destination_domain.domain = params.domain;
+    destination_domain.destination_contract = params.destination_contract;
+    destination_domain.enabled = true;
+
+    emit_cpi!(DestinationDomainEnabled {
+        domain: destination_domain.domain,
+        destination_contract: destination_domain.destination_contract,
+    });
+
+    Ok(())
+}
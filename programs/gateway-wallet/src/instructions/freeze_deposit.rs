@@ -0,0 +1,68 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! FreezeDeposit instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError, events::DepositFrozen, seeds::GATEWAY_WALLET_SEED,
+        state::GatewayWallet, utils,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FreezeDepositContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(address = gateway_wallet.denylister @ GatewayWalletError::InvalidAuthority)]
+    pub denylister: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    /// CHECK: Deposit PDA of a depositor, verified when it is loaded
+    #[account(mut)]
+    pub deposit: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn freeze_deposit(ctx: Context<FreezeDepositContext>) -> Result<()> {
+    let mut deposit = utils::load_deposit_if_initialized(&ctx.accounts.deposit, ctx.program_id)?
+        .ok_or(ErrorCode::AccountNotInitialized)?;
+    deposit.frozen = true;
+    utils::save_deposit(
+        &ctx.accounts.deposit,
+        &deposit,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    emit_cpi!(DepositFrozen {
+        token: deposit.token_mint,
+        depositor: deposit.depositor,
+    });
+
+    Ok(())
+}
@@ -27,7 +27,7 @@
 //! instruction's data to read the signature, public key, and message.
 //!
 //! Instruction data layout
-//! ```
+//! ```text
 //! offset  size  field
 //! 0       2     discriminator (custom 2-byte discriminator)
 //! 2       4     encoded_burn_data length (u32)
@@ -36,8 +36,9 @@
 //! 6+N+4   M     burn_signature
 //! ```
 //!
-//! When constructing the Ed25519 precompile instruction, use:
-//! ```
+//! The Ed25519 precompile instruction data is produced by
+//! `ed25519::gateway_burn_ed25519_instruction_data`, which is equivalent to:
+//! ```text
 //! const num_signatures = 1
 //! const padding = 0
 //! const signature_offset = 6 + BurnData::BURN_DATA_USER_SIGNATURE_OFFSET
@@ -55,36 +56,46 @@ use anchor_lang::solana_program::keccak::hash;
 use anchor_lang::solana_program::sysvar::instructions::{
     get_instruction_relative, load_current_index_checked,
 };
-use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken};
 use anchor_spl::token::{Mint, Token, TokenAccount};
+use gateway_shared::admin::TokenRegistry;
 use gateway_shared::{
     create_used_transfer_spec_hash_account, ethereum_signed_message_hash,
-    is_transfer_spec_hash_used, DISCRIMINATOR_SIZE, USED_TRANSFER_SPEC_HASH_SEED_PREFIX,
+    is_transfer_spec_hash_used,
+    replay::{mark_nonce_used, NonceNamespace, ReplayKey, MAX_TRANSFER_VALIDITY_SLOTS},
+    write_used_transfer_spec_hash_receipt,
 };
 
-use crate::ed25519::Ed25519InstructionData;
+use crate::ed25519::{gateway_burn_ed25519_instruction_data, Ed25519InstructionData};
 use crate::{
     burn_data::BurnData,
     error::GatewayWalletError,
-    events::{GatewayBurned, InsufficientBalance},
+    events::{FeeShare, GatewayBurned, InsufficientBalance},
     seeds::{
-        GATEWAY_DELEGATE_SEED, GATEWAY_DEPOSIT_SEED, GATEWAY_WALLET_CUSTODY_SEED,
-        GATEWAY_WALLET_SEED,
+        DESTINATION_DOMAIN_SEED, FEE_SCHEDULE_SEED, GATEWAY_DELEGATE_SEED,
+        GATEWAY_WALLET_CUSTODY_SEED, GATEWAY_WALLET_SEED, OUTFLOW_LIMIT_SEED, TOKEN_TOTALS_SEED,
+    },
+    state::{
+        BurnReceipt, DestinationDomain, FeeSchedule, GatewayDelegate, GatewayWallet, NonceBucket,
+        ReplayStore, UsedTransferSpecHash,
+    },
+    utils::{
+        load_deposit_if_initialized, load_if_initialized, record_outflow, remove_token_deposits,
+        save_deposit, validate_signer_authorization,
     },
-    state::{GatewayDelegate, GatewayDeposit, GatewayWallet, UsedTransferSpecHash},
-    utils::validate_signer_authorization,
 };
 
-// The expected index of the used transfer spec hash account in the remaining accounts
-const USED_TRANSFER_SPEC_HASH_ACCOUNT_INDEX: usize = 0;
+// The expected index of the replay protection account in the remaining accounts. This is the
+// used transfer spec hash account, or the nonce bucket account if the transfer is recorded by nonce.
+const REPLAY_ACCOUNT_INDEX: usize = 0;
 
-// The offset of the start of the burn data relative to the start of the gateway_burn instruction data
-// This includes the discriminator and a 4-byte size field for the size of the encoded_burn_data
-const BURN_DATA_OFFSET: u16 = (DISCRIMINATOR_SIZE + 4) as u16;
+// While migrating to nonce buckets, the used transfer spec hash account of a transfer recorded by
+// nonce follows the nonce bucket account, so that it can be checked as unused
+const MIGRATION_HASH_ACCOUNT_INDEX: usize = 1;
 
-// Required values for the Ed25519 instruction
-const ED25519_NUM_SIGNATURES: u8 = 1;
-const ED25519_PADDING: u8 = 0;
+// The fee split token accounts follow the replay protection accounts in the remaining accounts.
+// One token account follows for each entry in `gateway_wallet.fee_splits`, in the same order.
+// A token account that does not exist is allowed, and its share is paid to the fee recipient.
 
 #[event_cpi]
 #[derive(Accounts)]
@@ -119,12 +130,19 @@ pub struct GatewayBurnContext<'info> {
     )]
     pub fee_recipient_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Deposit PDA of the burn intent's source depositor for the token, verified when it
+    /// is loaded
+    #[account(mut)]
+    pub deposit: UncheckedAccount<'info>,
+
+    /// CHECK: Deposit totals PDA for the token. The totals of a token supported before they
+    /// existed are not tracked until they are initialized.
     #[account(
         mut,
-        seeds = [GATEWAY_DEPOSIT_SEED, token_mint.key().as_ref(), deposit.depositor.key().as_ref()],
-        bump = deposit.bump,
+        seeds = [TOKEN_TOTALS_SEED, token_mint.key().as_ref()],
+        bump
     )]
-    pub deposit: Account<'info, GatewayDeposit>,
+    pub token_totals: UncheckedAccount<'info>,
 
     #[account(
         seeds = [
@@ -137,6 +155,23 @@ pub struct GatewayBurnContext<'info> {
     )]
     pub delegate_account: Option<Account<'info, GatewayDelegate>>,
 
+    /// CHECK: Destination domain registry PDA for the burn intent's destination domain. The
+    /// address is validated in the handler, and the burn fails if the domain is disabled, or was
+    /// never registered once the owner enforces the registry.
+    pub destination_domain: UncheckedAccount<'info>,
+
+    /// CHECK: Fee schedule PDA for the token and the burn intent's destination domain. The
+    /// address is validated in the handler, and no minimum fee applies if it does not exist.
+    pub fee_schedule: UncheckedAccount<'info>,
+
+    /// CHECK: Outflow limit PDA for the token. No limit applies if it does not exist.
+    #[account(
+        mut,
+        seeds = [OUTFLOW_LIMIT_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub outflow_limit: UncheckedAccount<'info>,
+
     /// CHECK: Verify that this is the instructions sysvar
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions_sysvar: UncheckedAccount<'info>,
@@ -191,6 +226,16 @@ pub fn gateway_burn<'burn>(
         GatewayWalletError::BurnIntentExpired
     );
 
+    // While transfers are recorded by hash, they must expire in time for a migration to nonce
+    // buckets
+    if gateway_wallet.replay_store == ReplayStore::HashAccounts {
+        require_gte!(
+            current_slot.saturating_add(MAX_TRANSFER_VALIDITY_SLOTS),
+            max_block_height,
+            GatewayWalletError::BurnIntentValidityTooLong
+        );
+    }
+
     // Verify the source domain matches the local domain
     let source_domain = burn_data.source_domain()?;
     require_eq!(
@@ -207,6 +252,15 @@ pub fn gateway_burn<'burn>(
         GatewayWalletError::SourceContractMismatch
     );
 
+    // Verify the destination domain is enabled and the intent targets its registered contract
+    verify_destination(
+        &ctx.accounts.destination_domain,
+        burn_data.destination_domain()?,
+        burn_data.destination_contract()?,
+        ctx.accounts.gateway_wallet.destination_domains_enforced,
+        ctx.program_id,
+    )?;
+
     // Verify the token mint matches the source token in the burn intent
     let source_token = burn_data.source_token()?;
     require_keys_eq!(
@@ -215,11 +269,22 @@ pub fn gateway_burn<'burn>(
         GatewayWalletError::SourceTokenMismatch
     );
 
+    // Deposit accounts are only ever created at the PDA of their depositor and token, so checking
+    // the token is equivalent to checking the account's seeds
+    let mut deposit = load_deposit_if_initialized(&ctx.accounts.deposit, ctx.program_id)?
+        .ok_or(ErrorCode::AccountNotInitialized)?;
+    require_keys_eq!(
+        deposit.token_mint,
+        ctx.accounts.token_mint.key(),
+        ErrorCode::ConstraintSeeds
+    );
+    require!(!deposit.frozen, GatewayWalletError::DepositFrozen);
+
     // Verify the depositor matches the depositor in the burn intent
     let source_depositor = burn_data.source_depositor()?;
     require_keys_eq!(
         source_depositor,
-        ctx.accounts.deposit.depositor,
+        deposit.depositor,
         GatewayWalletError::SourceDepositorMismatch
     );
 
@@ -236,87 +301,143 @@ pub fn gateway_burn<'burn>(
     let fee = burn_data.fee()?;
     require_gte!(max_fee, fee, GatewayWalletError::BurnFeeExceedsMaxFee);
 
-    // Check sufficient balance in custody account
+    // Verify the fee meets the minimum configured for the destination domain
     let value: u64 = burn_data.value()?;
+    verify_minimum_fee(
+        &ctx.accounts.fee_schedule,
+        &ctx.accounts.token_mint.key(),
+        burn_data.destination_domain()?,
+        value,
+        fee,
+        ctx.program_id,
+    )?;
+
+    // Check sufficient balance in custody account
     require_gte!(
         ctx.accounts.custody_token_account.amount,
         value + fee,
         GatewayWalletError::InsufficientCustodyBalance
     );
 
-    // Get the transfer spec hash account
+    // Determine where the transfer is recorded. Explicit nonces are scoped to the depositor of
+    // this wallet.
+    let transfer_spec_hash = burn_data.transfer_spec_hash()?;
+    let replay_key = match gateway_wallet.replay_store {
+        ReplayStore::HashAccounts => ReplayKey::TransferSpecHash(transfer_spec_hash),
+        ReplayStore::Migrating | ReplayStore::NonceBuckets => {
+            let namespace = NonceNamespace {
+                source_domain,
+                source_contract,
+                source_depositor,
+            };
+            ReplayKey::from_salt(transfer_spec_hash, &burn_data.salt()?, namespace)
+        }
+    };
+    let is_migrating_nonce = gateway_wallet.replay_store == ReplayStore::Migrating
+        && matches!(replay_key, ReplayKey::Nonce { .. });
+    let fee_split_token_accounts_start = if is_migrating_nonce {
+        MIGRATION_HASH_ACCOUNT_INDEX + 1
+    } else {
+        REPLAY_ACCOUNT_INDEX + 1
+    };
+
     require_eq!(
         ctx.remaining_accounts.len(),
-        1,
+        fee_split_token_accounts_start + gateway_wallet.fee_splits.len(),
         GatewayWalletError::RemainingAccountsLengthMismatch
     );
-    let transfer_spec_hash = burn_data.transfer_spec_hash()?;
-    let hash_account = &ctx.remaining_accounts[USED_TRANSFER_SPEC_HASH_ACCOUNT_INDEX];
 
-    let (expected_pda, bump) = Pubkey::find_program_address(
-        &[USED_TRANSFER_SPEC_HASH_SEED_PREFIX, &transfer_spec_hash],
-        ctx.program_id,
-    );
-
-    require_keys_eq!(
-        expected_pda,
-        hash_account.key(),
-        GatewayWalletError::InvalidTransferSpecHashAccount
-    );
-
-    let is_used = {
-        let account_data = hash_account.try_borrow_data()?;
-        is_transfer_spec_hash_used(&account_data, UsedTransferSpecHash::DISCRIMINATOR)?
+    let receipt_space = if gateway_wallet.transfer_receipts_enabled {
+        BurnReceipt::INIT_SPACE
+    } else {
+        0
     };
-
-    if is_used {
-        return Err(GatewayWalletError::TransferSpecHashAlreadyUsed.into());
-    }
-
-    // Create and initialize the used transfer spec hash account
-    create_used_transfer_spec_hash_account(
-        hash_account,
-        &transfer_spec_hash,
-        bump,
+    mark_transfer_used(
+        replay_key,
+        transfer_spec_hash,
+        &ctx.remaining_accounts[..fee_split_token_accounts_start],
+        max_block_height,
+        receipt_space,
         &ctx.accounts.payer.to_account_info(),
         &ctx.accounts.system_program.to_account_info(),
         ctx.program_id,
-        UsedTransferSpecHash::DISCRIMINATOR,
     )?;
 
-    let (from_available, from_withdrawing) = ctx.accounts.deposit.reduce_balance(value + fee)?;
+    let (from_available, from_withdrawing) = deposit.reduce_balance(value + fee)?;
+    save_deposit(
+        &ctx.accounts.deposit,
+        &deposit,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
 
     let deducted_amount = from_available + from_withdrawing;
+    remove_token_deposits(&ctx.accounts.token_totals, deducted_amount, ctx.program_id)?;
     if deducted_amount < value + fee {
         emit_cpi!(InsufficientBalance {
             token: ctx.accounts.token_mint.key(),
-            depositor: ctx.accounts.deposit.depositor,
+            depositor: deposit.depositor,
             value: value + fee,
             available_balance: from_available,
             withdrawing_balance: from_withdrawing,
         });
     }
 
+    // Verify the amount leaving custody is within the token's outflow limit
+    record_outflow(&ctx.accounts.outflow_limit, deducted_amount, ctx.program_id)?;
+
     let actual_fee_charged = deducted_amount.saturating_sub(value);
 
-    // Transfer the fee to the fee recipient
-    if actual_fee_charged > 0 {
-        let authority_seeds: &[&[&[u8]]] =
-            &[&[GATEWAY_WALLET_SEED, &[ctx.accounts.gateway_wallet.bump]]];
-
-        let transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::Transfer {
-                from: ctx.accounts.custody_token_account.to_account_info(),
-                to: ctx.accounts.fee_recipient_token_account.to_account_info(),
-                authority: ctx.accounts.gateway_wallet.to_account_info(),
-            },
-            authority_seeds,
+    // Split the fee between the partner fee recipients and the primary fee recipient, which
+    // receives whatever remains after rounding each partner share down
+    let partner_shares = gateway_wallet.compute_fee_shares(actual_fee_charged);
+    let mut primary_share = actual_fee_charged - partner_shares.iter().sum::<u64>();
+
+    // Transfer each partner share to the partner's associated token account. The share of a
+    // partner whose token account does not exist goes to the primary fee recipient instead, so
+    // that a missing or closed partner account cannot block burns of the token.
+    let mut partner_fee_shares = Vec::with_capacity(partner_shares.len());
+    for (i, (split, amount)) in gateway_wallet
+        .fee_splits
+        .iter()
+        .zip(partner_shares)
+        .enumerate()
+    {
+        let token_account = &ctx.remaining_accounts[fee_split_token_accounts_start + i];
+        require_keys_eq!(
+            token_account.key(),
+            get_associated_token_address(&split.recipient, &ctx.accounts.token_mint.key()),
+            GatewayWalletError::InvalidFeeSplitTokenAccount
         );
 
-        anchor_spl::token::transfer(transfer_ctx, actual_fee_charged)?;
+        let amount = if is_token_account_initialized(token_account) {
+            transfer_fee_share(&ctx, token_account.clone(), amount)?;
+            amount
+        } else {
+            primary_share += amount;
+            0
+        };
+
+        partner_fee_shares.push(FeeShare {
+            recipient: split.recipient,
+            amount,
+        });
     }
 
+    // Transfer the primary share to the fee recipient
+    transfer_fee_share(
+        &ctx,
+        ctx.accounts.fee_recipient_token_account.to_account_info(),
+        primary_share,
+    )?;
+
+    let mut fee_shares = Vec::with_capacity(partner_fee_shares.len() + 1);
+    fee_shares.push(FeeShare {
+        recipient: gateway_wallet.fee_recipient,
+        amount: primary_share,
+    });
+    fee_shares.extend(partner_fee_shares);
+
     // Burn everything else (deducted_amount - actual_fee_charged)
     let burn_amount = deducted_amount - actual_fee_charged;
 
@@ -329,9 +450,23 @@ pub fn gateway_burn<'burn>(
         burn_amount,
     )?;
 
+    // Record how the burn was settled, unless it is recorded in a nonce bucket
+    if receipt_space > 0 && matches!(replay_key, ReplayKey::TransferSpecHash(_)) {
+        write_used_transfer_spec_hash_receipt(
+            &ctx.remaining_accounts[REPLAY_ACCOUNT_INDEX],
+            &BurnReceipt {
+                slot: current_slot,
+                token: ctx.accounts.token_mint.key(),
+                depositor: deposit.depositor,
+                value: burn_amount,
+                fee: actual_fee_charged,
+            },
+        )?;
+    }
+
     emit_cpi!(GatewayBurned {
         token: ctx.accounts.token_mint.key(),
-        depositor: ctx.accounts.deposit.depositor,
+        depositor: deposit.depositor,
         transfer_spec_hash,
         destination_domain: burn_data.destination_domain()?,
         destination_recipient: burn_data.destination_recipient()?.to_bytes(),
@@ -340,11 +475,210 @@ pub fn gateway_burn<'burn>(
         fee: actual_fee_charged,
         from_available,
         from_withdrawing,
+        fee_shares,
     });
 
     Ok(())
 }
 
+/// Whether an account holds an initialized token account. The address of a fee split token
+/// account is checked against the partner's associated token address, so any initialized token
+/// account at that address belongs to the partner and holds the burned token.
+fn is_token_account_initialized(account: &AccountInfo) -> bool {
+    account.owner == &anchor_spl::token::ID && !account.data_is_empty()
+}
+
+fn transfer_fee_share<'burn>(
+    ctx: &Context<'_, '_, '_, 'burn, GatewayBurnContext<'burn>>,
+    to: AccountInfo<'burn>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let authority_seeds: &[&[&[u8]]] =
+        &[&[GATEWAY_WALLET_SEED, &[ctx.accounts.gateway_wallet.bump]]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        anchor_spl::token::Transfer {
+            from: ctx.accounts.custody_token_account.to_account_info(),
+            to,
+            authority: ctx.accounts.gateway_wallet.to_account_info(),
+        },
+        authority_seeds,
+    );
+
+    anchor_spl::token::transfer(transfer_ctx, amount)
+}
+
+/// Marks a transfer as used in its replay protection account, failing if it was already used.
+///
+/// While migrating to nonce buckets, a transfer recorded by nonce must also have an unused
+/// transfer spec hash account, as it may have been recorded there before the migration began.
+#[allow(clippy::too_many_arguments)]
+fn mark_transfer_used<'burn>(
+    replay_key: ReplayKey,
+    transfer_spec_hash: [u8; 32],
+    replay_accounts: &[AccountInfo<'burn>],
+    expiry_slot: u64,
+    receipt_space: usize,
+    payer: &AccountInfo<'burn>,
+    system_program: &AccountInfo<'burn>,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let replay_account = &replay_accounts[REPLAY_ACCOUNT_INDEX];
+    let (expected_pda, bump) = replay_key.find_address(program_id);
+
+    match replay_key {
+        ReplayKey::TransferSpecHash(_) => {
+            require_keys_eq!(
+                expected_pda,
+                replay_account.key(),
+                GatewayWalletError::InvalidTransferSpecHashAccount
+            );
+
+            let is_used = {
+                let account_data = replay_account.try_borrow_data()?;
+                is_transfer_spec_hash_used(&account_data, UsedTransferSpecHash::DISCRIMINATOR)?
+            };
+
+            if is_used {
+                return Err(GatewayWalletError::TransferSpecHashAlreadyUsed.into());
+            }
+
+            // Create and initialize the used transfer spec hash account
+            create_used_transfer_spec_hash_account(
+                replay_account,
+                &transfer_spec_hash,
+                bump,
+                payer,
+                system_program,
+                program_id,
+                UsedTransferSpecHash::DISCRIMINATOR,
+                expiry_slot,
+                receipt_space,
+            )
+        }
+        ReplayKey::Nonce { namespace, nonce } => {
+            require_keys_eq!(
+                expected_pda,
+                replay_account.key(),
+                GatewayWalletError::InvalidNonceBucketAccount
+            );
+
+            if let Some(hash_account) = replay_accounts.get(MIGRATION_HASH_ACCOUNT_INDEX) {
+                let (expected_hash_pda, _) =
+                    ReplayKey::TransferSpecHash(transfer_spec_hash).find_address(program_id);
+                require_keys_eq!(
+                    expected_hash_pda,
+                    hash_account.key(),
+                    GatewayWalletError::InvalidTransferSpecHashAccount
+                );
+
+                let is_used = {
+                    let account_data = hash_account.try_borrow_data()?;
+                    is_transfer_spec_hash_used(&account_data, UsedTransferSpecHash::DISCRIMINATOR)?
+                };
+
+                if is_used {
+                    return Err(GatewayWalletError::TransferSpecHashAlreadyUsed.into());
+                }
+            }
+
+            let is_marked = mark_nonce_used(
+                replay_account,
+                &namespace,
+                nonce,
+                bump,
+                payer,
+                system_program,
+                program_id,
+                NonceBucket::DISCRIMINATOR,
+            )?;
+            require!(is_marked, GatewayWalletError::TransferSpecHashAlreadyUsed);
+
+            Ok(())
+        }
+    }
+}
+
+fn verify_destination(
+    destination_domain_account: &AccountInfo,
+    destination_domain: u32,
+    destination_contract: Pubkey,
+    enforced: bool,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let (expected_pda, _) = Pubkey::find_program_address(
+        &[DESTINATION_DOMAIN_SEED, &destination_domain.to_be_bytes()],
+        program_id,
+    );
+    require_keys_eq!(
+        expected_pda,
+        destination_domain_account.key(),
+        GatewayWalletError::InvalidDestinationDomainAccount
+    );
+
+    // Once enforced, a domain that was never registered is treated the same as a disabled one.
+    // Until then, only the domains the owner has registered are checked.
+    let Some(registry) =
+        load_if_initialized::<DestinationDomain>(destination_domain_account, program_id)?
+    else {
+        require!(!enforced, GatewayWalletError::DestinationDomainNotEnabled);
+        return Ok(());
+    };
+    require!(
+        registry.enabled,
+        GatewayWalletError::DestinationDomainNotEnabled
+    );
+    require_keys_eq!(
+        registry.destination_contract,
+        destination_contract,
+        GatewayWalletError::DestinationContractMismatch
+    );
+
+    Ok(())
+}
+
+fn verify_minimum_fee(
+    fee_schedule_account: &AccountInfo,
+    token_mint: &Pubkey,
+    destination_domain: u32,
+    value: u64,
+    fee: u64,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let (expected_pda, _) = Pubkey::find_program_address(
+        &[
+            FEE_SCHEDULE_SEED,
+            token_mint.as_ref(),
+            &destination_domain.to_be_bytes(),
+        ],
+        program_id,
+    );
+    require_keys_eq!(
+        expected_pda,
+        fee_schedule_account.key(),
+        GatewayWalletError::InvalidFeeScheduleAccount
+    );
+
+    // No minimum applies until the owner sets a schedule
+    let Some(fee_schedule) = load_if_initialized::<FeeSchedule>(fee_schedule_account, program_id)?
+    else {
+        return Ok(());
+    };
+
+    require_gte!(
+        fee,
+        fee_schedule.required_fee(value),
+        GatewayWalletError::BurnFeeBelowMinimum
+    );
+
+    Ok(())
+}
+
 fn verify_user_signature<'burn>(
     instructions_sysvar: &UncheckedAccount<'burn>,
     burn_intent_message_length: usize,
@@ -374,42 +708,20 @@ fn verify_user_signature<'burn>(
         GatewayWalletError::PreviousInstructionNotEd25519Program
     );
 
-    // Parse the Ed25519 instruction data and ensure that it validated the expected signature, public key, and message
+    // Parse the Ed25519 instruction data and ensure that it validated the expected signature, public
+    // key, and message: the user signature within the burn data, the burn intent source signer, and
+    // the burn intent message, all within this instruction
     let data = Ed25519InstructionData::new(&previous_instruction.data)?;
-    let signature_offset: u16 = BURN_DATA_OFFSET + BurnData::BURN_DATA_USER_SIGNATURE_OFFSET as u16;
-    let source_signer_offset: u16 = BURN_DATA_OFFSET + BurnData::TS_SOURCE_SIGNER_OFFSET as u16;
-    let burn_intent_message_offset: u16 =
-        BURN_DATA_OFFSET + BurnData::BURN_INTENT_MESSAGE_PREFIX_OFFSET as u16;
-
-    let valid_signature = data.num_signatures()? == ED25519_NUM_SIGNATURES
-        && data.padding()? == ED25519_PADDING
-        // Ensure the signature offset is the start of the user signature within the burn data
-        && data.signature_offset()? == signature_offset
-        && data.signature_instruction_index()? == current_instruction_index
-        // Ensure the public key offset is the start of the burn intent source signer
-        && data.public_key_offset()? == source_signer_offset
-        && data.public_key_instruction_index()? == current_instruction_index
-        // Ensure the message data offset is the start of the burn intent message and has the correct size
-        && data.message_data_offset()? == burn_intent_message_offset
-        && data.message_data_size()? == burn_intent_message_length as u16
-        && data.message_instruction_index()? == current_instruction_index;
-
-    if !valid_signature {
-        let current_index_bytes = current_instruction_index.to_le_bytes();
-        let expected_data = [
-            [ED25519_NUM_SIGNATURES, ED25519_PADDING],
-            signature_offset.to_le_bytes(),
-            current_index_bytes,
-            source_signer_offset.to_le_bytes(),
-            current_index_bytes,
-            burn_intent_message_offset.to_le_bytes(),
-            (burn_intent_message_length as u16).to_le_bytes(),
-            current_index_bytes,
-        ];
+    let expected_data = gateway_burn_ed25519_instruction_data(
+        current_instruction_index,
+        burn_intent_message_length as u16,
+    );
+
+    if data.data() != expected_data {
         msg!(
             "Ed25519 ix data: {:?}, expected: {:?}",
             data.data(),
-            expected_data.concat()
+            expected_data
         );
         return err!(GatewayWalletError::InvalidEd25519InstructionData);
     }
@@ -0,0 +1,73 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GetBalance view instruction handler
+
+use {
+    crate::{seeds::GATEWAY_DEPOSIT_SEED, utils},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: DepositViewParams)]
+pub struct GetBalanceContext<'info> {
+    /// CHECK: Deposit PDA of the depositor for the token. The balance is zero if it does not
+    /// exist.
+    #[account(
+        seeds = [GATEWAY_DEPOSIT_SEED, params.token.as_ref(), params.depositor.as_ref()],
+        bump
+    )]
+    pub deposit: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct DepositViewParams {
+    /// The token mint key
+    pub token: Pubkey,
+    /// The depositor key
+    pub depositor: Pubkey,
+}
+
+/// The balance of a deposit, returned by `get_balance`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DepositBalance {
+    /// The balance that can be burned or withdrawn
+    pub available_amount: u64,
+    /// The balance of the withdrawal in progress, which can still be burned
+    pub withdrawing_amount: u64,
+    /// The slot from which the withdrawing balance can be withdrawn
+    pub withdrawal_block: u64,
+    /// Whether the denylister has frozen the deposit
+    pub frozen: bool,
+}
+
+pub fn get_balance(
+    ctx: Context<GetBalanceContext>,
+    _params: &DepositViewParams,
+) -> Result<DepositBalance> {
+    let deposit = utils::load_deposit_if_initialized(&ctx.accounts.deposit, ctx.program_id)?;
+
+    Ok(deposit
+        .map(|deposit| DepositBalance {
+            available_amount: deposit.available_amount,
+            withdrawing_amount: deposit.withdrawing_amount,
+            withdrawal_block: deposit.withdrawal_block,
+            frozen: deposit.frozen,
+        })
+        .unwrap_or_default())
+}
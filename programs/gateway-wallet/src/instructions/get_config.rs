@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GetConfig view instruction handler
+
+use {
+    crate::{
+        seeds::GATEWAY_WALLET_SEED,
+        state::{FeeSplit, GatewayWallet, ReplayStore, MAX_FEE_SPLITS, MAX_SUPPORTED_TOKENS},
+    },
+    anchor_lang::{prelude::*, solana_program::program::MAX_RETURN_DATA},
+};
+
+#[derive(Accounts)]
+pub struct GetConfigContext<'info> {
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+}
+
+/// The GatewayWallet configuration, returned by `get_config`
+///
+/// The burn signers are left out, so that the result always fits in the return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct WalletConfig {
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub pauser: Pubkey,
+    pub denylister: Pubkey,
+    pub token_controller: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub local_domain: u32,
+    pub version: u32,
+    pub withdrawal_delay: u64,
+    pub paused: bool,
+    #[max_len(MAX_SUPPORTED_TOKENS)]
+    pub supported_tokens: Vec<Pubkey>,
+    #[max_len(MAX_FEE_SPLITS)]
+    pub fee_splits: Vec<FeeSplit>,
+    pub replay_store: ReplayStore,
+    pub transfer_receipts_enabled: bool,
+    pub destination_domains_enforced: bool,
+    pub replay_migration_started_slot: u64,
+}
+
+const _: () = assert!(WalletConfig::INIT_SPACE <= MAX_RETURN_DATA);
+
+pub fn get_config(ctx: Context<GetConfigContext>) -> Result<WalletConfig> {
+    let gateway_wallet = &ctx.accounts.gateway_wallet;
+
+    Ok(WalletConfig {
+        owner: gateway_wallet.owner,
+        pending_owner: gateway_wallet.pending_owner,
+        pauser: gateway_wallet.pauser,
+        denylister: gateway_wallet.denylister,
+        token_controller: gateway_wallet.token_controller,
+        fee_recipient: gateway_wallet.fee_recipient,
+        local_domain: gateway_wallet.local_domain,
+        version: gateway_wallet.version,
+        withdrawal_delay: gateway_wallet.withdrawal_delay,
+        paused: gateway_wallet.paused,
+        supported_tokens: gateway_wallet.supported_tokens.clone(),
+        fee_splits: gateway_wallet.fee_splits.clone(),
+        replay_store: gateway_wallet.replay_store,
+        transfer_receipts_enabled: gateway_wallet.transfer_receipts_enabled,
+        destination_domains_enforced: gateway_wallet.destination_domains_enforced,
+        replay_migration_started_slot: gateway_wallet.replay_migration_started_slot,
+    })
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! GetWithdrawable view instruction handler
+
+use {
+    crate::{
+        instructions::DepositViewParams,
+        seeds::{GATEWAY_DEPOSIT_SEED, OUTFLOW_LIMIT_SEED},
+        state::OutflowLimit,
+        utils,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: DepositViewParams)]
+pub struct GetWithdrawableContext<'info> {
+    /// CHECK: Deposit PDA of the depositor for the token. Nothing is withdrawable if it does not
+    /// exist.
+    #[account(
+        seeds = [GATEWAY_DEPOSIT_SEED, params.token.as_ref(), params.depositor.as_ref()],
+        bump
+    )]
+    pub deposit: UncheckedAccount<'info>,
+
+    /// CHECK: Outflow limit PDA for the token. No limit applies if it does not exist.
+    #[account(
+        seeds = [OUTFLOW_LIMIT_SEED, params.token.as_ref()],
+        bump
+    )]
+    pub outflow_limit: UncheckedAccount<'info>,
+}
+
+/// Returns the amount the depositor can withdraw in the current slot, i.e. the withdrawing balance
+/// once the withdrawal delay has elapsed, unless the deposit is frozen, capped at what the token's
+/// outflow limit still allows. `withdraw` transfers the whole withdrawing balance, so it fails
+/// while the cap is below it.
+pub fn get_withdrawable(
+    ctx: Context<GetWithdrawableContext>,
+    _params: &DepositViewParams,
+) -> Result<u64> {
+    let Some(deposit) = utils::load_deposit_if_initialized(&ctx.accounts.deposit, ctx.program_id)?
+    else {
+        return Ok(0);
+    };
+
+    let slot = Clock::get()?.slot;
+    if deposit.frozen || slot < deposit.withdrawal_block {
+        return Ok(0);
+    }
+
+    let remaining_outflow =
+        utils::load_if_initialized::<OutflowLimit>(&ctx.accounts.outflow_limit, ctx.program_id)?
+            .map_or(u64::MAX, |outflow_limit| {
+                outflow_limit.remaining_outflow(slot)
+            });
+
+    Ok(deposit.withdrawing_amount.min(remaining_outflow))
+}
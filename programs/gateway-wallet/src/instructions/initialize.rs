@@ -20,8 +20,11 @@
 
 use {
     crate::{
-        error::GatewayWalletError, events::GatewayWalletInitialized, seeds::GATEWAY_WALLET_SEED,
-        state::GatewayWallet, utils,
+        error::GatewayWalletError,
+        events::GatewayWalletInitialized,
+        seeds::GATEWAY_WALLET_SEED,
+        state::{GatewayWallet, ReplayStore},
+        utils,
     },
     anchor_lang::prelude::*,
 };
@@ -86,6 +89,10 @@ pub fn initialize(ctx: Context<InitializeContext>, params: &InitializeParams) ->
     gateway_wallet_state.version = 1;
     gateway_wallet_state.withdrawal_delay = params.withdrawal_delay;
     gateway_wallet_state.paused = false;
+    gateway_wallet_state.replay_store = ReplayStore::HashAccounts;
+    gateway_wallet_state.transfer_receipts_enabled = false;
+    gateway_wallet_state.destination_domains_enforced = false;
+    gateway_wallet_state.replay_migration_started_slot = 0;
 
     emit_cpi!(GatewayWalletInitialized {});
 
@@ -0,0 +1,104 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+//! InitializeTokenTotals instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::TokenTotalsInitialized,
+        seeds::{GATEWAY_WALLET_CUSTODY_SEED, GATEWAY_WALLET_SEED, TOKEN_TOTALS_SEED},
+        state::{GatewayWallet, TokenTotals},
+        utils,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, TokenAccount},
+    gateway_shared::admin::TokenRegistry,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeTokenTotalsContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_controller: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        has_one = token_controller @ GatewayWalletError::InvalidAuthority
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    #[account(
+        constraint = gateway_wallet.is_token_supported(token_mint.key()) @ GatewayWalletError::TokenNotSupported
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_CUSTODY_SEED, token_mint.key().as_ref()],
+        bump = gateway_wallet.get_custody_token_account_bump(token_mint.key())?
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = utils::DISCRIMINATOR_SIZE + TokenTotals::INIT_SPACE,
+        seeds = [TOKEN_TOTALS_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_totals: Account<'info, TokenTotals>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct InitializeTokenTotalsParams {
+    /// The audited sum of the balances of every deposit of the token
+    pub total_deposits: u64,
+}
+
+/// Initializes the deposit totals of a token that was supported before the totals existed
+///
+/// The audited total cannot be checked on-chain, as the deposit accounts of a token cannot be
+/// enumerated. It must therefore cover the whole custody balance, so that no deposit the audit
+/// missed can appear as a surplus, and the custody surplus of the token is never swept.
+pub fn initialize_token_totals(
+    ctx: Context<InitializeTokenTotalsContext>,
+    params: &InitializeTokenTotalsParams,
+) -> Result<()> {
+    require_gte!(
+        params.total_deposits,
+        ctx.accounts.custody_token_account.amount,
+        GatewayWalletError::TotalDepositsBelowCustody
+    );
+
+    let token_totals = &mut ctx.accounts.token_totals;
+    token_totals.bump = ctx.bumps.token_totals;
+    token_totals.token = ctx.accounts.token_mint.key();
+    token_totals.total_deposits = params.total_deposits;
+    token_totals.initialized_from_audit = true;
+
+    emit_cpi!(TokenTotalsInitialized {
+        token: token_totals.token,
+        total_deposits: token_totals.total_deposits,
+    });
+
+    Ok(())
+}
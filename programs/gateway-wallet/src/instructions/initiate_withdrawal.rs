@@ -20,10 +20,8 @@
 
 use {
     crate::{
-        error::GatewayWalletError,
-        events::WithdrawalInitiated,
-        seeds::{GATEWAY_DEPOSIT_SEED, GATEWAY_WALLET_SEED},
-        state::{GatewayDeposit, GatewayWallet},
+        error::GatewayWalletError, events::WithdrawalInitiated, seeds::GATEWAY_WALLET_SEED,
+        state::GatewayWallet, utils,
     },
     anchor_lang::prelude::*,
 };
@@ -41,24 +39,34 @@ pub struct InitiateWithdrawalContext<'info> {
     )]
     pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
 
-    #[account(
-        mut,
-        seeds = [GATEWAY_DEPOSIT_SEED, deposit.token_mint.key().as_ref(), depositor.key().as_ref()],
-        bump = deposit.bump
-    )]
-    pub deposit: Account<'info, GatewayDeposit>,
+    /// CHECK: Deposit PDA of the depositor, verified when it is loaded
+    #[account(mut)]
+    pub deposit: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn initiate_withdrawal(ctx: Context<InitiateWithdrawalContext>, amount: u64) -> Result<()> {
-    let token_mint = ctx.accounts.deposit.token_mint;
+    let mut deposit = utils::load_deposit(
+        &ctx.accounts.deposit,
+        &ctx.accounts.depositor.key(),
+        ctx.program_id,
+    )?;
+    require!(!deposit.frozen, GatewayWalletError::DepositFrozen);
+    let token_mint = deposit.token_mint;
 
-    let (remaining_available, total_withdrawing, withdrawal_block) =
-        ctx.accounts.deposit.initiate_withdrawal(
-            amount,
-            ctx.accounts.gateway_wallet.withdrawal_delay,
-            &ctx.accounts.gateway_wallet,
-            token_mint,
-        )?;
+    let (remaining_available, total_withdrawing, withdrawal_block) = deposit.initiate_withdrawal(
+        amount,
+        ctx.accounts.gateway_wallet.withdrawal_delay,
+        &ctx.accounts.gateway_wallet,
+        token_mint,
+    )?;
+    utils::save_deposit(
+        &ctx.accounts.deposit,
+        &deposit,
+        &ctx.accounts.depositor,
+        &ctx.accounts.system_program,
+    )?;
 
     emit_cpi!(WithdrawalInitiated {
         token: token_mint,
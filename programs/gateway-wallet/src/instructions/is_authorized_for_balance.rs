@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! IsAuthorizedForBalance view instruction handler
+
+use {
+    crate::{
+        seeds::GATEWAY_DELEGATE_SEED,
+        state::{DelegateStatus, GatewayDelegate},
+        utils,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: IsAuthorizedForBalanceParams)]
+pub struct IsAuthorizedForBalanceContext<'info> {
+    /// CHECK: Delegate PDA of the address for the depositor and token. The address is not a
+    /// delegate if it does not exist.
+    #[account(
+        seeds = [
+            GATEWAY_DELEGATE_SEED,
+            params.token.as_ref(),
+            params.depositor.as_ref(),
+            params.addr.as_ref()
+        ],
+        bump
+    )]
+    pub delegate_account: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct IsAuthorizedForBalanceParams {
+    /// The token mint key
+    pub token: Pubkey,
+    /// The depositor key
+    pub depositor: Pubkey,
+    /// The address to check
+    pub addr: Pubkey,
+}
+
+/// Returns whether the address can currently authorize transfers of the depositor's balance,
+/// i.e. it is the depositor or a delegate that has not been revoked
+pub fn is_authorized_for_balance(
+    ctx: Context<IsAuthorizedForBalanceContext>,
+    params: &IsAuthorizedForBalanceParams,
+) -> Result<bool> {
+    // A depositor is always authorized for its own balance
+    if params.addr == params.depositor {
+        return Ok(true);
+    }
+
+    let delegate = utils::load_if_initialized::<GatewayDelegate>(
+        &ctx.accounts.delegate_account,
+        ctx.program_id,
+    )?;
+
+    Ok(delegate.is_some_and(|delegate| delegate.status == DelegateStatus::Authorized))
+}
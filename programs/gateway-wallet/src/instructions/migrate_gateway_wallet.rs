@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! MigrateGatewayWallet instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError, events::GatewayWalletMigrated, seeds::GATEWAY_WALLET_SEED,
+        state::GatewayWallet, utils,
+    },
+    anchor_lang::prelude::*,
+    gateway_shared::deserialize_padded,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateGatewayWalletContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: GatewayWallet state account, which may be too small to deserialize until it is
+    /// grown to the current layout. The owner is checked in the handler.
+    #[account(
+        mut,
+        seeds = [GATEWAY_WALLET_SEED],
+        bump,
+        owner = crate::ID
+    )]
+    pub gateway_wallet: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a GatewayWallet state account created before fields were appended to its layout. The
+/// state account was allocated for the longest lists, so it can only be read without this once
+/// its lists are no longer full. The appended fields start out as no fee splits, the destination
+/// domain registry not enforced and no replay store migration, which matches how the program
+/// behaved before.
+pub fn migrate_gateway_wallet(ctx: Context<MigrateGatewayWalletContext>) -> Result<()> {
+    let gateway_wallet = &ctx.accounts.gateway_wallet;
+    let new_space = utils::DISCRIMINATOR_SIZE + GatewayWallet::INIT_SPACE;
+
+    let gateway_wallet_state: GatewayWallet =
+        deserialize_padded(&gateway_wallet.try_borrow_data()?, new_space)?;
+    require_keys_eq!(
+        gateway_wallet_state.owner,
+        ctx.accounts.owner.key(),
+        GatewayWalletError::InvalidAuthority
+    );
+
+    let previous_space = utils::grow_account(
+        gateway_wallet,
+        new_space,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    emit_cpi!(GatewayWalletMigrated {
+        previous_space: previous_space as u64,
+        new_space: new_space as u64,
+    });
+
+    Ok(())
+}
@@ -23,6 +23,7 @@ use {
         error::GatewayWalletError, events::Paused, seeds::GATEWAY_WALLET_SEED, state::GatewayWallet,
     },
     anchor_lang::prelude::*,
+    gateway_shared::admin::Pausable,
 };
 
 #[event_cpi]
@@ -40,7 +41,7 @@ pub struct PauseContext<'info> {
 }
 
 pub fn pause(ctx: Context<PauseContext>) -> Result<()> {
-    ctx.accounts.gateway_wallet.paused = true;
+    ctx.accounts.gateway_wallet.pause();
 
     emit_cpi!(Paused {
         account: ctx.accounts.pauser.key(),
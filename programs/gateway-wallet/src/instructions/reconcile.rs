@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Reconcile instruction handler
+
+use {
+    crate::{
+        events::CustodyReconciled,
+        seeds::{GATEWAY_WALLET_CUSTODY_SEED, GATEWAY_WALLET_SEED, TOKEN_TOTALS_SEED},
+        state::{GatewayWallet, TokenTotals},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, TokenAccount},
+    gateway_shared::admin::TokenRegistry,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReconcileContext<'info> {
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_CUSTODY_SEED, token_mint.key().as_ref()],
+        bump = gateway_wallet.get_custody_token_account_bump(token_mint.key())?
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [TOKEN_TOTALS_SEED, token_mint.key().as_ref()],
+        bump = token_totals.bump
+    )]
+    pub token_totals: Account<'info, TokenTotals>,
+}
+
+pub fn reconcile(ctx: Context<ReconcileContext>) -> Result<()> {
+    let custody_balance = ctx.accounts.custody_token_account.amount;
+    let (surplus, deficit) = ctx.accounts.token_totals.reconcile(custody_balance);
+
+    emit_cpi!(CustodyReconciled {
+        token: ctx.accounts.token_mint.key(),
+        custody_balance,
+        total_deposits: ctx.accounts.token_totals.total_deposits,
+        surplus,
+        deficit,
+    });
+
+    Ok(())
+}
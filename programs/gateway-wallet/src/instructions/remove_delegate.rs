@@ -28,6 +28,7 @@ use {
     },
     anchor_lang::prelude::*,
     anchor_spl::token::Mint,
+    gateway_shared::admin::TokenRegistry,
 };
 
 #[event_cpi]
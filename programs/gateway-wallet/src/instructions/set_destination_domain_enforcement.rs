@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! SetDestinationDomainEnforcement instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError, events::DestinationDomainEnforcementSet,
+        seeds::GATEWAY_WALLET_SEED, state::GatewayWallet,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDestinationDomainEnforcementContext<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        has_one = owner @ GatewayWalletError::InvalidAuthority
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetDestinationDomainEnforcementParams {
+    /// Whether burns to a destination domain that was never registered are rejected
+    pub enforced: bool,
+}
+
+/// Turns the destination domain registry check on or off for domains that were never registered.
+/// The owner turns it on once every destination domain in use has been registered.
+pub fn set_destination_domain_enforcement(
+    ctx: Context<SetDestinationDomainEnforcementContext>,
+    params: &SetDestinationDomainEnforcementParams,
+) -> Result<()> {
+    ctx.accounts.gateway_wallet.destination_domains_enforced = params.enforced;
+
+    emit_cpi!(DestinationDomainEnforcementSet {
+        enforced: params.enforced,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! SetFeeSchedule instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::FeeScheduleSet,
+        seeds::{FEE_SCHEDULE_SEED, GATEWAY_WALLET_SEED},
+        state::{FeeSchedule, GatewayWallet, BPS_DENOMINATOR},
+        utils,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::Mint,
+    gateway_shared::admin::TokenRegistry,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SetFeeScheduleParams)]
+pub struct SetFeeScheduleContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        has_one = owner @ GatewayWalletError::InvalidAuthority
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    #[account(
+        constraint = gateway_wallet.is_token_supported(token_mint.key()) @ GatewayWalletError::TokenNotSupported
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = utils::DISCRIMINATOR_SIZE + FeeSchedule::INIT_SPACE,
+        seeds = [
+            FEE_SCHEDULE_SEED,
+            token_mint.key().as_ref(),
+            &params.destination_domain.to_be_bytes()
+        ],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetFeeScheduleParams {
+    pub destination_domain: u32,
+    pub min_fee: u64,
+    pub fee_bps: u16,
+}
+
+pub fn set_fee_schedule(
+    ctx: Context<SetFeeScheduleContext>,
+    params: &SetFeeScheduleParams,
+) -> Result<()> {
+    require_gte!(
+        BPS_DENOMINATOR,
+        params.fee_bps,
+        GatewayWalletError::InvalidFeeSchedule
+    );
+
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+    fee_schedule.bump = ctx.bumps.fee_schedule;
+    fee_schedule.token = ctx.accounts.token_mint.key();
+    fee_schedule.destination_domain = params.destination_domain;
+    fee_schedule.min_fee = params.min_fee;
+    fee_schedule.fee_bps = params.fee_bps;
+
+    emit_cpi!(FeeScheduleSet {
+        token: fee_schedule.token,
+        destination_domain: fee_schedule.destination_domain,
+        min_fee: fee_schedule.min_fee,
+        fee_bps: fee_schedule.fee_bps,
+    });
+
+    Ok(())
+}
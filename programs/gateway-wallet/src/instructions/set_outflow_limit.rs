@@ -0,0 +1,89 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! SetOutflowLimit instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::OutflowLimitSet,
+        seeds::{GATEWAY_WALLET_SEED, OUTFLOW_LIMIT_SEED},
+        state::{GatewayWallet, OutflowLimit},
+        utils,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::Mint,
+    gateway_shared::admin::TokenRegistry,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetOutflowLimitContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        has_one = owner @ GatewayWalletError::InvalidAuthority
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    #[account(
+        constraint = gateway_wallet.is_token_supported(token_mint.key()) @ GatewayWalletError::TokenNotSupported
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = utils::DISCRIMINATOR_SIZE + OutflowLimit::INIT_SPACE,
+        seeds = [OUTFLOW_LIMIT_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub outflow_limit: Account<'info, OutflowLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetOutflowLimitParams {
+    /// The length of the window in slots. Zero disables the limit.
+    pub window_slots: u64,
+    pub max_outflow: u64,
+}
+
+pub fn set_outflow_limit(
+    ctx: Context<SetOutflowLimitContext>,
+    params: &SetOutflowLimitParams,
+) -> Result<()> {
+    let outflow_limit = &mut ctx.accounts.outflow_limit;
+    outflow_limit.bump = ctx.bumps.outflow_limit;
+    outflow_limit.token = ctx.accounts.token_mint.key();
+    outflow_limit.configure(params.window_slots, params.max_outflow, Clock::get()?.slot);
+
+    emit_cpi!(OutflowLimitSet {
+        token: outflow_limit.token,
+        window_slots: outflow_limit.window_slots,
+        max_outflow: outflow_limit.max_outflow,
+    });
+
+    Ok(())
+}
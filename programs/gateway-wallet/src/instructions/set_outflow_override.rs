@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! SetOutflowOverride instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::OutflowOverrideSet,
+        seeds::{GATEWAY_WALLET_SEED, OUTFLOW_LIMIT_SEED},
+        state::{GatewayWallet, OutflowLimit},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetOutflowOverrideContext<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        has_one = owner @ GatewayWalletError::InvalidAuthority
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    #[account(
+        mut,
+        seeds = [OUTFLOW_LIMIT_SEED, outflow_limit.token.as_ref()],
+        bump = outflow_limit.bump
+    )]
+    pub outflow_limit: Account<'info, OutflowLimit>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetOutflowOverrideParams {
+    /// The amount that may flow out beyond the limit, replacing any unused allowance
+    pub override_allowance: u64,
+}
+
+pub fn set_outflow_override(
+    ctx: Context<SetOutflowOverrideContext>,
+    params: &SetOutflowOverrideParams,
+) -> Result<()> {
+    let outflow_limit = &mut ctx.accounts.outflow_limit;
+    outflow_limit.override_allowance = params.override_allowance;
+
+    emit_cpi!(OutflowOverrideSet {
+        token: outflow_limit.token,
+        override_allowance: outflow_limit.override_allowance,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! SetReplayStore instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::ReplayStoreSet,
+        seeds::GATEWAY_WALLET_SEED,
+        state::{GatewayWallet, ReplayStore},
+    },
+    anchor_lang::prelude::*,
+    gateway_shared::replay::is_replay_migration_complete,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReplayStoreContext<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        has_one = owner @ GatewayWalletError::InvalidAuthority
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetReplayStoreParams {
+    pub replay_store: ReplayStore,
+}
+
+/// Moves burns to the next replay store. The migration only goes forward, as transfers recorded
+/// in nonce buckets could otherwise be replayed against the used transfer spec hash accounts.
+///
+/// The slot the migration starts at is recorded when moving to `Migrating`. Moving on to
+/// `NonceBuckets` is only allowed once every transfer recorded in a used transfer spec hash account
+/// before then has expired, i.e. more than `MAX_TRANSFER_VALIDITY_SLOTS` later.
+pub fn set_replay_store(
+    ctx: Context<SetReplayStoreContext>,
+    params: &SetReplayStoreParams,
+) -> Result<()> {
+    let previous_replay_store = ctx.accounts.gateway_wallet.replay_store;
+    let is_next_store = matches!(
+        (previous_replay_store, params.replay_store),
+        (ReplayStore::HashAccounts, ReplayStore::Migrating)
+            | (ReplayStore::Migrating, ReplayStore::NonceBuckets)
+    );
+    require!(
+        is_next_store,
+        GatewayWalletError::InvalidReplayStoreTransition
+    );
+
+    let current_slot = Clock::get()?.slot;
+    let gateway_wallet = &mut ctx.accounts.gateway_wallet;
+    match params.replay_store {
+        ReplayStore::Migrating => gateway_wallet.replay_migration_started_slot = current_slot,
+        ReplayStore::NonceBuckets => require!(
+            is_replay_migration_complete(
+                gateway_wallet.replay_migration_started_slot,
+                current_slot
+            ),
+            GatewayWalletError::ReplayStoreMigrationIncomplete
+        ),
+        ReplayStore::HashAccounts => {}
+    }
+    gateway_wallet.replay_store = params.replay_store;
+
+    emit_cpi!(ReplayStoreSet {
+        previous_replay_store,
+        new_replay_store: params.replay_store,
+    });
+
+    Ok(())
+}
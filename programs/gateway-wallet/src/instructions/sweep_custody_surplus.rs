@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! SweepCustodySurplus instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::CustodySurplusSwept,
+        seeds::{GATEWAY_WALLET_CUSTODY_SEED, GATEWAY_WALLET_SEED, TOKEN_TOTALS_SEED},
+        state::{GatewayWallet, TokenTotals},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{self, Mint, Token, TokenAccount},
+    gateway_shared::admin::TokenRegistry,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepCustodySurplusContext<'info> {
+    pub token_controller: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        has_one = token_controller @ GatewayWalletError::InvalidAuthority
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [GATEWAY_WALLET_CUSTODY_SEED, token_mint.key().as_ref()],
+        bump = gateway_wallet.get_custody_token_account_bump(token_mint.key())?
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [TOKEN_TOTALS_SEED, token_mint.key().as_ref()],
+        bump = token_totals.bump
+    )]
+    pub token_totals: Account<'info, TokenTotals>,
+
+    #[account(
+        mut,
+        token::mint = token_mint
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Transfers the custody balance in excess of the total deposits. Tokens whose totals started from
+/// an audited total cannot be swept.
+pub fn sweep_custody_surplus(ctx: Context<SweepCustodySurplusContext>) -> Result<()> {
+    require!(
+        !ctx.accounts.token_totals.initialized_from_audit,
+        GatewayWalletError::CustodySurplusUnverifiable
+    );
+
+    let (surplus, _) = ctx
+        .accounts
+        .token_totals
+        .reconcile(ctx.accounts.custody_token_account.amount);
+    require_gt!(surplus, 0, GatewayWalletError::NoCustodySurplus);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[GATEWAY_WALLET_SEED, &[ctx.accounts.gateway_wallet.bump]]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::Transfer {
+            from: ctx.accounts.custody_token_account.to_account_info(),
+            to: ctx.accounts.destination_token_account.to_account_info(),
+            authority: ctx.accounts.gateway_wallet.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, surplus)?;
+
+    emit_cpi!(CustodySurplusSwept {
+        token: ctx.accounts.token_mint.key(),
+        destination_token_account: ctx.accounts.destination_token_account.key(),
+        amount: surplus,
+    });
+
+    Ok(())
+}
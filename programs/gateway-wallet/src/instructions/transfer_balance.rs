@@ -0,0 +1,194 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Transfer balance instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::BalanceTransferred,
+        seeds::{DENYLIST_SEED, GATEWAY_DELEGATE_SEED, GATEWAY_DEPOSIT_SEED, GATEWAY_WALLET_SEED},
+        state::{GatewayDelegate, GatewayWallet},
+        utils,
+    },
+    anchor_lang::prelude::*,
+    gateway_shared::admin::TokenRegistry,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: TransferBalanceParams)]
+pub struct TransferBalanceContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The depositor, or a delegate authorized for the depositor's balance
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        constraint = !gateway_wallet.paused @ GatewayWalletError::ProgramPaused
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+
+    /// CHECK: Deposit PDA of the depositor for the token
+    #[account(
+        mut,
+        seeds = [GATEWAY_DEPOSIT_SEED, params.token.as_ref(), params.depositor.as_ref()],
+        bump,
+        // Otherwise both deposit accounts would be the same account, and the last one written
+        // would win
+        constraint = params.recipient != params.depositor @ GatewayWalletError::CannotTransferToSelf
+    )]
+    pub deposit: UncheckedAccount<'info>,
+
+    /// CHECK: Deposit PDA of the recipient for the token. Created if the recipient has no deposit
+    /// of the token.
+    #[account(
+        mut,
+        seeds = [GATEWAY_DEPOSIT_SEED, params.token.as_ref(), params.recipient.as_ref()],
+        bump
+    )]
+    pub recipient_deposit: UncheckedAccount<'info>,
+
+    // Required if the signer is not the depositor
+    #[account(
+        seeds = [
+            GATEWAY_DELEGATE_SEED,
+            params.token.as_ref(),
+            params.depositor.as_ref(),
+            signer.key().as_ref()
+        ],
+        bump = delegate_account.bump,
+    )]
+    pub delegate_account: Option<Account<'info, GatewayDelegate>>,
+
+    /// CHECK: Signer denylist PDA. Account is denylisted if it exists at the expected PDA.
+    #[account(
+        seeds = [DENYLIST_SEED, signer.key().as_ref()],
+        bump,
+    )]
+    pub signer_denylist: UncheckedAccount<'info>,
+
+    /// CHECK: Depositor denylist PDA. Account is denylisted if it exists at the expected PDA.
+    #[account(
+        seeds = [DENYLIST_SEED, params.depositor.as_ref()],
+        bump,
+    )]
+    pub depositor_denylist: UncheckedAccount<'info>,
+
+    /// CHECK: Recipient denylist PDA. Account is denylisted if it exists at the expected PDA.
+    #[account(
+        seeds = [DENYLIST_SEED, params.recipient.as_ref()],
+        bump,
+    )]
+    pub recipient_denylist: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct TransferBalanceParams {
+    /// The token mint key
+    pub token: Pubkey,
+    /// The depositor whose available balance is transferred
+    pub depositor: Pubkey,
+    /// The depositor credited with the balance
+    pub recipient: Pubkey,
+    /// The amount to transfer
+    pub amount: u64,
+}
+
+pub fn transfer_balance(
+    ctx: Context<TransferBalanceContext>,
+    params: &TransferBalanceParams,
+) -> Result<()> {
+    require_keys_neq!(
+        params.recipient,
+        Pubkey::default(),
+        GatewayWalletError::InvalidDepositor
+    );
+
+    let token_mint = params.token;
+    require!(
+        ctx.accounts.gateway_wallet.is_token_supported(token_mint),
+        GatewayWalletError::TokenNotSupported
+    );
+
+    // A depositor is always authorized for its own balance. A delegate must not be revoked.
+    let signer = ctx.accounts.signer.key();
+    utils::validate_current_signer_authorization(
+        &signer,
+        &params.depositor,
+        ctx.accounts.delegate_account.as_ref(),
+    )?;
+
+    // Verify the signer, depositor and recipient are not denylisted
+    require!(
+        !utils::is_account_denylisted(&ctx.accounts.signer_denylist),
+        GatewayWalletError::AccountDenylisted
+    );
+    require!(
+        !utils::is_account_denylisted(&ctx.accounts.depositor_denylist),
+        GatewayWalletError::AccountDenylisted
+    );
+    require!(
+        !utils::is_account_denylisted(&ctx.accounts.recipient_denylist),
+        GatewayWalletError::AccountDenylisted
+    );
+
+    let mut deposit =
+        utils::load_deposit(&ctx.accounts.deposit, &params.depositor, ctx.program_id)?;
+    require!(!deposit.frozen, GatewayWalletError::DepositFrozen);
+
+    let mut recipient_deposit = utils::load_or_create_deposit(
+        &ctx.accounts.recipient_deposit,
+        ctx.bumps.recipient_deposit,
+        token_mint,
+        params.recipient,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.program_id,
+    )?;
+    require!(!recipient_deposit.frozen, GatewayWalletError::DepositFrozen);
+
+    deposit.transfer_balance(&mut recipient_deposit, params.amount)?;
+    utils::save_deposit(
+        &ctx.accounts.deposit,
+        &deposit,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+    utils::save_deposit(
+        &ctx.accounts.recipient_deposit,
+        &recipient_deposit,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
+    emit_cpi!(BalanceTransferred {
+        token: token_mint,
+        depositor: params.depositor,
+        recipient: params.recipient,
+        signer,
+        value: params.amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! UpdateFeeSplits instruction handler
+
+use {
+    crate::{
+        error::GatewayWalletError,
+        events::FeeSplitsChanged,
+        seeds::GATEWAY_WALLET_SEED,
+        state::{FeeSplit, GatewayWallet},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateFeeSplitsContext<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GATEWAY_WALLET_SEED],
+        bump = gateway_wallet.bump,
+        has_one = owner @ GatewayWalletError::InvalidAuthority
+    )]
+    pub gateway_wallet: Box<Account<'info, GatewayWallet>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateFeeSplitsParams {
+    pub fee_splits: Vec<FeeSplit>,
+}
+
+pub fn update_fee_splits(
+    ctx: Context<UpdateFeeSplitsContext>,
+    params: &UpdateFeeSplitsParams,
+) -> Result<()> {
+    let state = ctx.accounts.gateway_wallet.as_mut();
+
+    let old_fee_splits = state.fee_splits.clone();
+    state.set_fee_splits(params.fee_splits.clone())?;
+
+    emit_cpi!(FeeSplitsChanged {
+        old_fee_splits,
+        new_fee_splits: state.fee_splits.clone(),
+    });
+    Ok(())
+}
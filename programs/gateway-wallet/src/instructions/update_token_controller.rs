@@ -24,6 +24,7 @@ use {
         state::GatewayWallet,
     },
     anchor_lang::prelude::*,
+    gateway_shared::admin::TokenRegistry,
 };
 
 #[event_cpi]
@@ -49,20 +50,14 @@ pub fn update_token_controller(
     ctx: Context<UpdateTokenControllerContext>,
     params: &UpdateTokenControllerParams,
 ) -> Result<()> {
-    let state = ctx.accounts.gateway_wallet.as_mut();
-
-    require_keys_neq!(
-        params.new_token_controller,
-        Pubkey::default(),
-        GatewayWalletError::InvalidTokenController
-    );
-
-    let previous_token_controller = state.token_controller;
-    state.token_controller = params.new_token_controller;
+    let previous_token_controller = ctx
+        .accounts
+        .gateway_wallet
+        .update_token_controller(params.new_token_controller)?;
 
     emit_cpi!(TokenControllerUpdated {
         previous_token_controller,
-        new_token_controller: state.token_controller,
+        new_token_controller: params.new_token_controller,
     });
     Ok(())
 }
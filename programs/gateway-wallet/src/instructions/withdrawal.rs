@@ -22,11 +22,16 @@ use {
     crate::{
         error::GatewayWalletError,
         events::WithdrawalCompleted,
-        seeds::{GATEWAY_DEPOSIT_SEED, GATEWAY_WALLET_CUSTODY_SEED, GATEWAY_WALLET_SEED},
-        state::{GatewayDeposit, GatewayWallet},
+        seeds::{
+            GATEWAY_DEPOSIT_SEED, GATEWAY_WALLET_CUSTODY_SEED, GATEWAY_WALLET_SEED,
+            OUTFLOW_LIMIT_SEED, TOKEN_TOTALS_SEED,
+        },
+        state::GatewayWallet,
+        utils,
     },
     anchor_lang::prelude::*,
     anchor_spl::token::{Token, TokenAccount},
+    gateway_shared::admin::TokenRegistry,
 };
 
 #[event_cpi]
@@ -57,19 +62,43 @@ pub struct WithdrawContext<'info> {
     )]
     pub depositor_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Deposit PDA of the depositor for the token
     #[account(
         mut,
-        seeds = [GATEWAY_DEPOSIT_SEED, deposit.token_mint.key().as_ref(), depositor.key().as_ref()],
-        bump = deposit.bump,
-        constraint = deposit.token_mint == custody_token_account.mint
+        seeds = [GATEWAY_DEPOSIT_SEED, custody_token_account.mint.as_ref(), depositor.key().as_ref()],
+        bump
     )]
-    pub deposit: Account<'info, GatewayDeposit>,
+    pub deposit: UncheckedAccount<'info>,
+
+    /// CHECK: Deposit totals PDA for the token. The totals of a token supported before they
+    /// existed are not tracked until they are initialized.
+    #[account(
+        mut,
+        seeds = [TOKEN_TOTALS_SEED, custody_token_account.mint.as_ref()],
+        bump
+    )]
+    pub token_totals: UncheckedAccount<'info>,
+
+    /// CHECK: Outflow limit PDA for the token. No limit applies if it does not exist.
+    #[account(
+        mut,
+        seeds = [OUTFLOW_LIMIT_SEED, custody_token_account.mint.as_ref()],
+        bump
+    )]
+    pub outflow_limit: UncheckedAccount<'info>,
 
     pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn withdraw(ctx: Context<WithdrawContext>) -> Result<()> {
-    let deposit = &mut ctx.accounts.deposit;
+    let mut deposit = utils::load_deposit(
+        &ctx.accounts.deposit,
+        &ctx.accounts.depositor.key(),
+        ctx.program_id,
+    )?;
+    require!(!deposit.frozen, GatewayWalletError::DepositFrozen);
     let gateway_wallet = &ctx.accounts.gateway_wallet;
     let token_mint = ctx.accounts.custody_token_account.mint;
 
@@ -86,6 +115,13 @@ pub fn withdraw(ctx: Context<WithdrawContext>) -> Result<()> {
         GatewayWalletError::WithdrawalDelayNotElapsed
     );
 
+    // Verify the amount leaving custody is within the token's outflow limit
+    utils::record_outflow(
+        &ctx.accounts.outflow_limit,
+        deposit.withdrawing_amount,
+        ctx.program_id,
+    )?;
+
     let signer_seeds: &[&[&[u8]]] = &[&[GATEWAY_WALLET_SEED, &[gateway_wallet.bump]]];
 
     let withdrawal_amount = deposit.complete_withdrawal(
@@ -95,6 +131,17 @@ pub fn withdraw(ctx: Context<WithdrawContext>) -> Result<()> {
         gateway_wallet,
         signer_seeds,
     )?;
+    utils::save_deposit(
+        &ctx.accounts.deposit,
+        &deposit,
+        &ctx.accounts.depositor,
+        &ctx.accounts.system_program,
+    )?;
+    utils::remove_token_deposits(
+        &ctx.accounts.token_totals,
+        withdrawal_amount,
+        ctx.program_id,
+    )?;
 
     emit_cpi!(WithdrawalCompleted {
         token: token_mint,
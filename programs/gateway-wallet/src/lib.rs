@@ -188,4 +188,167 @@ pub mod gateway_wallet {
     ) -> Result<()> {
         instructions::update_fee_recipient(ctx, &params)
     }
+
+    #[instruction(discriminator = [22, 22])]
+    pub fn batch_denylist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchDenylistContext<'info>>,
+        params: BatchDenylistParams,
+    ) -> Result<()> {
+        instructions::batch_denylist(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 23])]
+    pub fn batch_undenylist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchUndenylistContext<'info>>,
+        params: BatchUndenylistParams,
+    ) -> Result<()> {
+        instructions::batch_undenylist(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 24])]
+    pub fn freeze_deposit(ctx: Context<FreezeDepositContext>) -> Result<()> {
+        instructions::freeze_deposit(ctx)
+    }
+
+    #[instruction(discriminator = [22, 25])]
+    pub fn unfreeze_deposit(ctx: Context<UnfreezeDepositContext>) -> Result<()> {
+        instructions::unfreeze_deposit(ctx)
+    }
+
+    #[instruction(discriminator = [22, 26])]
+    pub fn update_fee_splits(
+        ctx: Context<UpdateFeeSplitsContext>,
+        params: UpdateFeeSplitsParams,
+    ) -> Result<()> {
+        instructions::update_fee_splits(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 27])]
+    pub fn set_fee_schedule(
+        ctx: Context<SetFeeScheduleContext>,
+        params: SetFeeScheduleParams,
+    ) -> Result<()> {
+        instructions::set_fee_schedule(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 28])]
+    pub fn enable_destination_domain(
+        ctx: Context<EnableDestinationDomainContext>,
+        params: EnableDestinationDomainParams,
+    ) -> Result<()> {
+        instructions::enable_destination_domain(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 29])]
+    pub fn disable_destination_domain(ctx: Context<DisableDestinationDomainContext>) -> Result<()> {
+        instructions::disable_destination_domain(ctx)
+    }
+
+    #[instruction(discriminator = [22, 30])]
+    pub fn set_outflow_limit(
+        ctx: Context<SetOutflowLimitContext>,
+        params: SetOutflowLimitParams,
+    ) -> Result<()> {
+        instructions::set_outflow_limit(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 31])]
+    pub fn set_outflow_override(
+        ctx: Context<SetOutflowOverrideContext>,
+        params: SetOutflowOverrideParams,
+    ) -> Result<()> {
+        instructions::set_outflow_override(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 32])]
+    pub fn close_used_transfer_spec_hash(
+        ctx: Context<CloseUsedTransferSpecHashContext>,
+        params: CloseUsedTransferSpecHashParams,
+    ) -> Result<()> {
+        instructions::close_used_transfer_spec_hash(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 33])]
+    pub fn set_replay_store(
+        ctx: Context<SetReplayStoreContext>,
+        params: SetReplayStoreParams,
+    ) -> Result<()> {
+        instructions::set_replay_store(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 34])]
+    pub fn set_transfer_receipts(
+        ctx: Context<SetTransferReceiptsContext>,
+        params: SetTransferReceiptsParams,
+    ) -> Result<()> {
+        instructions::set_transfer_receipts(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 35])]
+    pub fn reconcile(ctx: Context<ReconcileContext>) -> Result<()> {
+        instructions::reconcile(ctx)
+    }
+
+    #[instruction(discriminator = [22, 36])]
+    pub fn sweep_custody_surplus(ctx: Context<SweepCustodySurplusContext>) -> Result<()> {
+        instructions::sweep_custody_surplus(ctx)
+    }
+
+    #[instruction(discriminator = [22, 37])]
+    pub fn get_balance(
+        ctx: Context<GetBalanceContext>,
+        params: DepositViewParams,
+    ) -> Result<DepositBalance> {
+        instructions::get_balance(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 38])]
+    pub fn get_withdrawable(
+        ctx: Context<GetWithdrawableContext>,
+        params: DepositViewParams,
+    ) -> Result<u64> {
+        instructions::get_withdrawable(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 39])]
+    pub fn is_authorized_for_balance(
+        ctx: Context<IsAuthorizedForBalanceContext>,
+        params: IsAuthorizedForBalanceParams,
+    ) -> Result<bool> {
+        instructions::is_authorized_for_balance(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 40])]
+    pub fn get_config(ctx: Context<GetConfigContext>) -> Result<WalletConfig> {
+        instructions::get_config(ctx)
+    }
+
+    #[instruction(discriminator = [22, 41])]
+    pub fn transfer_balance(
+        ctx: Context<TransferBalanceContext>,
+        params: TransferBalanceParams,
+    ) -> Result<()> {
+        instructions::transfer_balance(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 42])]
+    pub fn initialize_token_totals(
+        ctx: Context<InitializeTokenTotalsContext>,
+        params: InitializeTokenTotalsParams,
+    ) -> Result<()> {
+        instructions::initialize_token_totals(ctx, &params)
+    }
+
+    #[instruction(discriminator = [22, 43])]
+    pub fn migrate_gateway_wallet(ctx: Context<MigrateGatewayWalletContext>) -> Result<()> {
+        instructions::migrate_gateway_wallet(ctx)
+    }
+
+    #[instruction(discriminator = [22, 44])]
+    pub fn set_destination_domain_enforcement(
+        ctx: Context<SetDestinationDomainEnforcementContext>,
+        params: SetDestinationDomainEnforcementParams,
+    ) -> Result<()> {
+        instructions::set_destination_domain_enforcement(ctx, &params)
+    }
 }
@@ -23,3 +23,7 @@ pub const GATEWAY_WALLET_CUSTODY_SEED: &[u8] = b"gateway_wallet_custody";
 pub const GATEWAY_DEPOSIT_SEED: &[u8] = b"gateway_deposit";
 pub const GATEWAY_DELEGATE_SEED: &[u8] = b"gateway_delegate";
 pub const DENYLIST_SEED: &[u8] = b"denylist";
+pub const FEE_SCHEDULE_SEED: &[u8] = b"fee_schedule";
+pub const DESTINATION_DOMAIN_SEED: &[u8] = b"destination_domain";
+pub const OUTFLOW_LIMIT_SEED: &[u8] = b"outflow_limit";
+pub const TOKEN_TOTALS_SEED: &[u8] = b"token_totals";
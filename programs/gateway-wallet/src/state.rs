@@ -20,6 +20,8 @@ use crate::error::GatewayWalletError;
 use crate::seeds::GATEWAY_WALLET_SEED;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use gateway_shared::admin::{AdminState, Ownable, Pausable, TokenRegistry};
+use gateway_shared::replay::NONCE_BUCKET_BITMAP_SIZE;
 
 /// Delegate status for GatewayDelegate account
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
@@ -32,8 +34,33 @@ pub enum DelegateStatus {
     Revoked,
 }
 
+/// Where gateway burns record used transfers to prevent replay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplayStore {
+    /// Every transfer is recorded in its own used transfer spec hash account
+    HashAccounts,
+    /// Transfers with an explicit nonce are recorded in nonce buckets, after checking that they
+    /// were not recorded in a used transfer spec hash account before the migration
+    Migrating,
+    /// Transfers with an explicit nonce are recorded in nonce buckets only
+    NonceBuckets,
+}
+
 pub const MAX_SUPPORTED_TOKENS: usize = 10;
 pub const MAX_BURN_SIGNERS: usize = 10;
+pub const MAX_FEE_SPLITS: usize = 5;
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+/// A share of burn fees paid to a partner fee recipient
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeSplit {
+    /// The wallet whose associated token account receives the share
+    pub recipient: Pubkey,
+    /// The share of each fee, in basis points
+    pub bps: u16,
+}
 
 #[account(discriminator = [21, 0])]
 #[derive(Debug, InitSpace)]
@@ -56,6 +83,15 @@ pub struct GatewayWallet {
     pub custody_token_account_bumps: Vec<u8>,
     #[max_len(MAX_BURN_SIGNERS)]
     pub burn_signers: Vec<Pubkey>,
+    #[max_len(MAX_FEE_SPLITS)]
+    pub fee_splits: Vec<FeeSplit>,
+    pub replay_store: ReplayStore,
+    pub transfer_receipts_enabled: bool,
+    /// Whether burns to a destination domain that was never registered are rejected. Until the
+    /// owner turns this on, only registered domains are checked against the registry.
+    pub destination_domains_enforced: bool,
+    /// The slot at which burns started migrating to the `Migrating` replay store
+    pub replay_migration_started_slot: u64,
 }
 
 #[account(discriminator = [21, 1])]
@@ -68,6 +104,7 @@ pub struct GatewayDeposit {
     pub available_amount: u64,
     pub withdrawing_amount: u64,
     pub withdrawal_block: u64,
+    pub frozen: bool,
 }
 
 #[account(discriminator = [21, 2])]
@@ -110,6 +147,21 @@ impl GatewayDelegate {
         // Otherwise, check that the stored authorization status is either `Authorized` or `Revoked`
         self.status != DelegateStatus::Unauthorized
     }
+
+    /// Check if an address is currently authorized to transfer tokens on behalf of a depositor.
+    /// Unlike `was_ever_authorized_for_balance`, this excludes revoked authorizations.
+    ///
+    /// @param depositor   The depositor to check against
+    /// @param addr        The address to check
+    /// @return            `true` if the address is authorized, `false` otherwise
+    pub fn is_authorized_for_balance(&self, depositor: Pubkey, addr: Pubkey) -> bool {
+        // A depositor is always authorized for its own balance
+        if addr == depositor {
+            return true;
+        }
+
+        self.status == DelegateStatus::Authorized
+    }
 }
 
 #[account(discriminator = [21, 3])]
@@ -118,46 +170,316 @@ impl GatewayDelegate {
 pub struct Denylist {}
 
 #[account(discriminator = [21, 4])]
+#[derive(Debug, InitSpace)]
 /// Used transfer spec hash state for a transfer spec hash
-pub struct UsedTransferSpecHash;
+pub struct UsedTransferSpecHash {
+    /// The last slot at which the transfer spec hash can be used
+    pub expiry_slot: u64,
+    /// The account that paid the rent, refunded when the account is closed
+    pub rent_payer: Pubkey,
+    /// How the burn was settled, if transfer receipts were enabled when it was executed
+    pub receipt: Option<BurnReceipt>,
+}
 
-impl GatewayWallet {
-    const BURN_SIGNATURE_LENGTH: usize = 65;
+/// Settlement details of a burn, stored in its used transfer spec hash account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+pub struct BurnReceipt {
+    /// The slot in which the burn was executed
+    pub slot: u64,
+    /// The token mint key
+    pub token: Pubkey,
+    /// The depositor whose balance was burned
+    pub depositor: Pubkey,
+    /// The amount burned
+    pub value: u64,
+    /// The fee charged, which is below the intent's fee if the balance was insufficient
+    pub fee: u64,
+}
+
+#[account(discriminator = [21, 5])]
+#[derive(Debug, InitSpace)]
+/// Minimum fee configuration for burns of a token to a destination domain
+pub struct FeeSchedule {
+    /// The bump of the fee schedule account
+    pub bump: u8,
+    /// The token mint key
+    pub token: Pubkey,
+    /// The destination domain the schedule applies to
+    pub destination_domain: u32,
+    /// The minimum fee, in token base units
+    pub min_fee: u64,
+    /// The minimum fee as a share of the burn value, in basis points
+    pub fee_bps: u16,
+}
+
+#[account(discriminator = [21, 6])]
+#[derive(Debug, InitSpace)]
+/// Registry entry for a destination domain that burns may target
+pub struct DestinationDomain {
+    /// The bump of the destination domain account
+    pub bump: u8,
+    /// The destination domain
+    pub domain: u32,
+    /// The GatewayMinter contract that burn intents to this domain must target
+    pub destination_contract: Pubkey,
+    /// Whether burns to this domain are currently allowed
+    pub enabled: bool,
+}
+
+#[account(discriminator = [21, 7])]
+#[derive(Debug, InitSpace)]
+/// Sliding-window cap on the amount of a token leaving custody through burns and withdrawals
+///
+/// Outflow is tracked in two fixed windows. The outflow in the sliding window ending at the
+/// current slot is estimated as the current window's outflow plus the previous window's
+/// outflow weighted by how much of the previous window the sliding window still covers.
+pub struct OutflowLimit {
+    /// The bump of the outflow limit account
+    pub bump: u8,
+    /// The token mint key
+    pub token: Pubkey,
+    /// The length of the window in slots. Zero disables the limit.
+    pub window_slots: u64,
+    /// The maximum outflow within a window, in token base units
+    pub max_outflow: u64,
+    /// The first slot of the current window
+    pub current_window_start: u64,
+    /// The outflow recorded in the current window
+    pub current_window_outflow: u64,
+    /// The outflow recorded in the window before the current window
+    pub previous_window_outflow: u64,
+    /// An owner-granted amount that may flow out beyond the limit, for planned large movements
+    pub override_allowance: u64,
+}
 
-    pub fn is_token_supported(&self, token_mint: Pubkey) -> bool {
-        self.supported_tokens.contains(&token_mint)
+#[account(discriminator = [21, 8])]
+#[derive(Debug, InitSpace)]
+/// Bitmap of used nonces, shared by the transfers of a namespace with nonces in the same range
+pub struct NonceBucket {
+    /// The source domain of the transfers
+    pub source_domain: u32,
+    /// The source contract of the transfers
+    pub source_contract: Pubkey,
+    /// The source depositor of the transfers
+    pub source_depositor: Pubkey,
+    /// The index of the bucket, which records nonces from `bucket_index * NONCES_PER_BUCKET`
+    pub bucket_index: u64,
+    /// One bit per nonce, set once the nonce is used
+    pub bitmap: [u8; NONCE_BUCKET_BITMAP_SIZE],
+}
+
+#[account(discriminator = [21, 9])]
+#[derive(Debug, InitSpace)]
+/// Running totals of the deposits of a supported token, which the custody token account must cover
+pub struct TokenTotals {
+    /// The bump of the token totals account
+    pub bump: u8,
+    /// The token mint key
+    pub token: Pubkey,
+    /// The sum of `available_amount` and `withdrawing_amount` over every deposit of the token
+    pub total_deposits: u64,
+    /// Whether `total_deposits` started from the audited total of a token supported before the
+    /// totals existed rather than from zero. A custody surplus of such a token cannot be told
+    /// apart from deposits the audit missed, so it is not swept.
+    pub initialized_from_audit: bool,
+}
+
+impl TokenTotals {
+    /// Adds deposited tokens to the total deposits
+    pub fn add_deposits(&mut self, amount: u64) -> Result<()> {
+        self.total_deposits = self
+            .total_deposits
+            .checked_add(amount)
+            .ok_or(GatewayWalletError::TotalDepositsOverflow)?;
+        Ok(())
     }
 
-    pub fn get_token_index(&self, token_mint: Pubkey) -> Option<usize> {
-        self.supported_tokens
-            .iter()
-            .position(|token| token == &token_mint)
+    /// Removes tokens that left custody from the total deposits
+    pub fn remove_deposits(&mut self, amount: u64) -> Result<()> {
+        self.total_deposits = self
+            .total_deposits
+            .checked_sub(amount)
+            .ok_or(GatewayWalletError::TotalDepositsUnderflow)?;
+        Ok(())
     }
 
-    pub fn add_token(&mut self, token_mint: Pubkey, bump: u8) -> Result<()> {
-        if self.is_token_supported(token_mint) {
-            return Ok(());
+    /// Compares the total deposits with the balance of the custody token account
+    ///
+    /// # Returns
+    /// * `(surplus, deficit)` - How much the custody balance exceeds the total deposits, and how
+    ///   much it falls short of them. At most one of them is non-zero.
+    pub fn reconcile(&self, custody_balance: u64) -> (u64, u64) {
+        (
+            custody_balance.saturating_sub(self.total_deposits),
+            self.total_deposits.saturating_sub(custody_balance),
+        )
+    }
+}
+
+impl FeeSchedule {
+    /// Computes the minimum fee required to burn a value
+    ///
+    /// The basis-point fee is rounded up, so that a non-zero rate never allows a zero fee.
+    ///
+    /// # Arguments
+    /// * `value` - The value being burned
+    ///
+    /// # Returns
+    /// * The larger of `min_fee` and `fee_bps` of `value`
+    pub fn required_fee(&self, value: u64) -> u64 {
+        let denominator = BPS_DENOMINATOR as u128;
+        let bps_fee = (value as u128 * self.fee_bps as u128).div_ceil(denominator);
+
+        self.min_fee.max(bps_fee as u64)
+    }
+}
+
+impl OutflowLimit {
+    pub fn is_enabled(&self) -> bool {
+        self.window_slots > 0
+    }
+
+    /// Updates the window length and maximum outflow
+    ///
+    /// Recorded outflow is kept if the window length is unchanged, and cleared otherwise.
+    pub fn configure(&mut self, window_slots: u64, max_outflow: u64, slot: u64) {
+        if window_slots != self.window_slots {
+            self.current_window_start = slot;
+            self.current_window_outflow = 0;
+            self.previous_window_outflow = 0;
         }
+        self.window_slots = window_slots;
+        self.max_outflow = max_outflow;
+    }
 
-        if self.supported_tokens.len() >= MAX_SUPPORTED_TOKENS {
-            return err!(GatewayWalletError::MaxTokensSupported);
+    /// Advances the windows so that the current window contains `slot`
+    fn advance_windows(&mut self, slot: u64) {
+        let elapsed_windows = slot.saturating_sub(self.current_window_start) / self.window_slots;
+        if elapsed_windows == 0 {
+            return;
         }
 
-        self.supported_tokens.push(token_mint);
-        self.custody_token_account_bumps.push(bump);
+        self.previous_window_outflow = if elapsed_windows == 1 {
+            self.current_window_outflow
+        } else {
+            0
+        };
+        self.current_window_outflow = 0;
+        self.current_window_start = self
+            .current_window_start
+            .saturating_add(elapsed_windows.saturating_mul(self.window_slots));
+    }
 
-        Ok(())
+    /// Estimates the outflow in the sliding window ending at `slot`
+    ///
+    /// Assumes the windows have been advanced to `slot`.
+    fn sliding_window_outflow(&self, slot: u64) -> u64 {
+        let window_slots = self.window_slots as u128;
+        let elapsed_slots = slot.saturating_sub(self.current_window_start) as u128;
+        let previous_weight = window_slots.saturating_sub(elapsed_slots);
+        let weighted_previous =
+            self.previous_window_outflow as u128 * previous_weight / window_slots;
+
+        (weighted_previous as u64).saturating_add(self.current_window_outflow)
     }
 
-    pub fn get_custody_token_account_bump(&self, token_mint: Pubkey) -> Result<u8> {
-        let index = self.get_token_index(token_mint);
-        if index.is_none() {
-            return err!(GatewayWalletError::TokenNotSupported);
+    /// Returns how much can leave custody at `slot` under the limit and the override allowance,
+    /// or `u64::MAX` if the limit is disabled
+    pub fn remaining_outflow(&self, slot: u64) -> u64 {
+        if !self.is_enabled() {
+            return u64::MAX;
         }
 
-        Ok(self.custody_token_account_bumps[index.unwrap()])
+        let mut outflow_limit = self.clone();
+        outflow_limit.advance_windows(slot);
+        outflow_limit
+            .max_outflow
+            .saturating_sub(outflow_limit.sliding_window_outflow(slot))
+            .saturating_add(outflow_limit.override_allowance)
     }
 
+    /// Records an outflow, failing if it exceeds the limit
+    ///
+    /// The part of the outflow that does not fit under the limit is taken from the override
+    /// allowance.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount leaving custody
+    /// * `slot` - The current slot
+    ///
+    /// # Errors
+    /// Returns `OutflowLimitExceeded` if the amount exceeds the remaining limit and override allowance
+    pub fn record_outflow(&mut self, amount: u64, slot: u64) -> Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        self.advance_windows(slot);
+        let remaining_limit = self
+            .max_outflow
+            .saturating_sub(self.sliding_window_outflow(slot));
+        let within_limit = amount.min(remaining_limit);
+
+        self.override_allowance = self
+            .override_allowance
+            .checked_sub(amount - within_limit)
+            .ok_or(GatewayWalletError::OutflowLimitExceeded)?;
+        self.current_window_outflow = self.current_window_outflow.saturating_add(within_limit);
+
+        Ok(())
+    }
+}
+
+impl AdminState for GatewayWallet {
+    type Error = GatewayWalletError;
+}
+
+impl Ownable for GatewayWallet {
+    fn owner_mut(&mut self) -> &mut Pubkey {
+        &mut self.owner
+    }
+
+    fn pending_owner_mut(&mut self) -> &mut Pubkey {
+        &mut self.pending_owner
+    }
+}
+
+impl Pausable for GatewayWallet {
+    fn pauser_mut(&mut self) -> &mut Pubkey {
+        &mut self.pauser
+    }
+
+    fn paused_mut(&mut self) -> &mut bool {
+        &mut self.paused
+    }
+}
+
+impl TokenRegistry for GatewayWallet {
+    const MAX_SUPPORTED_TOKENS: usize = MAX_SUPPORTED_TOKENS;
+
+    fn token_controller_mut(&mut self) -> &mut Pubkey {
+        &mut self.token_controller
+    }
+
+    fn supported_tokens(&self) -> &[Pubkey] {
+        &self.supported_tokens
+    }
+
+    fn custody_token_account_bumps(&self) -> &[u8] {
+        &self.custody_token_account_bumps
+    }
+
+    fn token_registry_mut(&mut self) -> (&mut Vec<Pubkey>, &mut Vec<u8>) {
+        (
+            &mut self.supported_tokens,
+            &mut self.custody_token_account_bumps,
+        )
+    }
+}
+
+impl GatewayWallet {
+    const BURN_SIGNATURE_LENGTH: usize = 65;
+
     pub fn is_burn_signer(&self, signer: Pubkey) -> bool {
         self.burn_signers.contains(&signer)
     }
@@ -219,6 +541,60 @@ impl GatewayWallet {
         Ok(())
     }
 
+    pub fn set_fee_splits(&mut self, fee_splits: Vec<FeeSplit>) -> Result<()> {
+        require_gte!(
+            MAX_FEE_SPLITS,
+            fee_splits.len(),
+            GatewayWalletError::FeeSplitLimitExceeded
+        );
+
+        let mut total_bps: u16 = 0;
+        for (i, split) in fee_splits.iter().enumerate() {
+            require_keys_neq!(
+                split.recipient,
+                Pubkey::default(),
+                GatewayWalletError::InvalidFeeSplit
+            );
+            require_gt!(split.bps, 0, GatewayWalletError::InvalidFeeSplit);
+            require!(
+                !fee_splits[..i]
+                    .iter()
+                    .any(|other| other.recipient == split.recipient),
+                GatewayWalletError::InvalidFeeSplit
+            );
+
+            total_bps = total_bps
+                .checked_add(split.bps)
+                .ok_or(GatewayWalletError::FeeSplitBpsExceedsMax)?;
+        }
+        require_gte!(
+            BPS_DENOMINATOR,
+            total_bps,
+            GatewayWalletError::FeeSplitBpsExceedsMax
+        );
+
+        self.fee_splits = fee_splits;
+
+        Ok(())
+    }
+
+    /// Computes each partner's share of a fee, in the order of `fee_splits`
+    ///
+    /// Each share is rounded down, so the shares never sum to more than `fee`. Whatever
+    /// is left after the partner shares belongs to the primary fee recipient.
+    ///
+    /// # Arguments
+    /// * `fee` - The total fee charged
+    ///
+    /// # Returns
+    /// * The amount owed to each fee split recipient
+    pub fn compute_fee_shares(&self, fee: u64) -> Vec<u64> {
+        self.fee_splits
+            .iter()
+            .map(|split| (fee as u128 * split.bps as u128 / BPS_DENOMINATOR as u128) as u64)
+            .collect()
+    }
+
     /// Burn tokens from custody
     ///
     /// # Arguments
@@ -259,14 +635,6 @@ impl GatewayWallet {
 }
 
 impl GatewayDeposit {
-    pub fn initialize_if_needed(&mut self, bump: u8, depositor: Pubkey, token_mint: Pubkey) {
-        if self.bump == 0 {
-            self.bump = bump;
-            self.depositor = depositor;
-            self.token_mint = token_mint;
-        }
-    }
-
     pub fn deposit<'info>(
         &mut self,
         token_program: &Program<'info, Token>,
@@ -293,6 +661,24 @@ impl GatewayDeposit {
         Ok(())
     }
 
+    /// Moves part of the available balance to another deposit of the same token
+    ///
+    /// # Arguments
+    /// * `recipient` - The deposit that receives the balance
+    /// * `amount` - The amount to move
+    pub fn transfer_balance(&mut self, recipient: &mut GatewayDeposit, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, GatewayWalletError::InvalidTransferAmount);
+        require!(
+            amount <= self.available_amount,
+            GatewayWalletError::InsufficientDepositBalance
+        );
+
+        self.available_amount -= amount;
+        recipient.available_amount += amount;
+
+        Ok(())
+    }
+
     pub fn initiate_withdrawal(
         &mut self,
         amount: u64,
@@ -19,12 +19,20 @@
 //! Common utility functions.
 
 use {
-    crate::{error::GatewayWalletError, state::GatewayDelegate},
+    crate::{
+        error::GatewayWalletError,
+        seeds::GATEWAY_DEPOSIT_SEED,
+        state::{Denylist, GatewayDelegate, GatewayDeposit, OutflowLimit, TokenTotals},
+    },
     anchor_lang::prelude::*,
+    gateway_shared::{create_pda_account, deserialize_padded},
 };
 
 // Re-export from shared library for convenience
-pub use gateway_shared::DISCRIMINATOR_SIZE;
+pub use gateway_shared::{grow_account, load_if_initialized, DISCRIMINATOR_SIZE};
+
+/// The space allocated for a deposit account
+const DEPOSIT_SPACE: usize = DISCRIMINATOR_SIZE + GatewayDeposit::INIT_SPACE;
 
 /// Check if denylist account exists
 ///
@@ -39,6 +47,220 @@ pub fn is_account_denylisted<'info>(denylist_account: &UncheckedAccount<'info>)
     !denylist_account.data_is_empty()
 }
 
+/// Check if a denylist PDA has already been created by this program
+///
+/// # Arguments
+/// * `denylist_account` - The denylist PDA account info
+/// * `program_id` - The GatewayWallet program ID
+///
+/// # Returns
+/// * `true` - Account is owned by the program and holds the Denylist discriminator
+/// * `false` - Account has not been created yet
+pub fn is_denylist_account_initialized(
+    denylist_account: &AccountInfo,
+    program_id: &Pubkey,
+) -> bool {
+    denylist_account.owner == program_id
+        && denylist_account
+            .try_borrow_data()
+            .is_ok_and(|data| data.starts_with(Denylist::DISCRIMINATOR))
+}
+
+/// Deserializes a deposit PDA that may not have been created yet. Deposits created before fields
+/// were appended to the layout are read with those fields set to their defaults.
+///
+/// # Arguments
+/// * `deposit_account` - The deposit PDA account info
+/// * `program_id` - The GatewayWallet program ID
+///
+/// # Returns
+/// * `Ok(Some(deposit))` if the account is owned by the program
+/// * `Ok(None)` if the account has not been created yet
+pub fn load_deposit_if_initialized(
+    deposit_account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<Option<GatewayDeposit>> {
+    if deposit_account.owner != program_id {
+        return Ok(None);
+    }
+
+    let data = deposit_account.try_borrow_data()?;
+    Ok(Some(deserialize_padded(&data, DEPOSIT_SPACE)?))
+}
+
+/// Deserializes the existing deposit of a depositor
+///
+/// # Arguments
+/// * `deposit_account` - The deposit PDA account info
+/// * `depositor` - The depositor the deposit must belong to
+/// * `program_id` - The GatewayWallet program ID
+///
+/// # Returns
+/// * `Ok(deposit)` if the account is a deposit of `depositor`
+/// * `Err` if the account has not been created, or belongs to another depositor
+pub fn load_deposit(
+    deposit_account: &AccountInfo,
+    depositor: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<GatewayDeposit> {
+    let deposit = load_deposit_if_initialized(deposit_account, program_id)?
+        .ok_or(ErrorCode::AccountNotInitialized)?;
+
+    // Deposit accounts are only ever created at the PDA of their depositor and token, so this
+    // is equivalent to checking the account's seeds
+    require_keys_eq!(deposit.depositor, *depositor, ErrorCode::ConstraintSeeds);
+
+    Ok(deposit)
+}
+
+/// Deserializes the deposit of a depositor, creating the account if this is the depositor's first
+/// deposit of the token
+///
+/// # Arguments
+/// * `deposit_account` - The deposit PDA account info (with seeds constraint)
+/// * `bump` - The bump of the deposit PDA
+/// * `token_mint` - The token mint key
+/// * `depositor` - The depositor key
+/// * `payer` - The account that pays the rent if the account is created
+/// * `system_program` - The system program account info
+/// * `program_id` - The GatewayWallet program ID
+pub fn load_or_create_deposit<'info>(
+    deposit_account: &AccountInfo<'info>,
+    bump: u8,
+    token_mint: Pubkey,
+    depositor: Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+) -> Result<GatewayDeposit> {
+    if let Some(deposit) = load_deposit_if_initialized(deposit_account, program_id)? {
+        return Ok(deposit);
+    }
+
+    create_pda_account(
+        deposit_account,
+        DEPOSIT_SPACE,
+        &[
+            GATEWAY_DEPOSIT_SEED,
+            token_mint.as_ref(),
+            depositor.as_ref(),
+            &[bump],
+        ],
+        payer,
+        system_program,
+        program_id,
+    )?;
+
+    Ok(GatewayDeposit {
+        bump,
+        depositor,
+        token_mint,
+        available_amount: 0,
+        withdrawing_amount: 0,
+        withdrawal_block: 0,
+        frozen: false,
+    })
+}
+
+/// Writes a deposit to its account, first growing accounts created before fields were appended to
+/// the layout
+///
+/// # Arguments
+/// * `deposit_account` - The deposit PDA account info
+/// * `deposit` - The deposit state to write
+/// * `payer` - The account that pays the additional rent if the account is grown
+/// * `system_program` - The system program account info
+pub fn save_deposit<'info>(
+    deposit_account: &AccountInfo<'info>,
+    deposit: &GatewayDeposit,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    grow_account(deposit_account, DEPOSIT_SPACE, payer, system_program)?;
+    deposit.try_serialize(&mut &mut deposit_account.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// Records an outflow against a token's outflow limit, if the owner has set one
+///
+/// # Arguments
+/// * `outflow_limit_account` - The outflow limit PDA (with seeds constraint)
+/// * `amount` - The amount leaving custody
+/// * `program_id` - The GatewayWallet program ID
+///
+/// # Returns
+/// * `Ok(())` if the outflow is within the limit, or no limit has been set
+/// * `Err(GatewayWalletError::OutflowLimitExceeded)` if the outflow exceeds the limit
+pub fn record_outflow(
+    outflow_limit_account: &AccountInfo,
+    amount: u64,
+    program_id: &Pubkey,
+) -> Result<()> {
+    // No limit applies until the owner sets one
+    let Some(mut outflow_limit) =
+        load_if_initialized::<OutflowLimit>(outflow_limit_account, program_id)?
+    else {
+        return Ok(());
+    };
+
+    outflow_limit.record_outflow(amount, Clock::get()?.slot)?;
+    outflow_limit.try_serialize(&mut &mut outflow_limit_account.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// Adds deposited tokens to a token's deposit totals, once they are tracked
+///
+/// # Arguments
+/// * `token_totals_account` - The token totals PDA (with seeds constraint)
+/// * `amount` - The amount deposited into custody
+/// * `program_id` - The GatewayWallet program ID
+pub fn add_token_deposits(
+    token_totals_account: &AccountInfo,
+    amount: u64,
+    program_id: &Pubkey,
+) -> Result<()> {
+    update_token_totals(token_totals_account, program_id, |token_totals| {
+        token_totals.add_deposits(amount)
+    })
+}
+
+/// Removes tokens that left custody from a token's deposit totals, once they are tracked
+///
+/// # Arguments
+/// * `token_totals_account` - The token totals PDA (with seeds constraint)
+/// * `amount` - The amount leaving custody
+/// * `program_id` - The GatewayWallet program ID
+pub fn remove_token_deposits(
+    token_totals_account: &AccountInfo,
+    amount: u64,
+    program_id: &Pubkey,
+) -> Result<()> {
+    update_token_totals(token_totals_account, program_id, |token_totals| {
+        token_totals.remove_deposits(amount)
+    })
+}
+
+// The totals of a token supported before they existed are not tracked until the token controller
+// initializes them from the audited total deposits
+fn update_token_totals(
+    token_totals_account: &AccountInfo,
+    program_id: &Pubkey,
+    update: impl FnOnce(&mut TokenTotals) -> Result<()>,
+) -> Result<()> {
+    let Some(mut token_totals) =
+        load_if_initialized::<TokenTotals>(token_totals_account, program_id)?
+    else {
+        return Ok(());
+    };
+
+    update(&mut token_totals)?;
+    token_totals.try_serialize(&mut &mut token_totals_account.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
 /// Validates that a signer was ever authorized for a depositor's balance.
 /// A depositor is always authorized for their own balance.
 /// Otherwise, checks for a delegate account with Authorized or Revoked status.
@@ -55,6 +277,46 @@ pub fn validate_signer_authorization<'info>(
     source_signer: &Pubkey,
     source_depositor: &Pubkey,
     delegate_account: Option<&Account<'info, GatewayDelegate>>,
+) -> Result<()> {
+    validate_delegate_authorization(
+        source_signer,
+        source_depositor,
+        delegate_account,
+        GatewayDelegate::was_ever_authorized_for_balance,
+    )
+}
+
+/// Validates that a signer is currently authorized for a depositor's balance.
+/// A depositor is always authorized for their own balance.
+/// Otherwise, checks for a delegate account with Authorized status, so a revoked delegate is
+/// rejected.
+///
+/// # Arguments
+/// * `source_signer` - The signer to validate
+/// * `source_depositor` - The depositor whose balance is moved
+/// * `delegate_account` - Optional delegate account if signer != depositor
+///
+/// # Returns
+/// * `Ok(())` if the signer is authorized
+/// * `Err` if the signer is not authorized
+pub fn validate_current_signer_authorization<'info>(
+    source_signer: &Pubkey,
+    source_depositor: &Pubkey,
+    delegate_account: Option<&Account<'info, GatewayDelegate>>,
+) -> Result<()> {
+    validate_delegate_authorization(
+        source_signer,
+        source_depositor,
+        delegate_account,
+        GatewayDelegate::is_authorized_for_balance,
+    )
+}
+
+fn validate_delegate_authorization<'info>(
+    source_signer: &Pubkey,
+    source_depositor: &Pubkey,
+    delegate_account: Option<&Account<'info, GatewayDelegate>>,
+    is_authorized: fn(&GatewayDelegate, Pubkey, Pubkey) -> bool,
 ) -> Result<()> {
     // A depositor is always authorized for their own balance
     if source_signer == source_depositor {
@@ -79,7 +341,7 @@ pub fn validate_signer_authorization<'info>(
     );
 
     require!(
-        delegate_account.was_ever_authorized_for_balance(*source_depositor, *source_signer),
+        is_authorized(delegate_account, *source_depositor, *source_signer),
         GatewayWalletError::DelegateSignerNotAuthorized
     );
 
@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use anchor_lang::prelude::Pubkey;
+use gateway_wallet::burn_data::{BurnData, BurnDataStruct, BurnIntent, TransferSpec};
+
+fn transfer_spec(hook_data: Vec<u8>) -> TransferSpec {
+    TransferSpec {
+        version: 1,
+        source_domain: 5,
+        destination_domain: 6,
+        source_contract: Pubkey::new_unique(),
+        destination_contract: Pubkey::new_unique(),
+        source_token: Pubkey::new_unique(),
+        destination_token: Pubkey::new_unique(),
+        source_depositor: Pubkey::new_unique(),
+        destination_recipient: Pubkey::new_unique(),
+        source_signer: Pubkey::new_unique(),
+        destination_caller: Pubkey::default(),
+        value: u64::MAX,
+        salt: [7; 32],
+        hook_data,
+    }
+}
+
+fn burn_data(hook_data: Vec<u8>) -> BurnDataStruct {
+    BurnDataStruct {
+        fee: 42,
+        user_signature: [9; 64],
+        burn_intent: BurnIntent {
+            max_block_height: 1_000_000,
+            max_fee: u64::MAX - 1,
+            transfer_spec: transfer_spec(hook_data),
+        },
+    }
+}
+
+fn assert_matches_parser(expected: &BurnDataStruct, encoded: &[u8]) {
+    let parsed = BurnData::new(encoded).unwrap();
+    let intent = &expected.burn_intent;
+    let spec = &intent.transfer_spec;
+
+    assert_eq!(parsed.fee().unwrap(), expected.fee);
+    assert_eq!(parsed.user_signature().unwrap(), expected.user_signature);
+    assert_eq!(parsed.max_block_height().unwrap(), intent.max_block_height);
+    assert_eq!(parsed.max_fee().unwrap(), intent.max_fee);
+    assert_eq!(parsed.encoded_transfer_spec().unwrap(), spec.encode());
+    assert_eq!(parsed.transfer_spec_hash().unwrap(), spec.hash());
+    assert_eq!(parsed.version().unwrap(), spec.version);
+    assert_eq!(parsed.source_domain().unwrap(), spec.source_domain);
+    assert_eq!(
+        parsed.destination_domain().unwrap(),
+        spec.destination_domain
+    );
+    assert_eq!(parsed.source_contract().unwrap(), spec.source_contract);
+    assert_eq!(
+        parsed.destination_contract().unwrap(),
+        spec.destination_contract
+    );
+    assert_eq!(parsed.source_token().unwrap(), spec.source_token);
+    assert_eq!(parsed.destination_token().unwrap(), spec.destination_token);
+    assert_eq!(parsed.source_depositor().unwrap(), spec.source_depositor);
+    assert_eq!(
+        parsed.destination_recipient().unwrap(),
+        spec.destination_recipient
+    );
+    assert_eq!(parsed.source_signer().unwrap(), spec.source_signer);
+    assert_eq!(
+        parsed.destination_caller().unwrap(),
+        spec.destination_caller
+    );
+    assert_eq!(parsed.value().unwrap(), spec.value);
+    assert_eq!(parsed.salt().unwrap(), spec.salt);
+    assert_eq!(parsed.hook_data().unwrap(), spec.hook_data.as_slice());
+}
+
+#[test]
+fn encoded_burn_data_matches_parser() {
+    for hook_data in [vec![], vec![1, 2, 3, 4, 5]] {
+        let burn_data = burn_data(hook_data);
+        let encoded = burn_data.encode();
+
+        let hook_data_length = burn_data.burn_intent.transfer_spec.hook_data.len();
+        assert_eq!(encoded.len(), 500 + hook_data_length);
+        assert_matches_parser(&burn_data, &encoded);
+    }
+}
+
+#[test]
+fn burn_data_round_trips_byte_for_byte() {
+    let burn_data = burn_data(vec![0xab; 33]);
+    let encoded = burn_data.encode();
+
+    let decoded = BurnDataStruct::decode(&encoded).unwrap();
+    assert_eq!(decoded, burn_data);
+    assert_eq!(decoded.encode(), encoded);
+}
+
+#[test]
+fn burn_intent_and_transfer_spec_round_trip() {
+    let burn_intent = burn_data(vec![0xcd; 7]).burn_intent;
+
+    let encoded_intent = burn_intent.encode();
+    assert_eq!(BurnIntent::decode(&encoded_intent).unwrap(), burn_intent);
+    assert_eq!(
+        burn_intent.message()[..16],
+        BurnData::BURN_INTENT_MESSAGE_PREFIX
+    );
+    assert_eq!(burn_intent.message()[16..], encoded_intent[..]);
+
+    let encoded_spec = burn_intent.transfer_spec.encode();
+    assert_eq!(encoded_intent[72..], encoded_spec[..]);
+    assert_eq!(
+        TransferSpec::decode(&encoded_spec).unwrap(),
+        burn_intent.transfer_spec
+    );
+}
+
+#[test]
+fn u256_fields_are_left_padded() {
+    let burn_data = burn_data(vec![]);
+    let encoded = burn_data.encode();
+
+    // max_block_height, max_fee and value are u256 fields holding a u64 in the low 8 bytes
+    for (offset, value) in [
+        (92, burn_data.burn_intent.max_block_height),
+        (124, burn_data.burn_intent.max_fee),
+        (432, burn_data.burn_intent.transfer_spec.value),
+    ] {
+        assert_eq!(encoded[offset..offset + 24], [0; 24]);
+        assert_eq!(encoded[offset + 24..offset + 32], value.to_be_bytes());
+    }
+}
+
+#[test]
+fn decode_rejects_malformed_input() {
+    let mut encoded = burn_data(vec![]).encode();
+    encoded[BurnData::BURN_INTENT_OFFSET] ^= 0xff;
+    assert!(BurnDataStruct::decode(&encoded).is_err());
+
+    let encoded_spec = transfer_spec(vec![1]).encode();
+    assert!(TransferSpec::decode(&encoded_spec[..encoded_spec.len() - 1]).is_err());
+}
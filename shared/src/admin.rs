@@ -0,0 +1,313 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Ownership, pausing and token registry logic shared by the Gateway program states.
+//!
+//! Each program keeps its own instructions, events and error codes, and implements these traits
+//! for its state account by exposing the fields they manage. The behavior itself lives here.
+
+use anchor_lang::prelude::*;
+
+/// Failures of the shared admin logic, which each program converts into its own error code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdminError {
+    InvalidPauser,
+    InvalidTokenController,
+    MaxTokensSupported,
+    TokenNotSupported,
+}
+
+/// A program state account that uses the shared admin logic
+pub trait AdminState {
+    /// The program's error type
+    type Error: From<AdminError> + Into<Error>;
+
+    /// Converts an admin failure into the program's error
+    fn admin_error(error: AdminError) -> Error {
+        Self::Error::from(error).into()
+    }
+}
+
+/// Two-step ownership transfers
+pub trait Ownable: AdminState {
+    fn owner_mut(&mut self) -> &mut Pubkey;
+
+    fn pending_owner_mut(&mut self) -> &mut Pubkey;
+
+    /// Nominates `new_owner`, who becomes the owner once it accepts. Returns the current owner.
+    fn transfer_ownership(&mut self, new_owner: Pubkey) -> Pubkey {
+        *self.pending_owner_mut() = new_owner;
+        *self.owner_mut()
+    }
+
+    /// Makes the pending owner the owner. Returns the previous owner.
+    fn accept_ownership(&mut self) -> Pubkey {
+        let new_owner = std::mem::take(self.pending_owner_mut());
+        std::mem::replace(self.owner_mut(), new_owner)
+    }
+}
+
+/// A pauser that can halt the program
+pub trait Pausable: AdminState {
+    fn pauser_mut(&mut self) -> &mut Pubkey;
+
+    fn paused_mut(&mut self) -> &mut bool;
+
+    /// Replaces the pauser. Returns the previous pauser.
+    fn update_pauser(&mut self, new_pauser: Pubkey) -> Result<Pubkey> {
+        if new_pauser == Pubkey::default() {
+            return Err(Self::admin_error(AdminError::InvalidPauser));
+        }
+
+        Ok(std::mem::replace(self.pauser_mut(), new_pauser))
+    }
+
+    fn pause(&mut self) {
+        *self.paused_mut() = true;
+    }
+
+    fn unpause(&mut self) {
+        *self.paused_mut() = false;
+    }
+}
+
+/// Supported tokens, their custody token account bumps, and the token controller that manages them
+pub trait TokenRegistry: AdminState {
+    /// The maximum number of supported tokens
+    const MAX_SUPPORTED_TOKENS: usize;
+
+    fn token_controller_mut(&mut self) -> &mut Pubkey;
+
+    fn supported_tokens(&self) -> &[Pubkey];
+
+    fn custody_token_account_bumps(&self) -> &[u8];
+
+    /// Returns the supported tokens and their custody token account bumps for modification
+    fn token_registry_mut(&mut self) -> (&mut Vec<Pubkey>, &mut Vec<u8>);
+
+    /// Replaces the token controller. Returns the previous token controller.
+    fn update_token_controller(&mut self, new_token_controller: Pubkey) -> Result<Pubkey> {
+        if new_token_controller == Pubkey::default() {
+            return Err(Self::admin_error(AdminError::InvalidTokenController));
+        }
+
+        Ok(std::mem::replace(
+            self.token_controller_mut(),
+            new_token_controller,
+        ))
+    }
+
+    fn is_token_supported(&self, token_mint: Pubkey) -> bool {
+        self.supported_tokens().contains(&token_mint)
+    }
+
+    fn get_token_index(&self, token_mint: Pubkey) -> Option<usize> {
+        self.supported_tokens()
+            .iter()
+            .position(|token| token == &token_mint)
+    }
+
+    /// Adds a supported token with the bump of its custody token account. Adding a token that is
+    /// already supported does nothing.
+    fn add_token(&mut self, token_mint: Pubkey, bump: u8) -> Result<()> {
+        if self.is_token_supported(token_mint) {
+            return Ok(());
+        }
+
+        if self.supported_tokens().len() >= Self::MAX_SUPPORTED_TOKENS {
+            return Err(Self::admin_error(AdminError::MaxTokensSupported));
+        }
+
+        let (supported_tokens, custody_token_account_bumps) = self.token_registry_mut();
+        supported_tokens.push(token_mint);
+        custody_token_account_bumps.push(bump);
+
+        Ok(())
+    }
+
+    fn get_custody_token_account_bump(&self, token_mint: Pubkey) -> Result<u8> {
+        let index = self
+            .get_token_index(token_mint)
+            .ok_or_else(|| Self::admin_error(AdminError::TokenNotSupported))?;
+
+        Ok(self.custody_token_account_bumps()[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[error_code]
+    enum TestError {
+        InvalidPauser,
+        InvalidTokenController,
+        MaxTokensSupported,
+        TokenNotSupported,
+    }
+
+    impl From<AdminError> for TestError {
+        fn from(error: AdminError) -> Self {
+            match error {
+                AdminError::InvalidPauser => TestError::InvalidPauser,
+                AdminError::InvalidTokenController => TestError::InvalidTokenController,
+                AdminError::MaxTokensSupported => TestError::MaxTokensSupported,
+                AdminError::TokenNotSupported => TestError::TokenNotSupported,
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct TestState {
+        owner: Pubkey,
+        pending_owner: Pubkey,
+        pauser: Pubkey,
+        paused: bool,
+        token_controller: Pubkey,
+        supported_tokens: Vec<Pubkey>,
+        custody_token_account_bumps: Vec<u8>,
+    }
+
+    impl AdminState for TestState {
+        type Error = TestError;
+    }
+
+    impl Ownable for TestState {
+        fn owner_mut(&mut self) -> &mut Pubkey {
+            &mut self.owner
+        }
+
+        fn pending_owner_mut(&mut self) -> &mut Pubkey {
+            &mut self.pending_owner
+        }
+    }
+
+    impl Pausable for TestState {
+        fn pauser_mut(&mut self) -> &mut Pubkey {
+            &mut self.pauser
+        }
+
+        fn paused_mut(&mut self) -> &mut bool {
+            &mut self.paused
+        }
+    }
+
+    impl TokenRegistry for TestState {
+        const MAX_SUPPORTED_TOKENS: usize = 2;
+
+        fn token_controller_mut(&mut self) -> &mut Pubkey {
+            &mut self.token_controller
+        }
+
+        fn supported_tokens(&self) -> &[Pubkey] {
+            &self.supported_tokens
+        }
+
+        fn custody_token_account_bumps(&self) -> &[u8] {
+            &self.custody_token_account_bumps
+        }
+
+        fn token_registry_mut(&mut self) -> (&mut Vec<Pubkey>, &mut Vec<u8>) {
+            (
+                &mut self.supported_tokens,
+                &mut self.custody_token_account_bumps,
+            )
+        }
+    }
+
+    #[test]
+    fn ownership_transfer_takes_effect_once_accepted() {
+        let owner = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+        let mut state = TestState {
+            owner,
+            ..Default::default()
+        };
+
+        assert_eq!(state.transfer_ownership(new_owner), owner);
+        assert_eq!(state.owner, owner);
+        assert_eq!(state.pending_owner, new_owner);
+
+        assert_eq!(state.accept_ownership(), owner);
+        assert_eq!(state.owner, new_owner);
+        assert_eq!(state.pending_owner, Pubkey::default());
+    }
+
+    #[test]
+    fn pauser_is_replaced_and_can_pause() {
+        let pauser = Pubkey::new_unique();
+        let mut state = TestState::default();
+
+        assert_eq!(state.update_pauser(pauser).unwrap(), Pubkey::default());
+        assert_eq!(state.pauser, pauser);
+        assert_eq!(
+            state.update_pauser(Pubkey::default()).unwrap_err(),
+            TestError::InvalidPauser.into()
+        );
+
+        state.pause();
+        assert!(state.paused);
+        state.unpause();
+        assert!(!state.paused);
+    }
+
+    #[test]
+    fn token_controller_cannot_be_cleared() {
+        let token_controller = Pubkey::new_unique();
+        let mut state = TestState::default();
+
+        assert_eq!(
+            state.update_token_controller(token_controller).unwrap(),
+            Pubkey::default()
+        );
+        assert_eq!(
+            state
+                .update_token_controller(Pubkey::default())
+                .unwrap_err(),
+            TestError::InvalidTokenController.into()
+        );
+        assert_eq!(state.token_controller, token_controller);
+    }
+
+    #[test]
+    fn tokens_are_added_once_up_to_the_limit() {
+        let (first, second, third) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut state = TestState::default();
+
+        state.add_token(first, 254).unwrap();
+        state.add_token(first, 253).unwrap();
+        state.add_token(second, 252).unwrap();
+        assert_eq!(state.supported_tokens, vec![first, second]);
+        assert_eq!(state.get_custody_token_account_bump(first).unwrap(), 254);
+        assert_eq!(state.get_custody_token_account_bump(second).unwrap(), 252);
+
+        assert_eq!(
+            state.add_token(third, 251).unwrap_err(),
+            TestError::MaxTokensSupported.into()
+        );
+        assert!(!state.is_token_supported(third));
+        assert_eq!(
+            state.get_custody_token_account_bump(third).unwrap_err(),
+            TestError::TokenNotSupported.into()
+        );
+    }
+}
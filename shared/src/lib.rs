@@ -22,6 +22,9 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{keccak::hash, secp256k1_recover::secp256k1_recover};
 use libsecp256k1::Signature as EVMSignature;
 
+pub mod admin;
+pub mod replay;
+
 pub const DISCRIMINATOR_SIZE: usize = 2;
 
 /// Errors that can occur during EVM signature recovery
@@ -34,8 +37,12 @@ pub enum EvmSignatureError {
     InvalidSignatureSValue,
 }
 
-/// Space required for UsedTransferSpecHash account (only discriminator)
-pub const USED_TRANSFER_SPEC_HASH_ACCOUNT_SPACE: usize = DISCRIMINATOR_SIZE;
+/// Space required for UsedTransferSpecHash account without a receipt (discriminator, expiry slot,
+/// rent payer and the receipt option tag)
+pub const USED_TRANSFER_SPEC_HASH_ACCOUNT_SPACE: usize = DISCRIMINATOR_SIZE + 8 + 32 + 1;
+
+// The offset of the optional receipt in a used transfer spec hash account
+const USED_TRANSFER_SPEC_HASH_RECEIPT_OFFSET: usize = USED_TRANSFER_SPEC_HASH_ACCOUNT_SPACE - 1;
 
 /// Seed prefix for used transfer spec hash PDA
 pub const USED_TRANSFER_SPEC_HASH_SEED_PREFIX: &[u8] = b"used_transfer_spec_hash";
@@ -52,6 +59,10 @@ pub fn is_transfer_spec_hash_used(account_data: &[u8], discriminator: &[u8]) ->
 /// 1. Verifies the account hasn't been used already
 /// 2. Creates/initializes the account with proper rent and ownership
 /// 3. Writes the discriminator to mark the transfer spec hash as used
+/// 4. Records the expiry slot and the rent payer, so the account can be closed once expired
+///
+/// The account has no receipt, but reserves `receipt_space` bytes so that one can be written
+/// with `write_used_transfer_spec_hash_receipt` later in the instruction.
 ///
 /// # Arguments
 ///
@@ -62,12 +73,15 @@ pub fn is_transfer_spec_hash_used(account_data: &[u8], discriminator: &[u8]) ->
 /// * `system_program` - The system program account info
 /// * `program_id` - The program ID that will own the account
 /// * `discriminator` - The discriminator to write to the account
+/// * `expiry_slot` - The last slot at which the transfer spec hash can be used
+/// * `receipt_space` - The space to reserve for a receipt, or zero to store no receipt
 ///
 /// # Returns
 ///
 /// Returns `Ok(())` on success, or an error if:
 /// - The transfer spec hash has already been used
 /// - Account creation/initialization fails
+#[allow(clippy::too_many_arguments)]
 pub fn create_used_transfer_spec_hash_account<'info>(
     hash_account: &AccountInfo<'info>,
     transfer_spec_hash: &[u8; 32],
@@ -76,10 +90,168 @@ pub fn create_used_transfer_spec_hash_account<'info>(
     system_program: &AccountInfo<'info>,
     program_id: &Pubkey,
     discriminator: &[u8],
+    expiry_slot: u64,
+    receipt_space: usize,
+) -> Result<()> {
+    create_pda_account(
+        hash_account,
+        USED_TRANSFER_SPEC_HASH_ACCOUNT_SPACE + receipt_space,
+        &[
+            USED_TRANSFER_SPEC_HASH_SEED_PREFIX,
+            transfer_spec_hash,
+            &[bump],
+        ],
+        payer,
+        system_program,
+        program_id,
+    )?;
+
+    // Write the discriminator to mark this transfer spec hash as used
+    let mut account_data = hash_account.try_borrow_mut_data()?;
+    account_data[..DISCRIMINATOR_SIZE].copy_from_slice(discriminator);
+    account_data[DISCRIMINATOR_SIZE..DISCRIMINATOR_SIZE + 8]
+        .copy_from_slice(&expiry_slot.to_le_bytes());
+    account_data[DISCRIMINATOR_SIZE + 8..USED_TRANSFER_SPEC_HASH_RECEIPT_OFFSET]
+        .copy_from_slice(payer.key.as_ref());
+
+    // The receipt option tag is left zeroed, i.e. no receipt
+    Ok(())
+}
+
+/// Writes a receipt into a used transfer spec hash account, in the space reserved for it when
+/// the account was created.
+///
+/// # Arguments
+///
+/// * `hash_account` - The account info for the used transfer spec hash PDA
+/// * `receipt` - The receipt describing how the transfer was settled
+pub fn write_used_transfer_spec_hash_receipt<T: AnchorSerialize>(
+    hash_account: &AccountInfo,
+    receipt: &T,
+) -> Result<()> {
+    let mut account_data = hash_account.try_borrow_mut_data()?;
+    Some(receipt)
+        .serialize(&mut &mut account_data[USED_TRANSFER_SPEC_HASH_RECEIPT_OFFSET..])
+        .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+
+    Ok(())
+}
+
+/// Shrinks an expired used transfer spec hash account down to its discriminator, and refunds the
+/// rent that is no longer needed to the rent payer.
+///
+/// The account is not closed: a new attestation or burn intent with a later expiry may sign the
+/// same transfer spec hash, so the discriminator stays behind as a tombstone that keeps the hash
+/// marked as used.
+///
+/// # Arguments
+///
+/// * `hash_account` - The account info for the used transfer spec hash PDA
+/// * `rent_payer` - The account that paid the rent when the transfer spec hash was used
+pub fn tombstone_used_transfer_spec_hash_account(
+    hash_account: &AccountInfo,
+    rent_payer: &AccountInfo,
+) -> Result<()> {
+    let tombstone_rent = Rent::get()?.minimum_balance(DISCRIMINATOR_SIZE);
+    let refund = hash_account.lamports().saturating_sub(tombstone_rent);
+
+    hash_account.realloc(DISCRIMINATOR_SIZE, false)?;
+    hash_account.sub_lamports(refund)?;
+    rent_payer.add_lamports(refund)?;
+
+    Ok(())
+}
+
+/// Closes an expired used transfer spec hash account, and refunds all of its rent to the rent
+/// payer.
+///
+/// Unlike a tombstone, a closed account no longer marks the hash as used. The caller is
+/// responsible for recording the transfer elsewhere first, e.g. in the nonce bucket of its
+/// explicit nonce.
+///
+/// # Arguments
+///
+/// * `hash_account` - The account info for the used transfer spec hash PDA
+/// * `rent_payer` - The account that paid the rent when the transfer spec hash was used
+pub fn close_used_transfer_spec_hash_account(
+    hash_account: &AccountInfo,
+    rent_payer: &AccountInfo,
+) -> Result<()> {
+    let refund = hash_account.lamports();
+
+    hash_account.sub_lamports(refund)?;
+    rent_payer.add_lamports(refund)?;
+    hash_account.assign(&anchor_lang::system_program::ID);
+    hash_account.realloc(0, false)?;
+
+    Ok(())
+}
+
+/// Deserializes a PDA that may not have been created yet
+///
+/// # Arguments
+/// * `account` - The PDA account info (with seeds constraint)
+/// * `program_id` - The program ID that owns the account once created
+///
+/// # Returns
+/// * `Ok(Some(state))` if the account is owned by the program
+/// * `Ok(None)` if the account has not been created yet
+pub fn load_if_initialized<T: AccountDeserialize>(
+    account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<Option<T>> {
+    if account.owner != program_id {
+        return Ok(None);
+    }
+
+    let data = account.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+/// Deserializes a program account whose layout may have had fields appended since it was created
+///
+/// Accounts created before fields were appended to their layout end before those fields, and are
+/// read as if they were zero-padded to `space`. Every appended field must therefore read zero as
+/// its default. Writing one back requires growing it first, see `grow_account`.
+///
+/// # Arguments
+/// * `data` - The account data, including the discriminator
+/// * `space` - The number of bytes the current layout allocates for the account
+pub fn deserialize_padded<T: AccountDeserialize>(data: &[u8], space: usize) -> Result<T> {
+    if data.len() >= space {
+        return T::try_deserialize(&mut &data[..]);
+    }
+
+    let mut padded = data.to_vec();
+    padded.resize(space, 0);
+    T::try_deserialize(&mut &padded[..])
+}
+
+/// Creates a PDA account owned by `program_id`, following the same steps Anchor uses for `init`.
+///
+/// If the account already holds lamports (e.g. it was pre-funded by a third party), the rent is
+/// topped up and the account is allocated and assigned instead of created. The caller is
+/// responsible for writing the account data, including the discriminator.
+///
+/// # Arguments
+///
+/// * `account` - The account info for the PDA to create
+/// * `space` - The number of bytes to allocate for the account
+/// * `signer_seeds` - The seeds of the PDA, including the bump
+/// * `payer` - The account that pays for the account creation
+/// * `system_program` - The system program account info
+/// * `program_id` - The program ID that will own the account
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
 ) -> Result<()> {
     // Calculate required rent
-    let required_rent = Rent::get()?.minimum_balance(USED_TRANSFER_SPEC_HASH_ACCOUNT_SPACE);
-    let current_lamports = hash_account.lamports();
+    let required_rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
 
     // Replicate Anchor logic for creating accounts
     // https://github.com/solana-foundation/anchor/blob/d5d7eb97979234eb1e9e32fcef66ce171a928b62/lang/syn/src/codegen/accounts/constraints.rs#L1626-L1679
@@ -90,16 +262,12 @@ pub fn create_used_transfer_spec_hash_account<'info>(
                 system_program.clone(),
                 anchor_lang::system_program::CreateAccount {
                     from: payer.clone(),
-                    to: hash_account.clone(),
+                    to: account.clone(),
                 },
-                &[&[
-                    USED_TRANSFER_SPEC_HASH_SEED_PREFIX,
-                    transfer_spec_hash,
-                    &[bump],
-                ]],
+                &[signer_seeds],
             ),
             required_rent,
-            USED_TRANSFER_SPEC_HASH_ACCOUNT_SPACE as u64,
+            space as u64,
             program_id,
         )?;
     } else {
@@ -110,7 +278,7 @@ pub fn create_used_transfer_spec_hash_account<'info>(
                     system_program.clone(),
                     anchor_lang::system_program::Transfer {
                         from: payer.clone(),
-                        to: hash_account.clone(),
+                        to: account.clone(),
                     },
                 ),
                 required_rent - current_lamports,
@@ -122,15 +290,11 @@ pub fn create_used_transfer_spec_hash_account<'info>(
             CpiContext::new_with_signer(
                 system_program.clone(),
                 anchor_lang::system_program::Allocate {
-                    account_to_allocate: hash_account.clone(),
+                    account_to_allocate: account.clone(),
                 },
-                &[&[
-                    USED_TRANSFER_SPEC_HASH_SEED_PREFIX,
-                    transfer_spec_hash,
-                    &[bump],
-                ]],
+                &[signer_seeds],
             ),
-            USED_TRANSFER_SPEC_HASH_ACCOUNT_SPACE as u64,
+            space as u64,
         )?;
 
         // Assign the account to our program
@@ -138,25 +302,65 @@ pub fn create_used_transfer_spec_hash_account<'info>(
             CpiContext::new_with_signer(
                 system_program.clone(),
                 anchor_lang::system_program::Assign {
-                    account_to_assign: hash_account.clone(),
+                    account_to_assign: account.clone(),
                 },
-                &[&[
-                    USED_TRANSFER_SPEC_HASH_SEED_PREFIX,
-                    transfer_spec_hash,
-                    &[bump],
-                ]],
+                &[signer_seeds],
             ),
             program_id,
         )?;
     }
 
-    // Write the discriminator to mark this transfer spec hash as used
-    let mut account_data = hash_account.try_borrow_mut_data()?;
-    account_data[..DISCRIMINATOR_SIZE].copy_from_slice(discriminator);
-
     Ok(())
 }
 
+/// Grows a program account to `space` bytes and tops up its rent from `payer`. Accounts that
+/// already hold at least `space` bytes are left unchanged.
+///
+/// The new bytes are zeroed, which deserializes as an empty `Vec`, `false`, zero or the first
+/// enum variant. Fields appended to an account layout with those defaults can therefore be added
+/// to accounts created before them by growing the account, see `deserialize_padded`.
+///
+/// # Arguments
+///
+/// * `account` - The account info of the program account to grow
+/// * `space` - The number of bytes the account must hold
+/// * `payer` - The account that pays for the additional rent
+/// * `system_program` - The system program account info
+///
+/// # Returns
+///
+/// The number of bytes the account held before it was grown
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<usize> {
+    let previous_space = account.data_len();
+    if previous_space >= space {
+        return Ok(previous_space);
+    }
+
+    let required_rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+    if current_lamports < required_rent {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required_rent - current_lamports,
+        )?;
+    }
+
+    account.realloc(space, true)?;
+
+    Ok(previous_space)
+}
+
 const ETHEREUM_SIGNED_MSG_INPUT_HASH_LEN: usize = 32;
 const SIGNATURE_LENGTH: usize = 65;
 
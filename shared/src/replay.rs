@@ -0,0 +1,288 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Nonce-bucket replay protection.
+//!
+//! Instead of creating one account per transfer spec hash, a transfer that carries an explicit
+//! nonce is recorded as a single bit in a nonce bucket account. Each bucket covers
+//! `NONCES_PER_BUCKET` consecutive nonces of a namespace, so sequential nonces share one account.
+//!
+//! Nonce bucket account layout:
+//! ```text
+//! offset  size  field
+//! 0       2     discriminator
+//! 2       4     source_domain (u32, little-endian)
+//! 6       32    source_contract
+//! 38      32    source_depositor
+//! 70      8     bucket_index (u64, little-endian)
+//! 78      1024  bitmap
+//! ```
+
+use crate::{create_pda_account, DISCRIMINATOR_SIZE, USED_TRANSFER_SPEC_HASH_SEED_PREFIX};
+use anchor_lang::prelude::*;
+
+/// Seed prefix for nonce bucket PDA
+pub const NONCE_BUCKET_SEED_PREFIX: &[u8] = b"nonce_bucket";
+
+/// Size in bytes of the bitmap held by a nonce bucket
+pub const NONCE_BUCKET_BITMAP_SIZE: usize = 1024;
+
+/// The longest a transfer can stay valid while transfers are recorded by hash, in slots (about a
+/// week of 400ms slots). Every transfer recorded in a used transfer spec hash account before a
+/// migration to nonce buckets starts has therefore expired this many slots after its start.
+pub const MAX_TRANSFER_VALIDITY_SLOTS: u64 = 1_512_000;
+
+/// Number of nonces recorded by a single nonce bucket
+pub const NONCES_PER_BUCKET: u64 = NONCE_BUCKET_BITMAP_SIZE as u64 * 8;
+
+/// Space required for a nonce bucket account
+pub const NONCE_BUCKET_ACCOUNT_SPACE: usize =
+    DISCRIMINATOR_SIZE + 4 + 32 + 32 + 8 + NONCE_BUCKET_BITMAP_SIZE;
+
+// Byte offsets of the fields of an encoded transfer spec that key its record, and the length of an
+// encoded transfer spec without hook data
+const TRANSFER_SPEC_SOURCE_DOMAIN_OFFSET: usize = 8;
+const TRANSFER_SPEC_SOURCE_CONTRACT_OFFSET: usize = 16;
+const TRANSFER_SPEC_SOURCE_DEPOSITOR_OFFSET: usize = 144;
+const TRANSFER_SPEC_SALT_OFFSET: usize = 304;
+const TRANSFER_SPEC_MIN_LENGTH: usize = 340;
+
+const NAMESPACE_OFFSET: usize = DISCRIMINATOR_SIZE;
+const BUCKET_INDEX_OFFSET: usize = NAMESPACE_OFFSET + 4 + 32 + 32;
+const BITMAP_OFFSET: usize = BUCKET_INDEX_OFFSET + 8;
+
+/// The scope of explicit nonces: a depositor of a source contract on a source domain.
+///
+/// A depositor key only identifies a depositor on its own domain, so the same key on another
+/// domain or contract has nonces of its own.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NonceNamespace {
+    pub source_domain: u32,
+    pub source_contract: Pubkey,
+    pub source_depositor: Pubkey,
+}
+
+impl NonceNamespace {
+    fn write(&self, data: &mut [u8]) {
+        data[..4].copy_from_slice(&self.source_domain.to_le_bytes());
+        data[4..36].copy_from_slice(self.source_contract.as_ref());
+        data[36..68].copy_from_slice(self.source_depositor.as_ref());
+    }
+}
+
+/// The record that marks a transfer as used
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReplayKey {
+    /// A used transfer spec hash account for the hash
+    TransferSpecHash([u8; 32]),
+    /// A bit in the nonce bucket of a namespace
+    Nonce {
+        namespace: NonceNamespace,
+        nonce: u64,
+    },
+}
+
+impl ReplayKey {
+    /// Returns a nonce key if the salt is an explicit nonce, or a transfer spec hash key otherwise.
+    ///
+    /// Nonces are scoped to a namespace, so that one party can't use up the nonces of another.
+    /// The key only depends on signed data, so the same transfer always maps to the same record.
+    pub fn from_salt(
+        transfer_spec_hash: [u8; 32],
+        salt: &[u8; 32],
+        namespace: NonceNamespace,
+    ) -> Self {
+        match explicit_nonce(salt) {
+            Some(nonce) => Self::Nonce { namespace, nonce },
+            None => Self::TransferSpecHash(transfer_spec_hash),
+        }
+    }
+
+    /// Returns the key of an encoded transfer spec, from its salt and its source domain, contract
+    /// and depositor, or `None` if it is too short to be a transfer spec.
+    ///
+    /// The caller is responsible for verifying that `transfer_spec_hash` is the hash of
+    /// `encoded_transfer_spec`.
+    pub fn from_transfer_spec(
+        transfer_spec_hash: [u8; 32],
+        encoded_transfer_spec: &[u8],
+    ) -> Option<Self> {
+        if encoded_transfer_spec.len() < TRANSFER_SPEC_MIN_LENGTH {
+            return None;
+        }
+
+        let mut salt = [0u8; 32];
+        salt.copy_from_slice(
+            &encoded_transfer_spec[TRANSFER_SPEC_SALT_OFFSET..TRANSFER_SPEC_SALT_OFFSET + 32],
+        );
+        let source_domain = u32::from_be_bytes(
+            encoded_transfer_spec
+                [TRANSFER_SPEC_SOURCE_DOMAIN_OFFSET..TRANSFER_SPEC_SOURCE_DOMAIN_OFFSET + 4]
+                .try_into()
+                .ok()?,
+        );
+        let source_contract = Pubkey::try_from(
+            &encoded_transfer_spec
+                [TRANSFER_SPEC_SOURCE_CONTRACT_OFFSET..TRANSFER_SPEC_SOURCE_CONTRACT_OFFSET + 32],
+        )
+        .ok()?;
+        let source_depositor = Pubkey::try_from(
+            &encoded_transfer_spec
+                [TRANSFER_SPEC_SOURCE_DEPOSITOR_OFFSET..TRANSFER_SPEC_SOURCE_DEPOSITOR_OFFSET + 32],
+        )
+        .ok()?;
+        let namespace = NonceNamespace {
+            source_domain,
+            source_contract,
+            source_depositor,
+        };
+
+        Some(Self::from_salt(transfer_spec_hash, &salt, namespace))
+    }
+
+    /// Finds the PDA that records the key and its bump
+    pub fn find_address(&self, program_id: &Pubkey) -> (Pubkey, u8) {
+        match self {
+            Self::TransferSpecHash(transfer_spec_hash) => Pubkey::find_program_address(
+                &[USED_TRANSFER_SPEC_HASH_SEED_PREFIX, transfer_spec_hash],
+                program_id,
+            ),
+            Self::Nonce { namespace, nonce } => Pubkey::find_program_address(
+                &[
+                    NONCE_BUCKET_SEED_PREFIX,
+                    &namespace.source_domain.to_be_bytes(),
+                    namespace.source_contract.as_ref(),
+                    namespace.source_depositor.as_ref(),
+                    &nonce_bucket_index(*nonce).to_le_bytes(),
+                ],
+                program_id,
+            ),
+        }
+    }
+}
+
+/// Returns whether every transfer recorded by hash before a migration to nonce buckets that
+/// started at `migration_started_slot` has expired
+pub fn is_replay_migration_complete(migration_started_slot: u64, current_slot: u64) -> bool {
+    current_slot > migration_started_slot.saturating_add(MAX_TRANSFER_VALIDITY_SLOTS)
+}
+
+/// Returns the salt as an explicit nonce if its upper 24 bytes are zero
+pub fn explicit_nonce(salt: &[u8; 32]) -> Option<u64> {
+    if salt[..24].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+
+    let mut nonce = [0u8; 8];
+    nonce.copy_from_slice(&salt[24..]);
+    Some(u64::from_be_bytes(nonce))
+}
+
+/// Returns the index of the bucket that records the nonce
+pub fn nonce_bucket_index(nonce: u64) -> u64 {
+    nonce / NONCES_PER_BUCKET
+}
+
+/// Returns the byte offset and bit mask of the nonce in a nonce bucket account
+fn nonce_bit(nonce: u64) -> (usize, u8) {
+    let bit = nonce % NONCES_PER_BUCKET;
+    (BITMAP_OFFSET + (bit / 8) as usize, 1 << (bit % 8))
+}
+
+/// Checks if a nonce has already been used
+pub fn is_nonce_used(bucket_data: &[u8], discriminator: &[u8], nonce: u64) -> bool {
+    if bucket_data.len() < NONCE_BUCKET_ACCOUNT_SPACE
+        || &bucket_data[..DISCRIMINATOR_SIZE] != discriminator
+    {
+        return false;
+    }
+
+    let (offset, mask) = nonce_bit(nonce);
+    bucket_data[offset] & mask != 0
+}
+
+/// Marks a nonce as used to prevent replay attacks, creating its nonce bucket account if needed.
+///
+/// The caller is responsible for verifying that `bucket_account` is the nonce bucket PDA of the
+/// namespace and nonce.
+///
+/// # Arguments
+///
+/// * `bucket_account` - The account info for the nonce bucket PDA
+/// * `namespace` - The namespace of the nonce
+/// * `nonce` - The nonce to be marked as used
+/// * `bump` - The bump seed for the PDA
+/// * `payer` - The account that pays for the account creation
+/// * `system_program` - The system program account info
+/// * `program_id` - The program ID that owns the account
+/// * `discriminator` - The discriminator of nonce bucket accounts
+///
+/// # Returns
+///
+/// Returns `Ok(false)` if the nonce has already been used, and `Ok(true)` once it is marked.
+#[allow(clippy::too_many_arguments)]
+pub fn mark_nonce_used<'info>(
+    bucket_account: &AccountInfo<'info>,
+    namespace: &NonceNamespace,
+    nonce: u64,
+    bump: u8,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    discriminator: &[u8],
+) -> Result<bool> {
+    let is_initialized = {
+        let account_data = bucket_account.try_borrow_data()?;
+        bucket_account.owner == program_id
+            && account_data.len() >= DISCRIMINATOR_SIZE
+            && &account_data[..DISCRIMINATOR_SIZE] == discriminator
+    };
+
+    if !is_initialized {
+        let bucket_index = nonce_bucket_index(nonce).to_le_bytes();
+        create_pda_account(
+            bucket_account,
+            NONCE_BUCKET_ACCOUNT_SPACE,
+            &[
+                NONCE_BUCKET_SEED_PREFIX,
+                &namespace.source_domain.to_be_bytes(),
+                namespace.source_contract.as_ref(),
+                namespace.source_depositor.as_ref(),
+                &bucket_index,
+                &[bump],
+            ],
+            payer,
+            system_program,
+            program_id,
+        )?;
+
+        let mut account_data = bucket_account.try_borrow_mut_data()?;
+        account_data[..DISCRIMINATOR_SIZE].copy_from_slice(discriminator);
+        namespace.write(&mut account_data[NAMESPACE_OFFSET..BUCKET_INDEX_OFFSET]);
+        account_data[BUCKET_INDEX_OFFSET..BITMAP_OFFSET].copy_from_slice(&bucket_index);
+    }
+
+    let mut account_data = bucket_account.try_borrow_mut_data()?;
+    let (offset, mask) = nonce_bit(nonce);
+    if account_data[offset] & mask != 0 {
+        return Ok(false);
+    }
+    account_data[offset] |= mask;
+
+    Ok(true)
+}
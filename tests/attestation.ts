@@ -39,6 +39,18 @@ export const TRANSFER_SPEC_HASH_OFFSET = 72;
 export const HOOK_DATA_LENGTH_OFFSET = 104;
 export const HOOK_DATA_OFFSET = 108;
 
+// Mint hook offsets
+export const MINT_HOOK_MAGIC = 0x0e64dc89;
+export const MINT_HOOK_PROGRAM_ID_OFFSET = 4;
+export const MINT_HOOK_NUM_ACCOUNTS_OFFSET = 36;
+export const MINT_HOOK_ACCOUNTS_OFFSET = 40;
+export const MINT_HOOK_ACCOUNT_LENGTH = 33;
+
+export interface MintHookAccount {
+  pubkey: PublicKey;
+  isWritable: boolean;
+}
+
 export type MintAttestationSet = {
   version: number;
   destinationDomain: number;
@@ -111,6 +123,30 @@ export function encodeMintAttestationElement(
   return out;
 }
 
+/**
+ * Encode a mint hook to be used as an attestation element's hook data
+ */
+export function encodeMintHook(
+  programId: PublicKey,
+  accounts: MintHookAccount[],
+  instructionData: Buffer
+): Buffer {
+  const instructionDataOffset =
+    MINT_HOOK_ACCOUNTS_OFFSET + accounts.length * MINT_HOOK_ACCOUNT_LENGTH;
+  const out = Buffer.alloc(instructionDataOffset + instructionData.length);
+
+  out.writeUInt32BE(MINT_HOOK_MAGIC, MAGIC_OFFSET);
+  programId.toBuffer().copy(out, MINT_HOOK_PROGRAM_ID_OFFSET);
+  out.writeUInt32BE(accounts.length, MINT_HOOK_NUM_ACCOUNTS_OFFSET);
+  accounts.forEach((account, i) => {
+    const offset = MINT_HOOK_ACCOUNTS_OFFSET + i * MINT_HOOK_ACCOUNT_LENGTH;
+    account.pubkey.toBuffer().copy(out, offset);
+    out.writeUInt8(account.isWritable ? 1 : 0, offset + 32);
+  });
+  instructionData.copy(out, instructionDataOffset);
+  return out;
+}
+
 /**
  * Encode a MintAttestationSet to a buffer
  */
@@ -263,7 +299,7 @@ export function generateMintAttestationSet(
     destinationDomain: SOLANA_DOMAIN,
     destinationContract: randomPublicKey(),
     destinationCaller: randomPublicKey(),
-    maxBlockHeight: new anchor.BN(Number.MAX_SAFE_INTEGER),
+    maxBlockHeight: new anchor.BN(1000000),
     numAttestations: attestations.length,
     attestations: attestations,
   };
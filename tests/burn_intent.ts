@@ -22,6 +22,13 @@ import { SOLANA_DOMAIN } from "./constants";
 
 // TransferSpec constants (relative to start of TransferSpec, not BurnIntent)
 export const TRANSFER_SPEC_MAGIC = 0xca85def7;
+// Defaults for generated transfer specs. Burn tests register this destination
+// domain and contract in the GatewayWallet destination domain registry.
+export const DEFAULT_DESTINATION_DOMAIN = 1;
+export const DEFAULT_DESTINATION_CONTRACT = new PublicKey(
+  Buffer.alloc(32, 0xdc)
+);
+
 export const TS_MAGIC_OFFSET = 0;
 export const TS_VERSION_OFFSET = 4;
 export const TS_SOURCE_DOMAIN_OFFSET = 8;
@@ -154,9 +161,9 @@ export function generateTransferSpec(
     magic: TRANSFER_SPEC_MAGIC,
     version: 1,
     sourceDomain: SOLANA_DOMAIN,
-    destinationDomain: 1,
+    destinationDomain: DEFAULT_DESTINATION_DOMAIN,
     sourceContract: randomPublicKey(),
-    destinationContract: randomPublicKey(),
+    destinationContract: DEFAULT_DESTINATION_CONTRACT,
     sourceToken: randomPublicKey(),
     destinationToken: randomPublicKey(),
     sourceDepositor: randomPublicKey(),
@@ -177,7 +184,7 @@ export function generateBurnIntent(
   const transferSpec = generateTransferSpec();
   const defaults: BurnIntent = {
     magic: BURN_INTENT_MAGIC,
-    maxBlockHeight: BigInt("18446744073709551615"),
+    maxBlockHeight: BigInt(1000000),
     maxFee: BigInt("18446744073709551615"),
     transferSpecLength: TS_HOOK_DATA_OFFSET + transferSpec.hookData.length,
     transferSpec: transferSpec,
@@ -0,0 +1,144 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { LiteSVM } from "litesvm";
+import { GatewayMinterTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expectAnchorError, getEvents } from "../utils";
+import { SOLANA_DOMAIN } from "../constants";
+
+describe("configureTokenMinter", () => {
+  let svm: LiteSVM;
+  let client: GatewayMinterTestClient;
+  let tokenMint: PublicKey;
+  let mintAuthority: Keypair;
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    client = new GatewayMinterTestClient(svm);
+    await client.initialize({
+      localDomain: SOLANA_DOMAIN,
+    });
+
+    mintAuthority = Keypair.generate();
+    tokenMint = await client.createTokenMint(mintAuthority.publicKey, 6);
+    await client.addToken({ tokenMint });
+  });
+
+  it("should default new tokens to custody mode", async () => {
+    // Tokens have no config account, and mint from custody, until configured
+    const tokenConfig =
+      await client.gatewayMinterProgram.account.tokenConfig.fetchNullable(
+        client.tokenConfigPDA(tokenMint)
+      );
+    expect(tokenConfig).to.be.null;
+  });
+
+  it("should configure mint-authority mode with a minting allowance", async () => {
+    await client.setMintAuthority(
+      tokenMint,
+      client.pdas.gatewayMinter.publicKey,
+      mintAuthority
+    );
+
+    const txSignature = await client.configureTokenMinter({
+      tokenMint,
+      mintMode: "mintAuthority",
+      mintingAllowance: 500000000,
+    });
+
+    const tokenConfig = await client.fetchTokenConfig(tokenMint);
+    expect(tokenConfig.mintMode).to.deep.equal({ mintAuthority: {} });
+    expect(tokenConfig.mintingAllowance.toString()).to.equal("500000000");
+
+    const events = getEvents(svm, txSignature, client.gatewayMinterProgram);
+    expect(events).to.have.length(1);
+    expect(events[0].name).to.equal("tokenMinterConfigured");
+    expect(events[0].data.token).to.deep.equal(tokenMint);
+    expect(events[0].data.mintMode).to.deep.equal({ mintAuthority: {} });
+    expect(events[0].data.mintingAllowance.toString()).to.equal("500000000");
+  });
+
+  it("should switch a token back to custody mode", async () => {
+    await client.setMintAuthority(
+      tokenMint,
+      client.pdas.gatewayMinter.publicKey,
+      mintAuthority
+    );
+    await client.configureTokenMinter({
+      tokenMint,
+      mintMode: "mintAuthority",
+      mintingAllowance: 500000000,
+    });
+
+    await client.configureTokenMinter({
+      tokenMint,
+      mintMode: "custody",
+      mintingAllowance: 0,
+    });
+
+    const tokenConfig = await client.fetchTokenConfig(tokenMint);
+    expect(tokenConfig.mintMode).to.deep.equal({ custody: {} });
+    expect(tokenConfig.mintingAllowance.toString()).to.equal("0");
+  });
+
+  it("should fail if gateway minter is not the token mint authority", async () => {
+    await expectAnchorError(
+      client.configureTokenMinter({
+        tokenMint,
+        mintMode: "mintAuthority",
+        mintingAllowance: 500000000,
+      }),
+      "InvalidTokenMintAuthority"
+    );
+  });
+
+  it("should fail if token is not supported", async () => {
+    const unsupportedTokenMint = await client.createTokenMint(
+      mintAuthority.publicKey,
+      6
+    );
+
+    await expectAnchorError(
+      client.configureTokenMinter({
+        tokenMint: unsupportedTokenMint,
+        mintMode: "custody",
+        mintingAllowance: 0,
+      }),
+      "TokenNotSupported"
+    );
+  });
+
+  it("should fail if not signed by token controller", async () => {
+    const randomKey = Keypair.generate();
+    svm.airdrop(randomKey.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+    await expectAnchorError(
+      client.configureTokenMinter(
+        {
+          tokenMint,
+          mintMode: "custody",
+          mintingAllowance: 0,
+        },
+        randomKey
+      ),
+      "InvalidAuthority"
+    );
+  });
+});
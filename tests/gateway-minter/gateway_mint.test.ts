@@ -16,13 +16,16 @@
  * limitations under the License.
  */
 
-import { LiteSVM, Clock } from "litesvm";
+import { LiteSVM, Clock, TransactionMetadata } from "litesvm";
 import { GatewayMinterTestClient } from "./test_client";
 import { expect } from "chai";
 import { SOLANA_DOMAIN } from "../constants";
 import {
   ATTESTATION_SET_MAGIC,
+  MINT_HOOK_MAGIC,
+  MintAttestationSet,
   encodeMintAttestationSet,
+  encodeMintHook,
   generateMintAttestationElement,
   generateMintAttestationSet,
 } from "../attestation";
@@ -31,6 +34,7 @@ import {
   expectAnchorError,
   findPDA,
   createGatewayMintRemainingAccounts,
+  createGatewayMintRecipientOwnerRemainingAccounts,
   EvmKeypair,
   generateSignerKeypair,
   signAttestation,
@@ -46,9 +50,17 @@ import {
   SystemProgram,
 } from "@solana/web3.js";
 import * as anchor from "@coral-xyz/anchor";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  getMint,
+} from "@solana/spl-token";
 import { Wallet } from "ethers";
 
+const MEMO_PROGRAM_ID = new PublicKey(
+  "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+);
+
 describe("gatewayMint instruction", () => {
   let svm: LiteSVM;
   let client: GatewayMinterTestClient;
@@ -556,119 +568,935 @@ describe("gatewayMint instruction", () => {
             attesterKey: validAttester.privateKey,
           },
         }),
-        "AttestationExpired"
+        "AttestationExpired"
+      );
+    });
+
+    it("should reject when attestation expires just before current slot", async () => {
+      setClockToSlot(15000);
+
+      const attestation = generateDefaultAttestation();
+      attestation.maxBlockHeight = new anchor.BN(14999); // Expires just before current slot
+      const attestationBytes = encodeMintAttestationSet(attestation);
+      const signature = getValidSignature(attestationBytes);
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          signature,
+        }),
+        "AttestationExpired"
+      );
+    });
+
+    it("should not allow a later expiry attestation to be replayed after closing", async () => {
+      setClockToSlot(EXPIRY_SLOT + 1);
+      await client.closeUsedTransferSpecHash({
+        transferSpecHash,
+        rentPayer: rentPayer.publicKey,
+      });
+
+      attestation.maxBlockHeight = new anchor.BN(EXPIRY_SLOT + 100);
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "TransferSpecHashAlreadyUsed"
+      );
+    });
+  });
+
+  describe("destination caller authorization", () => {
+    it("should pass when attestation has zero address destination caller", async () => {
+      const attestation = generateDefaultAttestation();
+      attestation.destinationCaller = PublicKey.default;
+      attestation.attestations[0].value = new anchor.BN(75000000);
+
+      await client.gatewayMint({
+        attestation,
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+    });
+
+    it("should reject when unauthorized caller tries to execute", async () => {
+      const authorizedCaller = Keypair.generate();
+      const unauthorizedCaller = Keypair.generate();
+      svm.airdrop(unauthorizedCaller.publicKey, BigInt(1000000000));
+
+      const attestation = generateDefaultAttestation();
+      attestation.destinationCaller = authorizedCaller.publicKey;
+      attestation.attestations[0].value = new anchor.BN(75000000);
+      const attestationBytes = encodeMintAttestationSet(attestation);
+      const signature = getValidSignature(attestationBytes);
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          signature,
+          signers: {
+            destinationCaller: unauthorizedCaller,
+          },
+        }),
+        "DestinationCallerMismatch"
+      );
+    });
+  });
+
+  describe("destination domain validation", () => {
+    it("should reject when attestation has wrong destination domain", async () => {
+      const attestation = generateDefaultAttestation();
+      attestation.destinationDomain = 0;
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "DestinationDomainMismatch"
+      );
+    });
+  });
+
+  describe("destination contract validation", () => {
+    it("should reject invalid destination contract", async () => {
+      const wrongProgram = Keypair.generate().publicKey;
+
+      const attestation = generateDefaultAttestation();
+      attestation.destinationContract = wrongProgram;
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "DestinationContractMismatch"
+      );
+    });
+  });
+
+  describe("remaining accounts validation", () => {
+    it("should fail when remaining accounts is less than expected", async () => {
+      const attestation = generateMintAttestationSet({
+        destinationCaller: client.owner.publicKey,
+        destinationContract: client.gatewayMinterProgram.programId,
+        attestations: [
+          generateMintAttestationElement({
+            destinationToken: tokenMint,
+            destinationRecipient: destinationTokenAccount,
+          }),
+          generateMintAttestationElement({
+            destinationToken: tokenMint,
+            destinationRecipient: destinationTokenAccount,
+          }),
+        ],
+      });
+
+      const remainingAccounts = createGatewayMintRemainingAccounts(
+        attestation,
+        client.gatewayMinterProgram.programId
+      );
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          remainingAccounts: remainingAccounts.slice(0, -1),
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "RemainingAccountsLengthMismatch"
+      );
+    });
+
+    it("should fail when remaining accounts is greater than expected", async () => {
+      const attestation = generateDefaultAttestation();
+      const remainingAccounts = createGatewayMintRemainingAccounts(
+        attestation,
+        client.gatewayMinterProgram.programId
+      );
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          remainingAccounts: [...remainingAccounts, ...remainingAccounts],
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "RemainingAccountsLengthMismatch"
+      );
+    });
+
+    it("should fail when providing remaining accounts in wrong order for attestation set", async () => {
+      const attestation = generateMintAttestationSet({
+        destinationCaller: client.owner.publicKey,
+        destinationContract: client.gatewayMinterProgram.programId,
+        attestations: [
+          generateMintAttestationElement({
+            destinationToken: tokenMint,
+            destinationRecipient: destinationTokenAccount,
+          }),
+          generateMintAttestationElement({
+            destinationToken: tokenMint,
+            destinationRecipient: destinationTokenAccount,
+          }),
+        ],
+      });
+
+      const remainingAccounts = createGatewayMintRemainingAccounts(
+        attestation,
+        client.gatewayMinterProgram.programId
+      );
+
+      // Provide PDAs in wrong order (reversed)
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+          remainingAccounts: [
+            ...remainingAccounts.slice(3),
+            ...remainingAccounts.slice(0, 3),
+          ],
+        }),
+        "InvalidTransferSpecHashAccount"
+      );
+    });
+  });
+
+  describe("mint authority mode", () => {
+    const mintingAllowance = 150000000;
+
+    beforeEach(async () => {
+      await client.setMintAuthority(
+        tokenMint,
+        client.pdas.gatewayMinter.publicKey,
+        mintAuthority
+      );
+      await client.configureTokenMinter({
+        tokenMint,
+        mintMode: "mintAuthority",
+        mintingAllowance,
+      });
+    });
+
+    const createMintAuthorityRemainingAccounts = (
+      attestation: ReturnType<typeof generateDefaultAttestation>
+    ) => {
+      const remainingAccounts = createGatewayMintRemainingAccounts(
+        attestation,
+        client.gatewayMinterProgram.programId
+      );
+      remainingAccounts[0] = {
+        pubkey: tokenMint,
+        isWritable: true,
+        isSigner: false,
+      };
+      return remainingAccounts;
+    };
+
+    it("should mint new tokens and decrease the minting allowance", async () => {
+      const initialCustodyBalance = await client.getTokenAccount(
+        custodyTokenAccountPDA
+      );
+      const initialSupply = (
+        await getMint(client.provider.connection, tokenMint)
+      ).supply;
+
+      const attestation = generateDefaultAttestation();
+      const txSignature = await client.gatewayMint({
+        attestation,
+        remainingAccounts: createMintAuthorityRemainingAccounts(attestation),
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      const events = getEvents(
+        client.svm,
+        txSignature,
+        client.gatewayMinterProgram
+      );
+      expectAttestationUsedToEqual(events, attestation.attestations);
+
+      const destinationBalance = await client.getTokenAccount(
+        destinationTokenAccount
+      );
+      expect(destinationBalance.amount).to.equal(BigInt(100000000));
+
+      const finalCustodyBalance = await client.getTokenAccount(
+        custodyTokenAccountPDA
+      );
+      expect(finalCustodyBalance.amount).to.equal(initialCustodyBalance.amount);
+
+      const finalSupply = (
+        await getMint(client.provider.connection, tokenMint)
+      ).supply;
+      expect(finalSupply).to.equal(initialSupply + BigInt(100000000));
+
+      const tokenConfig = await client.fetchTokenConfig(tokenMint);
+      expect(tokenConfig.mintingAllowance.toString()).to.equal((mintingAllowance - 100000000).toString());
+    });
+
+    it("should fail when the mint exceeds the minting allowance", async () => {
+      const firstAttestation = generateDefaultAttestation();
+      await client.gatewayMint({
+        attestation: firstAttestation,
+        remainingAccounts:
+          createMintAuthorityRemainingAccounts(firstAttestation),
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      const secondAttestation = generateDefaultAttestation();
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation: secondAttestation,
+          remainingAccounts:
+            createMintAuthorityRemainingAccounts(secondAttestation),
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "MintingAllowanceExceeded"
+      );
+    });
+
+    it("should fail when the custody token account is provided instead of the token mint", async () => {
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation: generateDefaultAttestation(),
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "InvalidTokenMint"
+      );
+    });
+
+    it("should fail when a different token mint is provided", async () => {
+      const otherTokenMint = await client.createTokenMint(
+        client.pdas.gatewayMinter.publicKey,
+        6
+      );
+      const attestation = generateDefaultAttestation();
+      const remainingAccounts =
+        createMintAuthorityRemainingAccounts(attestation);
+      remainingAccounts[0] = {
+        pubkey: otherTokenMint,
+        isWritable: true,
+        isSigner: false,
+      };
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          remainingAccounts,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "DestinationTokenMismatch"
+      );
+    });
+  });
+
+  describe("mint rate limit", () => {
+    beforeEach(async () => {
+      await client.setMintRateLimit({
+        tokenMint,
+        capacity: 150000000,
+        refillPerSlot: 1000000,
+      });
+    });
+
+    const mintDefaultAttestation = () =>
+      client.gatewayMint({
+        attestation: generateDefaultAttestation(),
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+    it("should take mints from the rate limit", async () => {
+      await mintDefaultAttestation();
+
+      const { rateLimit } = await client.fetchTokenConfig(tokenMint);
+      expect(rateLimit.available.toString()).to.equal("50000000");
+    });
+
+    it("should skip a mint exceeding the rate limit", async () => {
+      await mintDefaultAttestation();
+
+      const attestation = generateDefaultAttestation();
+      const txSignature = await client.gatewayMint({
+        attestation,
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      const events = getEvents(
+        client.svm,
+        txSignature,
+        client.gatewayMinterProgram
+      );
+      expect(events.length).to.equal(1);
+      expect(events[0].name).to.equal("mintRateLimited");
+      expect(events[0].data.value.toString()).to.equal("100000000");
+
+      const destinationBalance = await client.getTokenAccount(
+        destinationTokenAccount
+      );
+      expect(destinationBalance.amount).to.equal(BigInt(100000000));
+    });
+
+    it("should allow mints again after the rate limit refills", async () => {
+      await mintDefaultAttestation();
+
+      setClockToSlot(10050);
+      await mintDefaultAttestation();
+
+      const destinationBalance = await client.getTokenAccount(
+        destinationTokenAccount
+      );
+      expect(destinationBalance.amount).to.equal(BigInt(200000000));
+    });
+
+    it("should not limit other tokens", async () => {
+      const otherToken = await createSupportedToken();
+      const otherDestination = await client.createTokenAccount(
+        otherToken.mint,
+        Keypair.generate()
+      );
+
+      for (let i = 0; i < 3; i++) {
+        await client.gatewayMint({
+          attestation: generateMintAttestationSet({
+            destinationCaller: client.owner.publicKey,
+            destinationContract: client.gatewayMinterProgram.programId,
+            attestations: [
+              generateMintAttestationElement({
+                destinationToken: otherToken.mint,
+                destinationRecipient: otherDestination,
+                value: new anchor.BN(100000000),
+              }),
+            ],
+          }),
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        });
+      }
+
+      const destinationBalance = await client.getTokenAccount(otherDestination);
+      expect(destinationBalance.amount).to.equal(BigInt(300000000));
+    });
+  });
+
+  describe("recipient token account creation", () => {
+    let recipientOwner: PublicKey;
+    let recipientTokenAccount: PublicKey;
+
+    beforeEach(() => {
+      recipientOwner = Keypair.generate().publicKey;
+      recipientTokenAccount = getAssociatedTokenAddressSync(
+        tokenMint,
+        recipientOwner
+      );
+    });
+
+    const generateRecipientOwnerAttestation = (value = 100000000) =>
+      generateMintAttestationSet({
+        destinationCaller: client.owner.publicKey,
+        destinationContract: client.gatewayMinterProgram.programId,
+        attestations: [
+          generateMintAttestationElement({
+            destinationToken: tokenMint,
+            destinationRecipient: recipientOwner,
+            value: new anchor.BN(value),
+          }),
+        ],
+      });
+
+    it("should create the recipient's associated token account and mint to it", async () => {
+      expect(svm.getAccount(recipientTokenAccount)).to.be.null;
+
+      const attestation = generateRecipientOwnerAttestation();
+      const txSignature = await client.gatewayMint({
+        attestation,
+        remainingAccounts: createGatewayMintRecipientOwnerRemainingAccounts(
+          attestation,
+          client.gatewayMinterProgram.programId
+        ),
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      const events = getEvents(
+        client.svm,
+        txSignature,
+        client.gatewayMinterProgram
+      );
+      expectAttestationUsedToEqual(events, attestation.attestations);
+
+      const recipientAccount = await client.getTokenAccount(
+        recipientTokenAccount
+      );
+      expect(recipientAccount.owner).to.deep.equal(recipientOwner);
+      expect(recipientAccount.mint).to.deep.equal(tokenMint);
+      expect(recipientAccount.amount).to.equal(BigInt(100000000));
+    });
+
+    it("should mint to the recipient's existing associated token account", async () => {
+      const firstAttestation = generateRecipientOwnerAttestation(100000000);
+      await client.gatewayMint({
+        attestation: firstAttestation,
+        remainingAccounts: createGatewayMintRecipientOwnerRemainingAccounts(
+          firstAttestation,
+          client.gatewayMinterProgram.programId
+        ),
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      const secondAttestation = generateRecipientOwnerAttestation(50000000);
+      await client.gatewayMint({
+        attestation: secondAttestation,
+        remainingAccounts: createGatewayMintRecipientOwnerRemainingAccounts(
+          secondAttestation,
+          client.gatewayMinterProgram.programId
+        ),
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      const recipientAccount = await client.getTokenAccount(
+        recipientTokenAccount
+      );
+      expect(recipientAccount.amount).to.equal(BigInt(150000000));
+    });
+
+    it("should charge the token account rent to the payer", async () => {
+      const payer = Keypair.generate();
+      svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+      const attestation = generateRecipientOwnerAttestation();
+      await client.gatewayMint({
+        attestation,
+        remainingAccounts: createGatewayMintRecipientOwnerRemainingAccounts(
+          attestation,
+          client.gatewayMinterProgram.programId
+        ),
+        signers: {
+          payer,
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      const rent = svm.getAccount(recipientTokenAccount).lamports;
+      const payerBalance = svm.getBalance(payer.publicKey);
+      expect(payerBalance <= BigInt(LAMPORTS_PER_SOL - rent)).to.be.true;
+    });
+
+    it("should fail when the token account creation accounts are missing", async () => {
+      const attestation = generateRecipientOwnerAttestation();
+      const remainingAccounts =
+        createGatewayMintRecipientOwnerRemainingAccounts(
+          attestation,
+          client.gatewayMinterProgram.programId
+        );
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          remainingAccounts: remainingAccounts.slice(0, 4),
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "RemainingAccountsLengthMismatch"
+      );
+    });
+
+    it("should fail when the recipient owner account does not match the attestation", async () => {
+      const attestation = generateRecipientOwnerAttestation();
+      const remainingAccounts =
+        createGatewayMintRecipientOwnerRemainingAccounts(
+          attestation,
+          client.gatewayMinterProgram.programId
+        );
+      remainingAccounts[6] = {
+        pubkey: Keypair.generate().publicKey,
+        isWritable: false,
+        isSigner: false,
+      };
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          remainingAccounts,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "DestinationRecipientMismatch"
+      );
+    });
+
+    it("should fail when the token mint account does not match the attestation", async () => {
+      const attestation = generateRecipientOwnerAttestation();
+      const remainingAccounts =
+        createGatewayMintRecipientOwnerRemainingAccounts(
+          attestation,
+          client.gatewayMinterProgram.programId
+        );
+      remainingAccounts[4] = {
+        pubkey: Keypair.generate().publicKey,
+        isWritable: false,
+        isSigner: false,
+      };
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          remainingAccounts,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "DestinationTokenMismatch"
+      );
+    });
+
+    it("should fail when the associated token program is invalid", async () => {
+      const attestation = generateRecipientOwnerAttestation();
+      const remainingAccounts =
+        createGatewayMintRecipientOwnerRemainingAccounts(
+          attestation,
+          client.gatewayMinterProgram.programId
+        );
+      remainingAccounts[5] = {
+        pubkey: SystemProgram.programId,
+        isWritable: false,
+        isSigner: false,
+      };
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          remainingAccounts,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "InvalidAssociatedTokenProgram"
+      );
+    });
+
+    it("should fail when the attested recipient is a token account", async () => {
+      recipientOwner = destinationTokenAccount;
+      const attestation = generateRecipientOwnerAttestation();
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          remainingAccounts: createGatewayMintRecipientOwnerRemainingAccounts(
+            attestation,
+            client.gatewayMinterProgram.programId
+          ),
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "InvalidDestinationRecipientOwner"
+      );
+    });
+  });
+
+  describe("hooks", () => {
+    const generateHookAttestation = (hookData: Buffer) =>
+      generateMintAttestationSet({
+        destinationCaller: client.owner.publicKey,
+        destinationContract: client.gatewayMinterProgram.programId,
+        attestations: [
+          generateMintAttestationElement({
+            destinationToken: tokenMint,
+            destinationRecipient: destinationTokenAccount,
+            value: new anchor.BN(100000000),
+            hookData,
+            hookDataLength: hookData.length,
+          }),
+        ],
+      });
+
+    const getLogs = (txSignature: string) =>
+      (
+        svm.getTransaction(
+          anchor.utils.bytes.bs58.decode(txSignature)
+        ) as TransactionMetadata
+      ).logs();
+
+    const memoHookAccounts = [
+      { pubkey: MEMO_PROGRAM_ID, isWritable: false, isSigner: false },
+    ];
+
+    it("should execute a hook after minting", async () => {
+      const hookData = encodeMintHook(
+        MEMO_PROGRAM_ID,
+        [],
+        Buffer.from("gateway mint hook")
+      );
+      const attestation = generateHookAttestation(hookData);
+      const remainingAccounts = createGatewayMintRemainingAccounts(
+        attestation,
+        client.gatewayMinterProgram.programId
+      );
+
+      const txSignature = await client.gatewayMint({
+        attestation,
+        remainingAccounts: [...remainingAccounts, ...memoHookAccounts],
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      const events = getEvents(
+        client.svm,
+        txSignature,
+        client.gatewayMinterProgram
+      );
+      expect(events.length).to.equal(1);
+      expectAttestationUsedToEqual(events, attestation.attestations);
+
+      const logs = getLogs(txSignature);
+      expect(logs).to.include(`Program ${MEMO_PROGRAM_ID} invoke [2]`);
+      expect(logs).to.include(
+        `Program log: Memo (len 17): "gateway mint hook"`
+      );
+
+      const destinationBalance = await client.getTokenAccount(
+        destinationTokenAccount
+      );
+      expect(destinationBalance.amount).to.equal(BigInt(100000000));
+    });
+
+    it("should not pass signer privileges to the hook", async () => {
+      const hookData = encodeMintHook(
+        MEMO_PROGRAM_ID,
+        [{ pubkey: client.owner.publicKey, isWritable: false }],
+        Buffer.from("signed")
+      );
+      const attestation = generateHookAttestation(hookData);
+      const remainingAccounts = createGatewayMintRemainingAccounts(
+        attestation,
+        client.gatewayMinterProgram.programId
+      );
+
+      try {
+        await client.gatewayMint({
+          attestation,
+          remainingAccounts: [
+            ...remainingAccounts,
+            ...memoHookAccounts,
+            {
+              pubkey: client.owner.publicKey,
+              isWritable: false,
+              isSigner: true,
+            },
+          ],
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        });
+        expect.fail("Expected the hook to fail");
+      } catch (err) {
+        expect(err.transactionError.logs).to.include(
+          `Program ${MEMO_PROGRAM_ID} failed: missing required signature for instruction`
+        );
+      }
+    });
+
+    it("should fail when a hook account does not match the attested account", async () => {
+      const hookData = encodeMintHook(
+        MEMO_PROGRAM_ID,
+        [{ pubkey: client.owner.publicKey, isWritable: false }],
+        Buffer.from("memo")
+      );
+      const attestation = generateHookAttestation(hookData);
+      const remainingAccounts = createGatewayMintRemainingAccounts(
+        attestation,
+        client.gatewayMinterProgram.programId
+      );
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          remainingAccounts: [
+            ...remainingAccounts,
+            ...memoHookAccounts,
+            {
+              pubkey: Keypair.generate().publicKey,
+              isWritable: false,
+              isSigner: false,
+            },
+          ],
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "InvalidHookAccount"
       );
     });
 
-    it("should reject when attestation expires just before current slot", async () => {
-      setClockToSlot(15000);
-
-      const attestation = generateDefaultAttestation();
-      attestation.maxBlockHeight = new anchor.BN(14999); // Expires just before current slot
-      const attestationBytes = encodeMintAttestationSet(attestation);
-      const signature = getValidSignature(attestationBytes);
+    it("should fail when an attested writable hook account is passed as read-only", async () => {
+      const hookAccount = Keypair.generate().publicKey;
+      const hookData = encodeMintHook(
+        MEMO_PROGRAM_ID,
+        [{ pubkey: hookAccount, isWritable: true }],
+        Buffer.from("memo")
+      );
+      const attestation = generateHookAttestation(hookData);
+      const remainingAccounts = createGatewayMintRemainingAccounts(
+        attestation,
+        client.gatewayMinterProgram.programId
+      );
 
       await expectAnchorError(
         client.gatewayMint({
           attestation,
-          signature,
+          remainingAccounts: [
+            ...remainingAccounts,
+            ...memoHookAccounts,
+            { pubkey: hookAccount, isWritable: false, isSigner: false },
+          ],
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
         }),
-        "AttestationExpired"
+        "InvalidHookAccount"
       );
     });
-  });
 
-  describe("destination caller authorization", () => {
-    it("should pass when attestation has zero address destination caller", async () => {
-      const attestation = generateDefaultAttestation();
-      attestation.destinationCaller = PublicKey.default;
-      attestation.attestations[0].value = new anchor.BN(75000000);
+    it("should ignore hook data without the hook magic", async () => {
+      const hookData = encodeMintHook(
+        MEMO_PROGRAM_ID,
+        [],
+        Buffer.from("not a hook")
+      );
+      hookData.writeUInt32BE(MINT_HOOK_MAGIC + 1, 0);
+      const attestation = generateHookAttestation(hookData);
 
-      await client.gatewayMint({
+      const txSignature = await client.gatewayMint({
         attestation,
         signers: {
           attesterKey: validAttester.privateKey,
         },
       });
-    });
 
-    it("should reject when unauthorized caller tries to execute", async () => {
-      const authorizedCaller = Keypair.generate();
-      const unauthorizedCaller = Keypair.generate();
-      svm.airdrop(unauthorizedCaller.publicKey, BigInt(1000000000));
+      const events = getEvents(
+        client.svm,
+        txSignature,
+        client.gatewayMinterProgram
+      );
+      expectAttestationUsedToEqual(events, attestation.attestations);
+    });
 
-      const attestation = generateDefaultAttestation();
-      attestation.destinationCaller = authorizedCaller.publicKey;
-      attestation.attestations[0].value = new anchor.BN(75000000);
-      const attestationBytes = encodeMintAttestationSet(attestation);
-      const signature = getValidSignature(attestationBytes);
+    it("should fail when the hook accounts are missing", async () => {
+      const hookData = encodeMintHook(MEMO_PROGRAM_ID, [], Buffer.from("memo"));
+      const attestation = generateHookAttestation(hookData);
 
       await expectAnchorError(
         client.gatewayMint({
           attestation,
-          signature,
           signers: {
-            destinationCaller: unauthorizedCaller,
+            attesterKey: validAttester.privateKey,
           },
         }),
-        "DestinationCallerMismatch"
+        "RemainingAccountsLengthMismatch"
       );
     });
-  });
 
-  describe("destination domain validation", () => {
-    it("should reject when attestation has wrong destination domain", async () => {
-      const attestation = generateDefaultAttestation();
-      attestation.destinationDomain = 0;
+    it("should fail when fewer hook accounts than num_accounts are provided", async () => {
+      const hookData = encodeMintHook(
+        MEMO_PROGRAM_ID,
+        [
+          { pubkey: client.owner.publicKey, isWritable: false },
+          { pubkey: client.owner.publicKey, isWritable: false },
+        ],
+        Buffer.from("memo")
+      );
+      const attestation = generateHookAttestation(hookData);
+      const remainingAccounts = createGatewayMintRemainingAccounts(
+        attestation,
+        client.gatewayMinterProgram.programId
+      );
 
       await expectAnchorError(
         client.gatewayMint({
           attestation,
+          remainingAccounts: [
+            ...remainingAccounts,
+            ...memoHookAccounts,
+            {
+              pubkey: client.owner.publicKey,
+              isWritable: false,
+              isSigner: false,
+            },
+          ],
           signers: {
             attesterKey: validAttester.privateKey,
           },
         }),
-        "DestinationDomainMismatch"
+        "RemainingAccountsLengthMismatch"
       );
     });
-  });
-
-  describe("destination contract validation", () => {
-    it("should reject invalid destination contract", async () => {
-      const wrongProgram = Keypair.generate().publicKey;
 
-      const attestation = generateDefaultAttestation();
-      attestation.destinationContract = wrongProgram;
+    it("should fail when the hook program account does not match the hook", async () => {
+      const hookData = encodeMintHook(MEMO_PROGRAM_ID, [], Buffer.from("memo"));
+      const attestation = generateHookAttestation(hookData);
+      const remainingAccounts = createGatewayMintRemainingAccounts(
+        attestation,
+        client.gatewayMinterProgram.programId
+      );
 
       await expectAnchorError(
         client.gatewayMint({
           attestation,
+          remainingAccounts: [
+            ...remainingAccounts,
+            {
+              pubkey: SystemProgram.programId,
+              isWritable: false,
+              isSigner: false,
+            },
+          ],
           signers: {
             attesterKey: validAttester.privateKey,
           },
         }),
-        "DestinationContractMismatch"
+        "InvalidHookProgram"
       );
     });
-  });
-
-  describe("remaining accounts validation", () => {
-    it("should fail when remaining accounts is less than expected", async () => {
-      const attestation = generateMintAttestationSet({
-        destinationCaller: client.owner.publicKey,
-        destinationContract: client.gatewayMinterProgram.programId,
-        attestations: [
-          generateMintAttestationElement({
-            destinationToken: tokenMint,
-            destinationRecipient: destinationTokenAccount,
-          }),
-          generateMintAttestationElement({
-            destinationToken: tokenMint,
-            destinationRecipient: destinationTokenAccount,
-          }),
-        ],
-      });
 
+    it("should fail when the hook program is not executable", async () => {
+      const notAProgram = Keypair.generate().publicKey;
+      const hookData = encodeMintHook(notAProgram, [], Buffer.from("memo"));
+      const attestation = generateHookAttestation(hookData);
       const remainingAccounts = createGatewayMintRemainingAccounts(
         attestation,
         client.gatewayMinterProgram.programId
@@ -677,69 +1505,88 @@ describe("gatewayMint instruction", () => {
       await expectAnchorError(
         client.gatewayMint({
           attestation,
-          remainingAccounts: remainingAccounts.slice(0, -1),
+          remainingAccounts: [
+            ...remainingAccounts,
+            { pubkey: notAProgram, isWritable: false, isSigner: false },
+          ],
           signers: {
             attesterKey: validAttester.privateKey,
           },
         }),
-        "RemainingAccountsLengthMismatch"
+        "InvalidHookProgram"
       );
     });
 
-    it("should fail when remaining accounts is greater than expected", async () => {
-      const attestation = generateDefaultAttestation();
+    it("should fail when the hook targets the gateway minter", async () => {
+      const programId = client.gatewayMinterProgram.programId;
+      const hookData = encodeMintHook(programId, [], Buffer.from("memo"));
+      const attestation = generateHookAttestation(hookData);
       const remainingAccounts = createGatewayMintRemainingAccounts(
         attestation,
-        client.gatewayMinterProgram.programId
+        programId
       );
 
       await expectAnchorError(
         client.gatewayMint({
           attestation,
-          remainingAccounts: [...remainingAccounts, ...remainingAccounts],
+          remainingAccounts: [
+            ...remainingAccounts,
+            { pubkey: programId, isWritable: false, isSigner: false },
+          ],
           signers: {
             attesterKey: validAttester.privateKey,
           },
         }),
-        "RemainingAccountsLengthMismatch"
+        "InvalidHookProgram"
       );
     });
 
-    it("should fail when providing remaining accounts in wrong order for attestation set", async () => {
-      const attestation = generateMintAttestationSet({
-        destinationCaller: client.owner.publicKey,
-        destinationContract: client.gatewayMinterProgram.programId,
-        attestations: [
-          generateMintAttestationElement({
-            destinationToken: tokenMint,
-            destinationRecipient: destinationTokenAccount,
-          }),
-          generateMintAttestationElement({
-            destinationToken: tokenMint,
-            destinationRecipient: destinationTokenAccount,
-          }),
-        ],
-      });
+    it("should fail when the hook data is shorter than the hook header", async () => {
+      const hookData = encodeMintHook(MEMO_PROGRAM_ID, [], Buffer.alloc(0));
+      const attestation = generateHookAttestation(hookData.subarray(0, 39));
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "MalformedHookData"
+      );
+    });
 
+    it("should revert the mint when the hook fails", async () => {
+      const hookData = encodeMintHook(
+        MEMO_PROGRAM_ID,
+        [],
+        Buffer.from([0xff, 0xfe])
+      );
+      const attestation = generateHookAttestation(hookData);
       const remainingAccounts = createGatewayMintRemainingAccounts(
         attestation,
         client.gatewayMinterProgram.programId
       );
 
-      // Provide PDAs in wrong order (reversed)
-      await expectAnchorError(
-        client.gatewayMint({
+      try {
+        await client.gatewayMint({
           attestation,
+          remainingAccounts: [...remainingAccounts, ...memoHookAccounts],
           signers: {
             attesterKey: validAttester.privateKey,
           },
-          remainingAccounts: [
-            ...remainingAccounts.slice(3),
-            ...remainingAccounts.slice(0, 3),
-          ],
-        }),
-        "InvalidTransferSpecHashAccount"
+        });
+        expect.fail("Expected the hook to fail");
+      } catch (err) {
+        expect(err.transactionError.logs).to.include(
+          `Program ${MEMO_PROGRAM_ID} failed: invalid instruction data`
+        );
+      }
+
+      const destinationBalance = await client.getTokenAccount(
+        destinationTokenAccount
       );
+      expect(destinationBalance.amount).to.equal(BigInt(0));
     });
   });
 
@@ -996,7 +1843,7 @@ describe("gatewayMint instruction", () => {
           signers: {
             attesterKey: validAttester.privateKey,
           },
-          // Add extra remaining accounts so that it matches the stated number of attestations * 3
+          // Add extra remaining accounts so that it matches the stated number of attestations * 4
           remainingAccounts: [...remainingAccounts, ...remainingAccounts],
         }),
         "AttestationTooShort"
@@ -1031,8 +1878,8 @@ describe("gatewayMint instruction", () => {
           signers: {
             attesterKey: validAttester.privateKey,
           },
-          // Remove remaining accounts so that it matches the stated number of attestations * 3
-          remainingAccounts: remainingAccounts.slice(0, 3),
+          // Remove remaining accounts so that it matches the stated number of attestations * 4
+          remainingAccounts: remainingAccounts.slice(0, 4),
         }),
         "AttestationTooLong"
       );
@@ -1106,7 +1953,7 @@ describe("gatewayMint instruction", () => {
       );
 
       const attestation = generateDefaultAttestation();
-      // Override the custody account (first account in the triplet)
+      // Override the custody account (first account in the quadruplet)
       const remainingAccounts = createGatewayMintRemainingAccounts(
         attestation,
         client.gatewayMinterProgram.programId
@@ -1296,9 +2143,20 @@ describe("gatewayMint instruction", () => {
   });
 
   describe("used_transfer_spec_hash account funding", () => {
-    const DISCRIMINATOR_SIZE = 2;
+    // Discriminator, expiry slot, rent payer and receipt option tag
+    const USED_TRANSFER_SPEC_HASH_ACCOUNT_SIZE = 2 + 8 + 32 + 1;
     const usedTransferSpecHashDiscriminator = Buffer.from([11, 1]); // UsedTransferSpecHash discriminator for Minter
 
+    const expectedUsedTransferSpecHashData = (
+      attestation: MintAttestationSet
+    ) =>
+      Buffer.concat([
+        usedTransferSpecHashDiscriminator,
+        attestation.maxBlockHeight.toArrayLike(Buffer, "le", 8),
+        client.owner.publicKey.toBuffer(),
+        Buffer.from([0]),
+      ]);
+
     describe("when account doesn't exist", () => {
       it("should create account when it doesn't exist (lamports == 0)", async () => {
         const attestation = generateDefaultAttestation();
@@ -1324,14 +2182,14 @@ describe("gatewayMint instruction", () => {
         );
         const rentExemptBalance =
           await client.provider.connection.getMinimumBalanceForRentExemption(
-            DISCRIMINATOR_SIZE
+            USED_TRANSFER_SPEC_HASH_ACCOUNT_SIZE
           );
         expect(accountInfo!.lamports).to.equal(rentExemptBalance);
         expect(accountInfo!.owner.toBase58()).to.equal(
           client.gatewayMinterProgram.programId.toBase58()
         );
         expect(accountInfo!.data).to.deep.equal(
-          usedTransferSpecHashDiscriminator
+          expectedUsedTransferSpecHashData(attestation)
         );
       });
     });
@@ -1378,7 +2236,9 @@ describe("gatewayMint instruction", () => {
             client.gatewayMinterProgram.programId.toBase58()
           );
           expect(accountInfo!.lamports).to.equal(LAMPORTS_PER_SOL);
-          expect(accountInfo!.data.length).to.equal(DISCRIMINATOR_SIZE);
+          expect(accountInfo!.data.length).to.equal(
+            USED_TRANSFER_SPEC_HASH_ACCOUNT_SIZE
+          );
         });
 
         it("should handle account with insufficient funds", async () => {
@@ -1392,7 +2252,7 @@ describe("gatewayMint instruction", () => {
           )[0];
           const rentExemptBalance =
             await client.provider.connection.getMinimumBalanceForRentExemption(
-              DISCRIMINATOR_SIZE
+              USED_TRANSFER_SPEC_HASH_ACCOUNT_SIZE
             );
 
           client.svm.airdrop(hashPDA, BigInt(rentExemptBalance - 100));
@@ -1424,7 +2284,7 @@ describe("gatewayMint instruction", () => {
           );
           expect(accountInfo!.lamports).to.equal(rentExemptBalance);
           expect(accountInfo!.data).to.deep.equal(
-            usedTransferSpecHashDiscriminator
+            expectedUsedTransferSpecHashData(attestation)
           );
         });
       });
@@ -1456,7 +2316,7 @@ describe("gatewayMint instruction", () => {
             client.gatewayMinterProgram.programId.toBase58()
           );
           expect(accountInfoBefore!.data).to.deep.equal(
-            usedTransferSpecHashDiscriminator
+            expectedUsedTransferSpecHashData(attestation)
           );
 
           const lamportsBefore = accountInfoBefore!.lamports;
@@ -1480,13 +2340,178 @@ describe("gatewayMint instruction", () => {
             client.gatewayMinterProgram.programId.toBase58()
           );
           expect(accountInfoAfter!.data).to.deep.equal(
-            usedTransferSpecHashDiscriminator
+            expectedUsedTransferSpecHashData(attestation)
           );
         });
       });
     });
   });
 
+  describe("transfer receipts", () => {
+    const fetchUsedTransferSpecHash = (attestation: MintAttestationSet) =>
+      client.gatewayMinterProgram.account.usedTransferSpecHash.fetch(
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("used_transfer_spec_hash"),
+            attestation.attestations[0].transferSpecHash,
+          ],
+          client.gatewayMinterProgram.programId
+        )[0]
+      );
+
+    it("should not store a receipt by default", async () => {
+      const attestation = generateDefaultAttestation();
+      await client.gatewayMint({
+        attestation,
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      expect((await fetchUsedTransferSpecHash(attestation)).receipt).to.be.null;
+    });
+
+    it("should store a receipt matching the mint when enabled", async () => {
+      await client.setTransferReceipts({ enabled: true });
+
+      const attestation = generateDefaultAttestation();
+      await client.gatewayMint({
+        attestation,
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      const { receipt } = await fetchUsedTransferSpecHash(attestation);
+      expect(receipt).to.deep.include({
+        token: tokenMint,
+        recipient: destinationTokenAccount,
+      });
+      expect(receipt.slot.toString()).to.equal(
+        svm.getClock().slot.toString()
+      );
+      expect(receipt.value.toString()).to.equal("100000000");
+    });
+  });
+
+  describe("closeUsedTransferSpecHash", () => {
+    const EXPIRY_SLOT = 15000;
+    let rentPayer: Keypair;
+    let attestation: MintAttestationSet;
+    let transferSpecHash: Buffer;
+
+    beforeEach(async () => {
+      rentPayer = Keypair.generate();
+      svm.airdrop(rentPayer.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+      setClockToSlot(EXPIRY_SLOT);
+      attestation = generateDefaultAttestation();
+      attestation.maxBlockHeight = new anchor.BN(EXPIRY_SLOT);
+      transferSpecHash = attestation.attestations[0].transferSpecHash;
+
+      await client.gatewayMint({
+        attestation,
+        signers: {
+          payer: rentPayer,
+          attesterKey: validAttester.privateKey,
+        },
+      });
+    });
+
+    it("should shrink the account to a tombstone and refund the rent payer after expiry", async () => {
+      const hashPDA = PublicKey.findProgramAddressSync(
+        [Buffer.from("used_transfer_spec_hash"), transferSpecHash],
+        client.gatewayMinterProgram.programId
+      )[0];
+      const rent = svm.getAccount(hashPDA).lamports;
+      const balanceBefore = svm.getBalance(rentPayer.publicKey);
+
+      setClockToSlot(EXPIRY_SLOT + 1);
+      const txSignature = await client.closeUsedTransferSpecHash({
+        transferSpecHash,
+        rentPayer: rentPayer.publicKey,
+      });
+
+      const events = getEvents(
+        client.svm,
+        txSignature,
+        client.gatewayMinterProgram
+      );
+      expect(events.length).to.equal(1);
+      expect(events[0].name).to.equal("usedTransferSpecHashClosed");
+      expect(Buffer.from(events[0].data.transferSpecHash)).to.deep.equal(
+        transferSpecHash
+      );
+      expect(events[0].data.rentPayer).to.deep.equal(rentPayer.publicKey);
+
+      // The discriminator stays behind, holding only the rent it needs
+      const tombstone = svm.getAccount(hashPDA);
+      expect(tombstone.data.length).to.equal(2);
+      expect(svm.getBalance(rentPayer.publicKey)).to.equal(
+        balanceBefore + BigInt(rent) - BigInt(tombstone.lamports)
+      );
+    });
+
+    it("should fail before the expiry slot has passed", async () => {
+      await expectAnchorError(
+        client.closeUsedTransferSpecHash({
+          transferSpecHash,
+          rentPayer: rentPayer.publicKey,
+        }),
+        "TransferSpecHashNotExpired"
+      );
+    });
+
+    it("should fail when the rent payer does not match", async () => {
+      setClockToSlot(EXPIRY_SLOT + 1);
+
+      await expectAnchorError(
+        client.closeUsedTransferSpecHash({
+          transferSpecHash,
+          rentPayer: Keypair.generate().publicKey,
+        }),
+        "InvalidRentPayer"
+      );
+    });
+
+    it("should not allow the attestation to be replayed after closing", async () => {
+      setClockToSlot(EXPIRY_SLOT + 1);
+      await client.closeUsedTransferSpecHash({
+        transferSpecHash,
+        rentPayer: rentPayer.publicKey,
+      });
+
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "AttestationExpired"
+      );
+    });
+
+    it("should not allow a later expiry attestation to be replayed after closing", async () => {
+      setClockToSlot(EXPIRY_SLOT + 1);
+      await client.closeUsedTransferSpecHash({
+        transferSpecHash,
+        rentPayer: rentPayer.publicKey,
+      });
+
+      attestation.maxBlockHeight = new anchor.BN(EXPIRY_SLOT + 100);
+      await expectAnchorError(
+        client.gatewayMint({
+          attestation,
+          signers: {
+            attesterKey: validAttester.privateKey,
+          },
+        }),
+        "TransferSpecHashAlreadyUsed"
+      );
+    });
+  });
+
   describe("should validate attestation parameters", () => {
     it("should fail if attestation destination recipient doesn't match destination token account", async () => {
       const attestation = generateDefaultAttestation();
@@ -1499,7 +2524,7 @@ describe("gatewayMint instruction", () => {
         attestation,
         client.gatewayMinterProgram.programId
       );
-      // Override the destination token account (second account in the triplet) with wrong mint
+      // Override the destination token account (second account in the quadruplet) with wrong mint
       remainingAccounts[1] = {
         pubkey: wrongDestinationTokenAccount,
         isWritable: true,
@@ -1536,7 +2561,7 @@ describe("gatewayMint instruction", () => {
         attestation,
         client.gatewayMinterProgram.programId
       );
-      // Override the destination token account (second account in the triplet) with wrong mint
+      // Override the destination token account (second account in the quadruplet) with wrong mint
       remainingAccounts[1] = {
         pubkey: wrongDestination,
         isWritable: true,
@@ -1562,7 +2587,7 @@ describe("gatewayMint instruction", () => {
         attestation,
         client.gatewayMinterProgram.programId
       );
-      // Override the custody token account (first account in the triplet) with wrong mint
+      // Override the custody token account (first account in the quadruplet) with wrong mint
       remainingAccounts[0] = {
         pubkey: wrongCustodyTokenAccount,
         isWritable: true,
@@ -33,11 +33,15 @@ import {
   createMalformedSignature,
   signAttestation,
   createGatewayMintRemainingAccounts,
+  createGatewayMintRecipientOwnerRemainingAccounts,
   createAddressLookupTable,
   expectAttestationUsedToEqual,
 } from "../utils";
 import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import * as anchor from "@coral-xyz/anchor";
 
 describe("gatewayMintWithParams", () => {
@@ -286,6 +290,42 @@ describe("gatewayMintWithParams", () => {
       const balance = await client.getTokenAccount(destinationTokenAccount);
       expect(Number(balance.amount)).to.equal(100000000);
     });
+
+    it("should create the associated token account of a recipient wallet owner", async () => {
+      const recipientOwner = Keypair.generate().publicKey;
+      const attestation = generateMintAttestationSet({
+        destinationCaller: client.owner.publicKey,
+        destinationContract: client.gatewayMinterProgram.programId,
+        attestations: [
+          generateMintAttestationElement({
+            destinationToken: tokenMint,
+            destinationRecipient: recipientOwner,
+            value: new anchor.BN(100000000),
+          }),
+        ],
+      });
+
+      const tx = await client.gatewayMint({
+        attestation,
+        withParams: true,
+        recipientOwners: true,
+        remainingAccounts: createGatewayMintRecipientOwnerRemainingAccounts(
+          attestation,
+          client.gatewayMinterProgram.programId
+        ),
+        signers: {
+          attesterKey: validAttester.privateKey,
+        },
+      });
+
+      const events = getEvents(client.svm, tx, client.gatewayMinterProgram);
+      expectAttestationUsedToEqual(events, attestation.attestations);
+
+      const balance = await client.getTokenAccount(
+        getAssociatedTokenAddressSync(tokenMint, recipientOwner)
+      );
+      expect(Number(balance.amount)).to.equal(100000000);
+    });
   });
 
   describe("parameters validation", () => {
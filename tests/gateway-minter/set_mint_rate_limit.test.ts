@@ -0,0 +1,172 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { Clock, LiteSVM } from "litesvm";
+import { GatewayMinterTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expectAnchorError, getEvents } from "../utils";
+import { SOLANA_DOMAIN } from "../constants";
+
+describe("setMintRateLimit", () => {
+  let svm: LiteSVM;
+  let client: GatewayMinterTestClient;
+  let tokenMint: PublicKey;
+  let mintAuthority: Keypair;
+
+  const setClockToSlot = (slot: number) => {
+    svm.setClock(
+      new Clock(
+        BigInt(slot),
+        BigInt(0),
+        BigInt(0),
+        BigInt(0),
+        BigInt(Math.floor(Date.now() / 1000))
+      )
+    );
+  };
+
+  const fetchRateLimit = async () => {
+    const tokenConfig = await client.fetchTokenConfig(tokenMint);
+    return tokenConfig.rateLimit;
+  };
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    setClockToSlot(10000);
+
+    client = new GatewayMinterTestClient(svm);
+    await client.initialize({
+      localDomain: SOLANA_DOMAIN,
+    });
+
+    mintAuthority = Keypair.generate();
+    tokenMint = await client.createTokenMint(mintAuthority.publicKey, 6);
+    await client.addToken({ tokenMint });
+  });
+
+  it("should be disabled by default", async () => {
+    const rateLimit = await fetchRateLimit();
+    expect(rateLimit.capacity.toString()).to.equal("0");
+    expect(rateLimit.refillPerSlot.toString()).to.equal("0");
+  });
+
+  it("should enable a rate limit with a full bucket", async () => {
+    const txSignature = await client.setMintRateLimit({
+      tokenMint,
+      capacity: 1000,
+      refillPerSlot: 10,
+    });
+
+    const rateLimit = await fetchRateLimit();
+    expect(rateLimit.capacity.toString()).to.equal("1000");
+    expect(rateLimit.refillPerSlot.toString()).to.equal("10");
+    expect(rateLimit.available.toString()).to.equal("1000");
+    expect(rateLimit.lastRefillSlot.toString()).to.equal("10000");
+
+    const events = getEvents(svm, txSignature, client.gatewayMinterProgram);
+    expect(events).to.have.length(1);
+    expect(events[0].name).to.equal("mintRateLimitSet");
+    expect(events[0].data.token).to.deep.equal(tokenMint);
+    expect(events[0].data.capacity.toString()).to.equal("1000");
+    expect(events[0].data.refillPerSlot.toString()).to.equal("10");
+  });
+
+  it("should cap the available amount when lowering the capacity", async () => {
+    await client.setMintRateLimit({
+      tokenMint,
+      capacity: 1000,
+      refillPerSlot: 10,
+    });
+
+    setClockToSlot(10010);
+    await client.setMintRateLimit({
+      tokenMint,
+      capacity: 500,
+      refillPerSlot: 5,
+    });
+
+    const rateLimit = await fetchRateLimit();
+    expect(rateLimit.capacity.toString()).to.equal("500");
+    expect(rateLimit.refillPerSlot.toString()).to.equal("5");
+    expect(rateLimit.available.toString()).to.equal("500");
+    expect(rateLimit.lastRefillSlot.toString()).to.equal("10010");
+  });
+
+  it("should disable the rate limit with zero capacity", async () => {
+    await client.setMintRateLimit({
+      tokenMint,
+      capacity: 1000,
+      refillPerSlot: 10,
+    });
+
+    await client.setMintRateLimit({
+      tokenMint,
+      capacity: 0,
+      refillPerSlot: 0,
+    });
+
+    const rateLimit = await fetchRateLimit();
+    expect(rateLimit.capacity.toString()).to.equal("0");
+    expect(rateLimit.available.toString()).to.equal("0");
+  });
+
+  it("should fail to set a refill rate without a capacity", async () => {
+    await expectAnchorError(
+      client.setMintRateLimit({
+        tokenMint,
+        capacity: 0,
+        refillPerSlot: 10,
+      }),
+      "InvalidMintRateLimit"
+    );
+  });
+
+  it("should fail if token is not supported", async () => {
+    const unsupportedTokenMint = await client.createTokenMint(
+      mintAuthority.publicKey,
+      6
+    );
+
+    await expectAnchorError(
+      client.setMintRateLimit({
+        tokenMint: unsupportedTokenMint,
+        capacity: 1000,
+        refillPerSlot: 10,
+      }),
+      "TokenNotSupported"
+    );
+  });
+
+  it("should fail if not signed by token controller", async () => {
+    const randomKey = Keypair.generate();
+    svm.airdrop(randomKey.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+    await expectAnchorError(
+      client.setMintRateLimit(
+        {
+          tokenMint,
+          capacity: 1000,
+          refillPerSlot: 10,
+        },
+        randomKey
+      ),
+      "InvalidAuthority"
+    );
+  });
+});
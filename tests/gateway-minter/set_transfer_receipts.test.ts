@@ -0,0 +1,77 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { LiteSVM } from "litesvm";
+import { GatewayMinterTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { SOLANA_DOMAIN } from "../constants";
+import { getEvents, expectAnchorError } from "../utils";
+
+describe("setTransferReceipts", () => {
+  let svm: LiteSVM;
+  let client: GatewayMinterTestClient;
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    client = new GatewayMinterTestClient(svm);
+    await client.initialize({
+      localDomain: SOLANA_DOMAIN,
+    });
+  });
+
+  async function getTransferReceiptsEnabled() {
+    return (
+      await client.gatewayMinterProgram.account.gatewayMinter.fetch(
+        client.pdas.gatewayMinter.publicKey
+      )
+    ).transferReceiptsEnabled;
+  }
+
+  it("should start with transfer receipts disabled", async () => {
+    expect(await getTransferReceiptsEnabled()).to.be.false;
+  });
+
+  it("should enable transfer receipts", async () => {
+    const txSig = await client.setTransferReceipts({ enabled: true });
+
+    const events = getEvents(svm, txSig, client.gatewayMinterProgram);
+    expect(events.length).to.equal(1);
+    expect(events[0].name).to.equal("transferReceiptsSet");
+    expect(events[0].data.enabled).to.be.true;
+
+    expect(await getTransferReceiptsEnabled()).to.be.true;
+  });
+
+  it("should disable transfer receipts", async () => {
+    await client.setTransferReceipts({ enabled: true });
+    await client.setTransferReceipts({ enabled: false });
+
+    expect(await getTransferReceiptsEnabled()).to.be.false;
+  });
+
+  it("should fail when called by non-owner", async () => {
+    const nonOwner = Keypair.generate();
+    svm.airdrop(nonOwner.publicKey, BigInt(10_000_000_000));
+
+    await expectAnchorError(
+      client.setTransferReceipts({ enabled: true }, nonOwner),
+      "InvalidAuthority"
+    );
+  });
+});
@@ -50,6 +50,8 @@ import {
   createInitializeAccountInstruction,
   createInitializeMint2Instruction,
   createMintToInstruction,
+  createSetAuthorityInstruction,
+  AuthorityType,
   getAccount,
   getAccountLenForMint,
   getMinimumBalanceForRentExemptMint,
@@ -211,6 +213,117 @@ export class GatewayMinterTestClient {
       .rpc();
   }
 
+  async configureTokenMinter(
+    params: {
+      tokenMint: PublicKey;
+      mintMode: "custody" | "mintAuthority";
+      mintingAllowance: number | BN;
+    },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayMinterProgram.methods
+      .configureTokenMinter({
+        mintMode:
+          params.mintMode === "custody"
+            ? { custody: {} }
+            : { mintAuthority: {} },
+        mintingAllowance: new BN(params.mintingAllowance.toString()),
+      })
+      .accountsPartial({
+        tokenController: signer.publicKey,
+        gatewayMinter: this.pdas.gatewayMinter.publicKey,
+        tokenMint: params.tokenMint,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async setMintRateLimit(
+    params: {
+      tokenMint: PublicKey;
+      capacity: number | BN;
+      refillPerSlot: number | BN;
+    },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayMinterProgram.methods
+      .setMintRateLimit({
+        capacity: new BN(params.capacity.toString()),
+        refillPerSlot: new BN(params.refillPerSlot.toString()),
+      })
+      .accountsPartial({
+        tokenController: signer.publicKey,
+        gatewayMinter: this.pdas.gatewayMinter.publicKey,
+        tokenMint: params.tokenMint,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async closeUsedTransferSpecHash(params: {
+    transferSpecHash: Buffer;
+    rentPayer: PublicKey;
+  }) {
+    const usedTransferSpecHashPDA = findPDA(
+      [Buffer.from("used_transfer_spec_hash"), params.transferSpecHash],
+      this.gatewayMinterProgram.programId
+    );
+
+    this.svm.expireBlockhash();
+    return this.gatewayMinterProgram.methods
+      .closeUsedTransferSpecHash({
+        transferSpecHash: Array.from(params.transferSpecHash),
+        transferSpec: null,
+      })
+      .accountsPartial({
+        rentPayer: params.rentPayer,
+        usedTransferSpecHash: usedTransferSpecHashPDA.publicKey,
+        gatewayMinter: this.pdas.gatewayMinter.publicKey,
+        payer: null,
+        nonceBucket: null,
+        systemProgram: null,
+      })
+      .rpc();
+  }
+
+  async setReplayStore(
+    params: { replayStore: "hashAccounts" | "migrating" | "nonceBuckets" },
+    signer: Keypair = this.owner
+  ) {
+    const replayStores = {
+      hashAccounts: { hashAccounts: {} },
+      migrating: { migrating: {} },
+      nonceBuckets: { nonceBuckets: {} },
+    };
+
+    this.svm.expireBlockhash();
+    return this.gatewayMinterProgram.methods
+      .setReplayStore({ replayStore: replayStores[params.replayStore] })
+      .accountsPartial({
+        owner: signer.publicKey,
+        gatewayMinter: this.pdas.gatewayMinter.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async setTransferReceipts(
+    params: { enabled: boolean },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayMinterProgram.methods
+      .setTransferReceipts({ enabled: params.enabled })
+      .accountsPartial({
+        owner: signer.publicKey,
+        gatewayMinter: this.pdas.gatewayMinter.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
   async updatePauser(
     params: { newPauser: PublicKey },
     signer: Keypair = this.owner
@@ -293,6 +406,22 @@ export class GatewayMinterTestClient {
       .rpc();
   }
 
+  tokenConfigPDA(tokenMint: PublicKey): PublicKey {
+    return findPDA(
+      [
+        Buffer.from(utils.bytes.utf8.encode("token_config")),
+        tokenMint.toBuffer(),
+      ],
+      this.gatewayMinterProgram.programId
+    ).publicKey;
+  }
+
+  async fetchTokenConfig(tokenMint: PublicKey) {
+    return this.gatewayMinterProgram.account.tokenConfig.fetch(
+      this.tokenConfigPDA(tokenMint)
+    );
+  }
+
   async createTokenMint(
     mintAuthority: PublicKey,
     decimals: number
@@ -338,6 +467,22 @@ export class GatewayMinterTestClient {
     return this.provider.sendAndConfirm(transaction, [authority]);
   }
 
+  async setMintAuthority(
+    mint: PublicKey,
+    newAuthority: PublicKey,
+    currentAuthority: Signer
+  ): Promise<TransactionSignature> {
+    const transaction = new Transaction().add(
+      createSetAuthorityInstruction(
+        mint,
+        currentAuthority.publicKey,
+        AuthorityType.MintTokens,
+        newAuthority
+      )
+    );
+    return this.provider.sendAndConfirm(transaction, [currentAuthority]);
+  }
+
   async createTokenAccount(
     mint: PublicKey,
     account: Signer
@@ -378,7 +523,9 @@ export class GatewayMinterTestClient {
   async gatewayMint(params: {
     attestation: MintAttestationSet;
     signature?: Buffer;
+    transferSpecs?: Buffer[];
     withParams?: boolean;
+    recipientOwners?: boolean;
     accounts?: {
       gatewayMinter?: PublicKey;
       systemProgram?: PublicKey;
@@ -423,12 +570,16 @@ export class GatewayMinterTestClient {
         .gatewayMintWithParams({
           isDefaultDestinationCaller: withParams.isDefaultDestinationCaller,
           maxBlockHeight: new BN(withParams.maxBlockHeight),
-          elements: withParams.elements.map((e) => ({
+          elements: withParams.elements.map((e, i) => ({
             value: new BN(e.value),
             transferSpecHash: Array.from(e.transferSpecHash),
             hookData: e.hookData,
+            recipientOwner: params.recipientOwners
+              ? params.attestation.attestations[i].destinationRecipient
+              : null,
           })),
           signature: sig,
+          transferSpecs: params.transferSpecs || [],
         })
         .accountsPartial({
           gatewayMinter: minter,
@@ -440,7 +591,11 @@ export class GatewayMinterTestClient {
         .remainingAccounts(remainingAccountsList);
     } else {
       methodsBuilder = this.gatewayMinterProgram.methods
-        .gatewayMint({ attestation: encoded, signature: sig })
+        .gatewayMint({
+          attestation: encoded,
+          signature: sig,
+          transferSpecs: params.transferSpecs || [],
+        })
         .accountsPartial({
           gatewayMinter: minter,
           destinationCaller: caller.publicKey,
@@ -0,0 +1,145 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { LiteSVM } from "litesvm";
+import { GatewayWalletTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expectAnchorError, getEvents } from "../utils";
+import { SOLANA_DOMAIN } from "../constants";
+
+describe("GatewayWallet: batchDenylist", () => {
+  let svm: LiteSVM;
+  let client: GatewayWalletTestClient;
+  let denylister: Keypair;
+  let targetAccounts: PublicKey[];
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    client = new GatewayWalletTestClient(svm);
+    await client.initialize({ localDomain: SOLANA_DOMAIN });
+
+    // Create a denylister (different from owner)
+    denylister = Keypair.generate();
+    svm.airdrop(denylister.publicKey, BigInt(LAMPORTS_PER_SOL));
+    await client.updateDenylister({ newDenylister: denylister.publicKey });
+
+    targetAccounts = [
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+    ];
+  });
+
+  describe("Valid batch denylist operations", () => {
+    it("denylister can denylist multiple accounts and emits an event for each", async () => {
+      const txSignature = await client.batchDenylist(
+        { accounts: targetAccounts },
+        { denylister }
+      );
+
+      for (const account of targetAccounts) {
+        expect(await client.getDenylistAccount(account)).to.not.equal(null);
+      }
+
+      const events = getEvents(svm, txSignature, client.gatewayWalletProgram);
+      expect(events).to.have.length(targetAccounts.length);
+      events.forEach((event, i) => {
+        expect(event.name).to.equal("denylisted");
+        expect(event.data.addr).to.deep.equal(targetAccounts[i]);
+      });
+    });
+
+    it("skips accounts that are already denylisted", async () => {
+      await client.denylist({ account: targetAccounts[1] }, { denylister });
+
+      const txSignature = await client.batchDenylist(
+        { accounts: targetAccounts },
+        { denylister }
+      );
+
+      for (const account of targetAccounts) {
+        expect(await client.getDenylistAccount(account)).to.not.equal(null);
+      }
+
+      const events = getEvents(svm, txSignature, client.gatewayWalletProgram);
+      expect(events).to.have.length(targetAccounts.length);
+    });
+
+    it("can apply the same batch twice (idempotent)", async () => {
+      await client.batchDenylist({ accounts: targetAccounts }, { denylister });
+      await client.batchDenylist({ accounts: targetAccounts }, { denylister });
+
+      for (const account of targetAccounts) {
+        expect(await client.getDenylistAccount(account)).to.not.equal(null);
+      }
+    });
+  });
+
+  describe("Error conditions", () => {
+    it("non-denylister cannot batch denylist", async () => {
+      const nonDenylister = Keypair.generate();
+      svm.airdrop(nonDenylister.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+      await expectAnchorError(
+        client.batchDenylist(
+          { accounts: targetAccounts },
+          { denylister: nonDenylister }
+        ),
+        "InvalidAuthority"
+      );
+
+      for (const account of targetAccounts) {
+        expect(await client.getDenylistAccount(account)).to.equal(null);
+      }
+    });
+
+    it("rejects an empty batch", async () => {
+      await expectAnchorError(
+        client.batchDenylist({ accounts: [] }, { denylister }),
+        "EmptyDenylistBatch"
+      );
+    });
+
+    it("rejects a mismatched number of remaining accounts", async () => {
+      await expectAnchorError(
+        client.batchDenylist(
+          { accounts: targetAccounts },
+          { denylister },
+          client.createDenylistRemainingAccounts(targetAccounts.slice(1))
+        ),
+        "RemainingAccountsLengthMismatch"
+      );
+    });
+
+    it("rejects a denylist account that does not match its address", async () => {
+      await expectAnchorError(
+        client.batchDenylist(
+          { accounts: targetAccounts },
+          { denylister },
+          client.createDenylistRemainingAccounts([...targetAccounts].reverse())
+        ),
+        "InvalidDenylistAccount"
+      );
+
+      for (const account of targetAccounts) {
+        expect(await client.getDenylistAccount(account)).to.equal(null);
+      }
+    });
+  });
+});
@@ -0,0 +1,136 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { LiteSVM } from "litesvm";
+import { GatewayWalletTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expectAnchorError, getEvents } from "../utils";
+import { SOLANA_DOMAIN } from "../constants";
+
+describe("GatewayWallet: batchUndenylist", () => {
+  let svm: LiteSVM;
+  let client: GatewayWalletTestClient;
+  let denylister: Keypair;
+  let targetAccounts: PublicKey[];
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    client = new GatewayWalletTestClient(svm);
+    await client.initialize({ localDomain: SOLANA_DOMAIN });
+
+    // Create a denylister (different from owner)
+    denylister = Keypair.generate();
+    svm.airdrop(denylister.publicKey, BigInt(LAMPORTS_PER_SOL));
+    await client.updateDenylister({ newDenylister: denylister.publicKey });
+
+    targetAccounts = [
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+    ];
+    await client.batchDenylist({ accounts: targetAccounts }, { denylister });
+  });
+
+  describe("Valid batch undenylist operations", () => {
+    it("denylister can undenylist multiple accounts and emits an event for each", async () => {
+      const txSignature = await client.batchUndenylist(
+        { accounts: targetAccounts },
+        { denylister }
+      );
+
+      for (const account of targetAccounts) {
+        expect(await client.getDenylistAccount(account)).to.equal(null);
+      }
+
+      const events = getEvents(svm, txSignature, client.gatewayWalletProgram);
+      expect(events).to.have.length(targetAccounts.length);
+      events.forEach((event, i) => {
+        expect(event.name).to.equal("unDenylisted");
+        expect(event.data.addr).to.deep.equal(targetAccounts[i]);
+      });
+    });
+
+    it("returns rent to the payer", async () => {
+      const payer = Keypair.generate();
+      svm.airdrop(payer.publicKey, BigInt(LAMPORTS_PER_SOL));
+      const balanceBefore = svm.getBalance(payer.publicKey);
+
+      await client.batchUndenylist(
+        { accounts: targetAccounts },
+        { payer, denylister }
+      );
+
+      const balanceAfter = svm.getBalance(payer.publicKey);
+      expect(Number(balanceAfter)).to.be.greaterThan(Number(balanceBefore));
+    });
+  });
+
+  describe("Error conditions", () => {
+    it("non-denylister cannot batch undenylist", async () => {
+      const nonDenylister = Keypair.generate();
+      svm.airdrop(nonDenylister.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+      await expectAnchorError(
+        client.batchUndenylist(
+          { accounts: targetAccounts },
+          { denylister: nonDenylister }
+        ),
+        "InvalidAuthority"
+      );
+
+      for (const account of targetAccounts) {
+        expect(await client.getDenylistAccount(account)).to.not.equal(null);
+      }
+    });
+
+    it("rejects an empty batch", async () => {
+      await expectAnchorError(
+        client.batchUndenylist({ accounts: [] }, { denylister }),
+        "EmptyDenylistBatch"
+      );
+    });
+
+    it("rejects a mismatched number of remaining accounts", async () => {
+      await expectAnchorError(
+        client.batchUndenylist(
+          { accounts: targetAccounts },
+          { denylister },
+          client.createDenylistRemainingAccounts(targetAccounts.slice(1))
+        ),
+        "RemainingAccountsLengthMismatch"
+      );
+    });
+
+    it("fails atomically when an account in the batch is not denylisted", async () => {
+      const nonDenylisted = Keypair.generate().publicKey;
+
+      await expectAnchorError(
+        client.batchUndenylist(
+          { accounts: [...targetAccounts, nonDenylisted] },
+          { denylister }
+        ),
+        "AccountNotInitialized"
+      );
+
+      for (const account of targetAccounts) {
+        expect(await client.getDenylistAccount(account)).to.not.equal(null);
+      }
+    });
+  });
+});
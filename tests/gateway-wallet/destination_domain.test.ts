@@ -0,0 +1,152 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { LiteSVM } from "litesvm";
+import { GatewayWalletTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { SOLANA_DOMAIN } from "../constants";
+import { getEvents, expectAnchorError } from "../utils";
+
+describe("enableDestinationDomain and disableDestinationDomain", () => {
+  let svm: LiteSVM;
+  let client: GatewayWalletTestClient;
+  let destinationContract: PublicKey;
+
+  const DOMAIN = 7;
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    client = new GatewayWalletTestClient(svm);
+    await client.initialize({
+      localDomain: SOLANA_DOMAIN,
+    });
+
+    destinationContract = Keypair.generate().publicKey;
+  });
+
+  async function getDestinationDomain(domain: number) {
+    return client.gatewayWalletProgram.account.destinationDomain.fetch(
+      client.getDestinationDomainPDA(domain).publicKey
+    );
+  }
+
+  describe("enableDestinationDomain", () => {
+    it("should register and enable a destination domain", async () => {
+      const txSig = await client.enableDestinationDomain({
+        domain: DOMAIN,
+        destinationContract,
+      });
+
+      const events = getEvents(svm, txSig, client.gatewayWalletProgram);
+      expect(events.length).to.equal(1);
+      expect(events[0].name).to.equal("destinationDomainEnabled");
+      expect(events[0].data.domain).to.equal(DOMAIN);
+      expect(events[0].data.destinationContract).to.deep.equal(
+        destinationContract
+      );
+
+      const destinationDomain = await getDestinationDomain(DOMAIN);
+      expect(destinationDomain.domain).to.equal(DOMAIN);
+      expect(destinationDomain.destinationContract).to.deep.equal(
+        destinationContract
+      );
+      expect(destinationDomain.enabled).to.equal(true);
+    });
+
+    it("should update the destination contract of a registered domain", async () => {
+      await client.enableDestinationDomain({
+        domain: DOMAIN,
+        destinationContract,
+      });
+      const newDestinationContract = Keypair.generate().publicKey;
+      await client.enableDestinationDomain({
+        domain: DOMAIN,
+        destinationContract: newDestinationContract,
+      });
+
+      expect(
+        (await getDestinationDomain(DOMAIN)).destinationContract
+      ).to.deep.equal(newDestinationContract);
+    });
+
+    it("should fail when called by non-owner", async () => {
+      const nonOwner = Keypair.generate();
+      svm.airdrop(nonOwner.publicKey, BigInt(10_000_000_000));
+
+      await expectAnchorError(
+        client.enableDestinationDomain(
+          { domain: DOMAIN, destinationContract },
+          nonOwner
+        ),
+        "InvalidAuthority"
+      );
+    });
+
+    it("should fail when the destination contract is the zero address", async () => {
+      await expectAnchorError(
+        client.enableDestinationDomain({
+          domain: DOMAIN,
+          destinationContract: PublicKey.default,
+        }),
+        "InvalidDestinationContract"
+      );
+    });
+  });
+
+  describe("disableDestinationDomain", () => {
+    beforeEach(async () => {
+      await client.enableDestinationDomain({
+        domain: DOMAIN,
+        destinationContract,
+      });
+    });
+
+    it("should disable a destination domain", async () => {
+      const txSig = await client.disableDestinationDomain({ domain: DOMAIN });
+
+      const events = getEvents(svm, txSig, client.gatewayWalletProgram);
+      expect(events.length).to.equal(1);
+      expect(events[0].name).to.equal("destinationDomainDisabled");
+      expect(events[0].data.domain).to.equal(DOMAIN);
+
+      const destinationDomain = await getDestinationDomain(DOMAIN);
+      expect(destinationDomain.enabled).to.equal(false);
+      expect(destinationDomain.destinationContract).to.deep.equal(
+        destinationContract
+      );
+    });
+
+    it("should fail when called by non-owner", async () => {
+      const nonOwner = Keypair.generate();
+      svm.airdrop(nonOwner.publicKey, BigInt(10_000_000_000));
+
+      await expectAnchorError(
+        client.disableDestinationDomain({ domain: DOMAIN }, nonOwner),
+        "InvalidAuthority"
+      );
+    });
+
+    it("should fail when the domain was never registered", async () => {
+      await expectAnchorError(
+        client.disableDestinationDomain({ domain: DOMAIN + 1 }),
+        "AccountNotInitialized"
+      );
+    });
+  });
+});
@@ -31,6 +31,8 @@ import {
   BI_TRANSFER_SPEC_OFFSET,
   TS_HOOK_DATA_OFFSET,
   TransferSpec,
+  DEFAULT_DESTINATION_CONTRACT,
+  DEFAULT_DESTINATION_DOMAIN,
 } from "../burn_intent";
 import {
   expectAnchorError,
@@ -82,6 +84,12 @@ describe("Ed25519 Precompile Validation", () => {
     // Add the token to the gateway wallet
     await client.addToken({ tokenMint });
 
+    // Enable the default destination domain of generated transfer specs
+    await client.enableDestinationDomain({
+      domain: DEFAULT_DESTINATION_DOMAIN,
+      destinationContract: DEFAULT_DESTINATION_CONTRACT,
+    });
+
     // Get the custody token account PDA
     custodyTokenAccountPDA = findPDA(
       [Buffer.from("gateway_wallet_custody"), tokenMint.toBuffer()],
@@ -463,6 +471,14 @@ describe("Ed25519 Precompile Validation", () => {
           feeRecipientTokenAccount,
           deposit,
           delegateAccount: null,
+          destinationDomain: client.getDestinationDomainPDA(
+            burnIntent.transferSpec.destinationDomain
+          ).publicKey,
+          feeSchedule: client.getFeeSchedulePDA(
+            tokenMint,
+            burnIntent.transferSpec.destinationDomain
+          ).publicKey,
+          outflowLimit: client.getOutflowLimitPDA(tokenMint).publicKey,
         })
         .remainingAccounts(
           createGatewayBurnRemainingAccounts(
@@ -0,0 +1,237 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { LiteSVM } from "litesvm";
+import { GatewayWalletTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expectAnchorError, getEvents } from "../utils";
+import { SOLANA_DOMAIN } from "../constants";
+
+describe("GatewayWallet: freezeDeposit and unfreezeDeposit", () => {
+  let svm: LiteSVM;
+  let client: GatewayWalletTestClient;
+  let denylister: Keypair;
+  let depositor: Keypair;
+  let tokenMint: PublicKey;
+  let userTokenAccount: PublicKey;
+
+  const INITIAL_DEPOSIT = 1_000_000; // 1 token
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    client = new GatewayWalletTestClient(svm);
+    await client.initialize({
+      localDomain: SOLANA_DOMAIN,
+      withdrawalDelay: 100,
+    });
+
+    denylister = Keypair.generate();
+    svm.airdrop(denylister.publicKey, BigInt(LAMPORTS_PER_SOL));
+    await client.updateDenylister({ newDenylister: denylister.publicKey });
+
+    tokenMint = await client.createTokenMint(client.owner.publicKey, 6);
+    await client.addToken({ tokenMint });
+
+    depositor = Keypair.generate();
+    svm.airdrop(depositor.publicKey, BigInt(LAMPORTS_PER_SOL));
+    userTokenAccount = await client.createTokenAccount(
+      tokenMint,
+      depositor.publicKey
+    );
+    await client.mintToken(
+      tokenMint,
+      userTokenAccount,
+      INITIAL_DEPOSIT * 2,
+      client.owner
+    );
+    await client.deposit(
+      {
+        tokenMint,
+        amount: INITIAL_DEPOSIT,
+        fromTokenAccount: userTokenAccount,
+      },
+      { owner: depositor }
+    );
+  });
+
+  async function getDeposit() {
+    return client.gatewayWalletProgram.account.gatewayDeposit.fetch(
+      client.getDepositPDA(tokenMint, depositor.publicKey).publicKey
+    );
+  }
+
+  describe("Valid operations", () => {
+    it("denylister can freeze a deposit and emits event", async () => {
+      const txSignature = await client.freezeDeposit(
+        { tokenMint, depositor: depositor.publicKey },
+        denylister
+      );
+
+      expect((await getDeposit()).frozen).to.equal(true);
+
+      const events = getEvents(svm, txSignature, client.gatewayWalletProgram);
+      expect(events).to.have.length(1);
+      expect(events[0].name).to.equal("depositFrozen");
+      expect(events[0].data.token).to.deep.equal(tokenMint);
+      expect(events[0].data.depositor).to.deep.equal(depositor.publicKey);
+    });
+
+    it("denylister can unfreeze a deposit and emits event", async () => {
+      await client.freezeDeposit(
+        { tokenMint, depositor: depositor.publicKey },
+        denylister
+      );
+      const txSignature = await client.unfreezeDeposit(
+        { tokenMint, depositor: depositor.publicKey },
+        denylister
+      );
+
+      expect((await getDeposit()).frozen).to.equal(false);
+
+      const events = getEvents(svm, txSignature, client.gatewayWalletProgram);
+      expect(events).to.have.length(1);
+      expect(events[0].name).to.equal("depositUnfrozen");
+      expect(events[0].data.token).to.deep.equal(tokenMint);
+      expect(events[0].data.depositor).to.deep.equal(depositor.publicKey);
+    });
+
+    it("freezing does not change the deposit balances", async () => {
+      const before = await getDeposit();
+      await client.freezeDeposit(
+        { tokenMint, depositor: depositor.publicKey },
+        denylister
+      );
+      const after = await getDeposit();
+
+      expect(after.availableAmount.toString()).to.equal(
+        before.availableAmount.toString()
+      );
+      expect(after.withdrawingAmount.toString()).to.equal(
+        before.withdrawingAmount.toString()
+      );
+    });
+
+    it("depositor can still deposit into a frozen balance", async () => {
+      await client.freezeDeposit(
+        { tokenMint, depositor: depositor.publicKey },
+        denylister
+      );
+      await client.deposit(
+        {
+          tokenMint,
+          amount: INITIAL_DEPOSIT,
+          fromTokenAccount: userTokenAccount,
+        },
+        { owner: depositor }
+      );
+
+      expect((await getDeposit()).availableAmount.toNumber()).to.equal(
+        INITIAL_DEPOSIT * 2
+      );
+    });
+  });
+
+  describe("Unauthorized attempts", () => {
+    it("non-denylister cannot freeze a deposit", async () => {
+      await expectAnchorError(
+        client.freezeDeposit(
+          { tokenMint, depositor: depositor.publicKey },
+          client.owner
+        ),
+        "InvalidAuthority"
+      );
+    });
+
+    it("non-denylister cannot unfreeze a deposit", async () => {
+      await client.freezeDeposit(
+        { tokenMint, depositor: depositor.publicKey },
+        denylister
+      );
+      await expectAnchorError(
+        client.unfreezeDeposit(
+          { tokenMint, depositor: depositor.publicKey },
+          depositor
+        ),
+        "InvalidAuthority"
+      );
+    });
+  });
+
+  describe("Enforcement", () => {
+    it("cannot initiate a withdrawal from a frozen deposit", async () => {
+      await client.freezeDeposit(
+        { tokenMint, depositor: depositor.publicKey },
+        denylister
+      );
+
+      await expectAnchorError(
+        client.initiateWithdrawal(
+          { tokenMint, amount: INITIAL_DEPOSIT },
+          depositor
+        ),
+        "DepositFrozen"
+      );
+    });
+
+    it("cannot complete a withdrawal from a frozen deposit", async () => {
+      await client.initiateWithdrawal(
+        { tokenMint, amount: INITIAL_DEPOSIT },
+        depositor
+      );
+      await client.freezeDeposit(
+        { tokenMint, depositor: depositor.publicKey },
+        denylister
+      );
+      svm.warpToSlot(BigInt((await getDeposit()).withdrawalBlock.toNumber()));
+
+      await expectAnchorError(
+        client.withdraw(
+          { tokenMint, toTokenAccount: userTokenAccount },
+          depositor
+        ),
+        "DepositFrozen"
+      );
+    });
+
+    it("can complete a withdrawal after the deposit is unfrozen", async () => {
+      await client.initiateWithdrawal(
+        { tokenMint, amount: INITIAL_DEPOSIT },
+        depositor
+      );
+      await client.freezeDeposit(
+        { tokenMint, depositor: depositor.publicKey },
+        denylister
+      );
+      await client.unfreezeDeposit(
+        { tokenMint, depositor: depositor.publicKey },
+        denylister
+      );
+      svm.warpToSlot(BigInt((await getDeposit()).withdrawalBlock.toNumber()));
+
+      await client.withdraw(
+        { tokenMint, toTokenAccount: userTokenAccount },
+        depositor
+      );
+
+      expect(await client.getTokenAccountBalance(userTokenAccount)).to.equal(
+        BigInt(INITIAL_DEPOSIT * 2)
+      );
+    });
+  });
+});
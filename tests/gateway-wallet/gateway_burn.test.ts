@@ -20,6 +20,7 @@ import { LiteSVM } from "litesvm";
 import { GatewayWalletTestClient } from "./test_client";
 import { expect } from "chai";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { SOLANA_DOMAIN } from "../constants";
 import {
   BI_TRANSFER_SPEC_LENGTH_OFFSET,
@@ -33,17 +34,24 @@ import {
   generateTransferSpec,
   BurnIntent,
   calculateTransferSpecHash,
+  DEFAULT_DESTINATION_CONTRACT,
+  DEFAULT_DESTINATION_DOMAIN,
 } from "../burn_intent";
 import {
   getEvents,
   expectAnchorError,
   findPDA,
   createGatewayBurnRemainingAccounts,
+  createGatewayBurnNonceRemainingAccounts,
   createSignedBurnIntent,
+  deriveNonceBucketPDA,
+  nonceSalt,
+  SignedBurnIntent,
   signBurnIntent,
   generateSignerKeypair,
   EvmKeypair,
   expectEd25519ProgramError,
+  MAX_TRANSFER_VALIDITY_SLOTS,
 } from "../utils";
 
 function expectgatewayBurnedToEqual(
@@ -107,6 +115,12 @@ describe("gatewayBurn", () => {
     // Add the token to the gateway minter
     await client.addToken({ tokenMint });
 
+    // Enable the default destination domain of generated transfer specs
+    await client.enableDestinationDomain({
+      domain: DEFAULT_DESTINATION_DOMAIN,
+      destinationContract: DEFAULT_DESTINATION_CONTRACT,
+    });
+
     // Get the custody token account PDA
     custodyTokenAccountPDA = findPDA(
       [Buffer.from("gateway_wallet_custody"), tokenMint.toBuffer()],
@@ -167,6 +181,7 @@ describe("gatewayBurn", () => {
     customBurnSigner?: EvmKeypair;
     intentOverrides?: Partial<BurnIntent>;
     customFeeRecipientTokenAccount?: PublicKey;
+    feeSplitTokenAccounts?: PublicKey[];
   }) {
     const {
       burnAmount,
@@ -176,6 +191,7 @@ describe("gatewayBurn", () => {
       customBurnSigner,
       intentOverrides,
       customFeeRecipientTokenAccount,
+      feeSplitTokenAccounts = [],
     } = options;
 
     const finalIntentOverrides: Partial<BurnIntent> = {
@@ -219,10 +235,13 @@ describe("gatewayBurn", () => {
           customFeeRecipientTokenAccount || feeRecipientTokenAccount,
         deposit: depositPDA,
         fee,
-        remainingAccounts: createGatewayBurnRemainingAccounts(
-          [intent],
-          client.gatewayWalletProgram.programId
-        ),
+        remainingAccounts: [
+          ...createGatewayBurnRemainingAccounts(
+            [intent],
+            client.gatewayWalletProgram.programId
+          ),
+          ...createFeeSplitRemainingAccounts(feeSplitTokenAccounts),
+        ],
       },
       customBurnSigner || defaultBurnSigner
     );
@@ -242,6 +261,7 @@ describe("gatewayBurn", () => {
     customDepositor?: Keypair;
     customBurnSigner?: EvmKeypair;
     customFeeRecipientTokenAccount?: PublicKey;
+    feeSplitTokenAccounts?: PublicKey[];
   }) {
     const {
       burnAmount,
@@ -251,6 +271,7 @@ describe("gatewayBurn", () => {
       customDepositor,
       customBurnSigner,
       customFeeRecipientTokenAccount,
+      feeSplitTokenAccounts = [],
     } = options;
 
     const intentOverrides: Partial<BurnIntent> = {};
@@ -292,10 +313,13 @@ describe("gatewayBurn", () => {
             customFeeRecipientTokenAccount || feeRecipientTokenAccount,
           deposit: depositPDA,
           fee,
-          remainingAccounts: createGatewayBurnRemainingAccounts(
-            [intent],
-            client.gatewayWalletProgram.programId
-          ),
+          remainingAccounts: [
+            ...createGatewayBurnRemainingAccounts(
+              [intent],
+              client.gatewayWalletProgram.programId
+            ),
+            ...createFeeSplitRemainingAccounts(feeSplitTokenAccounts),
+          ],
         },
         customBurnSigner || defaultBurnSigner
       ),
@@ -303,6 +327,14 @@ describe("gatewayBurn", () => {
     );
   }
 
+  function createFeeSplitRemainingAccounts(tokenAccounts: PublicKey[]) {
+    return tokenAccounts.map((pubkey) => ({
+      pubkey,
+      isWritable: true,
+      isSigner: false,
+    }));
+  }
+
   describe("should parse burn data", () => {
     it("should successfully process valid burn intents", async () => {
       // Test regular burn intent
@@ -2280,7 +2312,7 @@ describe("gatewayBurn", () => {
     });
 
     it("should succeed when burn intent has not expired", async () => {
-      const futureSlot = BigInt("18446744073709551615");
+      const futureSlot = BigInt(1000000);
 
       // Create burn intent with future expiration
       const { events } = await executeBurnAndGetEvents({
@@ -2587,4 +2619,830 @@ describe("gatewayBurn", () => {
       );
     });
   });
+
+  describe("frozen deposits", () => {
+    it("should fail when the deposit is frozen", async () => {
+      await client.freezeDeposit({
+        tokenMint,
+        depositor: depositor.publicKey,
+      });
+
+      await expectBurnToFail({
+        burnAmount: BigInt(100000000),
+        errorName: "DepositFrozen",
+      });
+    });
+
+    it("should succeed after the deposit is unfrozen", async () => {
+      await client.freezeDeposit({
+        tokenMint,
+        depositor: depositor.publicKey,
+      });
+      await client.unfreezeDeposit({
+        tokenMint,
+        depositor: depositor.publicKey,
+      });
+
+      const { events, intent } = await executeBurnAndGetEvents({
+        burnAmount: BigInt(100000000),
+      });
+      expect(events).to.have.length(1);
+      expectgatewayBurnedToEqual(
+        events[0],
+        intent,
+        tokenMint,
+        depositor.publicKey
+      );
+    });
+  });
+
+  describe("fee splits", () => {
+    let partnerA: PublicKey;
+    let partnerB: PublicKey;
+    let partnerATokenAccount: PublicKey;
+    let partnerBTokenAccount: PublicKey;
+
+    beforeEach(async () => {
+      partnerA = Keypair.generate().publicKey;
+      partnerB = Keypair.generate().publicKey;
+      partnerATokenAccount = await client.createAssociatedTokenAccount(
+        tokenMint,
+        partnerA
+      );
+      partnerBTokenAccount = await client.createAssociatedTokenAccount(
+        tokenMint,
+        partnerB
+      );
+
+      await client.updateFeeSplits({
+        feeSplits: [
+          { recipient: partnerA, bps: 2_500 },
+          { recipient: partnerB, bps: 3_333 },
+        ],
+      });
+    });
+
+    it("should distribute the fee across all recipients", async () => {
+      const { events } = await executeBurnAndGetEvents({
+        burnAmount: BigInt(100000000),
+        fee: BigInt(10000000),
+        feeSplitTokenAccounts: [partnerATokenAccount, partnerBTokenAccount],
+      });
+
+      expect(events.length).to.equal(1);
+      expect(events[0].data.fee.toString()).to.equal("10000000");
+      const feeShares = events[0].data.feeShares as {
+        recipient: PublicKey;
+        amount: { toString(): string };
+      }[];
+      expect(feeShares.map((share) => share.recipient)).to.deep.equal([
+        feeRecipient.publicKey,
+        partnerA,
+        partnerB,
+      ]);
+      expect(feeShares.map((share) => share.amount.toString())).to.deep.equal([
+        "4167000",
+        "2500000",
+        "3333000",
+      ]);
+
+      expect(
+        await client.getTokenAccountBalance(feeRecipientTokenAccount)
+      ).to.equal(BigInt(4167000));
+      expect(await client.getTokenAccountBalance(partnerATokenAccount)).to.equal(
+        BigInt(2500000)
+      );
+      expect(await client.getTokenAccountBalance(partnerBTokenAccount)).to.equal(
+        BigInt(3333000)
+      );
+    });
+
+    it("should round partner shares down and give the remainder to the fee recipient", async () => {
+      const { events } = await executeBurnAndGetEvents({
+        burnAmount: BigInt(100000000),
+        fee: BigInt(7),
+        feeSplitTokenAccounts: [partnerATokenAccount, partnerBTokenAccount],
+      });
+
+      const feeShares = events[0].data.feeShares as {
+        amount: { toString(): string };
+      }[];
+      // 7 * 2500 / 10000 = 1.75 -> 1, 7 * 3333 / 10000 = 2.33 -> 2
+      expect(feeShares.map((share) => share.amount.toString())).to.deep.equal([
+        "4",
+        "1",
+        "2",
+      ]);
+    });
+
+    it("should pay the share of a partner without a token account to the fee recipient", async () => {
+      const partnerC = Keypair.generate().publicKey;
+      const partnerCTokenAccount = getAssociatedTokenAddressSync(
+        tokenMint,
+        partnerC
+      );
+      await client.updateFeeSplits({
+        feeSplits: [
+          { recipient: partnerA, bps: 2_500 },
+          { recipient: partnerC, bps: 3_333 },
+        ],
+      });
+
+      const { events } = await executeBurnAndGetEvents({
+        burnAmount: BigInt(100000000),
+        fee: BigInt(10000000),
+        feeSplitTokenAccounts: [partnerATokenAccount, partnerCTokenAccount],
+      });
+
+      const feeShares = events[0].data.feeShares as {
+        amount: { toString(): string };
+      }[];
+      expect(feeShares.map((share) => share.amount.toString())).to.deep.equal([
+        "7500000",
+        "2500000",
+        "0",
+      ]);
+      expect(
+        await client.getTokenAccountBalance(feeRecipientTokenAccount)
+      ).to.equal(BigInt(7500000));
+    });
+
+    it("should fail when a fee split token account is missing", async () => {
+      await expectBurnToFail({
+        burnAmount: BigInt(100000000),
+        fee: BigInt(10000000),
+        feeSplitTokenAccounts: [partnerATokenAccount],
+        errorName: "RemainingAccountsLengthMismatch",
+      });
+    });
+
+    it("should fail when fee split token accounts are out of order", async () => {
+      await expectBurnToFail({
+        burnAmount: BigInt(100000000),
+        fee: BigInt(10000000),
+        feeSplitTokenAccounts: [partnerBTokenAccount, partnerATokenAccount],
+        errorName: "InvalidFeeSplitTokenAccount",
+      });
+    });
+  });
+
+  describe("minimum fee schedule", () => {
+    it("should fail when the fee is below the minimum fee", async () => {
+      await client.setFeeSchedule({
+        tokenMint,
+        destinationDomain: DEFAULT_DESTINATION_DOMAIN,
+        minFee: 1_000_000,
+        feeBps: 0,
+      });
+
+      await expectBurnToFail({
+        burnAmount: BigInt(100000000),
+        fee: BigInt(999_999),
+        errorName: "BurnFeeBelowMinimum",
+      });
+    });
+
+    it("should fail when the fee is below the basis-point fee", async () => {
+      await client.setFeeSchedule({
+        tokenMint,
+        destinationDomain: DEFAULT_DESTINATION_DOMAIN,
+        minFee: 1,
+        feeBps: 10,
+      });
+
+      // 10 bps of 100 tokens is 0.1 tokens
+      await expectBurnToFail({
+        burnAmount: BigInt(100000000),
+        fee: BigInt(99_999),
+        errorName: "BurnFeeBelowMinimum",
+      });
+    });
+
+    it("should round the basis-point fee up", async () => {
+      await client.setFeeSchedule({
+        tokenMint,
+        destinationDomain: DEFAULT_DESTINATION_DOMAIN,
+        minFee: 0,
+        feeBps: 1,
+      });
+
+      // 1 bps of 9999 base units is 0.9999, which rounds up to 1
+      await expectBurnToFail({
+        burnAmount: BigInt(9_999),
+        fee: BigInt(0),
+        errorName: "BurnFeeBelowMinimum",
+      });
+      const { events } = await executeBurnAndGetEvents({
+        burnAmount: BigInt(9_999),
+        fee: BigInt(1),
+      });
+      expect(events[0].data.fee.toString()).to.equal("1");
+    });
+
+    it("should succeed when the fee meets the minimum", async () => {
+      await client.setFeeSchedule({
+        tokenMint,
+        destinationDomain: DEFAULT_DESTINATION_DOMAIN,
+        minFee: 1_000_000,
+        feeBps: 10,
+      });
+
+      const { events } = await executeBurnAndGetEvents({
+        burnAmount: BigInt(100000000),
+        fee: BigInt(1_000_000),
+      });
+      expect(events.length).to.equal(1);
+      expect(events[0].data.fee.toString()).to.equal("1000000");
+    });
+
+    it("should not apply a schedule for another destination domain", async () => {
+      await client.setFeeSchedule({
+        tokenMint,
+        destinationDomain: DEFAULT_DESTINATION_DOMAIN + 1,
+        minFee: 1_000_000,
+        feeBps: 0,
+      });
+
+      const { events } = await executeBurnAndGetEvents({
+        burnAmount: BigInt(100000000),
+      });
+      expect(events[0].data.fee.toString()).to.equal("0");
+    });
+
+    it("should fail when the fee schedule account does not match the destination domain", async () => {
+      const { intent, bytes, signature } = createSignedBurnIntent({
+        signer: depositor,
+        transferSpecOverrides: {
+          sourceContract: client.gatewayWalletProgram.programId,
+          sourceToken: tokenMint,
+          sourceDepositor: depositor.publicKey,
+          value: BigInt(100000000),
+        },
+      });
+
+      await expectAnchorError(
+        client.gatewayBurn(
+          {
+            burnIntent: bytes,
+            userSignature: signature,
+            tokenMint,
+            custodyTokenAccount: custodyTokenAccountPDA,
+            feeRecipientTokenAccount,
+            deposit,
+            feeSchedule: client.getFeeSchedulePDA(
+              tokenMint,
+              DEFAULT_DESTINATION_DOMAIN + 1
+            ).publicKey,
+            remainingAccounts: createGatewayBurnRemainingAccounts(
+              [intent],
+              client.gatewayWalletProgram.programId
+            ),
+          },
+          defaultBurnSigner
+        ),
+        "InvalidFeeScheduleAccount"
+      );
+    });
+  });
+
+  describe("destination domain registry", () => {
+    it("should fail when the destination domain is disabled", async () => {
+      await client.disableDestinationDomain({
+        domain: DEFAULT_DESTINATION_DOMAIN,
+      });
+
+      await expectBurnToFail({
+        burnAmount: BigInt(100000000),
+        errorName: "DestinationDomainNotEnabled",
+      });
+    });
+
+    it("should succeed after the destination domain is re-enabled", async () => {
+      await client.disableDestinationDomain({
+        domain: DEFAULT_DESTINATION_DOMAIN,
+      });
+      await client.enableDestinationDomain({
+        domain: DEFAULT_DESTINATION_DOMAIN,
+        destinationContract: DEFAULT_DESTINATION_CONTRACT,
+      });
+
+      const { events } = await executeBurnAndGetEvents({
+        burnAmount: BigInt(100000000),
+      });
+      expect(events.length).to.equal(1);
+    });
+
+    it("should fail when the destination domain was never registered and the registry is enforced", async () => {
+      await client.setDestinationDomainEnforcement({ enforced: true });
+
+      const { intent, bytes, signature } = createSignedBurnIntent({
+        signer: depositor,
+        transferSpecOverrides: {
+          sourceContract: client.gatewayWalletProgram.programId,
+          sourceToken: tokenMint,
+          sourceDepositor: depositor.publicKey,
+          destinationDomain: DEFAULT_DESTINATION_DOMAIN + 1,
+          value: BigInt(100000000),
+        },
+      });
+
+      await expectAnchorError(
+        client.gatewayBurn(
+          {
+            burnIntent: bytes,
+            userSignature: signature,
+            tokenMint,
+            custodyTokenAccount: custodyTokenAccountPDA,
+            feeRecipientTokenAccount,
+            deposit,
+            remainingAccounts: createGatewayBurnRemainingAccounts(
+              [intent],
+              client.gatewayWalletProgram.programId
+            ),
+          },
+          defaultBurnSigner
+        ),
+        "DestinationDomainNotEnabled"
+      );
+    });
+
+    it("should fail when the destination contract does not match the registry", async () => {
+      await client.enableDestinationDomain({
+        domain: DEFAULT_DESTINATION_DOMAIN,
+        destinationContract: Keypair.generate().publicKey,
+      });
+
+      await expectBurnToFail({
+        burnAmount: BigInt(100000000),
+        errorName: "DestinationContractMismatch",
+      });
+    });
+
+    it("should fail when the destination domain account does not match the intent", async () => {
+      await client.enableDestinationDomain({
+        domain: DEFAULT_DESTINATION_DOMAIN + 1,
+        destinationContract: DEFAULT_DESTINATION_CONTRACT,
+      });
+
+      const { intent, bytes, signature } = createSignedBurnIntent({
+        signer: depositor,
+        transferSpecOverrides: {
+          sourceContract: client.gatewayWalletProgram.programId,
+          sourceToken: tokenMint,
+          sourceDepositor: depositor.publicKey,
+          value: BigInt(100000000),
+        },
+      });
+
+      await expectAnchorError(
+        client.gatewayBurn(
+          {
+            burnIntent: bytes,
+            userSignature: signature,
+            tokenMint,
+            custodyTokenAccount: custodyTokenAccountPDA,
+            feeRecipientTokenAccount,
+            deposit,
+            destinationDomain: client.getDestinationDomainPDA(
+              DEFAULT_DESTINATION_DOMAIN + 1
+            ).publicKey,
+            remainingAccounts: createGatewayBurnRemainingAccounts(
+              [intent],
+              client.gatewayWalletProgram.programId
+            ),
+          },
+          defaultBurnSigner
+        ),
+        "InvalidDestinationDomainAccount"
+      );
+    });
+  });
+
+  describe("outflow limit", () => {
+    it("should record the burned amount against the outflow limit", async () => {
+      await client.setOutflowLimit({
+        tokenMint,
+        windowSlots: 1000,
+        maxOutflow: BigInt(200000000),
+      });
+
+      await executeBurnAndGetEvents({ burnAmount: BigInt(100000000) });
+
+      const outflowLimit =
+        await client.gatewayWalletProgram.account.outflowLimit.fetch(
+          client.getOutflowLimitPDA(tokenMint).publicKey
+        );
+      expect(outflowLimit.currentWindowOutflow.toString()).to.equal(
+        "100000000"
+      );
+    });
+
+    it("should fail when the burn exceeds the outflow limit", async () => {
+      await client.setOutflowLimit({
+        tokenMint,
+        windowSlots: 1000,
+        maxOutflow: BigInt(50000000),
+      });
+
+      await expectBurnToFail({
+        burnAmount: BigInt(100000000),
+        errorName: "OutflowLimitExceeded",
+      });
+    });
+
+    it("should succeed when the override allowance covers the excess", async () => {
+      await client.setOutflowLimit({
+        tokenMint,
+        windowSlots: 1000,
+        maxOutflow: BigInt(50000000),
+      });
+      await client.setOutflowOverride({
+        tokenMint,
+        overrideAllowance: BigInt(50000000),
+      });
+
+      await executeBurnAndGetEvents({ burnAmount: BigInt(100000000) });
+
+      const outflowLimit =
+        await client.gatewayWalletProgram.account.outflowLimit.fetch(
+          client.getOutflowLimitPDA(tokenMint).publicKey
+        );
+      expect(outflowLimit.overrideAllowance.toString()).to.equal("0");
+    });
+  });
+
+  describe("closeUsedTransferSpecHash", () => {
+    const EXPIRY_SLOT = 1000;
+    let burnIntent: BurnIntent;
+    let burnBytes: Buffer;
+    let userSignature: Buffer;
+    let transferSpecHash: Buffer;
+
+    const burn = () =>
+      client.gatewayBurn(
+        {
+          burnIntent: burnBytes,
+          userSignature,
+          tokenMint,
+          custodyTokenAccount: custodyTokenAccountPDA,
+          feeRecipientTokenAccount,
+          deposit,
+          remainingAccounts: createGatewayBurnRemainingAccounts(
+            [burnIntent],
+            client.gatewayWalletProgram.programId
+          ),
+        },
+        defaultBurnSigner
+      );
+
+    beforeEach(async () => {
+      ({
+        intent: burnIntent,
+        bytes: burnBytes,
+        signature: userSignature,
+      } = createSignedBurnIntent({
+        signer: depositor,
+        burnIntentOverrides: {
+          maxBlockHeight: BigInt(EXPIRY_SLOT),
+        },
+        transferSpecOverrides: {
+          sourceContract: client.gatewayWalletProgram.programId,
+          sourceToken: tokenMint,
+          sourceDepositor: depositor.publicKey,
+          value: BigInt(100000000),
+        },
+      }));
+      transferSpecHash = calculateTransferSpecHash(burnIntent.transferSpec);
+
+      await burn();
+    });
+
+    it("should shrink the account to a tombstone and refund the rent payer after expiry", async () => {
+      const hashPDA = findPDA(
+        [Buffer.from("used_transfer_spec_hash"), transferSpecHash],
+        client.gatewayWalletProgram.programId
+      ).publicKey;
+      const balanceBefore = svm.getBalance(client.owner.publicKey);
+
+      svm.warpToSlot(BigInt(EXPIRY_SLOT + 1));
+      const txSig = await client.closeUsedTransferSpecHash({
+        transferSpecHash,
+        rentPayer: client.owner.publicKey,
+      });
+
+      const events = getEvents(svm, txSig, client.gatewayWalletProgram);
+      expect(events.length).to.equal(1);
+      expect(events[0].name).to.equal("usedTransferSpecHashClosed");
+      expect(Buffer.from(events[0].data.transferSpecHash)).to.deep.equal(
+        transferSpecHash
+      );
+      expect(events[0].data.rentPayer).to.deep.equal(client.owner.publicKey);
+
+      // The discriminator stays behind as a tombstone
+      expect(svm.getAccount(hashPDA).data.length).to.equal(2);
+      // The refunded rent exceeds the transaction fee paid by the owner
+      expect(svm.getBalance(client.owner.publicKey) > balanceBefore).to.be.true;
+    });
+
+    it("should fail before the expiry slot has passed", async () => {
+      await expectAnchorError(
+        client.closeUsedTransferSpecHash({
+          transferSpecHash,
+          rentPayer: client.owner.publicKey,
+        }),
+        "TransferSpecHashNotExpired"
+      );
+    });
+
+    it("should fail when the rent payer does not match", async () => {
+      svm.warpToSlot(BigInt(EXPIRY_SLOT + 1));
+
+      await expectAnchorError(
+        client.closeUsedTransferSpecHash({
+          transferSpecHash,
+          rentPayer: Keypair.generate().publicKey,
+        }),
+        "InvalidRentPayer"
+      );
+    });
+
+    it("should not allow the burn intent to be replayed after closing", async () => {
+      svm.warpToSlot(BigInt(EXPIRY_SLOT + 1));
+      await client.closeUsedTransferSpecHash({
+        transferSpecHash,
+        rentPayer: client.owner.publicKey,
+      });
+
+      await expectAnchorError(burn(), "BurnIntentExpired");
+    });
+  });
+
+  describe("nonce buckets", () => {
+    const signBurnIntent = (salt: Buffer) =>
+      createSignedBurnIntent({
+        signer: depositor,
+        burnIntentOverrides: {
+          maxBlockHeight: svm.getClock().slot + BigInt(1000),
+        },
+        transferSpecOverrides: {
+          sourceContract: client.gatewayWalletProgram.programId,
+          sourceToken: tokenMint,
+          sourceDepositor: depositor.publicKey,
+          value: BigInt(1000000),
+          salt,
+        },
+      });
+
+    const burn = (
+      signed: SignedBurnIntent,
+      remainingAccounts: {
+        pubkey: PublicKey;
+        isWritable: boolean;
+        isSigner: boolean;
+      }[]
+    ) =>
+      client.gatewayBurn(
+        {
+          burnIntent: signed.bytes,
+          userSignature: signed.signature,
+          tokenMint,
+          custodyTokenAccount: custodyTokenAccountPDA,
+          feeRecipientTokenAccount,
+          deposit,
+          remainingAccounts,
+        },
+        defaultBurnSigner
+      );
+
+    const burnWithNonce = (signed: SignedBurnIntent, migrating = false) =>
+      burn(
+        signed,
+        createGatewayBurnNonceRemainingAccounts(
+          signed.intent,
+          client.gatewayWalletProgram.programId,
+          migrating
+        )
+      );
+
+    describe("after the migration", () => {
+      beforeEach(async () => {
+        await client.setReplayStore({ replayStore: "migrating" });
+        svm.warpToSlot(
+          svm.getClock().slot + MAX_TRANSFER_VALIDITY_SLOTS + BigInt(1)
+        );
+        await client.setReplayStore({ replayStore: "nonceBuckets" });
+      });
+
+      it("should record sequential nonces in a single bucket", async () => {
+        const first = signBurnIntent(nonceSalt(BigInt(1)));
+        const second = signBurnIntent(nonceSalt(BigInt(2)));
+        await burnWithNonce(first);
+        await burnWithNonce(second);
+
+        const bucket = svm.getAccount(
+          deriveNonceBucketPDA(
+            first.intent.transferSpec,
+            BigInt(1),
+            client.gatewayWalletProgram.programId
+          )
+        );
+        // Discriminator, source domain, source contract, source depositor, bucket index and
+        // bitmap
+        expect(bucket.data.length).to.equal(2 + 4 + 32 + 32 + 8 + 1024);
+        expect(Buffer.from(bucket.data.slice(2, 6)).readUInt32LE()).to.equal(
+          first.intent.transferSpec.sourceDomain
+        );
+        expect(Buffer.from(bucket.data.slice(6, 38))).to.deep.equal(
+          client.gatewayWalletProgram.programId.toBuffer()
+        );
+        expect(Buffer.from(bucket.data.slice(38, 70))).to.deep.equal(
+          depositor.publicKey.toBuffer()
+        );
+        expect(bucket.data[78]).to.equal(0b110);
+
+        // No used transfer spec hash accounts are created
+        const [hashAccount] = createGatewayBurnRemainingAccounts(
+          [first.intent],
+          client.gatewayWalletProgram.programId
+        );
+        expect(svm.getAccount(hashAccount.pubkey)).to.be.null;
+      });
+
+      it("should fail to replay a burn recorded by nonce", async () => {
+        const signed = signBurnIntent(nonceSalt(BigInt(1)));
+        await burnWithNonce(signed);
+
+        await expectAnchorError(
+          burnWithNonce(signed),
+          "TransferSpecHashAlreadyUsed"
+        );
+      });
+
+      it("should record a burn without an explicit nonce by transfer spec hash", async () => {
+        const signed = signBurnIntent(Buffer.alloc(32, 0xff));
+        await burn(
+          signed,
+          createGatewayBurnRemainingAccounts(
+            [signed.intent],
+            client.gatewayWalletProgram.programId
+          )
+        );
+
+        await expectAnchorError(
+          burn(
+            signed,
+            createGatewayBurnRemainingAccounts(
+              [signed.intent],
+              client.gatewayWalletProgram.programId
+            )
+          ),
+          "TransferSpecHashAlreadyUsed"
+        );
+      });
+
+      it("should fail when the nonce bucket belongs to another depositor", async () => {
+        const signed = signBurnIntent(nonceSalt(BigInt(1)));
+
+        await expectAnchorError(
+          burn(signed, [
+            {
+              pubkey: deriveNonceBucketPDA(
+                {
+                  ...signed.intent.transferSpec,
+                  sourceDepositor: Keypair.generate().publicKey,
+                },
+                BigInt(1),
+                client.gatewayWalletProgram.programId
+              ),
+              isWritable: true,
+              isSigner: false,
+            },
+          ]),
+          "InvalidNonceBucketAccount"
+        );
+      });
+    });
+
+    describe("during the migration", () => {
+      it("should fail to replay a burn recorded before the migration", async () => {
+        const signed = signBurnIntent(nonceSalt(BigInt(3)));
+        await burn(
+          signed,
+          createGatewayBurnRemainingAccounts(
+            [signed.intent],
+            client.gatewayWalletProgram.programId
+          )
+        );
+
+        await client.setReplayStore({ replayStore: "migrating" });
+
+        await expectAnchorError(
+          burnWithNonce(signed, true),
+          "TransferSpecHashAlreadyUsed"
+        );
+      });
+
+      it("should record a new burn in a nonce bucket", async () => {
+        await client.setReplayStore({ replayStore: "migrating" });
+
+        const signed = signBurnIntent(nonceSalt(BigInt(3)));
+        await burnWithNonce(signed, true);
+
+        await expectAnchorError(
+          burnWithNonce(signed, true),
+          "TransferSpecHashAlreadyUsed"
+        );
+      });
+
+      it("should fail without the used transfer spec hash account", async () => {
+        await client.setReplayStore({ replayStore: "migrating" });
+
+        await expectAnchorError(
+          burnWithNonce(signBurnIntent(nonceSalt(BigInt(3)))),
+          "RemainingAccountsLengthMismatch"
+        );
+      });
+    });
+  });
+
+  describe("transfer receipts", () => {
+    let burnIntent: BurnIntent;
+    let transferSpecHash: Buffer;
+
+    const burn = async () => {
+      let burnBytes: Buffer;
+      let userSignature: Buffer;
+      ({
+        intent: burnIntent,
+        bytes: burnBytes,
+        signature: userSignature,
+      } = createSignedBurnIntent({
+        signer: depositor,
+        transferSpecOverrides: {
+          sourceContract: client.gatewayWalletProgram.programId,
+          sourceToken: tokenMint,
+          sourceDepositor: depositor.publicKey,
+          value: BigInt(100000000),
+        },
+      }));
+      transferSpecHash = calculateTransferSpecHash(burnIntent.transferSpec);
+
+      return client.gatewayBurn(
+        {
+          burnIntent: burnBytes,
+          userSignature,
+          tokenMint,
+          custodyTokenAccount: custodyTokenAccountPDA,
+          feeRecipientTokenAccount,
+          deposit,
+          remainingAccounts: createGatewayBurnRemainingAccounts(
+            [burnIntent],
+            client.gatewayWalletProgram.programId
+          ),
+        },
+        defaultBurnSigner
+      );
+    };
+
+    const fetchUsedTransferSpecHash = () =>
+      client.gatewayWalletProgram.account.usedTransferSpecHash.fetch(
+        findPDA(
+          [Buffer.from("used_transfer_spec_hash"), transferSpecHash],
+          client.gatewayWalletProgram.programId
+        ).publicKey
+      );
+
+    it("should not store a receipt by default", async () => {
+      await burn();
+
+      expect((await fetchUsedTransferSpecHash()).receipt).to.be.null;
+    });
+
+    it("should store a receipt matching the burn when enabled", async () => {
+      await client.setTransferReceipts({ enabled: true });
+
+      const txSig = await burn();
+
+      const events = getEvents(svm, txSig, client.gatewayWalletProgram);
+      const { receipt } = await fetchUsedTransferSpecHash();
+      expect(receipt.slot.toString()).to.equal(
+        svm.getClock().slot.toString()
+      );
+      expect(receipt.token).to.deep.equal(tokenMint);
+      expect(receipt.depositor).to.deep.equal(depositor.publicKey);
+      expect(receipt.value.toString()).to.equal(
+        events[0].data.value.toString()
+      );
+      expect(receipt.fee.toString()).to.equal(events[0].data.fee.toString());
+    });
+
+    it("should stop storing receipts once disabled", async () => {
+      await client.setTransferReceipts({ enabled: true });
+      await client.setTransferReceipts({ enabled: false });
+
+      await burn();
+
+      expect((await fetchUsedTransferSpecHash()).receipt).to.be.null;
+    });
+  });
 });
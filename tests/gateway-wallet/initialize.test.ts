@@ -68,6 +68,9 @@ describe("GatewayWallet: initialize", () => {
     expect(gatewayWalletAccount.version).to.equal(VERSION);
     expect(gatewayWalletAccount.supportedTokens).to.have.length(0);
     expect(gatewayWalletAccount.custodyTokenAccountBumps).to.have.length(0);
+    expect(gatewayWalletAccount.replayStore).to.deep.equal({
+      hashAccounts: {},
+    });
 
     const events = getEvents(
       client.svm,
@@ -0,0 +1,359 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { LiteSVM } from "litesvm";
+import { GatewayWalletTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { SOLANA_DOMAIN } from "../constants";
+import { getEvents, expectAnchorError } from "../utils";
+
+describe("setOutflowLimit and setOutflowOverride", () => {
+  let svm: LiteSVM;
+  let client: GatewayWalletTestClient;
+  let tokenMint: PublicKey;
+  let depositor: Keypair;
+  let depositorTokenAccount: PublicKey;
+
+  const WITHDRAWAL_DELAY = 10;
+  const WINDOW_SLOTS = 1000;
+  const MAX_OUTFLOW = 600_000;
+  const LIMIT_SET_SLOT = 1000;
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    client = new GatewayWalletTestClient(svm);
+    await client.initialize({
+      localDomain: SOLANA_DOMAIN,
+      withdrawalDelay: WITHDRAWAL_DELAY,
+    });
+
+    tokenMint = await client.createTokenMint(client.owner.publicKey, 6);
+    await client.addToken({ tokenMint });
+
+    depositor = Keypair.generate();
+    svm.airdrop(depositor.publicKey, BigInt(1_000_000_000));
+    depositorTokenAccount = await client.createTokenAccount(
+      tokenMint,
+      depositor.publicKey
+    );
+    await client.mintToken(
+      tokenMint,
+      depositorTokenAccount,
+      2_000_000,
+      client.owner
+    );
+    await client.deposit(
+      {
+        tokenMint,
+        amount: 2_000_000,
+        fromTokenAccount: depositorTokenAccount,
+      },
+      { owner: depositor }
+    );
+
+    svm.warpToSlot(BigInt(LIMIT_SET_SLOT));
+  });
+
+  async function getOutflowLimit() {
+    return client.gatewayWalletProgram.account.outflowLimit.fetch(
+      client.getOutflowLimitPDA(tokenMint).publicKey
+    );
+  }
+
+  // Initiates a withdrawal and completes it at the given slot
+  async function withdrawAt(amount: number, slot: number) {
+    await client.initiateWithdrawal({ tokenMint, amount }, depositor);
+    svm.warpToSlot(BigInt(slot));
+    return client.withdraw(
+      { tokenMint, toTokenAccount: depositorTokenAccount },
+      depositor
+    );
+  }
+
+  describe("setOutflowLimit", () => {
+    it("should create and configure the outflow limit", async () => {
+      const txSig = await client.setOutflowLimit({
+        tokenMint,
+        windowSlots: WINDOW_SLOTS,
+        maxOutflow: MAX_OUTFLOW,
+      });
+
+      const events = getEvents(svm, txSig, client.gatewayWalletProgram);
+      expect(events.length).to.equal(1);
+      expect(events[0].name).to.equal("outflowLimitSet");
+      expect(events[0].data.token).to.deep.equal(tokenMint);
+      expect(events[0].data.windowSlots.toNumber()).to.equal(WINDOW_SLOTS);
+      expect(events[0].data.maxOutflow.toNumber()).to.equal(MAX_OUTFLOW);
+
+      const outflowLimit = await getOutflowLimit();
+      expect(outflowLimit.token).to.deep.equal(tokenMint);
+      expect(outflowLimit.windowSlots.toNumber()).to.equal(WINDOW_SLOTS);
+      expect(outflowLimit.maxOutflow.toNumber()).to.equal(MAX_OUTFLOW);
+      expect(outflowLimit.currentWindowStart.toNumber()).to.equal(
+        LIMIT_SET_SLOT
+      );
+      expect(outflowLimit.currentWindowOutflow.toNumber()).to.equal(0);
+      expect(outflowLimit.previousWindowOutflow.toNumber()).to.equal(0);
+      expect(outflowLimit.overrideAllowance.toNumber()).to.equal(0);
+    });
+
+    it("should keep the recorded outflow when only the maximum changes", async () => {
+      await client.setOutflowLimit({
+        tokenMint,
+        windowSlots: WINDOW_SLOTS,
+        maxOutflow: MAX_OUTFLOW,
+      });
+      await withdrawAt(500_000, LIMIT_SET_SLOT + WITHDRAWAL_DELAY);
+
+      await client.setOutflowLimit({
+        tokenMint,
+        windowSlots: WINDOW_SLOTS,
+        maxOutflow: MAX_OUTFLOW * 2,
+      });
+
+      const outflowLimit = await getOutflowLimit();
+      expect(outflowLimit.maxOutflow.toNumber()).to.equal(MAX_OUTFLOW * 2);
+      expect(outflowLimit.currentWindowOutflow.toNumber()).to.equal(500_000);
+    });
+
+    it("should reset the recorded outflow when the window changes", async () => {
+      await client.setOutflowLimit({
+        tokenMint,
+        windowSlots: WINDOW_SLOTS,
+        maxOutflow: MAX_OUTFLOW,
+      });
+      await withdrawAt(500_000, LIMIT_SET_SLOT + WITHDRAWAL_DELAY);
+
+      await client.setOutflowLimit({
+        tokenMint,
+        windowSlots: WINDOW_SLOTS * 2,
+        maxOutflow: MAX_OUTFLOW,
+      });
+
+      const outflowLimit = await getOutflowLimit();
+      expect(outflowLimit.windowSlots.toNumber()).to.equal(WINDOW_SLOTS * 2);
+      expect(outflowLimit.currentWindowStart.toNumber()).to.equal(
+        LIMIT_SET_SLOT + WITHDRAWAL_DELAY
+      );
+      expect(outflowLimit.currentWindowOutflow.toNumber()).to.equal(0);
+    });
+
+    it("should fail when called by non-owner", async () => {
+      const nonOwner = Keypair.generate();
+      svm.airdrop(nonOwner.publicKey, BigInt(10_000_000_000));
+
+      await expectAnchorError(
+        client.setOutflowLimit(
+          { tokenMint, windowSlots: WINDOW_SLOTS, maxOutflow: MAX_OUTFLOW },
+          nonOwner
+        ),
+        "InvalidAuthority"
+      );
+    });
+
+    it("should fail when the token is not supported", async () => {
+      const unsupportedMint = await client.createTokenMint(
+        client.owner.publicKey,
+        6
+      );
+
+      await expectAnchorError(
+        client.setOutflowLimit({
+          tokenMint: unsupportedMint,
+          windowSlots: WINDOW_SLOTS,
+          maxOutflow: MAX_OUTFLOW,
+        }),
+        "TokenNotSupported"
+      );
+    });
+  });
+
+  describe("setOutflowOverride", () => {
+    beforeEach(async () => {
+      await client.setOutflowLimit({
+        tokenMint,
+        windowSlots: WINDOW_SLOTS,
+        maxOutflow: MAX_OUTFLOW,
+      });
+    });
+
+    it("should set the override allowance", async () => {
+      const txSig = await client.setOutflowOverride({
+        tokenMint,
+        overrideAllowance: 300_000,
+      });
+
+      const events = getEvents(svm, txSig, client.gatewayWalletProgram);
+      expect(events.length).to.equal(1);
+      expect(events[0].name).to.equal("outflowOverrideSet");
+      expect(events[0].data.token).to.deep.equal(tokenMint);
+      expect(events[0].data.overrideAllowance.toNumber()).to.equal(300_000);
+
+      expect((await getOutflowLimit()).overrideAllowance.toNumber()).to.equal(
+        300_000
+      );
+    });
+
+    it("should fail when called by non-owner", async () => {
+      const nonOwner = Keypair.generate();
+      svm.airdrop(nonOwner.publicKey, BigInt(10_000_000_000));
+
+      await expectAnchorError(
+        client.setOutflowOverride(
+          { tokenMint, overrideAllowance: 300_000 },
+          nonOwner
+        ),
+        "InvalidAuthority"
+      );
+    });
+  });
+
+  describe("enforcement", () => {
+    beforeEach(async () => {
+      await client.setOutflowLimit({
+        tokenMint,
+        windowSlots: WINDOW_SLOTS,
+        maxOutflow: MAX_OUTFLOW,
+      });
+    });
+
+    it("should not limit withdrawals when no outflow limit is configured", async () => {
+      const otherMint = await client.createTokenMint(client.owner.publicKey, 6);
+      await client.addToken({ tokenMint: otherMint });
+      const otherTokenAccount = await client.createTokenAccount(
+        otherMint,
+        depositor.publicKey
+      );
+      await client.mintToken(
+        otherMint,
+        otherTokenAccount,
+        2_000_000,
+        client.owner
+      );
+      await client.deposit(
+        {
+          tokenMint: otherMint,
+          amount: 2_000_000,
+          fromTokenAccount: otherTokenAccount,
+        },
+        { owner: depositor }
+      );
+
+      await client.initiateWithdrawal(
+        { tokenMint: otherMint, amount: 2_000_000 },
+        depositor
+      );
+      svm.warpToSlot(BigInt(LIMIT_SET_SLOT + WITHDRAWAL_DELAY));
+      await client.withdraw(
+        { tokenMint: otherMint, toTokenAccount: otherTokenAccount },
+        depositor
+      );
+
+      const tokenAccount = await client.getTokenAccount(otherTokenAccount);
+      expect(Number(tokenAccount.amount)).to.equal(2_000_000);
+    });
+
+    it("should record withdrawals within the limit", async () => {
+      await withdrawAt(500_000, LIMIT_SET_SLOT + WITHDRAWAL_DELAY);
+
+      const outflowLimit = await getOutflowLimit();
+      expect(outflowLimit.currentWindowOutflow.toNumber()).to.equal(500_000);
+    });
+
+    it("should fail when a withdrawal exceeds the limit", async () => {
+      await expectAnchorError(
+        withdrawAt(700_000, LIMIT_SET_SLOT + WITHDRAWAL_DELAY),
+        "OutflowLimitExceeded"
+      );
+    });
+
+    it("should fail when withdrawals in the same window exceed the limit", async () => {
+      await withdrawAt(500_000, LIMIT_SET_SLOT + WITHDRAWAL_DELAY);
+
+      await expectAnchorError(
+        withdrawAt(200_000, LIMIT_SET_SLOT + 2 * WITHDRAWAL_DELAY),
+        "OutflowLimitExceeded"
+      );
+    });
+
+    it("should use the override allowance for the excess", async () => {
+      await client.setOutflowOverride({
+        tokenMint,
+        overrideAllowance: 300_000,
+      });
+
+      await withdrawAt(700_000, LIMIT_SET_SLOT + WITHDRAWAL_DELAY);
+
+      const outflowLimit = await getOutflowLimit();
+      expect(outflowLimit.currentWindowOutflow.toNumber()).to.equal(
+        MAX_OUTFLOW
+      );
+      expect(outflowLimit.overrideAllowance.toNumber()).to.equal(200_000);
+    });
+
+    it("should fail when the override allowance does not cover the excess", async () => {
+      await client.setOutflowOverride({
+        tokenMint,
+        overrideAllowance: 50_000,
+      });
+
+      await expectAnchorError(
+        withdrawAt(700_000, LIMIT_SET_SLOT + WITHDRAWAL_DELAY),
+        "OutflowLimitExceeded"
+      );
+    });
+
+    it("should weight the previous window by the elapsed part of the current window", async () => {
+      await withdrawAt(500_000, LIMIT_SET_SLOT + WITHDRAWAL_DELAY);
+
+      // Halfway through the next window, half of the previous outflow counts
+      const halfwaySlot = LIMIT_SET_SLOT + WINDOW_SLOTS + WINDOW_SLOTS / 2;
+      await expectAnchorError(
+        withdrawAt(400_000, halfwaySlot),
+        "OutflowLimitExceeded"
+      );
+    });
+
+    it("should allow withdrawals within the weighted remaining limit", async () => {
+      await withdrawAt(500_000, LIMIT_SET_SLOT + WITHDRAWAL_DELAY);
+
+      const halfwaySlot = LIMIT_SET_SLOT + WINDOW_SLOTS + WINDOW_SLOTS / 2;
+      await withdrawAt(350_000, halfwaySlot);
+
+      const outflowLimit = await getOutflowLimit();
+      expect(outflowLimit.currentWindowStart.toNumber()).to.equal(
+        LIMIT_SET_SLOT + WINDOW_SLOTS
+      );
+      expect(outflowLimit.previousWindowOutflow.toNumber()).to.equal(500_000);
+      expect(outflowLimit.currentWindowOutflow.toNumber()).to.equal(350_000);
+    });
+
+    it("should reset the limit once two windows have passed", async () => {
+      await withdrawAt(MAX_OUTFLOW, LIMIT_SET_SLOT + WITHDRAWAL_DELAY);
+
+      await withdrawAt(MAX_OUTFLOW, LIMIT_SET_SLOT + 2 * WINDOW_SLOTS);
+
+      const outflowLimit = await getOutflowLimit();
+      expect(outflowLimit.previousWindowOutflow.toNumber()).to.equal(0);
+      expect(outflowLimit.currentWindowOutflow.toNumber()).to.equal(
+        MAX_OUTFLOW
+      );
+    });
+  });
+});
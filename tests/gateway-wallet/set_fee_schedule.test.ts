@@ -0,0 +1,156 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { LiteSVM } from "litesvm";
+import { GatewayWalletTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { SOLANA_DOMAIN } from "../constants";
+import { getEvents, expectAnchorError } from "../utils";
+
+describe("setFeeSchedule", () => {
+  let svm: LiteSVM;
+  let client: GatewayWalletTestClient;
+  let tokenMint: PublicKey;
+
+  const DESTINATION_DOMAIN = 1;
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    client = new GatewayWalletTestClient(svm);
+    await client.initialize({
+      localDomain: SOLANA_DOMAIN,
+    });
+
+    tokenMint = await client.createTokenMint(client.owner.publicKey, 6);
+    await client.addToken({ tokenMint });
+  });
+
+  async function getFeeSchedule(destinationDomain: number) {
+    return client.gatewayWalletProgram.account.feeSchedule.fetch(
+      client.getFeeSchedulePDA(tokenMint, destinationDomain).publicKey
+    );
+  }
+
+  it("should successfully set a fee schedule", async () => {
+    const txSig = await client.setFeeSchedule({
+      tokenMint,
+      destinationDomain: DESTINATION_DOMAIN,
+      minFee: 1_000,
+      feeBps: 5,
+    });
+
+    const events = getEvents(svm, txSig, client.gatewayWalletProgram);
+    expect(events.length).to.equal(1);
+    expect(events[0].name).to.equal("feeScheduleSet");
+    expect(events[0].data.token).to.deep.equal(tokenMint);
+    expect(events[0].data.destinationDomain).to.equal(DESTINATION_DOMAIN);
+    expect(events[0].data.minFee.toString()).to.equal("1000");
+    expect(events[0].data.feeBps).to.equal(5);
+
+    const feeSchedule = await getFeeSchedule(DESTINATION_DOMAIN);
+    expect(feeSchedule.token).to.deep.equal(tokenMint);
+    expect(feeSchedule.destinationDomain).to.equal(DESTINATION_DOMAIN);
+    expect(feeSchedule.minFee.toString()).to.equal("1000");
+    expect(feeSchedule.feeBps).to.equal(5);
+  });
+
+  it("should overwrite an existing fee schedule", async () => {
+    await client.setFeeSchedule({
+      tokenMint,
+      destinationDomain: DESTINATION_DOMAIN,
+      minFee: 1_000,
+      feeBps: 5,
+    });
+    await client.setFeeSchedule({
+      tokenMint,
+      destinationDomain: DESTINATION_DOMAIN,
+      minFee: 0,
+      feeBps: 0,
+    });
+
+    const feeSchedule = await getFeeSchedule(DESTINATION_DOMAIN);
+    expect(feeSchedule.minFee.toString()).to.equal("0");
+    expect(feeSchedule.feeBps).to.equal(0);
+  });
+
+  it("should keep schedules for different domains separate", async () => {
+    await client.setFeeSchedule({
+      tokenMint,
+      destinationDomain: 1,
+      minFee: 1_000,
+      feeBps: 0,
+    });
+    await client.setFeeSchedule({
+      tokenMint,
+      destinationDomain: 2,
+      minFee: 2_000,
+      feeBps: 0,
+    });
+
+    expect((await getFeeSchedule(1)).minFee.toString()).to.equal("1000");
+    expect((await getFeeSchedule(2)).minFee.toString()).to.equal("2000");
+  });
+
+  it("should fail when called by non-owner", async () => {
+    const nonOwner = Keypair.generate();
+    svm.airdrop(nonOwner.publicKey, BigInt(10_000_000_000));
+
+    await expectAnchorError(
+      client.setFeeSchedule(
+        {
+          tokenMint,
+          destinationDomain: DESTINATION_DOMAIN,
+          minFee: 1_000,
+          feeBps: 5,
+        },
+        nonOwner
+      ),
+      "InvalidAuthority"
+    );
+  });
+
+  it("should fail when fee bps exceed 10000", async () => {
+    await expectAnchorError(
+      client.setFeeSchedule({
+        tokenMint,
+        destinationDomain: DESTINATION_DOMAIN,
+        minFee: 0,
+        feeBps: 10_001,
+      }),
+      "InvalidFeeSchedule"
+    );
+  });
+
+  it("should fail when the token is not supported", async () => {
+    const unsupportedMint = await client.createTokenMint(
+      client.owner.publicKey,
+      6
+    );
+
+    await expectAnchorError(
+      client.setFeeSchedule({
+        tokenMint: unsupportedMint,
+        destinationDomain: DESTINATION_DOMAIN,
+        minFee: 1_000,
+        feeBps: 5,
+      }),
+      "TokenNotSupported"
+    );
+  });
+});
@@ -0,0 +1,125 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { LiteSVM } from "litesvm";
+import { GatewayWalletTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { SOLANA_DOMAIN } from "../constants";
+import {
+  getEvents,
+  expectAnchorError,
+  MAX_TRANSFER_VALIDITY_SLOTS,
+} from "../utils";
+
+describe("setReplayStore", () => {
+  let svm: LiteSVM;
+  let client: GatewayWalletTestClient;
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    client = new GatewayWalletTestClient(svm);
+    await client.initialize({
+      localDomain: SOLANA_DOMAIN,
+    });
+  });
+
+  async function getReplayStore() {
+    return (
+      await client.gatewayWalletProgram.account.gatewayWallet.fetch(
+        client.pdas.gatewayWallet.publicKey
+      )
+    ).replayStore;
+  }
+
+  it("should start migrating to nonce buckets", async () => {
+    svm.warpToSlot(BigInt(100));
+
+    const txSig = await client.setReplayStore({ replayStore: "migrating" });
+
+    const events = getEvents(svm, txSig, client.gatewayWalletProgram);
+    expect(events.length).to.equal(1);
+    expect(events[0].name).to.equal("replayStoreSet");
+    expect(events[0].data.previousReplayStore).to.deep.equal({
+      hashAccounts: {},
+    });
+    expect(events[0].data.newReplayStore).to.deep.equal({ migrating: {} });
+
+    expect(await getReplayStore()).to.deep.equal({ migrating: {} });
+    expect(
+      (
+        await client.gatewayWalletProgram.account.gatewayWallet.fetch(
+          client.pdas.gatewayWallet.publicKey
+        )
+      ).replayMigrationStartedSlot.toNumber()
+    ).to.equal(100);
+  });
+
+  it("should finish migrating to nonce buckets", async () => {
+    await client.setReplayStore({ replayStore: "migrating" });
+    svm.warpToSlot(
+      svm.getClock().slot + MAX_TRANSFER_VALIDITY_SLOTS + BigInt(1)
+    );
+    await client.setReplayStore({ replayStore: "nonceBuckets" });
+
+    expect(await getReplayStore()).to.deep.equal({ nonceBuckets: {} });
+  });
+
+  it("should fail to finish migrating before transfers recorded by hash expire", async () => {
+    await client.setReplayStore({ replayStore: "migrating" });
+    svm.warpToSlot(svm.getClock().slot + MAX_TRANSFER_VALIDITY_SLOTS);
+
+    await expectAnchorError(
+      client.setReplayStore({ replayStore: "nonceBuckets" }),
+      "ReplayStoreMigrationIncomplete"
+    );
+  });
+
+  it("should fail to skip the migration", async () => {
+    await expectAnchorError(
+      client.setReplayStore({ replayStore: "nonceBuckets" }),
+      "InvalidReplayStoreTransition"
+    );
+  });
+
+  it("should fail to move back to used transfer spec hash accounts", async () => {
+    await client.setReplayStore({ replayStore: "migrating" });
+
+    await expectAnchorError(
+      client.setReplayStore({ replayStore: "hashAccounts" }),
+      "InvalidReplayStoreTransition"
+    );
+  });
+
+  it("should fail to set the current replay store", async () => {
+    await expectAnchorError(
+      client.setReplayStore({ replayStore: "hashAccounts" }),
+      "InvalidReplayStoreTransition"
+    );
+  });
+
+  it("should fail when called by non-owner", async () => {
+    const nonOwner = Keypair.generate();
+    svm.airdrop(nonOwner.publicKey, BigInt(10_000_000_000));
+
+    await expectAnchorError(
+      client.setReplayStore({ replayStore: "migrating" }, nonOwner),
+      "InvalidAuthority"
+    );
+  });
+});
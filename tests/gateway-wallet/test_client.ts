@@ -21,6 +21,7 @@ import { LiteSVM } from "litesvm";
 import * as path from "path";
 import { readFileSync } from "fs";
 import {
+  AccountMeta,
   Ed25519Program,
   Keypair,
   LAMPORTS_PER_SOL,
@@ -68,6 +69,10 @@ import {
   encodeBurnSignerMessage,
   encodeEd25519InstructionData,
 } from "../burn_data";
+import {
+  BI_TRANSFER_SPEC_OFFSET,
+  TS_DESTINATION_DOMAIN_OFFSET,
+} from "../burn_intent";
 
 export class GatewayWalletTestClient {
   svm: LiteSVM;
@@ -254,6 +259,215 @@ export class GatewayWalletTestClient {
       .rpc();
   }
 
+  async updateFeeSplits(
+    params: { feeSplits: { recipient: PublicKey; bps: number }[] },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .updateFeeSplits({ feeSplits: params.feeSplits })
+      .accountsPartial({
+        owner: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async setFeeSchedule(
+    params: {
+      tokenMint: PublicKey;
+      destinationDomain: number;
+      minFee: number | bigint;
+      feeBps: number;
+    },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .setFeeSchedule({
+        destinationDomain: params.destinationDomain,
+        minFee: new anchor.BN(params.minFee.toString()),
+        feeBps: params.feeBps,
+      })
+      .accountsPartial({
+        payer: signer.publicKey,
+        owner: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+        tokenMint: params.tokenMint,
+        feeSchedule: this.getFeeSchedulePDA(
+          params.tokenMint,
+          params.destinationDomain
+        ).publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async setOutflowLimit(
+    params: {
+      tokenMint: PublicKey;
+      windowSlots: number;
+      maxOutflow: number | bigint;
+    },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .setOutflowLimit({
+        windowSlots: new anchor.BN(params.windowSlots),
+        maxOutflow: new anchor.BN(params.maxOutflow.toString()),
+      })
+      .accountsPartial({
+        payer: signer.publicKey,
+        owner: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+        tokenMint: params.tokenMint,
+        outflowLimit: this.getOutflowLimitPDA(params.tokenMint).publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async setOutflowOverride(
+    params: {
+      tokenMint: PublicKey;
+      overrideAllowance: number | bigint;
+    },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .setOutflowOverride({
+        overrideAllowance: new anchor.BN(params.overrideAllowance.toString()),
+      })
+      .accountsPartial({
+        owner: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+        outflowLimit: this.getOutflowLimitPDA(params.tokenMint).publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async closeUsedTransferSpecHash(params: {
+    transferSpecHash: Buffer;
+    rentPayer: PublicKey;
+  }) {
+    const usedTransferSpecHashPDA = findPDA(
+      [Buffer.from("used_transfer_spec_hash"), params.transferSpecHash],
+      this.gatewayWalletProgram.programId
+    );
+
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .closeUsedTransferSpecHash({
+        transferSpecHash: Array.from(params.transferSpecHash),
+        transferSpec: null,
+      })
+      .accountsPartial({
+        rentPayer: params.rentPayer,
+        usedTransferSpecHash: usedTransferSpecHashPDA.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+        payer: null,
+        nonceBucket: null,
+        systemProgram: null,
+      })
+      .rpc();
+  }
+
+  async setReplayStore(
+    params: { replayStore: "hashAccounts" | "migrating" | "nonceBuckets" },
+    signer: Keypair = this.owner
+  ) {
+    const replayStores = {
+      hashAccounts: { hashAccounts: {} },
+      migrating: { migrating: {} },
+      nonceBuckets: { nonceBuckets: {} },
+    };
+
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .setReplayStore({ replayStore: replayStores[params.replayStore] })
+      .accountsPartial({
+        owner: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async setTransferReceipts(
+    params: { enabled: boolean },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .setTransferReceipts({ enabled: params.enabled })
+      .accountsPartial({
+        owner: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async setDestinationDomainEnforcement(
+    params: { enforced: boolean },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .setDestinationDomainEnforcement({ enforced: params.enforced })
+      .accountsPartial({
+        owner: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async enableDestinationDomain(
+    params: { domain: number; destinationContract: PublicKey },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .enableDestinationDomain({
+        domain: params.domain,
+        destinationContract: params.destinationContract,
+      })
+      .accountsPartial({
+        payer: signer.publicKey,
+        owner: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+        destinationDomain: this.getDestinationDomainPDA(params.domain)
+          .publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async disableDestinationDomain(
+    params: { domain: number },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .disableDestinationDomain()
+      .accountsPartial({
+        owner: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+        destinationDomain: this.getDestinationDomainPDA(params.domain)
+          .publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
   async addToken(
     params: {
       tokenMint: PublicKey;
@@ -526,6 +740,47 @@ export class GatewayWalletTestClient {
       .rpc();
   }
 
+  async batchDenylist(
+    params: { accounts: PublicKey[] },
+    signers: {
+      payer?: Keypair;
+      denylister?: Keypair;
+    } = {},
+    remainingAccounts?: AccountMeta[]
+  ) {
+    const payer = signers.payer || this.owner;
+    const denylister = signers.denylister || this.owner;
+
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .batchDenylist({ accounts: params.accounts })
+      .accountsPartial({
+        payer: payer.publicKey,
+        denylister: denylister.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(
+        remainingAccounts ??
+          this.createDenylistRemainingAccounts(params.accounts)
+      )
+      .signers(
+        [payer, denylister].filter(
+          (s, i, arr) =>
+            arr.findIndex((x) => x.publicKey.equals(s.publicKey)) === i
+        )
+      )
+      .rpc();
+  }
+
+  createDenylistRemainingAccounts(accounts: PublicKey[]): AccountMeta[] {
+    return accounts.map((account) => ({
+      pubkey: this.findDenylistPDA(account).publicKey,
+      isWritable: true,
+      isSigner: false,
+    }));
+  }
+
   async gatewayBurn(
     params: {
       burnIntent: Buffer;
@@ -535,6 +790,9 @@ export class GatewayWalletTestClient {
       feeRecipientTokenAccount: PublicKey;
       deposit: PublicKey;
       delegateAccount?: PublicKey;
+      destinationDomain?: PublicKey;
+      feeSchedule?: PublicKey;
+      outflowLimit?: PublicKey;
       fee?: number | bigint;
       burnIntentMessagePrefix?: Buffer;
       excludeEd25519Instruction?: boolean;
@@ -577,6 +835,19 @@ export class GatewayWalletTestClient {
         feeRecipientTokenAccount: params.feeRecipientTokenAccount,
         deposit: params.deposit,
         delegateAccount: params.delegateAccount || null,
+        destinationDomain:
+          params.destinationDomain ||
+          this.getDestinationDomainPDA(readDestinationDomain(params.burnIntent))
+            .publicKey,
+        feeSchedule:
+          params.feeSchedule ||
+          this.getFeeSchedulePDA(
+            params.tokenMint,
+            readDestinationDomain(params.burnIntent)
+          ).publicKey,
+        outflowLimit:
+          params.outflowLimit ||
+          this.getOutflowLimitPDA(params.tokenMint).publicKey,
       })
       .remainingAccounts(params.remainingAccounts || [])
       .instruction();
@@ -678,6 +949,38 @@ export class GatewayWalletTestClient {
       .rpc();
   }
 
+  async batchUndenylist(
+    params: { accounts: PublicKey[] },
+    signers: {
+      payer?: Keypair;
+      denylister?: Keypair;
+    } = {},
+    remainingAccounts?: AccountMeta[]
+  ) {
+    const payer = signers.payer || this.owner;
+    const denylister = signers.denylister || this.owner;
+
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .batchUndenylist({ accounts: params.accounts })
+      .accountsPartial({
+        payer: payer.publicKey,
+        denylister: denylister.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+      })
+      .remainingAccounts(
+        remainingAccounts ??
+          this.createDenylistRemainingAccounts(params.accounts)
+      )
+      .signers(
+        [payer, denylister].filter(
+          (s, i, arr) =>
+            arr.findIndex((x) => x.publicKey.equals(s.publicKey)) === i
+        )
+      )
+      .rpc();
+  }
+
   async removeDelegate(
     params: {
       tokenMint: PublicKey;
@@ -791,12 +1094,55 @@ export class GatewayWalletTestClient {
         custodyTokenAccount: custodyTokenAccountPDA.publicKey,
         depositorTokenAccount: params.toTokenAccount,
         deposit: depositPDA.publicKey,
+        outflowLimit: this.getOutflowLimitPDA(params.tokenMint).publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([signer])
       .rpc();
   }
 
+  async freezeDeposit(
+    params: {
+      tokenMint: PublicKey;
+      depositor: PublicKey;
+    },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .freezeDeposit()
+      .accountsPartial({
+        payer: signer.publicKey,
+        denylister: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+        deposit: this.getDepositPDA(params.tokenMint, params.depositor)
+          .publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async unfreezeDeposit(
+    params: {
+      tokenMint: PublicKey;
+      depositor: PublicKey;
+    },
+    signer: Keypair = this.owner
+  ) {
+    this.svm.expireBlockhash();
+    return this.gatewayWalletProgram.methods
+      .unfreezeDeposit()
+      .accountsPartial({
+        payer: signer.publicKey,
+        denylister: signer.publicKey,
+        gatewayWallet: this.pdas.gatewayWallet.publicKey,
+        deposit: this.getDepositPDA(params.tokenMint, params.depositor)
+          .publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
   getDepositPDA(tokenMint: PublicKey, depositor: PublicKey): PDA {
     return findPDA(
       [
@@ -808,6 +1154,31 @@ export class GatewayWalletTestClient {
     );
   }
 
+  getDestinationDomainPDA(domain: number): PDA {
+    const domainBytes = Buffer.alloc(4);
+    domainBytes.writeUInt32BE(domain);
+    return findPDA(
+      [Buffer.from("destination_domain"), domainBytes],
+      this.gatewayWalletProgram.programId
+    );
+  }
+
+  getFeeSchedulePDA(tokenMint: PublicKey, destinationDomain: number): PDA {
+    const domain = Buffer.alloc(4);
+    domain.writeUInt32BE(destinationDomain);
+    return findPDA(
+      [Buffer.from("fee_schedule"), tokenMint.toBuffer(), domain],
+      this.gatewayWalletProgram.programId
+    );
+  }
+
+  getOutflowLimitPDA(tokenMint: PublicKey): PDA {
+    return findPDA(
+      [Buffer.from("outflow_limit"), tokenMint.toBuffer()],
+      this.gatewayWalletProgram.programId
+    );
+  }
+
   async getDenylistAccount(account: PublicKey) {
     const denylistPDA = findPDA(
       [Buffer.from("denylist"), account.toBuffer()],
@@ -907,3 +1278,10 @@ export class GatewayWalletTestClient {
     }
   }
 }
+
+// Reads the destination domain from an encoded burn intent, defaulting to 0 when the
+// intent is too short to contain one
+function readDestinationDomain(burnIntent: Buffer): number {
+  const offset = BI_TRANSFER_SPEC_OFFSET + TS_DESTINATION_DOMAIN_OFFSET;
+  return burnIntent.length >= offset + 4 ? burnIntent.readUInt32BE(offset) : 0;
+}
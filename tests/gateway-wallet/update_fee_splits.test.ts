@@ -0,0 +1,155 @@
+/*
+ * Copyright (c) 2025, Circle Internet Financial LTD All Rights Reserved.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { LiteSVM } from "litesvm";
+import { GatewayWalletTestClient } from "./test_client";
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { SOLANA_DOMAIN } from "../constants";
+import { getEvents, expectAnchorError } from "../utils";
+
+describe("updateFeeSplits", () => {
+  let svm: LiteSVM;
+  let client: GatewayWalletTestClient;
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    client = new GatewayWalletTestClient(svm);
+    await client.initialize({
+      localDomain: SOLANA_DOMAIN,
+    });
+  });
+
+  async function getFeeSplits() {
+    const gatewayWallet =
+      await client.gatewayWalletProgram.account.gatewayWallet.fetch(
+        client.pdas.gatewayWallet.publicKey
+      );
+    return gatewayWallet.feeSplits;
+  }
+
+  it("should successfully update fee splits", async () => {
+    const feeSplits = [
+      { recipient: Keypair.generate().publicKey, bps: 2_500 },
+      { recipient: Keypair.generate().publicKey, bps: 1_000 },
+    ];
+
+    const txSig = await client.updateFeeSplits({ feeSplits });
+
+    const events = getEvents(svm, txSig, client.gatewayWalletProgram);
+    expect(events.length).to.equal(1);
+    expect(events[0].name).to.equal("feeSplitsChanged");
+    expect(events[0].data.oldFeeSplits).to.deep.equal([]);
+    expect(events[0].data.newFeeSplits).to.deep.equal(feeSplits);
+
+    expect(await getFeeSplits()).to.deep.equal(feeSplits);
+  });
+
+  it("should replace existing fee splits", async () => {
+    const oldFeeSplits = [{ recipient: Keypair.generate().publicKey, bps: 500 }];
+    const newFeeSplits = [
+      { recipient: Keypair.generate().publicKey, bps: 10_000 },
+    ];
+    await client.updateFeeSplits({ feeSplits: oldFeeSplits });
+
+    const txSig = await client.updateFeeSplits({ feeSplits: newFeeSplits });
+
+    const events = getEvents(svm, txSig, client.gatewayWalletProgram);
+    expect(events[0].data.oldFeeSplits).to.deep.equal(oldFeeSplits);
+    expect(events[0].data.newFeeSplits).to.deep.equal(newFeeSplits);
+    expect(await getFeeSplits()).to.deep.equal(newFeeSplits);
+  });
+
+  it("should clear fee splits with an empty list", async () => {
+    await client.updateFeeSplits({
+      feeSplits: [{ recipient: Keypair.generate().publicKey, bps: 500 }],
+    });
+    await client.updateFeeSplits({ feeSplits: [] });
+
+    expect(await getFeeSplits()).to.deep.equal([]);
+  });
+
+  it("should fail when called by non-owner", async () => {
+    const nonOwner = Keypair.generate();
+    svm.airdrop(nonOwner.publicKey, BigInt(10_000_000_000));
+
+    await expectAnchorError(
+      client.updateFeeSplits(
+        { feeSplits: [{ recipient: Keypair.generate().publicKey, bps: 500 }] },
+        nonOwner
+      ),
+      "InvalidAuthority"
+    );
+  });
+
+  it("should fail when the total exceeds 10000 bps", async () => {
+    await expectAnchorError(
+      client.updateFeeSplits({
+        feeSplits: [
+          { recipient: Keypair.generate().publicKey, bps: 6_000 },
+          { recipient: Keypair.generate().publicKey, bps: 4_001 },
+        ],
+      }),
+      "FeeSplitBpsExceedsMax"
+    );
+  });
+
+  it("should fail when there are too many fee splits", async () => {
+    const feeSplits = Array.from({ length: 6 }, () => ({
+      recipient: Keypair.generate().publicKey,
+      bps: 100,
+    }));
+
+    await expectAnchorError(
+      client.updateFeeSplits({ feeSplits }),
+      "FeeSplitLimitExceeded"
+    );
+  });
+
+  it("should fail when a split has zero bps", async () => {
+    await expectAnchorError(
+      client.updateFeeSplits({
+        feeSplits: [{ recipient: Keypair.generate().publicKey, bps: 0 }],
+      }),
+      "InvalidFeeSplit"
+    );
+  });
+
+  it("should fail when a split recipient is the zero address", async () => {
+    await expectAnchorError(
+      client.updateFeeSplits({
+        feeSplits: [{ recipient: PublicKey.default, bps: 500 }],
+      }),
+      "InvalidFeeSplit"
+    );
+  });
+
+  it("should fail when a recipient appears twice", async () => {
+    const recipient = Keypair.generate().publicKey;
+
+    await expectAnchorError(
+      client.updateFeeSplits({
+        feeSplits: [
+          { recipient, bps: 500 },
+          { recipient, bps: 500 },
+        ],
+      }),
+      "InvalidFeeSplit"
+    );
+  });
+});
@@ -752,6 +752,8 @@ describe("GatewayWallet withdraw", () => {
           custodyTokenAccount: custodyTokenAccountPDA.publicKey,
           depositorTokenAccount: userTokenAccount,
           deposit: depositPDA2.publicKey, // Wrong deposit account (different mint)
+          outflowLimit:
+            testClient.getOutflowLimitPDA(testTokenMint).publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([depositor])
@@ -35,6 +35,10 @@ import {
   generateMintAttestationElement,
 } from "../attestation";
 import { SOLANA_DOMAIN } from "../constants";
+import {
+  DEFAULT_DESTINATION_CONTRACT,
+  DEFAULT_DESTINATION_DOMAIN,
+} from "../burn_intent";
 
 const DEPOSIT_AMOUNT = 1_000_000_000; // 1,000 tokens
 const MINT_AMOUNT = 600_000_000; // 600 tokens
@@ -105,6 +109,12 @@ describe("Attempted Double Spend", () => {
 
     tokenMint = await walletClient.createTokenMint(mintAuthority.publicKey, 6);
     await walletClient.addToken({ tokenMint });
+
+    // Enable the default destination domain of generated transfer specs
+    await walletClient.enableDestinationDomain({
+      domain: DEFAULT_DESTINATION_DOMAIN,
+      destinationContract: DEFAULT_DESTINATION_CONTRACT,
+    });
     await minterClient.addToken({ tokenMint });
 
     attestationSigner = generateSignerKeypair();
@@ -34,7 +34,11 @@ import {
   generateMintAttestationElement,
 } from "../attestation";
 import { SOLANA_DOMAIN } from "../constants";
-import { BurnIntent } from "../burn_intent";
+import {
+  BurnIntent,
+  DEFAULT_DESTINATION_CONTRACT,
+  DEFAULT_DESTINATION_DOMAIN,
+} from "../burn_intent";
 
 const DEPOSIT_AMOUNT = 1_000_000_000; // 1,000 tokens
 const MINT_AMOUNT = 300_000_000; // 300 tokens
@@ -103,6 +107,12 @@ describe("Multi Deposit And Mint Flow", () => {
 
     tokenMint = await walletClient.createTokenMint(mintAuthority.publicKey, 6);
     await walletClient.addToken({ tokenMint });
+
+    // Enable the default destination domain of generated transfer specs
+    await walletClient.enableDestinationDomain({
+      domain: DEFAULT_DESTINATION_DOMAIN,
+      destinationContract: DEFAULT_DESTINATION_CONTRACT,
+    });
     await minterClient.addToken({ tokenMint });
 
     attestationSigner = generateSignerKeypair();
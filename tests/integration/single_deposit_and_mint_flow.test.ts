@@ -35,6 +35,10 @@ import {
   generateMintAttestationElement,
 } from "../attestation";
 import { SOLANA_DOMAIN } from "../constants";
+import {
+  DEFAULT_DESTINATION_CONTRACT,
+  DEFAULT_DESTINATION_DOMAIN,
+} from "../burn_intent";
 
 const DEPOSIT_AMOUNT = 1_000_000_000; // 1,000 tokens
 const MINT_AMOUNT = 500_000_000; // 500 tokens
@@ -109,6 +113,12 @@ describe("Single Deposit And Mint Flow", () => {
     tokenMint = await walletClient.createTokenMint(mintAuthority.publicKey, 6);
 
     await walletClient.addToken({ tokenMint });
+
+    // Enable the default destination domain of generated transfer specs
+    await walletClient.enableDestinationDomain({
+      domain: DEFAULT_DESTINATION_DOMAIN,
+      destinationContract: DEFAULT_DESTINATION_CONTRACT,
+    });
     await minterClient.addToken({ tokenMint });
 
     attestationSigner = generateSignerKeypair();
@@ -33,6 +33,10 @@ import {
   LiteSVM,
   TransactionMetadata,
 } from "litesvm";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import bs58 from "bs58";
 import { ethers } from "ethers";
 import {
@@ -131,6 +135,9 @@ export function expectAttestationUsedToEqual(
       Buffer.from(expected[i].transferSpecHash as Uint8Array)
     );
     expect(data.value.toString()).to.equal(expected[i].value.toString());
+    expect(Buffer.from(data.hookData as Uint8Array)).to.deep.equal(
+      Buffer.from(expected[i].hookData)
+    );
   }
 }
 
@@ -388,6 +395,20 @@ function deriveTransferSpecHashPDA(
   return pda;
 }
 
+/**
+ * Derives the PDA of the GatewayMinter minting config of a token
+ */
+export function deriveTokenConfigPDA(
+  tokenMint: PublicKey,
+  programId: PublicKey
+): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("token_config"), tokenMint.toBuffer()],
+    programId
+  );
+  return pda;
+}
+
 /**
  * Creates remaining accounts array for gateway mint from attestations
  */
@@ -418,10 +439,46 @@ export function createGatewayMintRemainingAccounts(
         isWritable: true,
         isSigner: false,
       },
+      {
+        pubkey: deriveTokenConfigPDA(attestation.destinationToken, programId),
+        isWritable: true,
+        isSigner: false,
+      },
     ];
   });
 }
 
+/**
+ * Creates the gateway_mint remaining accounts for an attestation set whose destination
+ * recipients are wallet owners. Each recipient token account is the owner's associated
+ * token account, followed after the quadruplets by the accounts needed to create it.
+ */
+export function createGatewayMintRecipientOwnerRemainingAccounts(
+  attestation: MintAttestationSet,
+  programId: PublicKey
+): Array<{ pubkey: PublicKey; isWritable: boolean; isSigner: boolean }> {
+  const quadruplets = createGatewayMintRemainingAccounts(attestation, programId);
+  attestation.attestations.forEach((element, i) => {
+    quadruplets[i * 4 + 1].pubkey = getAssociatedTokenAddressSync(
+      element.destinationToken,
+      element.destinationRecipient,
+      true
+    );
+  });
+
+  const creationAccounts = attestation.attestations.flatMap((element) => [
+    {
+      pubkey: element.destinationRecipient,
+      isWritable: false,
+      isSigner: false,
+    },
+    { pubkey: element.destinationToken, isWritable: false, isSigner: false },
+    { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isWritable: false, isSigner: false },
+  ]);
+
+  return [...quadruplets, ...creationAccounts];
+}
+
 /**
  * Creates remaining accounts array for gateway burn from burn intents
  */
@@ -439,6 +496,82 @@ export function createGatewayBurnRemainingAccounts(
   }));
 }
 
+export const NONCES_PER_BUCKET = BigInt(8192);
+
+/**
+ * The longest a transfer recorded by hash may stay valid, and so how long a migration to nonce
+ * buckets lasts
+ */
+export const MAX_TRANSFER_VALIDITY_SLOTS = BigInt(1512000);
+
+/**
+ * The scope of explicit nonces: a depositor of a source contract on a source domain
+ */
+export type NonceNamespace = {
+  sourceDomain: number;
+  sourceContract: PublicKey;
+  sourceDepositor: PublicKey;
+};
+
+/**
+ * Derives the PDA of the nonce bucket that records a nonce of a namespace
+ */
+export function deriveNonceBucketPDA(
+  namespace: NonceNamespace,
+  nonce: bigint,
+  programId: PublicKey
+): PublicKey {
+  const sourceDomain = Buffer.alloc(4);
+  sourceDomain.writeUInt32BE(namespace.sourceDomain);
+  const bucketIndex = Buffer.alloc(8);
+  bucketIndex.writeBigUInt64LE(nonce / NONCES_PER_BUCKET);
+  const [pda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("nonce_bucket"),
+      sourceDomain,
+      namespace.sourceContract.toBuffer(),
+      namespace.sourceDepositor.toBuffer(),
+      bucketIndex,
+    ],
+    programId
+  );
+  return pda;
+}
+
+/**
+ * Encodes a nonce as a transfer spec salt
+ */
+export function nonceSalt(nonce: bigint): Buffer {
+  const salt = Buffer.alloc(32);
+  salt.writeBigUInt64BE(nonce, 24);
+  return salt;
+}
+
+/**
+ * Creates remaining accounts for a gateway burn whose salt is an explicit nonce.
+ * While migrating, the used transfer spec hash account follows the nonce bucket.
+ */
+export function createGatewayBurnNonceRemainingAccounts(
+  burnIntent: BurnIntent,
+  programId: PublicKey,
+  migrating: boolean = false
+): Array<{ pubkey: PublicKey; isWritable: boolean; isSigner: boolean }> {
+  const nonce = Buffer.from(burnIntent.transferSpec.salt).readBigUInt64BE(24);
+  const accounts = [
+    {
+      pubkey: deriveNonceBucketPDA(burnIntent.transferSpec, nonce, programId),
+      isWritable: true,
+      isSigner: false,
+    },
+  ];
+  if (migrating) {
+    accounts.push(
+      ...createGatewayBurnRemainingAccounts([burnIntent], programId)
+    );
+  }
+  return accounts;
+}
+
 export function signBurnIntent(
   message: Buffer,
   keypairBytes: Uint8Array,